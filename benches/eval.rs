@@ -0,0 +1,52 @@
+//! Evaluation strategies (Horner, FMA Horner and Estrin) over a sweep of orders, and Horner
+//! against forward differences on dense progressions.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use rsl_polynomials::Polynomial;
+
+const ORDERS: [usize; 6] = [2, 4, 8, 16, 32, 64];
+
+fn poly(order: usize) -> Polynomial<f64> {
+    let coef: Vec<f64> = (0..=order).map(|i| 1.0 / (i as f64 + 1.0)).collect();
+    Polynomial::build(&coef).unwrap()
+}
+
+fn bench_eval(c: &mut Criterion) {
+    let mut group = c.benchmark_group("eval");
+    for order in ORDERS {
+        let poly = poly(order);
+        group.bench_with_input(BenchmarkId::new("horner", order), &poly, |b, p| {
+            b.iter(|| p.eval(black_box(0.7)))
+        });
+        group.bench_with_input(BenchmarkId::new("fma", order), &poly, |b, p| {
+            b.iter(|| p.eval_fma(black_box(0.7)))
+        });
+        group.bench_with_input(BenchmarkId::new("estrin", order), &poly, |b, p| {
+            b.iter(|| p.eval_estrin(black_box(0.7)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_progression(c: &mut Criterion) {
+    let mut group = c.benchmark_group("progression");
+    for order in [2, 3, 5] {
+        let poly = poly(order);
+        group.bench_with_input(BenchmarkId::new("horner", order), &poly, |b, p| {
+            b.iter(|| {
+                (0..1000)
+                    .map(|i| p.eval(black_box(-1.0) + i as f64 * 0.002))
+                    .collect::<Vec<f64>>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("differences", order), &poly, |b, p| {
+            b.iter(|| p.eval_arithmetic_progression(black_box(-1.0), 0.002, 1000))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_eval, bench_progression);
+criterion_main!(benches);
@@ -0,0 +1,52 @@
+//! Closed-form solvers, and the general solvers over a sweep of orders.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use rsl_polynomials::{Polynomial, batch, homotopy, solve};
+
+const ORDERS: [usize; 5] = [4, 8, 16, 32, 64];
+
+fn bench_closed_form(c: &mut Criterion) {
+    let mut group = c.benchmark_group("closed_form");
+    group.bench_function("quadratic", |b| {
+        b.iter(|| solve::quadratic(black_box(1.0), black_box(-3.0), black_box(2.0)))
+    });
+    group.bench_function("cubic", |b| {
+        b.iter(|| solve::cubic(black_box(1.0), -6.0, 11.0, -6.0))
+    });
+    group.bench_function("quartic", |b| {
+        b.iter(|| solve::quartic(black_box(1.0), 0.0, -5.0, 0.0, 4.0))
+    });
+
+    let coefs: Vec<[f64; 4]> = (0..1024)
+        .map(|i| [1.0, -6.0, 11.0, -6.0 + i as f64 * 1e-3])
+        .collect();
+    group.bench_function("cubic_batch_1024", |b| {
+        b.iter(|| batch::solve_real_cubic_batch(black_box(&coefs)))
+    });
+    group.finish();
+}
+
+fn bench_general(c: &mut Criterion) {
+    let mut group = c.benchmark_group("general");
+    for order in ORDERS {
+        // Roots spread over [−1, 1]
+        let roots: Vec<f64> = (0..order)
+            .map(|i| -1.0 + 2.0 * (i as f64 + 0.5) / order as f64)
+            .collect();
+        let poly = Polynomial::from_roots(&roots);
+        group.bench_with_input(BenchmarkId::new("companion", order), &poly, |b, p| {
+            b.iter(|| p.solve_complex())
+        });
+        if order <= 16 {
+            group.bench_with_input(BenchmarkId::new("homotopy", order), &poly, |b, p| {
+                b.iter(|| homotopy::solve(p))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_closed_form, bench_general);
+criterion_main!(benches);
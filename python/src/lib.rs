@@ -0,0 +1,200 @@
+//! Python bindings of `rsl-polynomials`, built as the `rsl_polynomials` extension module.
+//!
+//! The module is built with maturin, which also enables `pyo3/extension-module`:
+//!
+//! ```text
+//! cd python && maturin develop --release
+//! ```
+//!
+//! ```python
+//! import numpy as np
+//! from rsl_polynomials import Polynomial
+//!
+//! p = Polynomial([-6.0, 11.0, -6.0, 1.0])
+//! p(2.5)                          # scalar evaluation
+//! p(np.linspace(0.0, 4.0, 1000))  # vectorized evaluation, returns a NumPy array
+//! p.solve_complex()               # [(1+0j), (2+0j), (3+0j)]
+//! ```
+
+use numpy::{IntoPyArray, PyReadonlyArrayDyn};
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use num::complex::Complex64;
+use rsl_polynomials::solve;
+
+create_exception!(
+    rsl_polynomials,
+    PolyError,
+    PyValueError,
+    "Raised for the errors of the Rust crate, e.g. invalid coefficients."
+);
+
+/// Converts an error of the Rust crate to a Python [`PolyError`].
+fn to_py_err(err: rsl_polynomials::PolyError) -> PyErr {
+    PolyError::new_err(err.to_string())
+}
+
+/// The points a [`Polynomial`] can be evaluated at: a float, or a NumPy array of any shape.
+#[derive(FromPyObject)]
+enum Points<'py> {
+    Scalar(f64),
+    Array(PyReadonlyArrayDyn<'py, f64>),
+}
+
+/// A polynomial with real coefficients, from constant to leading term.
+#[pyclass(frozen, module = "rsl_polynomials")]
+struct Polynomial {
+    inner: rsl_polynomials::Polynomial<f64>,
+}
+
+#[pymethods]
+impl Polynomial {
+    /// Creates a Polynomial from its coefficients, from constant to leading term.
+    ///
+    /// Empty coefficients create the zero Polynomial. Raises PolyError if any of the coefficients
+    /// is NaN or Infinity.
+    #[new]
+    fn new(coef: Vec<f64>) -> PyResult<Self> {
+        let inner = rsl_polynomials::Polynomial::build(&coef).map_err(to_py_err)?;
+        Ok(Polynomial { inner })
+    }
+
+    /// The coefficients, from constant to leading term.
+    #[getter]
+    fn coef(&self) -> Vec<f64> {
+        self.inner.coef.clone()
+    }
+
+    /// Evaluates the Polynomial at a float, or element-wise at a NumPy array.
+    fn __call__<'py>(&self, py: Python<'py>, x: Points<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match x {
+            Points::Scalar(x) => Ok(self.inner.eval(x).into_pyobject(py)?.into_any()),
+            Points::Array(xs) => Ok(xs
+                .as_array()
+                .mapv(|x| self.inner.eval(x))
+                .into_pyarray(py)
+                .into_any()),
+        }
+    }
+
+    /// Returns the value and the first n−1 derivatives at x.
+    fn eval_derivs(&self, x: f64, n: usize) -> Vec<f64> {
+        self.inner.eval_derivs(x, n)
+    }
+
+    /// Returns the derivative.
+    fn derivative(&self) -> Self {
+        Polynomial {
+            inner: self.inner.derivative(),
+        }
+    }
+
+    /// Returns the antiderivative with a zero constant term.
+    fn antiderivative(&self) -> Self {
+        Polynomial {
+            inner: self.inner.antiderivative(),
+        }
+    }
+
+    /// Returns the real roots of a quadratic Polynomial, in increasing order.
+    fn solve_real_quadratic(&self) -> PyResult<Vec<f64>> {
+        self.inner.solve_real_quadratic().map_err(to_py_err)
+    }
+
+    /// Returns the real roots of a cubic Polynomial, in increasing order.
+    fn solve_real_cubic(&self) -> PyResult<Vec<f64>> {
+        self.inner.solve_real_cubic().map_err(to_py_err)
+    }
+
+    /// Returns all the complex roots.
+    fn solve_complex(&self) -> PyResult<Vec<Complex64>> {
+        self.inner.solve_complex().map_err(to_py_err)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Polynomial({:?})", self.inner.coef)
+    }
+}
+
+/// Returns the real roots of ax²+bx+c = 0, in increasing order.
+#[pyfunction]
+fn solve_quadratic(a: f64, b: f64, c: f64) -> PyResult<Vec<f64>> {
+    Ok(solve::quadratic(a, b, c).map_err(to_py_err)?.to_vec())
+}
+
+/// Returns the real roots of ax³+bx²+cx+d = 0, in increasing order.
+#[pyfunction]
+fn solve_cubic(a: f64, b: f64, c: f64, d: f64) -> PyResult<Vec<f64>> {
+    Ok(solve::cubic(a, b, c, d).map_err(to_py_err)?.to_vec())
+}
+
+#[pymodule(name = "rsl_polynomials")]
+fn rsl_polynomials_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Polynomial>()?;
+    m.add_function(wrap_pyfunction!(solve_quadratic, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_cubic, m)?)?;
+    m.add("PolyError", m.py().get_type::<PolyError>())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs a Python snippet, with the module importable as `rsl_polynomials`.
+    fn run(code: &std::ffi::CStr) {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "rsl_polynomials").unwrap();
+            rsl_polynomials_py(&module).unwrap();
+            let modules = py.import("sys").unwrap().getattr("modules").unwrap();
+            modules.set_item("rsl_polynomials", module).unwrap();
+            if let Err(err) = py.run(code, None, None) {
+                panic!("{err}");
+            }
+        });
+    }
+
+    #[test]
+    fn test_py_polynomial() {
+        run(c"
+from rsl_polynomials import Polynomial
+p = Polynomial([-6.0, 11.0, -6.0, 1.0])
+assert p.coef == [-6.0, 11.0, -6.0, 1.0]
+assert p(2.0) == 0.0
+assert p.eval_derivs(1.0, 2) == [0.0, 2.0]
+assert p.derivative().coef == [11.0, -12.0, 3.0]
+assert p.antiderivative().coef[0] == 0.0
+assert [round(z.real, 12) for z in p.solve_complex()] == [1.0, 2.0, 3.0]
+assert repr(Polynomial([1.0, 2.0])) == 'Polynomial([1.0, 2.0])'
+");
+    }
+
+    #[test]
+    fn test_py_solvers() {
+        run(c"
+from rsl_polynomials import Polynomial, solve_quadratic, solve_cubic
+assert solve_quadratic(5.0, 0.0, -20.0) == [-2.0, 2.0]
+assert solve_cubic(1.0, -6.0, 11.0, -6.0) == [1.0, 2.0, 3.0]
+assert Polynomial([25.0, -20.0, 4.0]).solve_real_quadratic() == [2.5]
+");
+    }
+
+    #[test]
+    fn test_py_errors() {
+        run(c"
+from rsl_polynomials import Polynomial, PolyError, solve_cubic
+assert Polynomial([]).coef == [0.0]
+for f in [lambda: Polynomial([float('nan')]), lambda: Polynomial([1.0, float('inf')]),
+          lambda: solve_cubic(0.0, 1.0, 2.0, 3.0)]:
+    try:
+        f()
+        raise AssertionError
+    except PolyError:
+        pass
+assert issubclass(PolyError, ValueError)
+");
+    }
+}
@@ -0,0 +1,441 @@
+//! Polynomial approximation of functions: interpolation at Chebyshev nodes, and minimax
+//! polynomials with the Remez exchange algorithm.
+
+use crate::utils::{compose_linear, eval_checked};
+use crate::{PolyError, Polynomial, Result, linalg};
+
+/// Options of [`remez`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RemezOptions {
+    /// Convergence tolerance: the iterations stop when the largest error exceeds the levelled
+    /// error by at most `tol` times the largest error.
+    pub tol: f64,
+    /// Maximum number of exchange iterations.
+    pub max_iter: usize,
+    /// Number of points of the grid on which the extrema of the error are located, before they
+    /// are refined. At least 20 points per reference point are used.
+    pub grid: usize,
+}
+
+impl Default for RemezOptions {
+    /// A tolerance of 1e-8, 50 iterations and a grid of 1000 points.
+    fn default() -> Self {
+        RemezOptions {
+            tol: 1e-8,
+            max_iter: 50,
+            grid: 1000,
+        }
+    }
+}
+
+/// The result of [`remez`].
+#[derive(Clone, Debug)]
+pub struct MinimaxResult {
+    /// The minimax polynomial.
+    pub poly: Polynomial<f64>,
+    /// The largest absolute error max|f(x) − P(x)| on the interval.
+    pub error: f64,
+    /// The levelled error |E| of the last reference, with which f − P equioscillates on it. It is
+    /// a lower bound of the error of the best approximation, and [`error`](MinimaxResult::error)
+    /// is an upper bound.
+    pub levelled_error: f64,
+    /// The degree+2 points where the error alternates in sign with (nearly) maximal magnitude, in
+    /// increasing order.
+    pub reference: Vec<f64>,
+    /// The number of exchange iterations.
+    pub iterations: usize,
+}
+
+/// Evaluates the Chebyshev expansion Σcₖ·Tₖ(t) with Clenshaw's recurrence.
+fn chebyshev_eval(c: &[f64], t: f64) -> f64 {
+    let (b1, b2) = c
+        .iter()
+        .skip(1)
+        .rev()
+        .fold((0.0, 0.0), |(b1, b2), ck| (ck + 2.0 * t * b1 - b2, b1));
+    c.first().copied().unwrap_or(0.0) + t * b1 - b2
+}
+
+/// Converts the Chebyshev expansion Σcₖ·Tₖ(t) to the monomial basis.
+fn chebyshev_to_monomial(c: &[f64]) -> Polynomial<f64> {
+    let mut res = vec![0.0; c.len().max(1)];
+    // Tₖ₋₁ and Tₖ, with T₀ = 1 and T₁ = t
+    let (mut prev, mut cur) = (vec![1.0], vec![0.0, 1.0]);
+    for (k, ck) in c.iter().enumerate() {
+        let t = if k == 0 { &prev } else { &cur };
+        res.iter_mut().zip(t).for_each(|(r, tk)| *r += ck * tk);
+        if k >= 1 {
+            // Tₖ₊₁ = 2t·Tₖ − Tₖ₋₁
+            let mut next = vec![0.0; cur.len() + 1];
+            next[1..]
+                .iter_mut()
+                .zip(&cur)
+                .for_each(|(n, t)| *n = 2.0 * t);
+            next.iter_mut().zip(&prev).for_each(|(n, p)| *n -= p);
+            (prev, cur) = (cur, next);
+        }
+    }
+    Polynomial { coef: res }
+}
+
+/// A Chebyshev expansion Σcₖ·Tₖ(t) on `[a, b]`, where t = (2x − a − b)/(b − a) maps the interval
+/// to `[-1, 1]`, as returned by [`Polynomial::chebyshev_fit`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChebyshevFit {
+    /// The coefficients cₖ of the Chebyshev polynomials Tₖ, starting from T₀.
+    pub coef: Vec<f64>,
+    /// The start of the interval.
+    pub a: f64,
+    /// The end of the interval.
+    pub b: f64,
+}
+
+impl ChebyshevFit {
+    /// Evaluates the expansion at `x` with Clenshaw's recurrence, which is stable at any order.
+    pub fn eval(&self, x: f64) -> f64 {
+        let t = (2.0 * x - self.a - self.b) / (self.b - self.a);
+        chebyshev_eval(&self.coef, t)
+    }
+
+    /// Returns the derivative with respect to x, as a Chebyshev expansion on the same interval.
+    ///
+    /// The coefficients are found with the recurrence c'ₖ₋₁ = c'ₖ₊₁ + 2k·cₖ, without going
+    /// through the monomial basis.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let fit = Polynomial::chebyshev_fit(f64::sin, 0.0, 10.0, 40)?;
+    /// let derivative = fit.derivative();
+    ///
+    /// assert_eq!(derivative.coef.len(), 40);
+    /// assert!((derivative.eval(7.0) - 7f64.cos()).abs() < 1e-10);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn derivative(&self) -> ChebyshevFit {
+        let n = self.coef.len();
+        if n <= 1 {
+            return ChebyshevFit {
+                coef: vec![0.0],
+                a: self.a,
+                b: self.b,
+            };
+        }
+        let mut coef = vec![0.0; n + 1];
+        for k in (1..n).rev() {
+            coef[k - 1] = coef[k + 1] + 2.0 * k as f64 * self.coef[k];
+        }
+        coef.truncate(n - 1);
+        coef[0] *= 0.5;
+
+        // dt/dx
+        let scale = 2.0 / (self.b - self.a);
+        coef.iter_mut().for_each(|c| *c *= scale);
+        ChebyshevFit {
+            coef,
+            a: self.a,
+            b: self.b,
+        }
+    }
+
+    /// Returns the antiderivative with respect to x that vanishes at `a`, as a Chebyshev
+    /// expansion on the same interval.
+    ///
+    /// The coefficients are found with Cₖ = (cₖ₋₁ − cₖ₊₁)/2k, without going through the monomial
+    /// basis, so that e.g. the definite integral over the interval is the antiderivative's value
+    /// at `b`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let fit = Polynomial::chebyshev_fit(|x| (-x * x).exp(), -3.0, 3.0, 60)?;
+    /// let integral = fit.integral().eval(3.0);
+    ///
+    /// // ≈ √π·erf(3)
+    /// assert!((integral - 1.7724146965190428).abs() < 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn integral(&self) -> ChebyshevFit {
+        let n = self.coef.len();
+        let c = |k: usize| self.coef.get(k).copied().unwrap_or(0.0);
+        let mut coef: Vec<f64> = (0..=n)
+            .map(|k| match k {
+                0 => 0.0,
+                1 => c(0) - 0.5 * c(2),
+                _ => (c(k - 1) - c(k + 1)) / (2 * k) as f64,
+            })
+            .collect();
+
+        // dx/dt, then Tₖ(−1) = (−1)ᵏ for the value at a
+        let scale = 0.5 * (self.b - self.a);
+        coef.iter_mut().for_each(|c| *c *= scale);
+        coef[0] = -coef
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(k, c)| if k % 2 == 0 { *c } else { -c })
+            .sum::<f64>();
+        ChebyshevFit {
+            coef,
+            a: self.a,
+            b: self.b,
+        }
+    }
+
+    /// Returns the expansion as a polynomial in x, in the monomial basis.
+    ///
+    /// The monomial coefficients of high orders, or of intervals far from the origin, grow large
+    /// and cancel each other out; [`eval`](ChebyshevFit::eval) is more accurate.
+    pub fn to_monomial(&self) -> Polynomial<f64> {
+        let (mid, half) = (0.5 * (self.a + self.b), 0.5 * (self.b - self.a));
+        compose_linear(&chebyshev_to_monomial(&self.coef), 1.0 / half, -mid / half)
+    }
+}
+
+impl Polynomial<f64> {
+    /// Interpolates `f` at the n+1 Chebyshev nodes of the first kind on `[a, b]`, returning the
+    /// Chebyshev expansion of order `n` of the interpolating polynomial.
+    ///
+    /// For smooth `f` this is a near-minimax approximation: its error is at most a small factor
+    /// larger than that of [`remez`], for a fraction of the cost, and the coefficients decay as
+    /// fast as `f` is smooth, so that their tail estimates the error. The monomial coefficients
+    /// are available with [`ChebyshevFit::to_monomial`].
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `[a, b]` is not a valid finite interval, or if `f` returns NaN or
+    /// Infinity at any of the nodes.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let fit = Polynomial::chebyshev_fit(f64::cos, 0.0, 3.0, 12)?;
+    /// let poly = fit.to_monomial();
+    ///
+    /// assert_eq!(fit.coef.len(), 13);
+    /// assert!((fit.eval(1.3) - 1.3f64.cos()).abs() < 1e-10);
+    /// assert!((poly.eval(1.3) - 1.3f64.cos()).abs() < 1e-10);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn chebyshev_fit(f: impl Fn(f64) -> f64, a: f64, b: f64, n: usize) -> Result<ChebyshevFit> {
+        if !(a.is_finite() && b.is_finite() && a < b) {
+            return Err(PolyError::InvalidInterval(a, b));
+        }
+        let (mid, half) = (0.5 * (a + b), 0.5 * (b - a));
+        let m = n + 1;
+
+        // θⱼ = π(j + ½)/(n + 1), at the nodes tⱼ = cos θⱼ
+        let theta: Vec<f64> = (0..m)
+            .map(|j| std::f64::consts::PI * (j as f64 + 0.5) / m as f64)
+            .collect();
+        let values = theta
+            .iter()
+            .map(|th| eval_checked(&f, (mid + half * th.cos()).clamp(a, b)))
+            .collect::<Result<Vec<f64>>>()?;
+
+        // The discrete cosine transform cₖ = 2/(n + 1)·Σf(xⱼ)·cos(kθⱼ), with c₀ halved
+        let coef = (0..m)
+            .map(|k| {
+                let sum: f64 = values
+                    .iter()
+                    .zip(&theta)
+                    .map(|(y, th)| y * (k as f64 * th).cos())
+                    .sum();
+                let scale = if k == 0 { 1.0 } else { 2.0 };
+                scale * sum / m as f64
+            })
+            .collect();
+        Ok(ChebyshevFit { coef, a, b })
+    }
+}
+
+/// Maximizes `g` on `[lo, hi]` with golden-section search, returning the maximizer.
+fn golden_max(g: impl Fn(f64) -> f64, mut lo: f64, mut hi: f64) -> f64 {
+    const INV_PHI: f64 = 0.618_033_988_749_894_9;
+    let mut x1 = hi - INV_PHI * (hi - lo);
+    let mut x2 = lo + INV_PHI * (hi - lo);
+    let (mut g1, mut g2) = (g(x1), g(x2));
+    for _ in 0..60 {
+        if g1 < g2 {
+            lo = x1;
+            (x1, g1) = (x2, g2);
+            x2 = lo + INV_PHI * (hi - lo);
+            g2 = g(x2);
+        } else {
+            hi = x2;
+            (x2, g2) = (x1, g1);
+            x1 = hi - INV_PHI * (hi - lo);
+            g1 = g(x1);
+        }
+    }
+    if g1 < g2 { x2 } else { x1 }
+}
+
+/// Finds the minimax polynomial of order `degree` of `f` on `[a, b]`, i.e. the polynomial that
+/// minimizes max|f(x) − P(x)|, with the Remez exchange algorithm.
+///
+/// Starting from the Chebyshev extrema, every iteration solves for the polynomial whose error
+/// equioscillates with a levelled magnitude |E| on the degree+2 reference points, and then
+/// exchanges the reference for the alternating extrema of its error, located on a grid and
+/// refined with golden-section search. The polynomial is computed in the Chebyshev basis of
+/// the interval and converted to the monomial basis at the end.
+///
+/// `f` should be continuous on `[a, b]`. If `f` is even or odd around the middle of the interval,
+/// the best approximations of orders 2k and 2k+1 coincide, and the algorithm may not find enough
+/// alternations from the symmetric initial reference for the order whose parity doesn't match
+/// `f`'s; ask for the other one instead, e.g. order 3 rather than 2 for |x| on `[-1, 1]`.
+///
+/// # Error
+///
+/// Returns an error if `[a, b]` is not a valid finite interval, if `f` returns NaN or Infinity,
+/// if the error does not have enough alternating extrema, or if the iterations do not converge
+/// within `max_iter`.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::Result;
+/// use rsl_polynomials::approx::{RemezOptions, remez};
+///
+/// # fn main() -> Result<()> {
+/// let res = remez(f64::exp, 0.0, 1.0, 3, RemezOptions::default())?;
+///
+/// // The error of the best cubic equioscillates 5 times with magnitude ≈ 5.448e-4
+/// assert_eq!(res.reference.len(), 5);
+/// assert!((res.error - 5.448e-4).abs() < 1e-7);
+/// assert!((res.poly.eval(0.5) - 0.5f64.exp()).abs() <= res.error);
+/// # Ok(())
+/// # }
+/// ```
+pub fn remez(
+    f: impl Fn(f64) -> f64,
+    a: f64,
+    b: f64,
+    degree: usize,
+    opts: RemezOptions,
+) -> Result<MinimaxResult> {
+    if !(a.is_finite() && b.is_finite() && a < b) {
+        return Err(PolyError::InvalidInterval(a, b));
+    }
+    let (mid, half) = (0.5 * (a + b), 0.5 * (b - a));
+    let to_x = |t: f64| (mid + half * t).clamp(a, b);
+    let n = degree + 2;
+
+    // Chebyshev-distributed grid, denser near the ends where the extrema cluster
+    let m = opts.grid.max(20 * n);
+    let grid: Vec<f64> = (0..m)
+        .map(|k| -(std::f64::consts::PI * k as f64 / (m - 1) as f64).cos())
+        .collect();
+    let fgrid = grid
+        .iter()
+        .map(|t| eval_checked(&f, to_x(*t)))
+        .collect::<Result<Vec<f64>>>()?;
+    let fscale = fgrid.iter().fold(0.0, |acc: f64, y| acc.max(y.abs()));
+
+    let mut reference: Vec<f64> = (0..n)
+        .map(|i| -(std::f64::consts::PI * i as f64 / (n - 1) as f64).cos())
+        .collect();
+
+    for iteration in 1..=opts.max_iter {
+        // Σcₖ·Tₖ(tᵢ) + (−1)ⁱE = f(xᵢ)
+        let matrix: Vec<Vec<f64>> = reference
+            .iter()
+            .enumerate()
+            .map(|(i, t)| {
+                let mut row: Vec<f64> = (0..=degree)
+                    .map(|k| (k as f64 * t.clamp(-1.0, 1.0).acos()).cos())
+                    .collect();
+                row.push(if i % 2 == 0 { 1.0 } else { -1.0 });
+                row
+            })
+            .collect();
+        let rhs = reference
+            .iter()
+            .map(|t| eval_checked(&f, to_x(*t)))
+            .collect::<Result<Vec<f64>>>()?;
+        let (mut cheb, _) = linalg::lstsq(&matrix, &rhs)?;
+        let levelled = cheb.pop().unwrap_or(0.0).abs();
+        let err = |t: f64| f(to_x(t)) - chebyshev_eval(&cheb, t);
+
+        // The alternating extrema of the error, one for every run of the same sign
+        let egrid: Vec<f64> = grid
+            .iter()
+            .zip(&fgrid)
+            .map(|(t, y)| y - chebyshev_eval(&cheb, *t))
+            .collect();
+        let monomial = |coef: Vec<f64>| ChebyshevFit { coef, a, b }.to_monomial();
+        let grid_error = egrid.iter().fold(0.0, |acc: f64, e| acc.max(e.abs()));
+        if grid_error <= 4.0 * f64::EPSILON * fscale {
+            // f is a polynomial of order `degree` at most, with no alternations above rounding
+            return Ok(MinimaxResult {
+                poly: monomial(cheb),
+                error: grid_error,
+                levelled_error: levelled,
+                reference: reference.into_iter().map(to_x).collect(),
+                iterations: iteration,
+            });
+        }
+        let mut extrema: Vec<(f64, f64)> = Vec::new();
+        let mut start = 0;
+        while start < m {
+            let positive = egrid[start] >= 0.0;
+            let end = (start..m)
+                .find(|k| (egrid[*k] >= 0.0) != positive)
+                .unwrap_or(m);
+            let best = (start..end)
+                .max_by(|i, j| egrid[*i].abs().total_cmp(&egrid[*j].abs()))
+                .unwrap_or(start);
+            let sign = if positive { 1.0 } else { -1.0 };
+            let (lo, hi) = (grid[best.saturating_sub(1)], grid[(best + 1).min(m - 1)]);
+            let t = golden_max(|t| sign * err(t), lo, hi);
+            let (e, e_best) = (err(t), egrid[best]);
+            extrema.push(match sign * e > sign * e_best {
+                true => (t, e),
+                false => (grid[best], e_best),
+            });
+            start = end;
+        }
+
+        // Drop the smallest extrema, keeping the signs alternating
+        if extrema.len() < n {
+            return Err(PolyError::NoConvergence);
+        }
+        while extrema.len() > n {
+            let i = (0..extrema.len())
+                .min_by(|i, j| extrema[*i].1.abs().total_cmp(&extrema[*j].1.abs()))
+                .unwrap_or(0);
+            extrema.remove(i);
+            if i > 0 && i < extrema.len() {
+                let j = match extrema[i - 1].1.abs() < extrema[i].1.abs() {
+                    true => i - 1,
+                    false => i,
+                };
+                extrema.remove(j);
+            }
+        }
+
+        reference = extrema.iter().map(|(t, _)| *t).collect();
+        let error = extrema
+            .iter()
+            .fold(0.0, |acc: f64, (_, e)| acc.max(e.abs()));
+        if error - levelled <= opts.tol * error {
+            return Ok(MinimaxResult {
+                poly: monomial(cheb),
+                error,
+                levelled_error: levelled,
+                reference: reference.into_iter().map(to_x).collect(),
+                iterations: iteration,
+            });
+        }
+    }
+    Err(PolyError::NoConvergence)
+}
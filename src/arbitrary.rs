@@ -0,0 +1,103 @@
+//! Random polynomials for property-based testing, with the `proptest` and `quickcheck` features.
+//!
+//! Both features implement `Arbitrary` for `Polynomial<f64>`, with finite coefficients of
+//! moderate size. With `proptest`, the strategies of this module also generate polynomials with a
+//! prescribed root structure, along with their roots, e.g. to test solve → reconstruct round
+//! trips.
+
+#[cfg(feature = "proptest")]
+use num::complex::Complex64;
+#[cfg(feature = "proptest")]
+use proptest::prelude::*;
+
+use crate::Polynomial;
+
+/// Largest order of the `Arbitrary` polynomials.
+const ARBITRARY_MAX_ORDER: usize = 8;
+
+/// Largest magnitude of the `Arbitrary` coefficients.
+const ARBITRARY_MAX_COEF: f64 = 100.0;
+
+/// Generates polynomials of order at most `max_order`, with finite coefficients in
+/// [−100, 100]. The leading coefficient may be 0.
+#[cfg(feature = "proptest")]
+pub fn polynomial(max_order: usize) -> impl Strategy<Value = Polynomial<f64>> {
+    proptest::collection::vec(-ARBITRARY_MAX_COEF..=ARBITRARY_MAX_COEF, 1..=max_order + 1)
+        .prop_map(|coef| Polynomial { coef })
+}
+
+/// Generates polynomials with `real` real roots in [−10, 10] and `complex_pairs` pairs of
+/// complex conjugate roots with real parts in [−10, 10] and imaginary parts in ±[0.1, 10], along
+/// with their roots. The leading coefficient is in ±[0.5, 10].
+///
+/// The roots are independent, so they may be arbitrarily close to each other.
+///
+/// ## Example
+///
+/// ```
+/// # use proptest::prelude::*;
+/// # use rsl_polynomials::arbitrary;
+/// proptest!(|((poly, roots) in arbitrary::with_roots(2, 1))| {
+///     prop_assert_eq!(poly.coef.len(), 5);
+///     prop_assert_eq!(roots.len(), 4);
+/// });
+/// ```
+#[cfg(feature = "proptest")]
+pub fn with_roots(
+    real: usize,
+    complex_pairs: usize,
+) -> impl Strategy<Value = (Polynomial<f64>, Vec<Complex64>)> {
+    let reals = proptest::collection::vec(-10.0..=10.0f64, real);
+    let pairs = proptest::collection::vec((-10.0..=10.0f64, 0.1..=10.0f64), complex_pairs);
+    let lead =
+        (0.5..=10.0, any::<bool>()).prop_map(|(a, neg): (f64, bool)| if neg { -a } else { a });
+
+    (lead, reals, pairs).prop_map(|(lead, reals, pairs)| {
+        let mut roots: Vec<Complex64> = reals.iter().map(|r| Complex64::new(*r, 0.0)).collect();
+        for (re, im) in pairs {
+            roots.push(Complex64::new(re, im));
+            roots.push(Complex64::new(re, -im));
+        }
+        let complex = Polynomial::from_roots_with_leading(Complex64::new(lead, 0.0), &roots);
+        let poly = Polynomial {
+            coef: complex.coef.iter().map(|c| c.re).collect(),
+        };
+        (poly, roots)
+    })
+}
+
+#[cfg(feature = "proptest")]
+impl Arbitrary for Polynomial<f64> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Polynomials of order at most 8, with coefficients in [−100, 100].
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        polynomial(ARBITRARY_MAX_ORDER).boxed()
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for Polynomial<f64> {
+    /// Polynomials of order at most 8, with coefficients in [−100, 100].
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let len = <usize as quickcheck::Arbitrary>::arbitrary(g) % (ARBITRARY_MAX_ORDER + 1) + 1;
+        let coef = (0..len)
+            .map(|_| {
+                f64::from(<i16 as quickcheck::Arbitrary>::arbitrary(g))
+                    / (f64::from(i16::MAX) + 1.0)
+                    * ARBITRARY_MAX_COEF
+            })
+            .collect();
+        Polynomial { coef }
+    }
+
+    /// Shrinks the coefficients, keeping at least one.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            quickcheck::Arbitrary::shrink(&self.coef)
+                .filter(|coef| !coef.is_empty() && coef.iter().all(|c| c.is_finite()))
+                .map(|coef| Polynomial { coef }),
+        )
+    }
+}
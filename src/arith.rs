@@ -0,0 +1,365 @@
+//! Arithmetic operations between polynomials, and between polynomials and scalars.
+//!
+//! The operations are implemented for both owned polynomials and references. The results are not
+//! trimmed, i.e. the sum of two polynomials of order `n` is always of order `n`, even if the
+//! leading terms cancel out.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num::complex::{Complex32, Complex64};
+
+use crate::{PolyError, Polynomial, Result};
+
+/// Combines two coefficient slices term by term, treating missing terms as 0.
+fn zip_longest<T, F>(lhs: &[T], rhs: &[T], op: F) -> Vec<T>
+where
+    T: num::complex::ComplexFloat,
+    F: Fn(T, T) -> T,
+{
+    (0..lhs.len().max(rhs.len()))
+        .map(|i| {
+            let a = lhs.get(i).copied().unwrap_or(T::zero());
+            let b = rhs.get(i).copied().unwrap_or(T::zero());
+            op(a, b)
+        })
+        .collect()
+}
+
+impl<T> Add for &Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = Polynomial<T>;
+
+    fn add(self, rhs: Self) -> Polynomial<T> {
+        Polynomial {
+            coef: zip_longest(&self.coef, &rhs.coef, |a, b| a + b),
+        }
+    }
+}
+
+impl<T> Sub for &Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = Polynomial<T>;
+
+    fn sub(self, rhs: Self) -> Polynomial<T> {
+        Polynomial {
+            coef: zip_longest(&self.coef, &rhs.coef, |a, b| a - b),
+        }
+    }
+}
+
+impl<T> Mul for &Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs: Self) -> Polynomial<T> {
+        // A polynomial with no coefficients (e.g. a trimmed zero polynomial) is 0
+        if self.coef.is_empty() || rhs.coef.is_empty() {
+            return Polynomial::new();
+        }
+
+        let mut coef = vec![T::zero(); self.coef.len() + rhs.coef.len() - 1];
+        for (i, a) in self.coef.iter().enumerate() {
+            for (j, b) in rhs.coef.iter().enumerate() {
+                coef[i + j] = coef[i + j] + *a * *b;
+            }
+        }
+        Polynomial { coef }
+    }
+}
+
+impl<T> Neg for &Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Polynomial<T> {
+        Polynomial {
+            coef: self.coef.iter().map(|c| -*c).collect(),
+        }
+    }
+}
+
+/// Implements a binary operator for an owned polynomial type, by forwarding to the reference one.
+macro_rules! forward_owned_binop {
+    ($poly:ident, $imp:ident, $method:ident) => {
+        impl<T> std::ops::$imp for $poly<T>
+        where
+            T: num::complex::ComplexFloat + std::fmt::Debug,
+        {
+            type Output = $poly<T>;
+
+            fn $method(self, rhs: Self) -> $poly<T> {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+pub(crate) use forward_owned_binop;
+
+forward_owned_binop!(Polynomial, Add, add);
+forward_owned_binop!(Polynomial, Sub, sub);
+forward_owned_binop!(Polynomial, Mul, mul);
+
+impl<T> Neg for Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Polynomial<T> {
+        -&self
+    }
+}
+
+/// Implements an operator between a polynomial (owned and by reference) and a scalar of its
+/// coefficients' type, applying `$op` to the coefficients selected by `$range`.
+macro_rules! scalar_op {
+    ($imp:ident, $method:ident, $op:tt, $range:expr) => {
+        impl<T> $imp<T> for &Polynomial<T>
+        where
+            T: num::complex::ComplexFloat + std::fmt::Debug,
+        {
+            type Output = Polynomial<T>;
+
+            fn $method(self, rhs: T) -> Polynomial<T> {
+                let mut res = self.clone();
+                // A polynomial with no coefficients (e.g. a trimmed zero polynomial) is 0
+                if res.coef.is_empty() {
+                    res.coef.push(T::zero());
+                }
+                res.coef[$range].iter_mut().for_each(|c| *c = *c $op rhs);
+                res
+            }
+        }
+
+        impl<T> $imp<T> for Polynomial<T>
+        where
+            T: num::complex::ComplexFloat + std::fmt::Debug,
+        {
+            type Output = Polynomial<T>;
+
+            fn $method(self, rhs: T) -> Polynomial<T> {
+                (&self).$method(rhs)
+            }
+        }
+    };
+}
+
+// Adding a scalar only affects the constant term, while multiplying affects all the terms
+scalar_op!(Add, add, +, ..1);
+scalar_op!(Sub, sub, -, ..1);
+scalar_op!(Mul, mul, *, ..);
+scalar_op!(Div, div, /, ..);
+
+/// Implements the operators with the scalar on the left hand side, for each concrete scalar type.
+macro_rules! scalar_lhs_ops {
+    ($($scalar:ty),*) => {$(
+        impl Add<&Polynomial<$scalar>> for $scalar {
+            type Output = Polynomial<$scalar>;
+
+            fn add(self, rhs: &Polynomial<$scalar>) -> Polynomial<$scalar> {
+                rhs + self
+            }
+        }
+
+        impl Add<Polynomial<$scalar>> for $scalar {
+            type Output = Polynomial<$scalar>;
+
+            fn add(self, rhs: Polynomial<$scalar>) -> Polynomial<$scalar> {
+                &rhs + self
+            }
+        }
+
+        impl Sub<&Polynomial<$scalar>> for $scalar {
+            type Output = Polynomial<$scalar>;
+
+            fn sub(self, rhs: &Polynomial<$scalar>) -> Polynomial<$scalar> {
+                -rhs + self
+            }
+        }
+
+        impl Sub<Polynomial<$scalar>> for $scalar {
+            type Output = Polynomial<$scalar>;
+
+            fn sub(self, rhs: Polynomial<$scalar>) -> Polynomial<$scalar> {
+                -&rhs + self
+            }
+        }
+
+        impl Mul<&Polynomial<$scalar>> for $scalar {
+            type Output = Polynomial<$scalar>;
+
+            fn mul(self, rhs: &Polynomial<$scalar>) -> Polynomial<$scalar> {
+                rhs * self
+            }
+        }
+
+        impl Mul<Polynomial<$scalar>> for $scalar {
+            type Output = Polynomial<$scalar>;
+
+            fn mul(self, rhs: Polynomial<$scalar>) -> Polynomial<$scalar> {
+                &rhs * self
+            }
+        }
+    )*};
+}
+
+scalar_lhs_ops!(f32, f64, Complex32, Complex64);
+
+impl<T> Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Adds `alpha·other` to the polynomial in place, i.e. `self += alpha·other`.
+    ///
+    /// The polynomial is only extended (and reallocated, if its capacity is not enough) when
+    /// `other` is of higher order. Leading terms that cancel out are not trimmed.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let mut poly = Polynomial::build(&[1.0, 1.0])?;
+    /// let other = Polynomial::build(&[0.0, 1.0, 2.0])?;
+    /// poly.axpy(3.0, &other);
+    ///
+    /// assert_eq!(poly.coef, [1.0, 4.0, 6.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn axpy(&mut self, alpha: T, other: &Polynomial<T>) {
+        if other.coef.len() > self.coef.len() {
+            self.coef.resize(other.coef.len(), T::zero());
+        }
+        self.coef
+            .iter_mut()
+            .zip(other.coef.iter())
+            .for_each(|(c, o)| *c = *c + alpha * *o);
+    }
+
+    /// Divides the polynomial by `divisor` with polynomial long division, returning the quotient
+    /// and the remainder.
+    ///
+    /// The remainder has a lower order than the divisor (trailing zeros excluded).
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the divisor is the zero polynomial.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-1.0, 0.0, 0.0, 1.0])?; // x³-1
+    /// let divisor = Polynomial::build(&[-1.0, 1.0])?; // x-1
+    /// let (quotient, remainder) = poly.div_rem(&divisor)?;
+    ///
+    /// assert_eq!(quotient.coef, [1.0, 1.0, 1.0]);
+    /// assert_eq!(remainder.coef, [0.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn div_rem(&self, divisor: &Polynomial<T>) -> Result<(Polynomial<T>, Polynomial<T>)> {
+        let divisor = divisor.to_trimmed();
+        let m = divisor.coef.len();
+        if divisor.coef.last().is_none_or(|c| c.is_zero()) {
+            return Err(PolyError::DivisionByZero);
+        }
+
+        let n = self.coef.len();
+        if n < m {
+            return Ok((Polynomial::new(), self.clone()));
+        }
+
+        let lead = divisor.coef[m - 1];
+        let mut rem = self.coef.clone();
+        let mut quot = vec![T::zero(); n - m + 1];
+        for k in (0..=(n - m)).rev() {
+            let q = rem[k + m - 1] / lead;
+            quot[k] = q;
+            for (j, d) in divisor.coef.iter().enumerate() {
+                rem[k + j] = rem[k + j] - q * *d;
+            }
+        }
+        rem.truncate((m - 1).max(1));
+        if m == 1 {
+            rem[0] = T::zero();
+        }
+
+        Ok((Polynomial { coef: quot }, Polynomial { coef: rem }))
+    }
+
+    /// Multiplies the polynomial by `other` in the quotient ring F[x]/(m), i.e. returns the
+    /// remainder of `self·other` divided by `modulus`.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the modulus is the zero polynomial.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// // Cyclic convolution: reduction modulo x³−1
+    /// let modulus = Polynomial::build(&[-1.0, 0.0, 0.0, 1.0])?;
+    /// let a = Polynomial::build(&[1.0, 2.0, 3.0])?;
+    /// let b = Polynomial::build(&[0.0, 1.0])?; // x
+    ///
+    /// assert_eq!(a.mul_mod(&b, &modulus)?.coef, [3.0, 1.0, 2.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mul_mod(&self, other: &Polynomial<T>, modulus: &Polynomial<T>) -> Result<Polynomial<T>> {
+        Ok((self * other).div_rem(modulus)?.1)
+    }
+
+    /// Raises the polynomial to the power `k` in the quotient ring F[x]/(m), with repeated
+    /// squaring, so that the intermediate products never exceed twice the order of the modulus.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the modulus is the zero polynomial.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let modulus = Polynomial::build(&[-1.0, 0.0, 0.0, 1.0])?; // x³−1
+    /// let x = Polynomial::build(&[0.0, 1.0])?;
+    ///
+    /// assert_eq!(x.pow_mod(1000, &modulus)?.coef, [0.0, 1.0, 0.0]); // x¹⁰⁰⁰ = x
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pow_mod(&self, k: u64, modulus: &Polynomial<T>) -> Result<Polynomial<T>> {
+        let mut base = self.div_rem(modulus)?.1;
+        let mut res = Polynomial {
+            coef: vec![T::one()],
+        }
+        .div_rem(modulus)?
+        .1;
+
+        let mut k = k;
+        while k > 0 {
+            if k & 1 == 1 {
+                res = res.mul_mod(&base, modulus)?;
+            }
+            k >>= 1;
+            if k > 0 {
+                base = base.mul_mod(&base, modulus)?;
+            }
+        }
+        Ok(res)
+    }
+}
@@ -0,0 +1,110 @@
+//! Construction, evaluation and fitting of polynomials with [`ndarray`] arrays.
+//!
+//! Contiguous views are used in place; only non-contiguous ones (e.g. a column of a row-major
+//! matrix) are copied.
+
+use std::borrow::Cow;
+
+use ndarray::{Array, Array1, ArrayView, ArrayView1, Dimension};
+
+use crate::{FitResult, Polynomial, Result};
+
+/// Returns the elements of `view` as a slice, copying them only if they are not contiguous.
+fn as_slice<'a, T: Clone>(view: &'a ArrayView1<T>) -> Cow<'a, [T]> {
+    match view.as_slice() {
+        Some(slice) => Cow::Borrowed(slice),
+        None => Cow::Owned(view.to_vec()),
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Same as [`build`](Polynomial::build), but takes the coefficients as an array view.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if any of the coefficients is NaN or Infinity.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # use ndarray::array;
+    /// # fn main() -> Result<()> {
+    /// let coef = array![[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]];
+    /// let poly = Polynomial::from_array(coef.column(1))?; // 4+5x+6x²
+    ///
+    /// assert_eq!(poly.coef, [4.0, 5.0, 6.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_array(coef: ArrayView1<T>) -> Result<Self> {
+        Polynomial::build(&as_slice(&coef))
+    }
+
+    /// Returns the coefficients as an [`Array1`].
+    pub fn to_array(&self) -> Array1<T> {
+        Array1::from_vec(self.coef.clone())
+    }
+
+    /// Evaluates the polynomial at every element of `xs`, returning an array of the same shape.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # use ndarray::array;
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 0.0, 1.0])?; // 1+x²
+    /// let xs = array![[0.0, 1.0], [2.0, 3.0]];
+    ///
+    /// assert_eq!(poly.eval_array(xs.view()), array![[1.0, 2.0], [5.0, 10.0]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_array<D: Dimension>(&self, xs: ArrayView<T, D>) -> Array<T, D> {
+        xs.mapv(|x| self.eval(x))
+    }
+}
+
+impl Polynomial<f64> {
+    /// Same as [`fit`](Polynomial::fit), but takes the data points as array views.
+    ///
+    /// # Error
+    ///
+    /// Returns an error in the same cases as [`fit`](Polynomial::fit).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # use ndarray::array;
+    /// # fn main() -> Result<()> {
+    /// let data = array![[0.0, 1.0], [1.0, 3.0], [2.0, 5.0], [3.0, 7.0]];
+    /// let fit = Polynomial::fit_array(data.column(0), data.column(1), 1)?;
+    ///
+    /// assert!((fit.poly.coef[1] - 2.0).abs() < 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fit_array(xs: ArrayView1<f64>, ys: ArrayView1<f64>, degree: usize) -> Result<FitResult> {
+        Polynomial::fit(&as_slice(&xs), &as_slice(&ys), degree)
+    }
+
+    /// Same as [`fit_weighted`](Polynomial::fit_weighted), but takes the data points and the
+    /// weights as array views.
+    ///
+    /// # Error
+    ///
+    /// Returns an error in the same cases as [`fit_weighted`](Polynomial::fit_weighted).
+    pub fn fit_weighted_array(
+        xs: ArrayView1<f64>,
+        ys: ArrayView1<f64>,
+        weights: ArrayView1<f64>,
+        degree: usize,
+    ) -> Result<FitResult> {
+        Polynomial::fit_weighted(&as_slice(&xs), &as_slice(&ys), &as_slice(&weights), degree)
+    }
+}
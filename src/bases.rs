@@ -0,0 +1,466 @@
+//! Polynomials in the monomial, Chebyshev, Legendre, Bernstein and Newton forms, behind a common
+//! [`PolynomialBasis`] trait.
+//!
+//! Every form can be evaluated and differentiated in its own basis, and converted to any other
+//! one with [`PolynomialBasis::convert`]. The Chebyshev, Legendre and Bernstein forms can also be
+//! integrated in their own basis. Conversions interpolate the source at the natural nodes
+//! of the target, e.g. Chebyshev or Gauss–Legendre points of its interval, rather than going
+//! through the monomial basis, so they are exact up to the conditioning of the target basis.
+//!
+//! ## Example
+//!
+//! ```
+//! # use rsl_polynomials::{Polynomial, Result};
+//! use rsl_polynomials::approx::ChebyshevFit;
+//! use rsl_polynomials::bases::{BernsteinPoly, LegendreSeries, NewtonPoly, PolynomialBasis};
+//!
+//! # fn main() -> Result<()> {
+//! let cheb = ChebyshevFit::interpolate(f64::exp, 10, &(0.0, 2.0))?;
+//!
+//! let legendre: LegendreSeries = cheb.convert(&(0.0, 2.0))?;
+//! let bernstein: BernsteinPoly = legendre.convert(&(0.0, 2.0))?;
+//! let nodes: Vec<f64> = (0..=10).map(|i| i as f64 / 5.0).collect();
+//! let newton: NewtonPoly = bernstein.convert(&nodes)?;
+//! let monomial: Polynomial<f64> = newton.convert(&())?;
+//!
+//! for p in [legendre.eval(1.3), bernstein.eval(1.3), newton.eval(1.3), monomial.eval(1.3)] {
+//!     assert!((p - cheb.eval(1.3)).abs() < 1e-12);
+//! }
+//! assert!((bernstein.derivative().eval(1.3) - 1.3f64.exp()).abs() < 1e-8);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::approx::ChebyshevFit;
+use crate::quadrature::gauss_legendre;
+use crate::utils::eval_checked;
+use crate::{PolyError, Polynomial, Result, linalg};
+
+/// A representation of polynomials by their coefficients in a basis.
+pub trait PolynomialBasis: Sized {
+    /// What defines the basis besides the coefficients: the interval `(a, b)` of the Chebyshev,
+    /// Legendre and Bernstein forms, the nodes of the Newton form, or nothing for the monomial
+    /// form.
+    type Params;
+
+    /// Returns the coefficients in the basis.
+    fn coefficients(&self) -> &[f64];
+
+    /// Evaluates the polynomial at `x`.
+    fn eval(&self, x: f64) -> f64;
+
+    /// Returns the derivative with respect to x, in the same form.
+    fn derivative(&self) -> Self;
+
+    /// Interpolates `f` by a polynomial of order `degree` in the basis defined by `params`, at
+    /// the natural nodes of the basis.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `params` are invalid, e.g. an empty interval or too few distinct
+    /// nodes, or if `f` returns NaN or Infinity at any of the nodes.
+    fn interpolate(f: impl Fn(f64) -> f64, degree: usize, params: &Self::Params) -> Result<Self>;
+
+    /// Returns the order of the polynomial, counting any zero leading coefficients.
+    fn degree(&self) -> usize {
+        self.coefficients().len().saturating_sub(1)
+    }
+
+    /// Converts the polynomial to the form `B` defined by `params`.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `params` are invalid for `B`.
+    fn convert<B: PolynomialBasis>(&self, params: &B::Params) -> Result<B> {
+        B::interpolate(|x| self.eval(x), self.degree(), params)
+    }
+}
+
+/// Checks that `(a, b)` is a valid finite interval.
+fn check_interval((a, b): (f64, f64)) -> Result<()> {
+    match a.is_finite() && b.is_finite() && a < b {
+        true => Ok(()),
+        false => Err(PolyError::InvalidInterval(a, b)),
+    }
+}
+
+impl PolynomialBasis for Polynomial<f64> {
+    type Params = ();
+
+    fn coefficients(&self) -> &[f64] {
+        &self.coef
+    }
+
+    fn eval(&self, x: f64) -> f64 {
+        Polynomial::eval(self, x)
+    }
+
+    fn derivative(&self) -> Self {
+        Polynomial::derivative(self)
+    }
+
+    /// Interpolates at the Chebyshev nodes of [-1, 1], and converts the Chebyshev expansion to
+    /// the monomial basis.
+    fn interpolate(f: impl Fn(f64) -> f64, degree: usize, _: &()) -> Result<Self> {
+        Ok(Polynomial::chebyshev_fit(f, -1.0, 1.0, degree)?.to_monomial())
+    }
+}
+
+impl PolynomialBasis for ChebyshevFit {
+    type Params = (f64, f64);
+
+    fn coefficients(&self) -> &[f64] {
+        &self.coef
+    }
+
+    fn eval(&self, x: f64) -> f64 {
+        ChebyshevFit::eval(self, x)
+    }
+
+    fn derivative(&self) -> Self {
+        ChebyshevFit::derivative(self)
+    }
+
+    /// See [`Polynomial::chebyshev_fit`].
+    fn interpolate(f: impl Fn(f64) -> f64, degree: usize, &(a, b): &(f64, f64)) -> Result<Self> {
+        Polynomial::chebyshev_fit(f, a, b, degree)
+    }
+}
+
+/// A Legendre expansion Σcₖ·Pₖ(t) on `[a, b]`, where t = (2x − a − b)/(b − a) maps the interval
+/// to `[-1, 1]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegendreSeries {
+    /// The coefficients cₖ of the Legendre polynomials Pₖ, starting from P₀.
+    pub coef: Vec<f64>,
+    /// The start of the interval.
+    pub a: f64,
+    /// The end of the interval.
+    pub b: f64,
+}
+
+impl LegendreSeries {
+    /// Returns the antiderivative with respect to x that vanishes at `a`, as a Legendre expansion
+    /// on the same interval.
+    ///
+    /// The coefficients are found with Cₖ = cₖ₋₁/(2k − 1) − cₖ₊₁/(2k + 3), from
+    /// ∫Pₖ = (Pₖ₊₁ − Pₖ₋₁)/(2k + 1), as [`ChebyshevFit::integral`] does for the Chebyshev form.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::Result;
+    /// use rsl_polynomials::bases::{LegendreSeries, PolynomialBasis};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let series = LegendreSeries::interpolate(f64::cos, 20, &(0.0, 2.0))?;
+    ///
+    /// assert!((series.integral().eval(2.0) - 2f64.sin()).abs() < 1e-14);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn integral(&self) -> LegendreSeries {
+        let n = self.coef.len();
+        let c = |k: usize| self.coef.get(k).copied().unwrap_or(0.0);
+        let mut coef: Vec<f64> = (0..=n)
+            .map(|k| match k {
+                0 => 0.0,
+                _ => c(k - 1) / (2 * k - 1) as f64 - c(k + 1) / (2 * k + 3) as f64,
+            })
+            .collect();
+
+        // dx/dt, then Pₖ(−1) = (−1)ᵏ for the value at a
+        let scale = 0.5 * (self.b - self.a);
+        coef.iter_mut().for_each(|c| *c *= scale);
+        coef[0] = -coef
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(k, c)| if k % 2 == 0 { *c } else { -c })
+            .sum::<f64>();
+        LegendreSeries {
+            coef,
+            a: self.a,
+            b: self.b,
+        }
+    }
+}
+
+impl PolynomialBasis for LegendreSeries {
+    type Params = (f64, f64);
+
+    fn coefficients(&self) -> &[f64] {
+        &self.coef
+    }
+
+    /// Evaluates the expansion with the three-term recurrence of the Legendre polynomials.
+    fn eval(&self, x: f64) -> f64 {
+        let t = (2.0 * x - self.a - self.b) / (self.b - self.a);
+        let (mut prev, mut cur) = (0.0, 1.0);
+        let mut sum = 0.0;
+        for (k, c) in self.coef.iter().enumerate() {
+            sum += c * cur;
+            // (k + 1)Pₖ₊₁ = (2k + 1)t·Pₖ − k·Pₖ₋₁
+            let next = ((2 * k + 1) as f64 * t * cur - k as f64 * prev) / (k + 1) as f64;
+            (prev, cur) = (cur, next);
+        }
+        sum
+    }
+
+    /// Differentiates with c'ₖ = (2k + 1)·(cₖ₊₁ + cₖ₊₃ + ...), from Pₖ₊₁' − Pₖ₋₁' = (2k + 1)Pₖ.
+    fn derivative(&self) -> Self {
+        let n = self.coef.len();
+        let scale = 2.0 / (self.b - self.a);
+        let mut coef = vec![0.0; n.saturating_sub(1).max(1)];
+        // Sₖ = cₖ₊₁ + Sₖ₊₂
+        let (mut s1, mut s2) = (0.0, 0.0);
+        for k in (0..n.saturating_sub(1)).rev() {
+            let s = self.coef[k + 1] + s2;
+            coef[k] = (2 * k + 1) as f64 * s * scale;
+            (s2, s1) = (s1, s);
+        }
+        LegendreSeries {
+            coef,
+            a: self.a,
+            b: self.b,
+        }
+    }
+
+    /// Interpolates at the degree+1 Gauss–Legendre nodes of the interval, where the discrete
+    /// Legendre transform cₖ = (2k + 1)/2·Σwᵢf(xᵢ)Pₖ(tᵢ) is exact.
+    fn interpolate(f: impl Fn(f64) -> f64, degree: usize, &(a, b): &(f64, f64)) -> Result<Self> {
+        check_interval((a, b))?;
+        let (nodes, weights) = gauss_legendre(degree + 1)?;
+        let (mid, half) = (0.5 * (a + b), 0.5 * (b - a));
+
+        let mut coef = vec![0.0; degree + 1];
+        for (t, w) in nodes.iter().zip(&weights) {
+            let y = w * eval_checked(&f, (mid + half * t).clamp(a, b))?;
+            let (mut prev, mut cur) = (0.0, 1.0);
+            for (k, c) in coef.iter_mut().enumerate() {
+                *c += y * cur;
+                let next = ((2 * k + 1) as f64 * t * cur - k as f64 * prev) / (k + 1) as f64;
+                (prev, cur) = (cur, next);
+            }
+        }
+        coef.iter_mut()
+            .enumerate()
+            .for_each(|(k, c)| *c *= (2 * k + 1) as f64 / 2.0);
+        Ok(LegendreSeries { coef, a, b })
+    }
+}
+
+/// A polynomial in the Bernstein basis of `[a, b]`, Σcᵢ·C(n, i)sⁱ(1 − s)ⁿ⁻ⁱ with
+/// s = (x − a)/(b − a), whose coefficients are the control points of a Bézier curve.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BernsteinPoly {
+    /// The control points cᵢ.
+    pub coef: Vec<f64>,
+    /// The start of the interval.
+    pub a: f64,
+    /// The end of the interval.
+    pub b: f64,
+}
+
+/// Returns the Bernstein basis polynomials of order `n` at `s`, with their recurrence
+/// bᵢ,ₖ₊₁ = (1 − s)bᵢ,ₖ + s·bᵢ₋₁,ₖ.
+fn bernstein_values(n: usize, s: f64) -> Vec<f64> {
+    let mut values = vec![0.0; n + 1];
+    values[0] = 1.0;
+    for k in 1..=n {
+        for i in (1..=k).rev() {
+            values[i] = (1.0 - s) * values[i] + s * values[i - 1];
+        }
+        values[0] *= 1.0 - s;
+    }
+    values
+}
+
+impl BernsteinPoly {
+    /// Returns the antiderivative with respect to x that vanishes at `a`, in the Bernstein basis
+    /// of one order higher on the same interval.
+    ///
+    /// The control points are the scaled partial sums Cᵢ₊₁ = Cᵢ + (b − a)/(n + 1)·cᵢ, starting
+    /// from C₀ = 0, so the last one is the definite integral over the interval.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::Result;
+    /// use rsl_polynomials::bases::{BernsteinPoly, PolynomialBasis};
+    ///
+    /// # fn main() -> Result<()> {
+    /// let poly = BernsteinPoly {
+    ///     coef: vec![0.0, 1.0, 1.0],
+    ///     a: 0.0,
+    ///     b: 3.0,
+    /// };
+    /// let integral = poly.integral();
+    ///
+    /// assert_eq!(integral.coef, [0.0, 0.0, 1.0, 2.0]);
+    /// assert_eq!(integral.eval(3.0), 2.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn integral(&self) -> BernsteinPoly {
+        let step = (self.b - self.a) / self.coef.len() as f64;
+        let coef = std::iter::once(0.0)
+            .chain(self.coef.iter().scan(0.0, |sum, c| {
+                *sum += step * c;
+                Some(*sum)
+            }))
+            .collect();
+        BernsteinPoly {
+            coef,
+            a: self.a,
+            b: self.b,
+        }
+    }
+}
+
+impl PolynomialBasis for BernsteinPoly {
+    type Params = (f64, f64);
+
+    fn coefficients(&self) -> &[f64] {
+        &self.coef
+    }
+
+    /// Evaluates the polynomial with de Casteljau's algorithm.
+    fn eval(&self, x: f64) -> f64 {
+        let s = (x - self.a) / (self.b - self.a);
+        let mut points = self.coef.clone();
+        for k in (1..points.len()).rev() {
+            for i in 0..k {
+                points[i] = (1.0 - s) * points[i] + s * points[i + 1];
+            }
+        }
+        points.first().copied().unwrap_or(0.0)
+    }
+
+    /// Differentiates with c'ᵢ = n(cᵢ₊₁ − cᵢ)/(b − a).
+    fn derivative(&self) -> Self {
+        let n = self.coef.len().saturating_sub(1);
+        let scale = n as f64 / (self.b - self.a);
+        let coef = match n {
+            0 => vec![0.0],
+            _ => self
+                .coef
+                .windows(2)
+                .map(|w| scale * (w[1] - w[0]))
+                .collect(),
+        };
+        BernsteinPoly {
+            coef,
+            a: self.a,
+            b: self.b,
+        }
+    }
+
+    /// Interpolates at the degree+1 Chebyshev nodes of the interval, by solving the collocation
+    /// system.
+    fn interpolate(f: impl Fn(f64) -> f64, degree: usize, &(a, b): &(f64, f64)) -> Result<Self> {
+        check_interval((a, b))?;
+        let m = degree + 1;
+        let nodes: Vec<f64> = (0..m)
+            .map(|j| 0.5 - 0.5 * (std::f64::consts::PI * (j as f64 + 0.5) / m as f64).cos())
+            .collect();
+        let values = nodes
+            .iter()
+            .map(|s| eval_checked(&f, (a + (b - a) * s).clamp(a, b)))
+            .collect::<Result<Vec<f64>>>()?;
+        let matrix: Vec<Vec<f64>> = nodes.iter().map(|s| bernstein_values(degree, *s)).collect();
+        let (coef, _) = linalg::lstsq(&matrix, &values)?;
+        Ok(BernsteinPoly { coef, a, b })
+    }
+}
+
+/// A polynomial in Newton's form a₀ + a₁(x − x₀) + a₂(x − x₀)(x − x₁) + ..., whose coefficients
+/// are the divided differences of the interpolated values at the nodes xᵢ.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewtonPoly {
+    /// The coefficients aᵢ.
+    pub coef: Vec<f64>,
+    /// The interpolation nodes, as many as the coefficients. The last one is not a center of
+    /// the basis, but is kept for the derivative and conversions.
+    pub nodes: Vec<f64>,
+}
+
+/// Returns the divided differences f[x₀], f[x₀, x₁], ... of `values` at the distinct `nodes`.
+fn divided_differences(nodes: &[f64], values: &[f64]) -> Vec<f64> {
+    let mut coef = values.to_vec();
+    for k in 1..coef.len() {
+        for i in (k..coef.len()).rev() {
+            coef[i] = (coef[i] - coef[i - 1]) / (nodes[i] - nodes[i - k]);
+        }
+    }
+    coef
+}
+
+impl NewtonPoly {
+    /// Returns the value and the derivative at `x`, with the nested multiplication of Newton's
+    /// form.
+    fn eval_with_derivative(&self, x: f64) -> (f64, f64) {
+        let n = self.coef.len();
+        let (mut p, mut dp) = (self.coef.last().copied().unwrap_or(0.0), 0.0);
+        for k in (0..n.saturating_sub(1)).rev() {
+            let d = x - self.nodes[k];
+            dp = dp * d + p;
+            p = p * d + self.coef[k];
+        }
+        (p, dp)
+    }
+}
+
+impl PolynomialBasis for NewtonPoly {
+    type Params = Vec<f64>;
+
+    fn coefficients(&self) -> &[f64] {
+        &self.coef
+    }
+
+    fn eval(&self, x: f64) -> f64 {
+        self.eval_with_derivative(x).0
+    }
+
+    /// Differentiates by interpolating the derivative at all but the last node.
+    fn derivative(&self) -> Self {
+        let n = self.coef.len().saturating_sub(1).max(1);
+        let nodes = self.nodes[..n.min(self.nodes.len())].to_vec();
+        let values: Vec<f64> = match self.coef.len() {
+            0 | 1 => vec![0.0; nodes.len()],
+            _ => nodes
+                .iter()
+                .map(|x| self.eval_with_derivative(*x).1)
+                .collect(),
+        };
+        NewtonPoly {
+            coef: divided_differences(&nodes, &values),
+            nodes,
+        }
+    }
+
+    /// Interpolates at the first degree+1 of the nodes in `params`, with divided differences.
+    fn interpolate(f: impl Fn(f64) -> f64, degree: usize, nodes: &Vec<f64>) -> Result<Self> {
+        if nodes.len() < degree + 1 {
+            return Err(PolyError::NotEnoughPoints(degree + 1));
+        }
+        let nodes = nodes[..=degree].to_vec();
+        if nodes.iter().any(|x| !x.is_finite()) {
+            return Err(PolyError::InvalidData);
+        }
+        let mut sorted = nodes.clone();
+        sorted.sort_by(f64::total_cmp);
+        if sorted.windows(2).any(|w| w[0] == w[1]) {
+            return Err(PolyError::SingularMatrix);
+        }
+
+        let values = nodes
+            .iter()
+            .map(|x| eval_checked(&f, *x))
+            .collect::<Result<Vec<f64>>>()?;
+        Ok(NewtonPoly {
+            coef: divided_differences(&nodes, &values),
+            nodes,
+        })
+    }
+}
@@ -0,0 +1,144 @@
+//! Evaluation of expansions Σcᵢφᵢ(x) in a basis of polynomials φᵢ.
+
+use crate::{PolyError, Polynomial, Result};
+
+impl<T> Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Evaluates the expansion Σcᵢφᵢ(x) with coefficients `coeffs` in the basis `basis` at `x`,
+    /// without converting it to monomial form first.
+    ///
+    /// For repeated evaluations in the same basis, use a [`BasisEvaluator`].
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `coeffs` and `basis` have different lengths.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// // The first Chebyshev polynomials 1, x, 2x²-1
+    /// let basis = [
+    ///     Polynomial::build(&[1.0])?,
+    ///     Polynomial::build(&[0.0, 1.0])?,
+    ///     Polynomial::build(&[-1.0, 0.0, 2.0])?,
+    /// ];
+    ///
+    /// assert_eq!(Polynomial::eval_in_basis(&[1.0, 2.0, 3.0], &basis, 0.5)?, 0.5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_in_basis(coeffs: &[T], basis: &[Polynomial<T>], x: T) -> Result<T> {
+        if coeffs.len() != basis.len() {
+            return Err(PolyError::LengthMismatch(coeffs.len(), basis.len()));
+        }
+        Ok(coeffs
+            .iter()
+            .zip(basis)
+            .fold(T::zero(), |res, (c, phi)| res + *c * phi.eval(x)))
+    }
+}
+
+/// A precomputed polynomial basis φ₀, ..., φₙ₋₁, for the repeated evaluation of expansions
+/// Σcᵢφᵢ(x).
+///
+/// The basis' coefficients are stored by power, so that the Horner recurrences of all the basis
+/// members advance together, over contiguous memory.
+#[derive(Clone, Debug)]
+pub struct BasisEvaluator<T>
+where
+    T: std::fmt::Debug,
+{
+    /// `by_power[k][i]` is the coefficient of xᵏ in φᵢ.
+    by_power: Vec<Vec<T>>,
+}
+
+impl<T> BasisEvaluator<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Creates a new evaluator for the given basis.
+    pub fn new(basis: &[Polynomial<T>]) -> Self {
+        let len = basis.iter().map(|phi| phi.coef.len()).max().unwrap_or(0);
+        let by_power = (0..len)
+            .map(|k| {
+                basis
+                    .iter()
+                    .map(|phi| phi.coef.get(k).copied().unwrap_or(T::zero()))
+                    .collect()
+            })
+            .collect();
+        BasisEvaluator { by_power }
+    }
+
+    /// Returns the number of basis members.
+    pub fn len(&self) -> usize {
+        self.by_power.first().map_or(0, |row| row.len())
+    }
+
+    /// Returns `true` if the basis has no members.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Evaluates all the basis members at `x`, writing φᵢ(x) to `out[i]`. No allocation takes
+    /// place.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the length of `out` is not equal to the number of basis members.
+    pub fn values(&self, x: T, out: &mut [T]) -> Result<()> {
+        if out.len() != self.len() {
+            return Err(PolyError::LengthMismatch(out.len(), self.len()));
+        }
+
+        out.iter_mut().for_each(|v| *v = T::zero());
+        for row in self.by_power.iter().rev() {
+            out.iter_mut().zip(row).for_each(|(v, c)| *v = *c + x * *v);
+        }
+        Ok(())
+    }
+
+    /// Evaluates the expansion Σcᵢφᵢ(x) with coefficients `coeffs` at `x`. No allocation takes
+    /// place.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the length of `coeffs` is not equal to the number of basis members.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{BasisEvaluator, Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let basis = [
+    ///     Polynomial::build(&[1.0])?,
+    ///     Polynomial::build(&[0.0, 1.0])?,
+    ///     Polynomial::build(&[-1.0, 0.0, 2.0])?,
+    /// ];
+    /// let evaluator = BasisEvaluator::new(&basis);
+    ///
+    /// assert_eq!(evaluator.eval(&[1.0, 2.0, 3.0], 0.5)?, 0.5);
+    /// assert_eq!(evaluator.eval(&[0.0, 0.0, 1.0], 1.0)?, 1.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval(&self, coeffs: &[T], x: T) -> Result<T> {
+        if coeffs.len() != self.len() {
+            return Err(PolyError::LengthMismatch(coeffs.len(), self.len()));
+        }
+
+        // Σcᵢφᵢ(x) = Σₖxᵏ(Σᵢcᵢφᵢₖ), so a single Horner recurrence over the combined coefficients
+        // of every power suffices, without materialising the values φᵢ(x)
+        Ok(self.by_power.iter().rev().fold(T::zero(), |res, row| {
+            let combined = coeffs
+                .iter()
+                .zip(row)
+                .fold(T::zero(), |sum, (c, phi)| sum + *c * *phi);
+            combined + x * res
+        }))
+    }
+}
@@ -0,0 +1,154 @@
+//! Solvers for large batches of small equations, e.g. millions of ray-surface intersections per
+//! frame.
+//!
+//! The equations are given as arrays of coefficients, leading coefficient first, like the
+//! arguments of the [`solve`](crate::solve) functions. They are processed in chunks that are
+//! transposed to a structure-of-arrays layout, so that the validation and normalization of the
+//! coefficients run as straight-line loops over contiguous lanes, which the compiler can
+//! vectorize. The (branchy) root formulas then run lane by lane on the normalized coefficients.
+//!
+//! The result of every equation is independent, so a degenerate equation only results in an
+//! error at its own position.
+
+use crate::solve::{
+    solve_real_cubic_structured, solve_real_quadratic_structured, solve_real_quartic_arr,
+};
+use crate::utils::{check_finite_coef, order};
+use crate::{CubicRoots, PolyError, QuadraticRoots, Result};
+
+/// Number of equations that are processed together.
+const LANES: usize = 8;
+
+/// Transposes a chunk of at most [`LANES`] equations to one array per coefficient, normalized by
+/// the leading coefficient. Returns the normalized coefficients, and whether every lane is valid,
+/// i.e. finite with a non-zero leading coefficient.
+fn normalize_chunk<const N: usize>(chunk: &[[f64; N]]) -> ([[f64; LANES]; N], [bool; LANES]) {
+    let mut soa = [[0.0; LANES]; N];
+    for (lane, coef) in chunk.iter().enumerate() {
+        for (k, c) in coef.iter().enumerate() {
+            soa[k][lane] = *c;
+        }
+    }
+
+    let mut valid = [true; LANES];
+    for row in soa.iter() {
+        valid
+            .iter_mut()
+            .zip(row)
+            .for_each(|(v, c)| *v &= c.is_finite());
+    }
+    let mut inv = [0.0; LANES];
+    inv.iter_mut()
+        .zip(&soa[0])
+        .zip(&mut valid)
+        .for_each(|((i, a), v)| {
+            *v &= *a != 0.0;
+            *i = 1.0 / a;
+        });
+    for row in soa.iter_mut() {
+        row.iter_mut().zip(&inv).for_each(|(c, i)| *c *= i);
+    }
+
+    (soa, valid)
+}
+
+/// Solves every chunk of equations, calling `solve` with the normalized coefficients of each
+/// valid lane. Invalid lanes result in `invalid(coef)`.
+fn solve_batch<const N: usize, R>(
+    coefs: &[[f64; N]],
+    invalid: impl Fn(&[f64; N]) -> PolyError,
+    solve: impl Fn([f64; N]) -> Result<R>,
+) -> Vec<Result<R>> {
+    let mut res = Vec::with_capacity(coefs.len());
+    for chunk in coefs.chunks(LANES) {
+        let (soa, valid) = normalize_chunk(chunk);
+        for (lane, coef) in chunk.iter().enumerate() {
+            res.push(match valid[lane] {
+                true => solve(std::array::from_fn(|k| soa[k][lane])),
+                false => Err(invalid(coef)),
+            });
+        }
+    }
+    res
+}
+
+/// Returns the error of an equation with non-finite coefficients or a zero leading coefficient.
+fn invalid_error<const N: usize>(coef: &[f64; N]) -> PolyError {
+    let err = check_finite_coef(coef.iter().copied()).err();
+    err.unwrap_or_else(|| {
+        let reversed: [f64; N] = std::array::from_fn(|k| coef[N - 1 - k]);
+        PolyError::IncorrectOrder {
+            expected: N - 1,
+            got: order(&reversed),
+        }
+    })
+}
+
+/// Calculates the **real** roots of the quadratic equations `ax²+bx+c = 0`, given as `[a, b, c]`.
+///
+/// Unlike [`solve::quadratic`](crate::solve::quadratic), an equation with a=0 results in an
+/// error, instead of being solved as a linear equation.
+///
+/// # Error
+///
+/// The result of an equation is an error if its coefficients are NaN or Infinity, or if a=0.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{QuadraticRoots, batch};
+/// let roots = batch::solve_real_quadratic_batch(&[[1.0, -3.0, 2.0], [1.0, 0.0, 1.0]]);
+///
+/// assert_eq!(roots[0].as_ref().unwrap(), &QuadraticRoots::Two(1.0, 2.0));
+/// assert_eq!(roots[1].as_ref().unwrap(), &QuadraticRoots::None);
+/// ```
+pub fn solve_real_quadratic_batch(coefs: &[[f64; 3]]) -> Vec<Result<QuadraticRoots>> {
+    solve_batch(coefs, invalid_error, |[a, b, c]| {
+        solve_real_quadratic_structured(a, b, c)
+    })
+}
+
+/// Calculates the **real** roots of the cubic equations `ax³+bx²+cx+d = 0`, given as
+/// `[a, b, c, d]`.
+///
+/// # Error
+///
+/// The result of an equation is an error if its coefficients are NaN or Infinity, or if a=0.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{CubicRoots, batch};
+/// let roots = batch::solve_real_cubic_batch(&[[1.0, -6.0, 11.0, -6.0], [0.0, 1.0, 1.0, 1.0]]);
+///
+/// assert_eq!(roots[0].as_ref().unwrap(), &CubicRoots::Three([1.0, 2.0, 3.0]));
+/// assert!(roots[1].is_err());
+/// ```
+pub fn solve_real_cubic_batch(coefs: &[[f64; 4]]) -> Vec<Result<CubicRoots>> {
+    solve_batch(coefs, invalid_error, |[_, b, c, d]| {
+        Ok(solve_real_cubic_structured(b, c, d))
+    })
+}
+
+/// Calculates the **real** roots of the quartic equations `ax⁴+bx³+cx²+dx+e = 0`, given as
+/// `[a, b, c, d, e]`, like [`solve::quartic`](crate::solve::quartic).
+///
+/// # Error
+///
+/// The result of an equation is an error if its coefficients are NaN or Infinity, or if a=0.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::batch;
+/// let roots = batch::solve_real_quartic_batch(&[[1.0, 0.0, -5.0, 0.0, 4.0]]);
+/// let (x, n) = roots[0].as_ref().unwrap();
+///
+/// assert_eq!(*n, 4);
+/// assert!(x.iter().zip([-2.0, -1.0, 1.0, 2.0]).all(|(x, r)| (x - r).abs() < 1e-12));
+/// ```
+pub fn solve_real_quartic_batch(coefs: &[[f64; 5]]) -> Vec<Result<([f64; 4], usize)>> {
+    solve_batch(coefs, invalid_error, |[_, b, c, d, e]| {
+        Ok(solve_real_quartic_arr(b, c, d, e))
+    })
+}
@@ -0,0 +1,216 @@
+//! Real root isolation with Bézier clipping.
+
+use crate::utils::{check_finite_coef, compose_linear};
+use crate::{PolyError, Polynomial, Result};
+
+/// Minimum fraction by which a clipping step must shrink an interval; otherwise the interval is
+/// subdivided instead.
+const MIN_CLIP_REDUCTION: f64 = 0.2;
+
+/// Safety factor of the rounding error bounds of the control points.
+const ERROR_FACTOR: f64 = 4.0;
+
+impl Polynomial<f64> {
+    /// Finds the real roots of the polynomial in [a, b] with Bézier clipping, returning disjoint
+    /// intervals, in increasing order, that enclose them.
+    ///
+    /// The polynomial is converted to the Bernstein basis on [a, b]. By the convex hull property,
+    /// the polynomial cannot vanish outside the interval where the convex hull of its control
+    /// points crosses 0, so that part is clipped away, and intervals that can not be clipped
+    /// effectively are subdivided. The control points are widened by a bound of their rounding
+    /// errors before clipping, so only intervals that are proven to contain no roots are
+    /// discarded, and every root in [a, b] lies in one of the returned intervals.
+    ///
+    /// Every interval is at most `tol` wide, unless adjacent intervals were merged (e.g. around a
+    /// cluster of roots), or the rounding errors of the control points make the sign of the
+    /// polynomial undecidable across a wider interval (e.g. around a multiple root). An interval
+    /// may therefore also be returned where the polynomial only comes close to 0.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the polynomial is zero or has NaN or Infinity coefficients, if [a, b] is
+    /// not a finite interval, or if `tol` is not positive.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-6.0, 11.0, -6.0, 1.0])?; // (x−1)(x−2)(x−3)
+    /// let roots = poly.roots_in_interval_bezier(0.0, 2.5, 1e-10)?;
+    ///
+    /// assert_eq!(roots.len(), 2);
+    /// assert!(roots[0].0 <= 1.0 && 1.0 <= roots[0].1);
+    /// assert!(roots[1].0 <= 2.0 && 2.0 <= roots[1].1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn roots_in_interval_bezier(&self, a: f64, b: f64, tol: f64) -> Result<Vec<(f64, f64)>> {
+        if !(a.is_finite() && b.is_finite() && a < b) {
+            return Err(PolyError::InvalidInterval(a, b));
+        }
+        if tol.is_nan() || tol <= 0.0 {
+            return Err(PolyError::InvalidData);
+        }
+        check_finite_coef(self.coef.iter().copied())?;
+        let order = match self.coef.iter().rposition(|c| *c != 0.0) {
+            Some(order) => order,
+            None => return Err(PolyError::Trivial),
+        };
+        if order == 0 {
+            return Ok(Vec::new());
+        }
+
+        // P(a + (b−a)t) for t in [0, 1]
+        let trimmed = Polynomial {
+            coef: self.coef[..=order].to_vec(),
+        };
+        let local = compose_linear(&trimmed, b - a, a);
+        let control = to_bernstein(&local.coef);
+
+        // Rounding errors of the composition, bounded by Σ|cₖ|(|a|+|b−a|)ᵏ, and of the basis
+        // conversion, bounded by Σ|qₖ|
+        let scale = a.abs() + (b - a);
+        let composed = trimmed
+            .coef
+            .iter()
+            .rev()
+            .fold(0.0, |res, c| c.abs() + scale * res);
+        let converted: f64 = local.coef.iter().map(|c| c.abs()).sum();
+        let err = rounding_bound(order, composed + converted);
+
+        let mut intervals = Vec::new();
+        clip(&control, err, 0.0, 1.0, tol / (b - a), &mut intervals);
+
+        // Merge the intervals that touch or overlap
+        intervals.sort_by(|x, y| x.0.total_cmp(&y.0));
+        let mut merged: Vec<(f64, f64)> = Vec::new();
+        for (lo, hi) in intervals {
+            match merged.last_mut() {
+                Some(last) if lo <= last.1 => last.1 = last.1.max(hi),
+                _ => merged.push((lo, hi)),
+            }
+        }
+
+        // Widen the intervals by the rounding error of the mapping back to [a, b]
+        let pad = f64::EPSILON * scale;
+        Ok(merged
+            .into_iter()
+            .map(|(lo, hi)| {
+                let lo = (a + (b - a) * lo - pad).max(a);
+                let hi = (a + (b - a) * hi + pad).min(b);
+                (lo, hi)
+            })
+            .collect())
+    }
+}
+
+/// Bound of the accumulated rounding error of `n` floating point operations on values of total
+/// magnitude `magnitude`.
+fn rounding_bound(n: usize, magnitude: f64) -> f64 {
+    ERROR_FACTOR * (n + 1) as f64 * f64::EPSILON * magnitude
+}
+
+/// Converts the monomial coefficients of a polynomial on [0, 1] to Bernstein coefficients.
+fn to_bernstein(coef: &[f64]) -> Vec<f64> {
+    let n = coef.len() - 1;
+    // C(j, k)/C(n, k), built row by row
+    (0..=n)
+        .map(|j| {
+            let mut ratio = 1.0;
+            let mut res = coef[0];
+            for (k, c) in coef[1..=j].iter().enumerate() {
+                ratio *= (j - k) as f64 / (n - k) as f64;
+                res += ratio * c;
+            }
+            res
+        })
+        .collect()
+}
+
+/// Splits the Bernstein coefficients at the parameter `t` with de Casteljau's algorithm,
+/// returning the coefficients on [0, t] and [t, 1].
+fn de_casteljau(control: &[f64], t: f64) -> (Vec<f64>, Vec<f64>) {
+    let n = control.len();
+    let mut work = control.to_vec();
+    let mut left = Vec::with_capacity(n);
+    let mut right = Vec::with_capacity(n);
+    for k in 0..n {
+        left.push(work[0]);
+        right.push(work[n - 1 - k]);
+        for i in 0..n - 1 - k {
+            work[i] = (1.0 - t) * work[i] + t * work[i + 1];
+        }
+    }
+    right.reverse();
+    (left, right)
+}
+
+/// Returns the interval of parameters where the convex hull of the control points, each
+/// widened to [bᵢ − err, bᵢ + err], crosses 0, or `None` if it does not.
+fn hull_crossing(control: &[f64], err: f64) -> Option<(f64, f64)> {
+    let n = (control.len() - 1) as f64;
+    let points: Vec<(f64, f64)> = control
+        .iter()
+        .enumerate()
+        .flat_map(|(i, b)| [(i as f64 / n, b - err), (i as f64 / n, b + err)])
+        .collect();
+
+    let (mut lo, mut hi) = (f64::INFINITY, f64::NEG_INFINITY);
+    for (i, (ti, yi)) in points.iter().enumerate() {
+        if *yi == 0.0 {
+            lo = lo.min(*ti);
+            hi = hi.max(*ti);
+        }
+        for (tj, yj) in points.iter().skip(i + 1) {
+            if yi * yj < 0.0 {
+                let t = ti + (tj - ti) * yi / (yi - yj);
+                lo = lo.min(t);
+                hi = hi.max(t);
+            }
+        }
+    }
+    (lo <= hi).then_some((lo.clamp(0.0, 1.0), hi.clamp(0.0, 1.0)))
+}
+
+/// Recursively clips the Bézier curve with the given control points, whose absolute error is
+/// bounded by `err`, on [t0, t1], appending the intervals that may contain a root.
+fn clip(control: &[f64], err: f64, t0: f64, t1: f64, tol: f64, out: &mut Vec<(f64, f64)>) {
+    let Some((lo, hi)) = hull_crossing(control, err) else {
+        return;
+    };
+    let width = t1 - t0;
+    let (new_t0, new_t1) = (t0 + width * lo, t0 + width * hi);
+
+    // Either narrow enough, or the sign of the polynomial can not be decided anywhere in the
+    // interval
+    if (new_t1 - new_t0 <= tol) | control.iter().all(|b| b.abs() <= err) {
+        out.push((new_t0, new_t1));
+        return;
+    }
+
+    // Every de Casteljau step is a convex combination, so the existing errors do not grow, but
+    // new rounding errors are added
+    let n = control.len() - 1;
+    let magnitude = control.iter().fold(0.0, |max: f64, b| max.max(b.abs()));
+    let step_err = rounding_bound(n, magnitude);
+
+    if hi - lo > 1.0 - MIN_CLIP_REDUCTION {
+        let mid = t0 + 0.5 * width;
+        // Stop once the interval can no longer be split in floating point
+        if mid <= t0 || mid >= t1 {
+            out.push((t0, t1));
+            return;
+        }
+        let (left, right) = de_casteljau(control, 0.5);
+        clip(&left, err + step_err, t0, mid, tol, out);
+        clip(&right, err + step_err, mid, t1, tol, out);
+    } else {
+        let (_, right) = de_casteljau(control, lo);
+        let (clipped, _) = match lo < 1.0 {
+            true => de_casteljau(&right, (hi - lo) / (1.0 - lo)),
+            false => (right.clone(), right),
+        };
+        clip(&clipped, err + 2.0 * step_err, new_t0, new_t1, tol, out);
+    }
+}
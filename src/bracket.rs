@@ -0,0 +1,105 @@
+//! Safeguarded Newton iteration for a real root inside a bracket.
+
+use crate::{PolyError, Polynomial, Result};
+
+/// Options of [`Polynomial::root_in_bracket`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BracketOptions {
+    /// Absolute tolerance of the root.
+    pub xtol: f64,
+    /// Relative tolerance of the root.
+    pub rtol: f64,
+    /// Maximum number of iterations.
+    pub max_iter: usize,
+}
+
+impl Default for BracketOptions {
+    /// A relative tolerance of 4·EPSILON, no absolute tolerance and 100 iterations.
+    fn default() -> Self {
+        BracketOptions {
+            xtol: 0.0,
+            rtol: 4.0 * f64::EPSILON,
+            max_iter: 100,
+        }
+    }
+}
+
+impl Polynomial<f64> {
+    /// Finds a real root of the polynomial in `[a, b]`, where P(a) and P(b) have opposite signs.
+    ///
+    /// Newton's method is used while its steps stay inside the current bracket and shrink fast
+    /// enough, and bisection otherwise, so that the iteration always converges, quadratically for
+    /// simple roots. It stops when the step (or the bracket) is smaller than
+    /// `xtol + rtol·|x|`.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `[a, b]` is not a valid finite interval, if P(a) and P(b) have the same
+    /// sign, or if the tolerance is not reached within `max_iter` iterations.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{BracketOptions, Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-2.0, 0.0, 1.0])?; // x²-2
+    /// let root = poly.root_in_bracket(0.0, 2.0, BracketOptions::default())?;
+    ///
+    /// assert!((root - 2f64.sqrt()).abs() < 1e-15);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn root_in_bracket(&self, a: f64, b: f64, opts: BracketOptions) -> Result<f64> {
+        if !(a.is_finite() && b.is_finite() && a < b) {
+            return Err(PolyError::InvalidInterval(a, b));
+        }
+
+        let (fa, fb) = (self.eval(a), self.eval(b));
+        if fa == 0.0 {
+            return Ok(a);
+        }
+        if fb == 0.0 {
+            return Ok(b);
+        }
+        if fa.signum() == fb.signum() {
+            return Err(PolyError::RootNotBracketed(a, b));
+        }
+
+        // Orient the bracket so that P(lo) < 0 < P(hi)
+        let (mut lo, mut hi) = if fa < 0.0 { (a, b) } else { (b, a) };
+        let deriv = self.derivative();
+
+        let mut x = 0.5 * (a + b);
+        let mut dx_old = b - a;
+        let mut dx = dx_old;
+
+        for _ in 0..opts.max_iter {
+            let (f, df) = Polynomial::eval_pair(self, &deriv, x);
+            if f == 0.0 {
+                return Ok(x);
+            }
+            match f < 0.0 {
+                true => lo = x,
+                false => hi = x,
+            }
+
+            let out_of_bracket = ((x - hi) * df - f) * ((x - lo) * df - f) > 0.0;
+            let too_slow = (2.0 * f).abs() > (dx_old * df).abs();
+            dx_old = dx;
+            if out_of_bracket || too_slow {
+                dx = 0.5 * (hi - lo);
+                x = lo + dx;
+            } else {
+                dx = f / df;
+                x -= dx;
+            }
+
+            let tol = opts.xtol + opts.rtol * x.abs();
+            if (dx.abs() <= tol) || ((hi - lo).abs() <= tol) {
+                return Ok(x);
+            }
+        }
+
+        Err(PolyError::NoConvergence)
+    }
+}
@@ -0,0 +1,63 @@
+//! Differentiation and integration of polynomials.
+
+use crate::Polynomial;
+
+impl<T> Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Returns the derivative of the polynomial.
+    ///
+    /// The derivative of a constant polynomial is the zero polynomial.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 4.0, 3.0])?; // 1+4x+3x²
+    ///
+    /// assert_eq!(poly.derivative().coef, [4.0, 6.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn derivative(&self) -> Self {
+        if self.coef.len() <= 1 {
+            return Polynomial::new();
+        }
+
+        let coef = self
+            .coef
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| *c * T::from(i).unwrap())
+            .collect();
+        Polynomial { coef }
+    }
+
+    /// Returns the antiderivative of the polynomial with a zero constant term.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[4.0, 6.0])?; // 4+6x
+    ///
+    /// assert_eq!(poly.antiderivative().coef, [0.0, 4.0, 3.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn antiderivative(&self) -> Self {
+        let coef = std::iter::once(T::zero())
+            .chain(
+                self.coef
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| *c / T::from(i + 1).unwrap()),
+            )
+            .collect();
+        Polynomial { coef }
+    }
+}
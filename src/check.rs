@@ -0,0 +1,128 @@
+//! Internal consistency checks of a polynomial's evaluation, solvers and normalization.
+
+use num::complex::Complex64;
+
+use crate::utils::{check_finite_coef, convert_to_complex64, to_f64};
+use crate::{PolyErrorKind, Polynomial, Result, RootReport};
+
+/// Real points at which the evaluation routines are compared, along with the real parts of the
+/// roots.
+const EVAL_POINTS: [f64; 7] = [0.0, 0.5, -0.5, 1.0, -1.0, 2.0, -2.0];
+
+/// The results of [`Polynomial::self_check`]. Every error is relative, so that it can be compared
+/// with a multiple of the machine epsilon.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelfCheckReport {
+    /// The largest difference between [`eval`](Polynomial::eval) and the value returned by
+    /// [`eval_derivs`](Polynomial::eval_derivs), relative to Σ|aⱼ||x|ʲ.
+    pub eval_consistency: f64,
+    /// The [`RootReport`] of the roots found by [`solve_complex`](Polynomial::solve_complex), or
+    /// the kind of the error it returned, e.g. [`PolyErrorKind::ConstantPoly`] for a constant
+    /// polynomial or [`PolyErrorKind::NoConvergence`].
+    pub roots: std::result::Result<RootReport, PolyErrorKind>,
+    /// The largest difference between the polynomial and its [`to_monic`](Polynomial::to_monic)
+    /// version scaled back by the leading coefficient, relative to the largest coefficient.
+    pub monic_round_trip: f64,
+}
+
+impl SelfCheckReport {
+    /// Returns `true` if every error is at most `tol`, and the roots were found unless the
+    /// polynomial is constant.
+    pub fn passes(&self, tol: f64) -> bool {
+        let roots = match &self.roots {
+            Ok(report) => report.passes(tol),
+            Err(kind) => *kind == PolyErrorKind::ConstantPoly,
+        };
+        roots && self.eval_consistency <= tol && self.monic_round_trip <= tol
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Runs internal consistency checks, e.g. as a debug assertion when the results for some
+    /// inputs are suspect:
+    ///
+    /// - [`eval`](Polynomial::eval) against the value returned by
+    ///   [`eval_derivs`](Polynomial::eval_derivs), at a few real points and the real parts of the
+    ///   roots,
+    /// - the residuals of the roots found by [`solve_complex`](Polynomial::solve_complex), and
+    ///   the polynomial reconstructed from them,
+    /// - the round trip through [`to_monic`](Polynomial::to_monic).
+    ///
+    /// A solver failure is part of the report rather than an error.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if any of the coefficients is NaN or Infinity.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-6.0, 11.0, -6.0, 1.0])?;
+    /// let report = poly.self_check()?;
+    ///
+    /// assert!(report.passes(1e-14));
+    /// assert_eq!(report.roots.unwrap().residuals.len(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn self_check(&self) -> Result<SelfCheckReport> {
+        check_finite_coef(self.coef.iter().copied())?;
+        let complex = Polynomial {
+            coef: self
+                .coef
+                .iter()
+                .map(|c| convert_to_complex64(*c))
+                .collect::<Vec<_>>(),
+        };
+
+        let roots = self.solve_complex();
+        let mut points: Vec<f64> = EVAL_POINTS.to_vec();
+        if let Ok(roots) = &roots {
+            points.extend(roots.iter().map(|z| z.re));
+        }
+        let eval_consistency = points
+            .iter()
+            .filter_map(|x| T::from(*x))
+            .map(|x| {
+                let diff = to_f64((self.eval(x) - self.eval_derivs(x, 1)[0]).abs());
+                let scale = self
+                    .coef
+                    .iter()
+                    .rev()
+                    .fold(0.0, |acc, c| to_f64(c.abs()) + to_f64(x.abs()) * acc);
+                if scale == 0.0 { diff } else { diff / scale }
+            })
+            .fold(0.0, f64::max);
+
+        Ok(SelfCheckReport {
+            eval_consistency,
+            roots: roots
+                .map(|roots| complex.verify_roots(&roots))
+                .map_err(|err| err.kind()),
+            monic_round_trip: monic_round_trip(&complex),
+        })
+    }
+}
+
+/// Returns the largest difference between `poly` and its monic version times the leading
+/// coefficient, relative to the largest coefficient.
+fn monic_round_trip(poly: &Polynomial<Complex64>) -> f64 {
+    // Constants are left as is by `to_monic`
+    let trimmed = poly.to_trimmed();
+    let leading = match trimmed.coef[..] {
+        [] | [_] => return 0.0,
+        [.., leading] => leading,
+    };
+    let scale = trimmed.coef.iter().map(|c| c.norm()).fold(0.0, f64::max);
+    trimmed
+        .coef
+        .iter()
+        .zip(&trimmed.to_monic().coef)
+        .map(|(a, m)| (a - m * leading).norm() / scale)
+        .fold(0.0, f64::max)
+}
@@ -0,0 +1,159 @@
+//! Drop-in equivalents of NumPy's `polyval`, `polyfit` and `roots`, to port code from NumPy or
+//! MATLAB without reordering coefficients by hand.
+//!
+//! Like NumPy's, these functions take and return the coefficients in descending power order,
+//! from leading to constant term, unlike [`Polynomial::coef`].
+//!
+//! ## Example
+//!
+//! ```
+//! # use rsl_polynomials::Result;
+//! use rsl_polynomials::compat;
+//!
+//! # fn main() -> Result<()> {
+//! // np.polyfit(x, y, 2) → array([ 2., -3.,  1.])
+//! let xs = [0.0, 1.0, 2.0, 3.0];
+//! let ys = [1.0, 0.0, 3.0, 10.0];
+//! let p = compat::polyfit(&xs, &ys, 2)?;
+//!
+//! assert!(p.iter().zip([2.0, -3.0, 1.0]).all(|(a, b)| (a - b).abs() < 1e-12));
+//! assert!((compat::polyval(&p, 4.0) - 21.0).abs() < 1e-12);
+//! # Ok(())
+//! # }
+//! ```
+
+use num::complex::Complex64;
+
+use crate::{PolyError, Polynomial, Result, linalg};
+
+/// Evaluates the polynomial with coefficients `p`, in descending power order, at `x`, like
+/// `np.polyval(p, x)` for a scalar `x`. The polynomial with no coefficients is 0.
+///
+/// For an array `x`, map over its elements.
+///
+/// ## Example
+///
+/// ```
+/// use rsl_polynomials::compat::polyval;
+///
+/// assert_eq!(polyval(&[3.0, 0.0, 1.0], 5.0), 76.0); // 3x² + 1
+/// ```
+pub fn polyval<T>(p: &[T], x: T) -> T
+where
+    T: num::complex::ComplexFloat,
+{
+    p.iter().fold(T::zero(), |acc, c| acc * x + *c)
+}
+
+/// Fits a polynomial of order `deg` to the points `(xs[i], ys[i])` by least squares, like
+/// `np.polyfit(x, y, deg)`, returning its `deg+1` coefficients in descending power order.
+///
+/// See [`polyfit_rcond`] for the handling of rank-deficient problems; this uses NumPy's default
+/// relative condition number `len(x)·ε`.
+///
+/// # Error
+///
+/// Returns an error if the slices have different lengths, are empty, or contain NaN or Infinity.
+pub fn polyfit(xs: &[f64], ys: &[f64], deg: usize) -> Result<Vec<f64>> {
+    polyfit_rcond(xs, ys, deg, xs.len() as f64 * f64::EPSILON)
+}
+
+/// Same as [`polyfit`], with the relative condition number `rcond` of `np.polyfit(x, y, deg,
+/// rcond)`.
+///
+/// As in NumPy, the columns of the Vandermonde matrix are scaled to unit norm, and its singular
+/// values smaller than `rcond` times the largest one are treated as 0. Rank-deficient problems,
+/// e.g. with fewer distinct points than coefficients, are not an error: the minimum-norm solution
+/// is returned, as NumPy does after its `RankWarning`.
+///
+/// # Error
+///
+/// Returns an error if the slices have different lengths, are empty, or contain NaN or Infinity.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::Result;
+/// use rsl_polynomials::compat::polyfit_rcond;
+///
+/// # fn main() -> Result<()> {
+/// // A line through a single point: the minimum-norm solution of the scaled problem
+/// let p = polyfit_rcond(&[1.0, 1.0], &[2.0, 2.0], 1, 1e-12)?;
+///
+/// assert!(p.iter().all(|c| (c - 1.0).abs() < 1e-12));
+/// # Ok(())
+/// # }
+/// ```
+pub fn polyfit_rcond(xs: &[f64], ys: &[f64], deg: usize, rcond: f64) -> Result<Vec<f64>> {
+    if xs.len() != ys.len() {
+        return Err(PolyError::LengthMismatch(xs.len(), ys.len()));
+    }
+    if xs.is_empty() {
+        return Err(PolyError::NotEnoughPoints(1));
+    }
+    if xs.iter().chain(ys).any(|v| !v.is_finite()) {
+        return Err(PolyError::InvalidData);
+    }
+
+    // Vandermonde matrix with columns xᵈᵉᵍ, ..., x, 1
+    let mut design: Vec<Vec<f64>> = xs
+        .iter()
+        .map(|x| (0..=deg).rev().map(|j| x.powi(j as i32)).collect())
+        .collect();
+    let scale: Vec<f64> = (0..=deg)
+        .map(|j| design.iter().map(|row| row[j] * row[j]).sum::<f64>().sqrt())
+        .map(|norm| if norm == 0.0 { 1.0 } else { norm })
+        .collect();
+    design
+        .iter_mut()
+        .for_each(|row| row.iter_mut().zip(&scale).for_each(|(a, s)| *a /= s));
+
+    let coef = linalg::lstsq_svd(&design, ys, rcond);
+    Ok(coef.iter().zip(&scale).map(|(c, s)| c / s).collect())
+}
+
+/// Returns the roots of the polynomial with coefficients `p`, in descending power order, like
+/// `np.roots(p)`.
+///
+/// Leading zeros are ignored, and every trailing zero adds a root at 0, placed after the others.
+/// A constant polynomial, including the zero one, has no roots and is not an error. NumPy returns
+/// a real array when every root is real; here the roots are always complex.
+///
+/// # Error
+///
+/// Returns an error if any of the coefficients is NaN or Infinity, or if the solver fails to
+/// converge.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::Result;
+/// use rsl_polynomials::compat::roots;
+///
+/// # fn main() -> Result<()> {
+/// let r = roots(&[0.0, 1.0, -1.0, 0.0])?; // x² − x
+///
+/// assert_eq!(r.len(), 2);
+/// assert!((r[0].re - 1.0).abs() < 1e-15);
+/// assert_eq!(r[1].re, 0.0);
+/// assert!(roots(&[5.0])?.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+pub fn roots<T>(p: &[T]) -> Result<Vec<Complex64>>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    let poly = Polynomial::build_desc(p)?;
+    let Some(last) = poly.coef.iter().rposition(|c| !c.is_zero()) else {
+        return Ok(Vec::new());
+    };
+    let zeros = poly.coef.iter().take_while(|c| c.is_zero()).count();
+
+    let mut roots = match last - zeros {
+        0 => Vec::new(),
+        _ => Polynomial::from_raw_unchecked(poly.coef[zeros..=last].to_vec()).solve_complex()?,
+    };
+    roots.extend(std::iter::repeat_n(Complex64::new(0.0, 0.0), zeros));
+    Ok(roots)
+}
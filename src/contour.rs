@@ -0,0 +1,139 @@
+//! Counting the complex roots of polynomials inside contours with the argument principle.
+
+use std::f64::consts::PI;
+
+use num::complex::Complex64;
+
+use crate::utils::convert_to_complex64;
+use crate::{PolyError, Polynomial, Result};
+
+/// Initial number of contour points per root of the polynomial.
+const POINTS_PER_ROOT: usize = 8;
+
+/// Maximum change of the argument between neighbouring contour points, above which the arc is
+/// subdivided.
+const MAX_ARG_STEP: f64 = PI / 4.0;
+
+/// Maximum number of arc subdivisions.
+const MAX_DEPTH: usize = 40;
+
+/// Values of |P(z)| below this, relative to Σ|cᵢ||z|ⁱ, are considered to be zero.
+const CONTOUR_TOL: f64 = 1e3 * f64::EPSILON;
+
+impl<T> Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Counts the (complex) roots of the polynomial inside the disk |z − center| < radius, with
+    /// their multiplicities.
+    ///
+    /// The count is the winding number of P(z) around 0 along the disk's boundary (argument
+    /// principle). The boundary is sampled adaptively, so that the argument of P changes by less
+    /// than π/4 between neighbouring points, which makes the count reliable unless a root lies
+    /// (nearly) on the boundary.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the polynomial is zero, if `radius` is not positive and finite, or if
+    /// a root lies too close to the boundary for the count to be reliable.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # use num::complex::Complex64;
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 0.0, 0.0, 0.0, 1.0])?; // roots (±1±i)/√2
+    ///
+    /// assert_eq!(poly.count_roots_in_disk(Complex64::new(0.0, 0.0), 2.0)?, 4);
+    /// assert_eq!(poly.count_roots_in_disk(Complex64::new(1.0, 1.0), 0.5)?, 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn count_roots_in_disk(&self, center: Complex64, radius: f64) -> Result<usize> {
+        if !(radius.is_finite() && radius > 0.0) {
+            return Err(PolyError::InvalidRadius(radius));
+        }
+        let coef: Vec<Complex64> = self.coef.iter().map(|c| convert_to_complex64(*c)).collect();
+        let order = match coef.iter().rposition(|c| *c != Complex64::new(0.0, 0.0)) {
+            Some(order) => order,
+            None => return Err(PolyError::Trivial),
+        };
+
+        let contour = Contour {
+            coef: &coef[..=order],
+            center,
+            radius,
+        };
+        let n = POINTS_PER_ROOT * order.max(1);
+        let thetas: Vec<f64> = (0..=n).map(|k| 2.0 * PI * k as f64 / n as f64).collect();
+        let values = thetas
+            .iter()
+            .map(|theta| contour.eval(*theta))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut total = 0.0;
+        for k in 0..n {
+            total += contour.arg_change(thetas[k], thetas[k + 1], values[k], values[k + 1], 0)?;
+        }
+
+        let winding = total / (2.0 * PI);
+        match (winding - winding.round()).abs() < 0.25 && winding.round() >= 0.0 {
+            true => Ok(winding.round() as usize),
+            false => Err(PolyError::NoConvergence),
+        }
+    }
+}
+
+/// A polynomial evaluated on the circle z = center + radius·eⁱᶿ.
+struct Contour<'a> {
+    coef: &'a [Complex64],
+    center: Complex64,
+    radius: f64,
+}
+
+impl Contour<'_> {
+    /// Evaluates P on the contour, returning an error if the value is indistinguishable from 0.
+    fn eval(&self, theta: f64) -> Result<Complex64> {
+        let z = self.center + Complex64::from_polar(self.radius, theta);
+        let value = self
+            .coef
+            .iter()
+            .rev()
+            .fold(Complex64::new(0.0, 0.0), |res, c| c + z * res);
+        let scale = self
+            .coef
+            .iter()
+            .rev()
+            .fold(0.0, |res, c| c.norm() + z.norm() * res);
+
+        match value.norm() <= CONTOUR_TOL * scale {
+            true => Err(PolyError::RootOnContour),
+            false => Ok(value),
+        }
+    }
+
+    /// Returns the change of the argument of P along the arc [θ₀, θ₁], subdividing it until the
+    /// change between neighbouring points is small.
+    fn arg_change(
+        &self,
+        theta0: f64,
+        theta1: f64,
+        value0: Complex64,
+        value1: Complex64,
+        depth: usize,
+    ) -> Result<f64> {
+        let change = (value1 / value0).arg();
+        if change.abs() <= MAX_ARG_STEP {
+            return Ok(change);
+        }
+        if depth == MAX_DEPTH {
+            return Err(PolyError::RootOnContour);
+        }
+
+        let mid = 0.5 * (theta0 + theta1);
+        let value_mid = self.eval(mid)?;
+        Ok(self.arg_change(theta0, mid, value0, value_mid, depth + 1)?
+            + self.arg_change(mid, theta1, value_mid, value1, depth + 1)?)
+    }
+}
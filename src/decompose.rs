@@ -0,0 +1,105 @@
+//! Functional decomposition of polynomials, P = G∘H.
+
+use num::{Float, Zero};
+
+use crate::Polynomial;
+use crate::utils::cast;
+
+/// Relative tolerance under which the non-constant remainders of the decomposition are
+/// considered to be 0.
+const DECOMPOSE_TOL: f64 = 1e-9;
+
+impl<T> Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Finds polynomials G and H of order at least 2 such that P(x) = G(H(x)), with the method
+    /// of Kozen and Landau, if such a decomposition exists.
+    ///
+    /// The inner polynomial H is monic with H(0) = 0, which makes the decomposition unique for
+    /// a given order of H. The orders of H that divide the order of P are tried in increasing
+    /// order, and the first decomposition found is returned. It can then be applied again to G
+    /// or H, to find a complete decomposition.
+    ///
+    /// For a candidate order s of H, H is determined by the s leading coefficients of P, as the
+    /// approximate r-th root of P (r = n/s). P is then expanded in powers of H, which succeeds if
+    /// every remainder is a constant, up to a relative tolerance of 1e-9.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// // G(y) = y² + 1, H(x) = x² − 3x
+    /// let poly = Polynomial::build(&[1.0, 0.0, 9.0, -6.0, 1.0])?;
+    /// let (g, h) = poly.decompose().unwrap();
+    ///
+    /// assert!(g.approx_eq(&Polynomial::build(&[1.0, 0.0, 1.0])?, 1e-12, 1e-12));
+    /// assert!(h.approx_eq(&Polynomial::build(&[0.0, -3.0, 1.0])?, 1e-12, 1e-12));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decompose(&self) -> Option<(Polynomial<T>, Polynomial<T>)> {
+        let poly = self.to_trimmed();
+        let n = poly.coef.len().checked_sub(1)?;
+        let lead = *poly.coef.last()?;
+        let monic = poly.to_monic();
+
+        (2..n)
+            .filter(|s| n.is_multiple_of(*s))
+            .find_map(|s| decompose_monic(&monic, s))
+            .map(|(mut g, h)| {
+                g.coef.iter_mut().for_each(|c| *c = *c * lead);
+                (g, h)
+            })
+    }
+}
+
+/// Tries to decompose the monic polynomial `poly` of order n as G∘H, with H of order `s`.
+fn decompose_monic<T>(poly: &Polynomial<T>, s: usize) -> Option<(Polynomial<T>, Polynomial<T>)>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    let n = poly.coef.len() - 1;
+    let r = n / s;
+    let r_t = T::from(r)?;
+
+    // The coefficient of xⁿ⁻ᵏ of Hʳ is r·hₛ₋ₖ plus terms of the higher coefficients of H, so
+    // they can be matched against those of P one at a time, from the top.
+    let mut h = Polynomial {
+        coef: vec![T::zero(); s + 1],
+    };
+    h.coef[s] = T::one();
+    for k in 1..s {
+        let power = pow(&h, r);
+        h.coef[s - k] = (poly.coef[n - k] - power.coef[n - k]) / r_t;
+    }
+
+    // Expand P in powers of H, requiring every remainder to be a constant
+    let scale = poly
+        .coef
+        .iter()
+        .map(|c| c.abs())
+        .fold(T::Real::zero(), Float::max);
+    let tol = scale * cast::<T::Real>(DECOMPOSE_TOL);
+
+    let mut g = Vec::with_capacity(r + 1);
+    let mut quotient = poly.clone();
+    for _ in 0..=r {
+        let (q, rem) = quotient.div_rem(&h).ok()?;
+        if rem.coef.iter().skip(1).any(|c| c.abs() > tol) {
+            return None;
+        }
+        g.push(rem.coef[0]);
+        quotient = q;
+    }
+    Some((Polynomial { coef: g }, h))
+}
+
+/// Raises the polynomial to the power `k`, with repeated multiplication.
+fn pow<T>(poly: &Polynomial<T>, k: usize) -> Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    (1..k).fold(poly.clone(), |acc, _| &acc * poly)
+}
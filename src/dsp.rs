@@ -0,0 +1,205 @@
+//! Helpers for digital filter design, e.g. converting a transfer function to a cascade of
+//! second-order sections (biquads).
+
+use num::complex::Complex64;
+
+use crate::{PolyError, Polynomial, Result};
+
+/// How the overall gain of a transfer function is distributed among its second-order sections.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GainDistribution {
+    /// The whole gain is applied to the numerator of the first section, like scipy's `tf2sos`.
+    #[default]
+    First,
+    /// The whole gain is applied to the numerator of the last section.
+    Last,
+    /// Every section gets the same share |k|^(1/n) of the gain, with the sign applied to the
+    /// first one.
+    Even,
+}
+
+/// A monic real factor in z of order 1 or 2, with one of its roots.
+struct Factor {
+    poly: Polynomial<f64>,
+    root: Complex64,
+}
+
+/// Converts the transfer function H = B/A of a digital filter to second-order sections, returned
+/// as rows `[b0, b1, b2, a0, a1, a2]`, with H = ∏(b0 + b1z⁻¹ + b2z⁻²)/(a0 + a1z⁻¹ + a2z⁻²).
+///
+/// Like scipy's `tf2sos`, the coefficients of `num` and `den` are those of the powers of z⁻¹,
+/// i.e. `num.coef[k]` is bₖ in B = b₀ + b₁z⁻¹ + ⋯. Both are factored with
+/// [`factor_real`](Polynomial::factor_real), real roots are paired into quadratics (in increasing
+/// order), and every pair of poles is matched with the nearest remaining pair of zeros, starting
+/// from the poles closest to the unit circle. The sections are sorted by the magnitude of their
+/// poles, so the last section has the poles closest to the unit circle. Every section has a0 = 1,
+/// and the gain b₀/a₀ is applied according to `gain`.
+///
+/// # Error
+///
+/// Returns an error if a₀ = 0, or if the roots of `num` or `den` could not be found.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{Polynomial, Result, dsp};
+/// # fn main() -> Result<()> {
+/// // H = 0.5(1 + z⁻¹)² / ((1 − 0.5z⁻¹)(1 + 0.25z⁻¹))
+/// let num = Polynomial::build(&[0.5, 1.0, 0.5])?;
+/// let den = Polynomial::build(&[1.0, -0.25, -0.125])?;
+/// let sos = dsp::tf2sos(&num, &den, dsp::GainDistribution::First)?;
+///
+/// assert_eq!(sos.len(), 1);
+/// let expected = [0.5, 1.0, 0.5, 1.0, -0.25, -0.125];
+/// assert!(sos[0].iter().zip(expected).all(|(c, e)| (c - e).abs() < 1e-12));
+/// # Ok(())
+/// # }
+/// ```
+pub fn tf2sos(
+    num: &Polynomial<f64>,
+    den: &Polynomial<f64>,
+    gain: GainDistribution,
+) -> Result<Vec<[f64; 6]>> {
+    let a0 = den.coef.first().copied().unwrap_or(0.0);
+    if a0 == 0.0 {
+        return Err(PolyError::DivisionByZero);
+    }
+    let (num, den) = (num.to_trimmed(), den.to_trimmed());
+    let n = num.coef.len().max(den.coef.len()).saturating_sub(1);
+
+    // The polynomials in z, zⁿB and zⁿA
+    let in_z = |p: &Polynomial<f64>| {
+        let mut coef = p.coef.clone();
+        coef.resize(n + 1, 0.0);
+        coef.reverse();
+        Polynomial { coef }
+    };
+    let (b_lead, zeros) = real_factors(&in_z(&num))?;
+    let (_, mut poles) = real_factors(&in_z(&den))?;
+    let k = b_lead / a0;
+
+    // Sections from the poles closest to the unit circle first
+    let distance = |f: &Factor| (max_root_norm(&f.poly) - 1.0).abs();
+    poles.sort_by(|x, y| distance(x).total_cmp(&distance(y)));
+
+    let (mut quadratic, mut linear): (Vec<Factor>, Vec<Factor>) =
+        zeros.into_iter().partition(|f| f.poly.coef.len() == 3);
+    let mut sections: Vec<(Polynomial<f64>, Polynomial<f64>)> = Vec::with_capacity(poles.len());
+    for pole in poles.iter().filter(|p| p.poly.coef.len() == 3) {
+        let zero = take_nearest(&mut quadratic, pole.root);
+        sections.push((zero.map_or(one(), |z| z.poly), pole.poly.clone()));
+    }
+    if let Some(pole) = poles.iter().find(|p| p.poly.coef.len() == 2) {
+        let zero = take_nearest(&mut linear, pole.root);
+        sections.push((zero.map_or(one(), |z| z.poly), pole.poly.clone()));
+    }
+    // A remaining real zero goes to a quadratic section without zeros, which exists since the
+    // order of the numerator is at most that of the denominator
+    if let Some(zero) = linear.pop() {
+        let section = sections.iter_mut().rev().find(|(b, _)| b.coef.len() == 1);
+        if let Some((b, _)) = section {
+            *b = zero.poly;
+        }
+    }
+    if sections.is_empty() {
+        sections.push((one(), one()));
+    }
+    sections.sort_by(|(_, x), (_, y)| max_root_norm(x).total_cmp(&max_root_norm(y)));
+
+    let count = sections.len();
+    let shares: Vec<f64> = (0..count)
+        .map(|i| match gain {
+            GainDistribution::First if i == 0 => k,
+            GainDistribution::Last if i == count - 1 => k,
+            GainDistribution::First | GainDistribution::Last => 1.0,
+            GainDistribution::Even => {
+                let share = k.abs().powf(1.0 / count as f64);
+                match i == 0 && k < 0.0 {
+                    true => -share,
+                    false => share,
+                }
+            }
+        })
+        .collect();
+
+    Ok(sections
+        .iter()
+        .zip(shares)
+        .map(|((b, a), share)| {
+            // A section of order m in z is divided by zᵐ
+            let m = a.coef.len() - 1;
+            let row = |p: &Polynomial<f64>, scale: f64| -> [f64; 3] {
+                std::array::from_fn(|i| match m.checked_sub(i) {
+                    Some(j) => p.coef.get(j).copied().unwrap_or(0.0) * scale,
+                    None => 0.0,
+                })
+            };
+            let [b0, b1, b2] = row(b, share);
+            let [a0, a1, a2] = row(a, 1.0);
+            [b0, b1, b2, a0, a1, a2]
+        })
+        .collect())
+}
+
+/// Factors a real polynomial into its leading coefficient and monic factors of order 1 or 2, with
+/// the real roots paired into quadratics.
+fn real_factors(poly: &Polynomial<f64>) -> Result<(f64, Vec<Factor>)> {
+    let poly = poly.to_trimmed();
+    if poly.coef.len() <= 1 {
+        return Ok((poly.coef.first().copied().unwrap_or(0.0), Vec::new()));
+    }
+    let (factors, _) = poly.factor_real()?;
+    let lead = factors[0].coef[0];
+
+    let mut res = Vec::with_capacity(factors.len());
+    let mut reals = Vec::new();
+    for f in factors.into_iter().skip(1) {
+        match f.coef.len() {
+            2 => reals.push(-f.coef[0]),
+            _ => {
+                let re = -0.5 * f.coef[1];
+                let im = (f.coef[0] - re * re).max(0.0).sqrt();
+                res.push(Factor {
+                    poly: f,
+                    root: Complex64::new(re, im),
+                });
+            }
+        }
+    }
+    for pair in reals.chunks(2) {
+        let poly = Polynomial::from_roots(pair);
+        res.push(Factor {
+            poly,
+            root: Complex64::new(pair[0], 0.0),
+        });
+    }
+    Ok((lead, res))
+}
+
+/// Removes and returns the factor whose root is nearest to `root`.
+fn take_nearest(factors: &mut Vec<Factor>, root: Complex64) -> Option<Factor> {
+    let (i, _) = factors
+        .iter()
+        .enumerate()
+        .min_by(|(_, x), (_, y)| (x.root - root).norm().total_cmp(&(y.root - root).norm()))?;
+    Some(factors.swap_remove(i))
+}
+
+/// The largest magnitude of the roots of a monic factor of order at most 2.
+fn max_root_norm(poly: &Polynomial<f64>) -> f64 {
+    match poly.coef[..] {
+        [c, _] => c.abs(),
+        [c, b, _] => {
+            let det = b * b - 4.0 * c;
+            match det < 0.0 {
+                true => c.abs().sqrt(),
+                false => 0.5 * (b.abs() + det.sqrt()),
+            }
+        }
+        _ => 0.0,
+    }
+}
+
+fn one() -> Polynomial<f64> {
+    Polynomial { coef: vec![1.0] }
+}
@@ -1,9 +1,10 @@
 #[derive(thiserror::Error, std::fmt::Debug)]
 /// The error type for Polynomial operations.
 pub enum PolyError {
-    /// Supplied coefficients are NaN or Infinity
-    #[error("Supplied coefficients cannot be NaN or Infinity")]
-    InvalidCoefficients,
+    /// Supplied coefficients are NaN or Infinity. Holds the index of the first offending
+    /// coefficient in the supplied input and its value.
+    #[error("Supplied coefficients cannot be NaN or Infinity, got {value} at index {index}")]
+    InvalidCoefficients { index: usize, value: Box<str> },
 
     /// Cannot convert Complex to Float.
     #[error("Cannot convert complex {0} to float.")]
@@ -14,8 +15,8 @@ pub enum PolyError {
     Trivial,
 
     /// Supplied Polynomial has incorrect order.
-    #[error("Supplied Polynomial must be of order {0}")]
-    IncorrectOrder(usize),
+    #[error("Supplied Polynomial must be of order {expected}, got {got}")]
+    IncorrectOrder { expected: usize, got: usize },
 
     /// Supplied Polynomial is constant.
     #[error("Supplied Polynomial is constant.")]
@@ -36,4 +37,237 @@ pub enum PolyError {
     /// Discriminant calculation returned NaN.
     #[error("Discriminant calculation returned NaN.")]
     NanDiscriminant,
+
+    /// Supplied slices have different lengths.
+    #[error("Supplied slices have different lengths: {0} and {1}.")]
+    LengthMismatch(usize, usize),
+
+    /// Supplied data points are NaN or Infinity.
+    #[error("Supplied data cannot be NaN or Infinity.")]
+    InvalidData,
+
+    /// Supplied weights are negative.
+    #[error("Supplied weights cannot be negative.")]
+    InvalidWeights,
+
+    /// Not enough data points were supplied.
+    #[error("At least {0} data points are required.")]
+    NotEnoughPoints(usize),
+
+    /// A linear system is singular or too ill-conditioned to be solved.
+    #[error("Matrix is singular or ill-conditioned.")]
+    SingularMatrix,
+
+    /// Supplied confidence level is not in (0, 1).
+    #[error("Confidence level must be in (0, 1), got {0}.")]
+    InvalidConfidence(f64),
+
+    /// The fit has no degrees of freedom left to estimate the residual variance.
+    #[error("Fit has no degrees of freedom.")]
+    NoDegreesOfFreedom,
+
+    /// An iterative algorithm failed to converge.
+    #[error("Iterative algorithm failed to converge.")]
+    NoConvergence,
+
+    /// Supplied spectrum of a trigonometric polynomial has an even length.
+    #[error("Trigonometric polynomial spectrum must have odd length, got {0}.")]
+    InvalidTrigOrder(usize),
+
+    /// Supplied interval is empty, reversed or not finite.
+    #[error("Invalid interval [{0}, {1}].")]
+    InvalidInterval(f64, f64),
+
+    /// Evaluation of the polynomial resulted in NaN.
+    #[error("Evaluation at x = {0} resulted in NaN.")]
+    NanEvaluation(f64),
+
+    /// Variable index out of range for a multivariate polynomial.
+    #[error("Invalid variable index {0} for a polynomial in {1} variables.")]
+    InvalidVariable(usize, usize),
+
+    /// Multivariate polynomial depends on variables other than the requested one.
+    #[error("Polynomial depends on variables other than x{0}.")]
+    NotUnivariate(usize),
+
+    /// Division by the zero polynomial.
+    #[error("Division by the zero polynomial.")]
+    DivisionByZero,
+
+    /// Coefficient could not be identified as a rational number with a small denominator.
+    #[error("Coefficient {0} is not a rational number with a small denominator.")]
+    NotRational(f64),
+
+    /// Polynomial has non-integer coefficients.
+    #[error("Polynomial's coefficients must be integers.")]
+    NotIntegerCoefficients,
+
+    /// Integer coefficients are too large to be exactly representable.
+    #[error("Integer coefficients are too large to be exactly representable.")]
+    CoefficientOverflow,
+
+    /// Supplied matrix is not square.
+    #[error("Matrix must be square, got {0}×{1}.")]
+    NotSquareMatrix(usize, usize),
+
+    /// The polynomial has the same sign at both ends of the interval.
+    #[error("Polynomial has the same sign at {0} and {1}.")]
+    RootNotBracketed(f64, f64),
+
+    /// Supplied radius is not positive and finite.
+    #[error("Radius must be positive and finite, got {0}.")]
+    InvalidRadius(f64),
+
+    /// A root lies on (or too close to) the contour of a root count.
+    #[error("Polynomial has a root on or too close to the contour.")]
+    RootOnContour,
+
+    /// Rational function evaluated at one of its poles.
+    #[error("Rational function has a pole at x = {0}.")]
+    Pole(f64),
+
+    /// Not enough series coefficients were supplied.
+    #[error("At least {0} series coefficients are required.")]
+    NotEnoughCoefficients(usize),
+
+    /// Index of a polynomial family is out of range.
+    #[error("Invalid index {0}.")]
+    InvalidIndex(usize),
+
+    /// Polynomial is not of the form a·xⁿ + b.
+    #[error("Polynomial is not of the form a·xⁿ + b.")]
+    NotBinomial,
+
+    /// Exact integer arithmetic overflowed.
+    #[error("Integer arithmetic overflowed.")]
+    IntegerOverflow,
+
+    /// Power series has no (multiplicative or compositional) inverse.
+    #[error("Power series is not invertible.")]
+    NotInvertibleSeries,
+
+    /// Constant term of a power series is outside the domain of a function, e.g. of the logarithm.
+    #[error("Constant term of the series is outside the domain of the function.")]
+    InvalidConstantTerm,
+
+    /// An iterative algorithm was cancelled by its progress callback.
+    #[error("Iterative algorithm was cancelled.")]
+    Cancelled,
+
+    /// A complex root has no complex conjugate among the other roots.
+    #[error("Complex root {0} has no conjugate.")]
+    UnpairedRoot(num::complex::Complex64),
+
+    /// A tuning constant or threshold of a robust fit is not positive and finite.
+    #[error("Tuning constant must be positive and finite, got {0}.")]
+    InvalidTuning(f64),
+
+    /// Supplied number of cross-validation folds is less than 2.
+    #[error("At least 2 cross-validation folds are required, got {0}.")]
+    InvalidFolds(usize),
+}
+
+/// The kind of a [`PolyError`], without its payload, for programmatic matching.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{PolyErrorKind, Polynomial};
+/// let err = Polynomial::build(&[1.0, f64::NAN]).unwrap_err();
+///
+/// assert_eq!(err.kind(), PolyErrorKind::InvalidCoefficients);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolyErrorKind {
+    InvalidCoefficients,
+    ComplexTof64Conversion,
+    Trivial,
+    IncorrectOrder,
+    ConstantPoly,
+    NotQuadratic,
+    NoRealRoots,
+    NotRealCoefficients,
+    NanDiscriminant,
+    LengthMismatch,
+    InvalidData,
+    InvalidWeights,
+    NotEnoughPoints,
+    SingularMatrix,
+    InvalidConfidence,
+    NoDegreesOfFreedom,
+    NoConvergence,
+    InvalidTrigOrder,
+    InvalidInterval,
+    NanEvaluation,
+    InvalidVariable,
+    NotUnivariate,
+    DivisionByZero,
+    NotRational,
+    NotIntegerCoefficients,
+    CoefficientOverflow,
+    NotSquareMatrix,
+    RootNotBracketed,
+    InvalidRadius,
+    RootOnContour,
+    Pole,
+    NotEnoughCoefficients,
+    InvalidIndex,
+    NotBinomial,
+    IntegerOverflow,
+    NotInvertibleSeries,
+    InvalidConstantTerm,
+    Cancelled,
+    UnpairedRoot,
+    InvalidTuning,
+    InvalidFolds,
+}
+
+impl PolyError {
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> PolyErrorKind {
+        match self {
+            PolyError::InvalidCoefficients { .. } => PolyErrorKind::InvalidCoefficients,
+            PolyError::ComplexTof64Conversion(..) => PolyErrorKind::ComplexTof64Conversion,
+            PolyError::Trivial => PolyErrorKind::Trivial,
+            PolyError::IncorrectOrder { .. } => PolyErrorKind::IncorrectOrder,
+            PolyError::ConstantPoly => PolyErrorKind::ConstantPoly,
+            PolyError::NotQuadratic(..) => PolyErrorKind::NotQuadratic,
+            PolyError::NoRealRoots => PolyErrorKind::NoRealRoots,
+            PolyError::NotRealCoefficients => PolyErrorKind::NotRealCoefficients,
+            PolyError::NanDiscriminant => PolyErrorKind::NanDiscriminant,
+            PolyError::LengthMismatch(..) => PolyErrorKind::LengthMismatch,
+            PolyError::InvalidData => PolyErrorKind::InvalidData,
+            PolyError::InvalidWeights => PolyErrorKind::InvalidWeights,
+            PolyError::NotEnoughPoints(..) => PolyErrorKind::NotEnoughPoints,
+            PolyError::SingularMatrix => PolyErrorKind::SingularMatrix,
+            PolyError::InvalidConfidence(..) => PolyErrorKind::InvalidConfidence,
+            PolyError::NoDegreesOfFreedom => PolyErrorKind::NoDegreesOfFreedom,
+            PolyError::NoConvergence => PolyErrorKind::NoConvergence,
+            PolyError::InvalidTrigOrder(..) => PolyErrorKind::InvalidTrigOrder,
+            PolyError::InvalidInterval(..) => PolyErrorKind::InvalidInterval,
+            PolyError::NanEvaluation(..) => PolyErrorKind::NanEvaluation,
+            PolyError::InvalidVariable(..) => PolyErrorKind::InvalidVariable,
+            PolyError::NotUnivariate(..) => PolyErrorKind::NotUnivariate,
+            PolyError::DivisionByZero => PolyErrorKind::DivisionByZero,
+            PolyError::NotRational(..) => PolyErrorKind::NotRational,
+            PolyError::NotIntegerCoefficients => PolyErrorKind::NotIntegerCoefficients,
+            PolyError::CoefficientOverflow => PolyErrorKind::CoefficientOverflow,
+            PolyError::NotSquareMatrix(..) => PolyErrorKind::NotSquareMatrix,
+            PolyError::RootNotBracketed(..) => PolyErrorKind::RootNotBracketed,
+            PolyError::InvalidRadius(..) => PolyErrorKind::InvalidRadius,
+            PolyError::RootOnContour => PolyErrorKind::RootOnContour,
+            PolyError::Pole(..) => PolyErrorKind::Pole,
+            PolyError::NotEnoughCoefficients(..) => PolyErrorKind::NotEnoughCoefficients,
+            PolyError::InvalidIndex(..) => PolyErrorKind::InvalidIndex,
+            PolyError::NotBinomial => PolyErrorKind::NotBinomial,
+            PolyError::IntegerOverflow => PolyErrorKind::IntegerOverflow,
+            PolyError::NotInvertibleSeries => PolyErrorKind::NotInvertibleSeries,
+            PolyError::InvalidConstantTerm => PolyErrorKind::InvalidConstantTerm,
+            PolyError::Cancelled => PolyErrorKind::Cancelled,
+            PolyError::UnpairedRoot(..) => PolyErrorKind::UnpairedRoot,
+            PolyError::InvalidTuning(..) => PolyErrorKind::InvalidTuning,
+            PolyError::InvalidFolds(..) => PolyErrorKind::InvalidFolds,
+        }
+    }
 }
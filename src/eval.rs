@@ -0,0 +1,83 @@
+//! Guarded evaluation, for pipelines where out-of-range values or NaN must not propagate.
+
+use crate::{PolyError, Polynomial, Result};
+
+/// What to do when an evaluation produces NaN.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum NanPolicy {
+    /// Return NaN unchanged.
+    #[default]
+    Propagate,
+    /// Return [`PolyError::NanEvaluation`].
+    Error,
+    /// Return the given value instead.
+    Replace(f64),
+}
+
+impl Polynomial<f64> {
+    /// Evaluates the polynomial at `x`, saturating the result to `[lo, hi]`.
+    ///
+    /// Infinite values saturate to the corresponding bound, while NaN is propagated. See
+    /// [`eval_clamped_with`](Polynomial::eval_clamped_with) for handling NaN differently.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `lo > hi` or if either bound is NaN.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[0.0, 0.0, 1.0])?; // x²
+    ///
+    /// assert_eq!(poly.eval_clamped(0.5, -1.0, 1.0)?, 0.25);
+    /// assert_eq!(poly.eval_clamped(3.0, -1.0, 1.0)?, 1.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_clamped(&self, x: f64, lo: f64, hi: f64) -> Result<f64> {
+        self.eval_clamped_with(x, lo, hi, NanPolicy::Propagate)
+    }
+
+    /// Evaluates the polynomial at `x`, saturating the result to `[lo, hi]`, and handling a NaN
+    /// result according to `policy`.
+    ///
+    /// A replacement value given with [`NanPolicy::Replace`] is returned as is, without being
+    /// clamped.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `lo > hi`, if either bound is NaN, or if the result is NaN and the
+    /// policy is [`NanPolicy::Error`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{NanPolicy, Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 2.0])?;
+    ///
+    /// let y = poly.eval_clamped_with(f64::NAN, 0.0, 10.0, NanPolicy::Replace(0.0))?;
+    /// assert_eq!(y, 0.0);
+    /// assert!(poly.eval_clamped_with(f64::NAN, 0.0, 10.0, NanPolicy::Error).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_clamped_with(&self, x: f64, lo: f64, hi: f64, policy: NanPolicy) -> Result<f64> {
+        if lo.is_nan() || hi.is_nan() || lo > hi {
+            return Err(PolyError::InvalidInterval(lo, hi));
+        }
+
+        let y = self.eval(x);
+        if !y.is_nan() {
+            return Ok(y.clamp(lo, hi));
+        }
+
+        match policy {
+            NanPolicy::Propagate => Ok(f64::NAN),
+            NanPolicy::Error => Err(PolyError::NanEvaluation(x)),
+            NanPolicy::Replace(value) => Ok(value),
+        }
+    }
+}
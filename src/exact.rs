@@ -0,0 +1,228 @@
+//! Exact normalization of polynomials with rational coefficients.
+
+use num::Integer;
+
+use crate::{PolyError, Polynomial, Result};
+
+/// Largest denominator considered when recovering a rational number from a coefficient.
+///
+/// Every f64 is the rounding of some rational with a denominator of about 2²⁶, so the bound must
+/// be well below that for irrational coefficients to be rejected.
+const MAX_DENOMINATOR: i128 = 1 << 20;
+
+/// Largest integer up to which every integer is exactly representable as an f64 (2⁵³).
+const MAX_EXACT_INTEGER: i128 = 1 << 53;
+
+impl Polynomial<f64> {
+    /// Multiplies the polynomial with the least common multiple `d` of its coefficients'
+    /// denominators, returning the resulting polynomial with integer coefficients along with `d`,
+    /// so that P(x) = Pᵢ(x)/d.
+    ///
+    /// Each coefficient is converted to the first convergent p/q of its continued fraction
+    /// expansion that rounds to it. For example 0.1 and 1/3 are recovered as such, even though
+    /// they are not exactly representable.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if a coefficient is not close to a rational with a denominator of at most
+    /// 2²⁰, or if the resulting integers are not exactly representable as f64.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[0.5, 1.0 / 3.0, 0.25])?; // 1/2 + x/3 + x²/4
+    /// let (integer, denominator) = poly.clear_denominators()?;
+    ///
+    /// assert_eq!(integer.coef, [6.0, 4.0, 3.0]);
+    /// assert_eq!(denominator, 12.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn clear_denominators(&self) -> Result<(Polynomial<f64>, f64)> {
+        let rationals = self
+            .coef
+            .iter()
+            .map(|c| to_rational(*c).ok_or(PolyError::NotRational(*c)))
+            .collect::<Result<Vec<(i128, i128)>>>()?;
+
+        let mut lcm: i128 = 1;
+        for (_, q) in rationals.iter() {
+            lcm = lcm.lcm(q);
+            if lcm > MAX_EXACT_INTEGER {
+                return Err(PolyError::CoefficientOverflow);
+            }
+        }
+
+        let coef = rationals
+            .iter()
+            .map(|(p, q)| {
+                p.checked_mul(lcm / q)
+                    .filter(|a| a.abs() <= MAX_EXACT_INTEGER)
+                    .map(|a| a as f64)
+                    .ok_or(PolyError::CoefficientOverflow)
+            })
+            .collect::<Result<Vec<f64>>>()?;
+
+        Ok((Polynomial { coef }, lcm as f64))
+    }
+
+    /// Divides a polynomial with integer coefficients by its content, i.e. the greatest common
+    /// divisor of its coefficients, returning the primitive part and the content.
+    ///
+    /// The content has the sign of the leading coefficient, so that the primitive part's leading
+    /// coefficient is positive. This is the inverse of
+    /// [`clear_denominators`](Polynomial::clear_denominators) in the sense that both are needed to
+    /// bring a rational polynomial to a canonical primitive form. The zero polynomial has content
+    /// 0 and is returned as is.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if any coefficient is not an integer, or is too large to be exactly
+    /// representable.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[6.0, -4.0, -8.0])?;
+    /// let (primitive, content) = poly.divide_by_content()?;
+    ///
+    /// assert_eq!(primitive.coef, [-3.0, 2.0, 4.0]);
+    /// assert_eq!(content, -2.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn divide_by_content(&self) -> Result<(Polynomial<f64>, f64)> {
+        let integers = self
+            .coef
+            .iter()
+            .map(
+                |c| match (c.fract() == 0.0) & (c.abs() <= MAX_EXACT_INTEGER as f64) {
+                    true => Ok(*c as i128),
+                    false => Err(PolyError::NotIntegerCoefficients),
+                },
+            )
+            .collect::<Result<Vec<i128>>>()?;
+
+        let gcd = integers.iter().fold(0, |g: i128, a| g.gcd(a));
+        if gcd == 0 {
+            return Ok((self.clone(), 0.0));
+        }
+
+        let lead = integers.iter().rev().find(|a| **a != 0).unwrap_or(&1);
+        let content = gcd * lead.signum();
+        let coef = integers.iter().map(|a| (a / content) as f64).collect();
+
+        Ok((Polynomial { coef }, content as f64))
+    }
+
+    /// Returns the content of a polynomial with rational coefficients, i.e. the rational number c
+    /// such that P = c·Pₚ, where the [primitive part](Polynomial::primitive_part) Pₚ has coprime
+    /// integer coefficients and a positive leading coefficient. The zero polynomial has content 0.
+    ///
+    /// The coefficients are recovered as rationals as in
+    /// [`clear_denominators`](Polynomial::clear_denominators).
+    ///
+    /// # Error
+    ///
+    /// Returns an error if a coefficient is not close to a rational with a small denominator, or
+    /// if the resulting integers are not exactly representable as f64.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-1.0, 0.5, 1.5])?; // −1 + x/2 + 3x²/2
+    ///
+    /// assert_eq!(poly.content()?, 0.5);
+    /// assert_eq!(poly.primitive_part()?.coef, [-2.0, 1.0, 3.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn content(&self) -> Result<f64> {
+        let (integer, denominator) = self.clear_denominators()?;
+        Ok(integer.divide_by_content()?.1 / denominator)
+    }
+
+    /// Returns the primitive part of a polynomial with rational coefficients, i.e. the polynomial
+    /// with coprime integer coefficients and a positive leading coefficient that is a multiple of
+    /// it. See [`content`](Polynomial::content).
+    ///
+    /// # Error
+    ///
+    /// Returns an error if a coefficient is not close to a rational with a small denominator, or
+    /// if the resulting integers are not exactly representable as f64.
+    pub fn primitive_part(&self) -> Result<Polynomial<f64>> {
+        Ok(self.clear_denominators()?.0.divide_by_content()?.0)
+    }
+
+    /// Finds the distinct rational roots of a polynomial with rational coefficients exactly, as
+    /// pairs (p, q) of a numerator and a positive denominator in lowest terms, in increasing
+    /// order.
+    ///
+    /// The denominators are cleared as in [`clear_denominators`](Polynomial::clear_denominators),
+    /// and the candidates of the rational root theorem are tested exactly on the resulting integer
+    /// polynomial, as in `Polynomial::<i128>::rational_roots`.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if a coefficient is not close to a rational with a small denominator, if
+    /// the polynomial is the zero polynomial, or if testing a candidate overflows.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-1.0 / 3.0, -2.0 / 3.0, 1.0])?; // (x − 1)(x + 1/3)
+    ///
+    /// assert_eq!(poly.rational_roots()?, [(-1, 3), (1, 1)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rational_roots(&self) -> Result<Vec<(i128, i128)>> {
+        let (integer, _) = self.clear_denominators()?;
+        let coef: Vec<i128> = integer.coef.iter().map(|c| *c as i128).collect();
+        Polynomial::<i128>::from_integers(&coef).rational_roots()
+    }
+}
+
+/// Finds the first convergent p/q (q > 0) of the continued fraction expansion of `x` that rounds
+/// to `x`.
+fn to_rational(x: f64) -> Option<(i128, i128)> {
+    if !x.is_finite() {
+        return None;
+    }
+
+    let (mut p0, mut q0, mut p1, mut q1) = (0i128, 1i128, 1i128, 0i128);
+    let mut rem = x;
+
+    loop {
+        let a = rem.floor();
+        if a.abs() > MAX_EXACT_INTEGER as f64 {
+            return None;
+        }
+        let a = a as i128;
+
+        (p0, p1) = (p1, a.checked_mul(p1)?.checked_add(p0)?);
+        (q0, q1) = (q1, a.checked_mul(q1)?.checked_add(q0)?);
+        if q1 > MAX_DENOMINATOR {
+            return None;
+        }
+
+        // Both are exact integers, so the division is correctly rounded
+        if p1 as f64 / q1 as f64 == x {
+            return Some((p1, q1));
+        }
+
+        let frac = rem - rem.floor();
+        if frac == 0.0 {
+            return None;
+        }
+        rem = 1.0 / frac;
+    }
+}
@@ -0,0 +1,189 @@
+//! Stationary points, inflection points and extrema of polynomials on the real line.
+
+use crate::roots::{CLUSTER_TOL, cluster_roots, real_roots, real_roots_in};
+use crate::{PolyError, Polynomial, Result};
+
+/// The kind of a stationary point, where P'(x) = 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StationaryKind {
+    /// A local minimum.
+    Minimum,
+    /// A local maximum.
+    Maximum,
+    /// A stationary inflection point, where P' does not change sign.
+    Saddle,
+}
+
+/// A stationary point of a polynomial, found by [`Polynomial::critical_points`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CriticalPoint {
+    /// The point's location.
+    pub x: f64,
+    /// The polynomial's value at the point.
+    pub value: f64,
+    /// The point's kind.
+    pub kind: StationaryKind,
+}
+
+impl Polynomial<f64> {
+    /// Finds the real stationary points of the polynomial, where P'(x) = 0, in increasing order,
+    /// and classifies them as minima, maxima or saddle points.
+    ///
+    /// The roots of P' are found with [`solve_complex`](Polynomial::solve_complex), and nearly
+    /// equal ones are merged. A root of P' of multiplicity m is an extremum if m is odd, whose
+    /// kind is given by the sign of the (m+1)-th derivative, and a saddle point otherwise.
+    ///
+    /// Constant and linear polynomials have no stationary points.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the complex solver fails to converge.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result, StationaryKind};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[0.0, -3.0, 0.0, 1.0])?; // x³-3x
+    /// let points = poly.critical_points()?;
+    ///
+    /// assert_eq!(points.len(), 2);
+    /// assert_eq!(points[0].kind, StationaryKind::Maximum);
+    /// assert!((points[0].x + 1.0).abs() < 1e-14 && (points[0].value - 2.0).abs() < 1e-14);
+    /// assert_eq!(points[1].kind, StationaryKind::Minimum);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn critical_points(&self) -> Result<Vec<CriticalPoint>> {
+        let roots = real_roots(&self.derivative())?;
+
+        Ok(cluster_roots(&roots, CLUSTER_TOL)
+            .iter()
+            .map(|root| {
+                let m = root.multiplicity;
+                let derivs = self.eval_derivs(root.value, m + 2);
+                let kind = match (m % 2, derivs[m + 1] > 0.0) {
+                    (0, _) => StationaryKind::Saddle,
+                    (_, true) => StationaryKind::Minimum,
+                    (_, false) => StationaryKind::Maximum,
+                };
+                CriticalPoint {
+                    x: root.value,
+                    value: derivs[0],
+                    kind,
+                }
+            })
+            .collect())
+    }
+
+    /// Finds the real inflection points of the polynomial, where P'' changes sign, in increasing
+    /// order.
+    ///
+    /// These are the roots of P'' of odd multiplicity, nearly equal roots being merged. Saddle
+    /// points are inflection points too.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the complex solver fails to converge.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[0.0, 0.0, -6.0, 0.0, 1.0])?; // x⁴-6x²
+    /// let points = poly.inflection_points()?;
+    ///
+    /// assert_eq!(points.len(), 2);
+    /// assert!((points[1] - 1.0).abs() < 1e-14);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn inflection_points(&self) -> Result<Vec<f64>> {
+        let roots = real_roots(&self.derivative().derivative())?;
+
+        Ok(cluster_roots(&roots, CLUSTER_TOL)
+            .iter()
+            .filter(|root| root.multiplicity % 2 == 1)
+            .map(|root| root.value)
+            .collect())
+    }
+
+    /// Finds the global minimum of the polynomial on `[a, b]`, returning its location and value
+    /// `(x, P(x))`.
+    ///
+    /// The candidates are the interval's endpoints and the real critical points inside it. If the
+    /// minimum is attained at several points, the leftmost one is returned.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `[a, b]` is not a valid finite interval, or if the complex solver fails
+    /// to converge.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[0.0, -3.0, 0.0, 1.0])?; // x³-3x
+    ///
+    /// let (x, min) = poly.minimum_on(-1.5, 1.5)?;
+    /// assert!((x - 1.0).abs() < 1e-14 && (min + 2.0).abs() < 1e-14);
+    ///
+    /// let (x, min) = poly.minimum_on(-3.0, 1.5)?;
+    /// assert_eq!((x, min), (-3.0, -18.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn minimum_on(&self, a: f64, b: f64) -> Result<(f64, f64)> {
+        self.extremum_on(a, b, |value, best| value < best)
+    }
+
+    /// Finds the global maximum of the polynomial on `[a, b]`, returning its location and value
+    /// `(x, P(x))`.
+    ///
+    /// See [`minimum_on`](Polynomial::minimum_on).
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `[a, b]` is not a valid finite interval, or if the complex solver fails
+    /// to converge.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[0.0, -3.0, 0.0, 1.0])?; // x³-3x
+    /// let (x, max) = poly.maximum_on(-1.5, 1.5)?;
+    ///
+    /// assert!((x + 1.0).abs() < 1e-14 && (max - 2.0).abs() < 1e-14);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn maximum_on(&self, a: f64, b: f64) -> Result<(f64, f64)> {
+        self.extremum_on(a, b, |value, best| value > best)
+    }
+
+    /// Returns the candidate point whose value is `better` than all others.
+    fn extremum_on<F>(&self, a: f64, b: f64, better: F) -> Result<(f64, f64)>
+    where
+        F: Fn(f64, f64) -> bool,
+    {
+        if !(a.is_finite() && b.is_finite() && a <= b) {
+            return Err(PolyError::InvalidInterval(a, b));
+        }
+
+        let mut candidates = vec![a];
+        candidates.extend(real_roots_in(&self.derivative(), a, b)?);
+        candidates.push(b);
+
+        Ok(candidates
+            .iter()
+            .map(|x| (*x, self.eval(*x)))
+            .fold((a, self.eval(a)), |best, cur| match better(cur.1, best.1) {
+                true => cur,
+                false => best,
+            }))
+    }
+}
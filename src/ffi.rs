@@ -0,0 +1,363 @@
+//! C bindings with the symbols and signatures of GSL's polynomial routines, with the `ffi`
+//! feature, so that existing C (or Fortran) code can link against this crate instead of GSL.
+//!
+//! The crate has to be built as a C library, e.g. with
+//! `cargo rustc --release --features ffi --crate-type staticlib`, and linked in place of GSL's
+//! polynomial routines; the declarations of `gsl/gsl_poly.h` can be used unchanged.
+//!
+//! Like GSL, the solvers return the number of roots or a GSL error code, but the GSL error
+//! handler is never called.
+
+use std::ffi::c_int;
+
+use num::complex::Complex64;
+
+use crate::solve::{self, BuiltinQr};
+use crate::utils::eval_derivs_into;
+use crate::{CubicRoots, QuadraticRoots};
+
+/// `GSL_SUCCESS`.
+pub const GSL_SUCCESS: c_int = 0;
+/// `GSL_EFAULT`, returned for null pointers.
+pub const GSL_EFAULT: c_int = 3;
+/// `GSL_EINVAL`, returned for invalid arguments.
+pub const GSL_EINVAL: c_int = 4;
+/// `GSL_EFAILED`, returned when the general solver fails to converge.
+pub const GSL_EFAILED: c_int = 5;
+
+/// GSL's `gsl_complex`, a complex number stored as `[re, im]`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GslComplex {
+    /// The real and imaginary parts.
+    pub dat: [f64; 2],
+}
+
+impl From<Complex64> for GslComplex {
+    fn from(z: Complex64) -> Self {
+        GslComplex { dat: [z.re, z.im] }
+    }
+}
+
+impl From<GslComplex> for Complex64 {
+    fn from(z: GslComplex) -> Self {
+        Complex64::new(z.dat[0], z.dat[1])
+    }
+}
+
+/// GSL's `gsl_poly_complex_workspace`, with the same layout.
+#[repr(C)]
+#[derive(Debug)]
+pub struct GslPolyComplexWorkspace {
+    /// The order of the polynomials it solves.
+    pub nc: usize,
+    /// Storage of the companion matrix, unused by this implementation.
+    pub matrix: *mut f64,
+}
+
+/// Returns the slice of `len` elements at `ptr`, or an empty slice for a null pointer or a
+/// non-positive length.
+///
+/// # Safety
+///
+/// `ptr` must point to `len` valid elements, if it is not null and `len > 0`.
+unsafe fn slice_or_empty<'a, T>(ptr: *const T, len: usize) -> &'a [T] {
+    match ptr.is_null() || len == 0 {
+        true => &[],
+        // SAFETY: guaranteed by the caller
+        false => unsafe { std::slice::from_raw_parts(ptr, len) },
+    }
+}
+
+/// Evaluates the polynomial with the `len` coefficients `c` (constant term first) at `x`.
+///
+/// # Safety
+///
+/// `c` must point to `len` valid doubles.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gsl_poly_eval(c: *const f64, len: c_int, x: f64) -> f64 {
+    // SAFETY: guaranteed by the caller
+    let coef = unsafe { slice_or_empty(c, usize::try_from(len).unwrap_or(0)) };
+    coef.iter().rev().fold(0.0, |res, c| c + x * res)
+}
+
+/// Evaluates the polynomial with the `len` real coefficients `c` at the complex point `z`.
+///
+/// # Safety
+///
+/// `c` must point to `len` valid doubles.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gsl_poly_complex_eval(
+    c: *const f64,
+    len: c_int,
+    z: GslComplex,
+) -> GslComplex {
+    // SAFETY: guaranteed by the caller
+    let coef = unsafe { slice_or_empty(c, usize::try_from(len).unwrap_or(0)) };
+    let z = Complex64::from(z);
+    coef.iter()
+        .rev()
+        .fold(Complex64::default(), |res, c| c + z * res)
+        .into()
+}
+
+/// Evaluates the polynomial with the `len` complex coefficients `c` at the complex point `z`.
+///
+/// # Safety
+///
+/// `c` must point to `len` valid `gsl_complex` values.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gsl_complex_poly_complex_eval(
+    c: *const GslComplex,
+    len: c_int,
+    z: GslComplex,
+) -> GslComplex {
+    // SAFETY: guaranteed by the caller
+    let coef = unsafe { slice_or_empty(c, usize::try_from(len).unwrap_or(0)) };
+    let z = Complex64::from(z);
+    coef.iter()
+        .rev()
+        .fold(Complex64::default(), |res, c| Complex64::from(*c) + z * res)
+        .into()
+}
+
+/// Evaluates the polynomial with the `lenc` coefficients `c` and its first `lenres − 1`
+/// derivatives at `x`, storing them in `res`.
+///
+/// # Safety
+///
+/// `c` must point to `lenc` valid doubles, and `res` to `lenres` writable doubles.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gsl_poly_eval_derivs(
+    c: *const f64,
+    lenc: usize,
+    x: f64,
+    res: *mut f64,
+    lenres: usize,
+) -> c_int {
+    if res.is_null() && lenres > 0 {
+        return GSL_EFAULT;
+    }
+    // SAFETY: guaranteed by the caller
+    let coef = unsafe { slice_or_empty(c, lenc) };
+    let out = match lenres {
+        0 => &mut [],
+        // SAFETY: guaranteed by the caller, and `res` is not null
+        _ => unsafe { std::slice::from_raw_parts_mut(res, lenres) },
+    };
+    eval_derivs_into(coef, x, out);
+    GSL_SUCCESS
+}
+
+/// Finds the real roots of ax²+bx+c = 0, storing them in increasing order in `x0` and `x1`, and
+/// returns their number (a double root counts twice). A linear equation has 1 root, and a
+/// constant one none.
+///
+/// # Safety
+///
+/// `x0` and `x1` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gsl_poly_solve_quadratic(
+    a: f64,
+    b: f64,
+    c: f64,
+    x0: *mut f64,
+    x1: *mut f64,
+) -> c_int {
+    if x0.is_null() || x1.is_null() {
+        return GSL_EFAULT;
+    }
+    let (roots, n) = match solve::quadratic(a, b, c) {
+        Ok(QuadraticRoots::Two(r0, r1)) => ([r0, r1], 2),
+        Ok(QuadraticRoots::Double(r)) => ([r, r], 2),
+        Ok(QuadraticRoots::Linear(r)) => ([r, f64::NAN], 1),
+        Ok(QuadraticRoots::None) | Err(_) => return 0,
+    };
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        *x0 = roots[0];
+        if n == 2 {
+            *x1 = roots[1];
+        }
+    }
+    n
+}
+
+/// Finds the complex roots of ax²+bx+c = 0, storing them in `z0` and `z1`, sorted by their real
+/// and then imaginary part, and returns their number. A linear equation has 1 root, and a
+/// constant one none.
+///
+/// # Safety
+///
+/// `z0` and `z1` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gsl_poly_complex_solve_quadratic(
+    a: f64,
+    b: f64,
+    c: f64,
+    z0: *mut GslComplex,
+    z1: *mut GslComplex,
+) -> c_int {
+    if z0.is_null() || z1.is_null() {
+        return GSL_EFAULT;
+    }
+    if a == 0.0 {
+        return match b == 0.0 {
+            true => 0,
+            // SAFETY: guaranteed by the caller
+            false => unsafe {
+                *z0 = Complex64::new(-c / b, 0.0).into();
+                1
+            },
+        };
+    }
+    let [r0, r1] = match solve::solve_complex_quadratic(a.into(), b.into(), c.into()) {
+        Ok(roots) => roots,
+        Err(_) => return 0,
+    };
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        *z0 = r0.into();
+        *z1 = r1.into();
+    }
+    2
+}
+
+/// Finds the real roots of x³+ax²+bx+c = 0, storing them in increasing order in `x0`, `x1` and
+/// `x2`, and returns their number, 1 or 3 (repeated roots count as many times as their
+/// multiplicity).
+///
+/// # Safety
+///
+/// `x0`, `x1` and `x2` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gsl_poly_solve_cubic(
+    a: f64,
+    b: f64,
+    c: f64,
+    x0: *mut f64,
+    x1: *mut f64,
+    x2: *mut f64,
+) -> c_int {
+    if x0.is_null() || x1.is_null() || x2.is_null() {
+        return GSL_EFAULT;
+    }
+    let roots = match solve::cubic(1.0, a, b, c) {
+        Ok(CubicRoots::One(r)) => vec![r],
+        Ok(CubicRoots::Three(roots)) => roots.to_vec(),
+        Ok(CubicRoots::TripleRepeated(r)) => vec![r; 3],
+        Err(_) => return 0,
+    };
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        for (ptr, r) in [x0, x1, x2].into_iter().zip(&roots) {
+            *ptr = *r;
+        }
+    }
+    roots.len() as c_int
+}
+
+/// Finds the complex roots of x³+ax²+bx+c = 0, storing them in `z0`, `z1` and `z2`, sorted by
+/// their real and then imaginary part, and returns their number, 3.
+///
+/// # Safety
+///
+/// `z0`, `z1` and `z2` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gsl_poly_complex_solve_cubic(
+    a: f64,
+    b: f64,
+    c: f64,
+    z0: *mut GslComplex,
+    z1: *mut GslComplex,
+    z2: *mut GslComplex,
+) -> c_int {
+    if z0.is_null() || z1.is_null() || z2.is_null() {
+        return GSL_EFAULT;
+    }
+    let one = Complex64::new(1.0, 0.0);
+    let roots = match solve::solve_complex_cubic(one, a.into(), b.into(), c.into()) {
+        Ok(roots) => roots,
+        Err(_) => return 0,
+    };
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        for (ptr, r) in [z0, z1, z2].into_iter().zip(roots) {
+            *ptr = r.into();
+        }
+    }
+    3
+}
+
+/// Allocates a workspace for solving polynomials with `n` coefficients with
+/// [`gsl_poly_complex_solve`]. Returns null if n < 2.
+#[unsafe(no_mangle)]
+pub extern "C" fn gsl_poly_complex_workspace_alloc(n: usize) -> *mut GslPolyComplexWorkspace {
+    if n < 2 {
+        return std::ptr::null_mut();
+    }
+    let nc = n - 1;
+    let matrix = Box::into_raw(vec![0.0; nc * nc].into_boxed_slice()) as *mut f64;
+    Box::into_raw(Box::new(GslPolyComplexWorkspace { nc, matrix }))
+}
+
+/// Frees a workspace allocated with [`gsl_poly_complex_workspace_alloc`].
+///
+/// # Safety
+///
+/// `w` must be null, or a workspace returned by [`gsl_poly_complex_workspace_alloc`] that has
+/// not been freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gsl_poly_complex_workspace_free(w: *mut GslPolyComplexWorkspace) {
+    if w.is_null() {
+        return;
+    }
+    // SAFETY: `w` and its matrix were allocated by `gsl_poly_complex_workspace_alloc`
+    unsafe {
+        let w = Box::from_raw(w);
+        let len = w.nc * w.nc;
+        drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+            w.matrix, len,
+        )));
+    }
+}
+
+/// Finds the complex roots of the polynomial with the `n` real coefficients `a` (constant term
+/// first), storing them in `z` as `n − 1` packed pairs `[re, im]`.
+///
+/// Returns `GSL_EINVAL` if n < 2, if the leading coefficient is 0, if the coefficients are not
+/// finite, or if the workspace does not match n, and `GSL_EFAILED` if the solver fails to
+/// converge.
+///
+/// # Safety
+///
+/// `a` must point to `n` valid doubles, `w` must be a workspace returned by
+/// [`gsl_poly_complex_workspace_alloc`], and `z` must point to `2(n − 1)` writable doubles.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gsl_poly_complex_solve(
+    a: *const f64,
+    n: usize,
+    w: *mut GslPolyComplexWorkspace,
+    z: *mut f64,
+) -> c_int {
+    if a.is_null() || w.is_null() || z.is_null() {
+        return GSL_EFAULT;
+    }
+    // SAFETY: guaranteed by the caller
+    let (coef, nc) = unsafe { (std::slice::from_raw_parts(a, n), (*w).nc) };
+    let valid = n >= 2 && coef[n - 1] != 0.0 && nc == n - 1;
+    if !valid || coef.iter().any(|c| !c.is_finite()) {
+        return GSL_EINVAL;
+    }
+
+    let roots = match solve::solve_complex_companion(coef, &BuiltinQr) {
+        Ok(roots) => roots,
+        Err(_) => return GSL_EFAILED,
+    };
+    // SAFETY: guaranteed by the caller
+    let out = unsafe { std::slice::from_raw_parts_mut(z, 2 * nc) };
+    for (pair, r) in out.chunks_exact_mut(2).zip(roots) {
+        pair.copy_from_slice(&[r.re, r.im]);
+    }
+    GSL_SUCCESS
+}
@@ -0,0 +1,598 @@
+//! Least-squares fitting of polynomials to data.
+
+use crate::utils::compose_linear;
+use crate::{PolyError, Polynomial, Result, linalg, stats};
+
+/// The result of a least-squares polynomial fit.
+#[derive(Clone, Debug)]
+pub struct FitResult {
+    /// The fitted polynomial.
+    pub poly: Polynomial<f64>,
+    /// The covariance matrix of the fitted coefficients, ordered like [`Polynomial::coef`].
+    pub covariance: Vec<Vec<f64>>,
+    /// The (weighted) sum of squared residuals χ².
+    pub chi2: f64,
+    /// The degrees of freedom, i.e. the number of data points minus the number of coefficients.
+    pub dof: usize,
+    /// The unweighted residual variance Σ(yᵢ − P(xᵢ))²/dof, or NaN if there are no degrees of
+    /// freedom.
+    pub residual_variance: f64,
+    /// The factor that scales [`covariance`](FitResult::covariance) to s²(XᵀWX)⁻¹, where s² is
+    /// the weighted residual variance χ²/dof: 1 if the covariance is already scaled, χ²/dof
+    /// otherwise.
+    pub(crate) covariance_scale: f64,
+}
+
+impl FitResult {
+    /// Returns the reduced chi-square χ²/dof, or NaN if there are no degrees of freedom.
+    pub fn chi2_dof(&self) -> f64 {
+        match self.dof {
+            0 => f64::NAN,
+            dof => self.chi2 / dof as f64,
+        }
+    }
+
+    /// Returns the fitted value at `x` along with its standard error √(xᵀCx), where C is the
+    /// [`covariance`](FitResult::covariance) of the coefficients and x = (1, x, x², ...).
+    ///
+    /// This is the uncertainty of the fitted curve itself, propagated from the coefficients with
+    /// their correlations, e.g. for error bars on interpolated values. It is estimated from the
+    /// scatter of the data for [`fit`](Polynomial::fit), and absolute for
+    /// [`fit_weighted`](Polynomial::fit_weighted) with weights 1/σᵢ². It is NaN if the fit has
+    /// no degrees of freedom and the covariance is unknown. A new observation at `x` scatters
+    /// further; see [`prediction_interval`](FitResult::prediction_interval).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+    /// let ys = [0.9, 3.1, 5.0, 7.2, 8.9];
+    /// let fit = Polynomial::fit(&xs, &ys, 1)?;
+    ///
+    /// let (value, std) = fit.predict_with_std(2.0)?;
+    /// // At the mean of the abscissas, only the uncertainty of the mean remains
+    /// assert!((std - fit.residual_variance.sqrt() / 5f64.sqrt()).abs() < 1e-12);
+    ///
+    /// // ... and it grows away from the data
+    /// assert!(fit.predict_with_std(10.0)?.1 > 4.0 * std);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn predict_with_std(&self, x: f64) -> Result<(f64, f64)> {
+        if !x.is_finite() {
+            return Err(PolyError::InvalidData);
+        }
+        Ok((self.poly.eval(x), self.quadratic_form(x).sqrt()))
+    }
+
+    /// Returns the fitted value at `x` along with the half-width of its prediction interval,
+    /// i.e. the band expected to contain a new observation at `x` with probability
+    /// `confidence`.
+    ///
+    /// The new observation is assumed to have unit weight; see
+    /// [`prediction_interval_weighted`](FitResult::prediction_interval_weighted).
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `confidence` is not in (0, 1), or if the fit has no degrees of
+    /// freedom.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+    /// let ys = [0.9, 3.1, 5.0, 7.2, 8.9];
+    /// let fit = Polynomial::fit(&xs, &ys, 1)?;
+    ///
+    /// let (value, band) = fit.prediction_interval(2.5, 0.95)?;
+    /// assert!(band > 0.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn prediction_interval(&self, x: f64, confidence: f64) -> Result<(f64, f64)> {
+        self.prediction_interval_weighted(x, 1.0, confidence)
+    }
+
+    /// Same as [`prediction_interval`](FitResult::prediction_interval), for a new observation
+    /// at `x` with the given `weight`, in the same units as the weights of the fit.
+    ///
+    /// The data are assumed to have variances s²/wᵢ, with s² estimated by the weighted residual
+    /// variance χ²/dof. The band is then t·s·√(xᵀ(XᵀWX)⁻¹x + 1/w), where t is the corresponding
+    /// quantile of Student's t-distribution with [`dof`](FitResult::dof) degrees of freedom. For
+    /// an unweighted fit this reduces to the textbook t·√(xᵀCx + s²), with C the coefficients'
+    /// covariance matrix.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `confidence` is not in (0, 1), if `weight` is not positive and
+    /// finite, or if the fit has no degrees of freedom.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+    /// let ys = [1.0, 3.1, 4.8, 7.5, 8.6];
+    /// let sigma = [0.1, 0.1, 0.2, 0.4, 0.4];
+    /// let weights: Vec<f64> = sigma.iter().map(|s| 1.0 / (s * s)).collect();
+    /// let fit = Polynomial::fit_weighted(&xs, &ys, &weights, 1)?;
+    ///
+    /// // Observations are noisier at larger x
+    /// let (_, band_small) = fit.prediction_interval_weighted(0.5, 1.0 / 0.01, 0.95)?;
+    /// let (_, band_large) = fit.prediction_interval_weighted(3.5, 1.0 / 0.16, 0.95)?;
+    /// assert!(band_small < band_large);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn prediction_interval_weighted(
+        &self,
+        x: f64,
+        weight: f64,
+        confidence: f64,
+    ) -> Result<(f64, f64)> {
+        if !(confidence > 0.0 && confidence < 1.0) {
+            return Err(PolyError::InvalidConfidence(confidence));
+        }
+        if !(weight.is_finite() && weight > 0.0) {
+            return Err(PolyError::InvalidWeights);
+        }
+        if self.dof == 0 {
+            return Err(PolyError::NoDegreesOfFreedom);
+        }
+
+        let variance = self.quadratic_form(x);
+        let t = stats::student_t_quantile(0.5 + 0.5 * confidence, self.dof as f64);
+
+        Ok((
+            self.poly.eval(x),
+            t * (self.covariance_scale * variance + self.chi2_dof() / weight).sqrt(),
+        ))
+    }
+
+    /// Returns xᵀCx, with C the covariance of the coefficients and x = (1, x, x², ...).
+    fn quadratic_form(&self, x: f64) -> f64 {
+        let powers: Vec<f64> = (0..self.covariance.len())
+            .map(|i| x.powi(i as i32))
+            .collect();
+        self.covariance
+            .iter()
+            .zip(&powers)
+            .map(|(row, xi)| xi * row.iter().zip(&powers).map(|(c, xj)| c * xj).sum::<f64>())
+            .sum()
+    }
+}
+
+/// The result of [`Polynomial::fit_orthogonal`]: a least-squares polynomial expanded in the
+/// polynomials orthogonal on the data points.
+#[derive(Clone, Debug)]
+pub struct OrthogonalFit {
+    /// The fitted polynomial, in the monomial basis.
+    pub poly: Polynomial<f64>,
+    /// The coefficients bⱼ of the expansion Σbⱼpⱼ(t) in the orthogonal polynomials.
+    pub coef: Vec<f64>,
+    /// The sum of squared residuals.
+    pub chi2: f64,
+    /// The degrees of freedom, i.e. the number of data points minus the number of coefficients.
+    pub dof: usize,
+    /// The coefficients αⱼ of the recurrence pⱼ₊₁(t) = (t − αⱼ)pⱼ(t) − βⱼpⱼ₋₁(t).
+    alpha: Vec<f64>,
+    /// The coefficients βⱼ of the recurrence, with β₀ = 0.
+    beta: Vec<f64>,
+    /// The center of the data, mapped to t = 0.
+    shift: f64,
+    /// The half-width of the data, mapped to t = 1.
+    scale: f64,
+}
+
+impl OrthogonalFit {
+    /// Evaluates the fit at `x`, with the recurrence of the orthogonal polynomials.
+    ///
+    /// This is more accurate than evaluating [`poly`](OrthogonalFit::poly) at high orders,
+    /// whose monomial coefficients are large and cancel each other out.
+    pub fn eval(&self, x: f64) -> f64 {
+        let t = (x - self.shift) / self.scale;
+        let (mut prev, mut cur) = (0.0, 1.0);
+        let mut sum = 0.0;
+        for (j, b) in self.coef.iter().enumerate() {
+            sum += b * cur;
+            if j + 1 < self.coef.len() {
+                (prev, cur) = (cur, (t - self.alpha[j]) * cur - self.beta[j] * prev);
+            }
+        }
+        sum
+    }
+}
+
+/// The criterion by which [`Polynomial::select_degree`] scores every degree. Lower scores are
+/// better.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DegreeCriterion {
+    /// k-fold cross-validation: the mean squared error of the predictions for every fold, fitted
+    /// on the other k−1 folds. Point i belongs to fold i mod k, so that sorted data are spread
+    /// evenly across the folds.
+    KFold(usize),
+    /// Akaike's information criterion n·ln(RSS/n) + 2p, for p coefficients.
+    Aic,
+    /// The Bayesian information criterion n·ln(RSS/n) + p·ln(n), for p coefficients, which
+    /// penalizes complexity more than AIC for n ≥ 8.
+    Bic,
+}
+
+/// The result of [`Polynomial::select_degree`].
+#[derive(Clone, Debug)]
+pub struct DegreeSelection {
+    /// The selected degree, with the lowest score.
+    pub degree: usize,
+    /// The least-squares fit of all the data at the selected degree.
+    pub fit: FitResult,
+    /// The score of every degree from 0 up to the maximum, or Infinity for the degrees that
+    /// could not be fitted, e.g. with too few points.
+    pub scores: Vec<f64>,
+}
+
+impl Polynomial<f64> {
+    /// Fits a polynomial of order `degree` to the data points `(xs[i], ys[i])`, by minimizing the
+    /// sum of squared residuals.
+    ///
+    /// The covariance matrix of the coefficients is estimated from the scatter of the data, i.e.
+    /// it is scaled by χ²/dof. With no degrees of freedom it is filled with NaN.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the slices have different lengths, contain NaN or Infinity, contain
+    /// fewer than `degree+1` points, or if the abscissas do not determine a unique polynomial.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let xs = [0.0, 1.0, 2.0, 3.0];
+    /// let ys = [1.0, 3.0, 5.0, 7.0];
+    /// let fit = Polynomial::fit(&xs, &ys, 1)?;
+    ///
+    /// assert!((fit.poly.coef[0] - 1.0).abs() < 1e-12);
+    /// assert!((fit.poly.coef[1] - 2.0).abs() < 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "gsl_multifit_linear")]
+    pub fn fit(xs: &[f64], ys: &[f64], degree: usize) -> Result<FitResult> {
+        let weights = vec![1.0; xs.len()];
+        let mut result = Self::fit_weighted(xs, ys, &weights, degree)?;
+
+        let s2 = result.chi2_dof();
+        result
+            .covariance
+            .iter_mut()
+            .flatten()
+            .for_each(|c| *c *= s2);
+        result.covariance_scale = 1.0;
+
+        Ok(result)
+    }
+
+    /// Fits a polynomial of order `degree` to the data points `(xs[i], ys[i])`, by minimizing the
+    /// weighted sum of squared residuals  χ² = Σ wᵢ(yᵢ − P(xᵢ))².
+    ///
+    /// For data with known standard deviations σᵢ, the weights should be wᵢ = 1/σᵢ². In that
+    /// case, the returned covariance matrix (XᵀWX)⁻¹ is the absolute covariance of the
+    /// coefficients, and is not rescaled by χ²/dof.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the slices have different lengths, contain NaN or Infinity, contain
+    /// fewer than `degree+1` points, if any weight is negative, or if the abscissas do not
+    /// determine a unique polynomial.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let xs = [0.0, 1.0, 2.0, 3.0];
+    /// let ys = [1.1, 2.9, 5.2, 6.8];
+    /// let sigma = [0.1, 0.1, 0.2, 0.2];
+    /// let weights: Vec<f64> = sigma.iter().map(|s| 1.0 / (s * s)).collect();
+    ///
+    /// let fit = Polynomial::fit_weighted(&xs, &ys, &weights, 1)?;
+    /// let slope_error = fit.covariance[1][1].sqrt();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "gsl_multifit_wlinear")]
+    pub fn fit_weighted(
+        xs: &[f64],
+        ys: &[f64],
+        weights: &[f64],
+        degree: usize,
+    ) -> Result<FitResult> {
+        if xs.len() != ys.len() {
+            return Err(PolyError::LengthMismatch(xs.len(), ys.len()));
+        }
+        if xs.len() != weights.len() {
+            return Err(PolyError::LengthMismatch(xs.len(), weights.len()));
+        }
+        if xs.iter().chain(ys).chain(weights).any(|v| !v.is_finite()) {
+            return Err(PolyError::InvalidData);
+        }
+        if weights.iter().any(|w| *w < 0.0) {
+            return Err(PolyError::InvalidWeights);
+        }
+        if xs.len() < degree + 1 {
+            return Err(PolyError::NotEnoughPoints(degree + 1));
+        }
+
+        // Weighted Vandermonde system: √wᵢ·xᵢʲ·cⱼ = √wᵢ·yᵢ
+        let design: Vec<Vec<f64>> = xs
+            .iter()
+            .zip(weights)
+            .map(|(x, w)| (0..=degree).map(|j| w.sqrt() * x.powi(j as i32)).collect())
+            .collect();
+        let rhs: Vec<f64> = ys.iter().zip(weights).map(|(y, w)| w.sqrt() * y).collect();
+
+        let (coef, covariance) = linalg::lstsq(&design, &rhs)?;
+        let poly = Polynomial::build(&coef)?;
+
+        let residuals: Vec<f64> = xs.iter().zip(ys).map(|(x, y)| y - poly.eval(*x)).collect();
+        let chi2 = residuals.iter().zip(weights).map(|(r, w)| w * r * r).sum();
+        let dof = xs.len() - (degree + 1);
+        let residual_variance = match dof {
+            0 => f64::NAN,
+            _ => residuals.iter().map(|r| r * r).sum::<f64>() / dof as f64,
+        };
+
+        let covariance_scale = match dof {
+            0 => f64::NAN,
+            _ => chi2 / dof as f64,
+        };
+
+        Ok(FitResult {
+            poly,
+            covariance,
+            chi2,
+            dof,
+            residual_variance,
+            covariance_scale,
+        })
+    }
+
+    /// Fits a polynomial of order `degree` to the data points `(xs[i], ys[i])`, by minimizing the
+    /// sum of squared residuals, with Forsythe's method.
+    ///
+    /// Instead of the Vandermonde matrix, whose condition number grows exponentially with the
+    /// order and makes [`fit`](Polynomial::fit) silently inaccurate beyond orders of about 15,
+    /// the data are fitted in the polynomials pⱼ that are orthogonal on the data points, which
+    /// are generated by a three-term recurrence. The abscissas are first mapped to t in
+    /// `[-1, 1]`. The coefficients of the fit are then independent projections, computed with
+    /// modified Gram–Schmidt, which stays accurate at orders of 50 and more.
+    ///
+    /// The result holds both the coefficients in the orthogonal basis and the polynomial in the
+    /// monomial basis. At high orders, prefer [`OrthogonalFit::eval`] to evaluating the latter.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the slices have different lengths, contain NaN or Infinity, or
+    /// contain fewer than `degree+1` points or distinct abscissas.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let xs: Vec<f64> = (0..200).map(|i| i as f64 / 20.0).collect();
+    /// let ys: Vec<f64> = xs.iter().map(|x| x.sin()).collect();
+    /// let fit = Polynomial::fit_orthogonal(&xs, &ys, 30)?;
+    ///
+    /// assert!(fit.chi2 < 1e-20);
+    /// assert!((fit.eval(4.321) - 4.321f64.sin()).abs() < 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fit_orthogonal(xs: &[f64], ys: &[f64], degree: usize) -> Result<OrthogonalFit> {
+        if xs.len() != ys.len() {
+            return Err(PolyError::LengthMismatch(xs.len(), ys.len()));
+        }
+        if xs.iter().chain(ys).any(|v| !v.is_finite()) {
+            return Err(PolyError::InvalidData);
+        }
+        if xs.len() < degree + 1 {
+            return Err(PolyError::NotEnoughPoints(degree + 1));
+        }
+        let mut sorted = xs.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        sorted.dedup();
+        if sorted.len() < degree + 1 {
+            return Err(PolyError::SingularMatrix);
+        }
+
+        let (lo, hi) = (sorted[0], sorted[sorted.len() - 1]);
+        let shift = 0.5 * (lo + hi);
+        let scale = match 0.5 * (hi - lo) {
+            half if half > 0.0 => half,
+            _ => 1.0,
+        };
+        let ts: Vec<f64> = xs.iter().map(|x| (x - shift) / scale).collect();
+
+        // pⱼ and pⱼ₋₁ at the data points, and the residual left to fit
+        let mut cur = vec![1.0; ts.len()];
+        let mut prev = vec![0.0; ts.len()];
+        let mut residual = ys.to_vec();
+        let (mut coef, mut alpha, mut beta) = (Vec::new(), Vec::new(), Vec::new());
+        let mut prev_norm = 0.0;
+        for j in 0..=degree {
+            let norm: f64 = cur.iter().map(|p| p * p).sum();
+            if norm == 0.0 {
+                return Err(PolyError::SingularMatrix);
+            }
+            let b = cur.iter().zip(&residual).map(|(p, r)| p * r).sum::<f64>() / norm;
+            residual.iter_mut().zip(&cur).for_each(|(r, p)| *r -= b * p);
+            coef.push(b);
+            if j == degree {
+                break;
+            }
+
+            let a = cur.iter().zip(&ts).map(|(p, t)| t * p * p).sum::<f64>() / norm;
+            let bt = if j == 0 { 0.0 } else { norm / prev_norm };
+            let next: Vec<f64> = cur
+                .iter()
+                .zip(&prev)
+                .zip(&ts)
+                .map(|((p, q), t)| (t - a) * p - bt * q)
+                .collect();
+            (prev, cur, prev_norm) = (cur, next, norm);
+            alpha.push(a);
+            beta.push(bt);
+        }
+
+        // Σbⱼpⱼ(t) in the monomial basis, then in x
+        let mut monomial = vec![0.0; degree + 1];
+        let (mut p_prev, mut p_cur) = (vec![0.0], vec![1.0]);
+        for (j, b) in coef.iter().enumerate() {
+            monomial
+                .iter_mut()
+                .zip(&p_cur)
+                .for_each(|(m, p)| *m += b * p);
+            if j < degree {
+                let mut next = vec![0.0; p_cur.len() + 1];
+                next[1..].iter_mut().zip(&p_cur).for_each(|(n, p)| *n = *p);
+                next.iter_mut()
+                    .zip(&p_cur)
+                    .for_each(|(n, p)| *n -= alpha[j] * p);
+                next.iter_mut()
+                    .zip(&p_prev)
+                    .for_each(|(n, p)| *n -= beta[j] * p);
+                (p_prev, p_cur) = (p_cur, next);
+            }
+        }
+        let poly = compose_linear(&Polynomial { coef: monomial }, 1.0 / scale, -shift / scale);
+
+        Ok(OrthogonalFit {
+            poly,
+            coef,
+            chi2: residual.iter().map(|r| r * r).sum(),
+            dof: xs.len() - (degree + 1),
+            alpha,
+            beta,
+            shift,
+            scale,
+        })
+    }
+
+    /// Chooses the order of the least-squares polynomial of the data points `(xs[i], ys[i])`
+    /// among 0 to `max_degree`, by the lowest score of `criterion`, and fits it.
+    ///
+    /// Ties go to the lower degree. The information criteria are only computed for degrees that
+    /// leave at least one degree of freedom, and a perfect fit, with no residuals, scores
+    /// −Infinity.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the slices have different lengths, are empty or contain NaN or
+    /// Infinity, if there are fewer than 2 folds or fewer points than folds, or if no degree
+    /// could be scored.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{DegreeCriterion, Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let xs: Vec<f64> = (0..30).map(|i| i as f64 / 10.0).collect();
+    /// let noise = |i: usize| 0.05 * ((i * 13 % 7) as f64 - 3.0) / 3.0;
+    /// let ys: Vec<f64> = (0..30).map(|i| 1.0 - xs[i] + 0.5 * xs[i].powi(3) + noise(i)).collect();
+    ///
+    /// let selection = Polynomial::select_degree(&xs, &ys, 8, DegreeCriterion::KFold(5))?;
+    ///
+    /// assert_eq!(selection.degree, 3);
+    /// assert_eq!(selection.scores.len(), 9);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn select_degree(
+        xs: &[f64],
+        ys: &[f64],
+        max_degree: usize,
+        criterion: DegreeCriterion,
+    ) -> Result<DegreeSelection> {
+        if xs.len() != ys.len() {
+            return Err(PolyError::LengthMismatch(xs.len(), ys.len()));
+        }
+        if xs.iter().chain(ys).any(|v| !v.is_finite()) {
+            return Err(PolyError::InvalidData);
+        }
+        let n = xs.len();
+        if let DegreeCriterion::KFold(k) = criterion {
+            if k < 2 {
+                return Err(PolyError::InvalidFolds(k));
+            }
+            if n < k {
+                return Err(PolyError::NotEnoughPoints(k));
+            }
+        }
+        if n == 0 {
+            return Err(PolyError::NotEnoughPoints(1));
+        }
+
+        let scores: Vec<f64> = (0..=max_degree)
+            .map(|degree| match criterion {
+                DegreeCriterion::KFold(k) => cross_validate(xs, ys, degree, k),
+                DegreeCriterion::Aic | DegreeCriterion::Bic if degree + 1 >= n => f64::INFINITY,
+                DegreeCriterion::Aic | DegreeCriterion::Bic => {
+                    let Ok(fit) = Self::fit(xs, ys, degree) else {
+                        return f64::INFINITY;
+                    };
+                    let (nf, p) = (n as f64, (degree + 1) as f64);
+                    let penalty = match criterion {
+                        DegreeCriterion::Aic => 2.0 * p,
+                        _ => p * nf.ln(),
+                    };
+                    nf * (fit.chi2 / nf).ln() + penalty
+                }
+            })
+            .collect();
+
+        let degree = scores
+            .iter()
+            .enumerate()
+            .filter(|(_, score)| **score < f64::INFINITY)
+            .fold(None, |best: Option<(usize, f64)>, (d, score)| match best {
+                Some((_, s)) if s <= *score => best,
+                _ => Some((d, *score)),
+            })
+            .map(|(d, _)| d)
+            .ok_or(PolyError::NotEnoughPoints(2))?;
+
+        Ok(DegreeSelection {
+            degree,
+            fit: Self::fit(xs, ys, degree)?,
+            scores,
+        })
+    }
+}
+
+/// Returns the mean squared prediction error of the fits of order `degree` with `k`-fold
+/// cross-validation, or Infinity if any of the folds cannot be fitted.
+fn cross_validate(xs: &[f64], ys: &[f64], degree: usize, k: usize) -> f64 {
+    let mut sum_sq = 0.0;
+    for fold in 0..k {
+        let (train, test): (Vec<_>, Vec<_>) = xs
+            .iter()
+            .zip(ys)
+            .enumerate()
+            .partition(|(i, _)| i % k != fold);
+        let (tx, ty): (Vec<f64>, Vec<f64>) = train.iter().map(|(_, (x, y))| (**x, **y)).unzip();
+        let Ok(fit) = Polynomial::fit(&tx, &ty, degree) else {
+            return f64::INFINITY;
+        };
+        sum_sq += test
+            .iter()
+            .map(|(_, (x, y))| (**y - fit.poly.eval(**x)).powi(2))
+            .sum::<f64>();
+    }
+    sum_sq / xs.len() as f64
+}
@@ -0,0 +1,180 @@
+//! Fixed-size polynomials, stored inline without any heap allocation.
+
+use num::Float;
+use num::traits::FloatConst;
+
+use crate::utils::{check_finite_coef, eval_derivs_into};
+use crate::{CubicRoots, PolyError, Polynomial, QuadraticRoots, Result, solve};
+
+/// Representation of a polynomial with `N` coefficients, i.e. of order at most N−1, stored in an
+/// array instead of a `Vec`.
+///
+/// `N` counts the coefficients rather than the order, since an `[T; N + 1]` array cannot be
+/// spelled with a generic `N` on stable Rust. A quadratic is therefore a `PolyN<T, 3>`.
+///
+/// None of its methods allocate, which makes it suitable for embedded and real-time code. It
+/// converts to and from the dynamic [`Polynomial`].
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{PolyN, QuadraticRoots, Result};
+/// # fn main() -> Result<()> {
+/// let poly = PolyN::build([-2.0, 1.0, 1.0])?; // −2+x+x²
+///
+/// assert_eq!(poly.eval(2.0), 4.0);
+/// assert_eq!(poly.solve()?, QuadraticRoots::Two(-2.0, 1.0));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PolyN<T, const N: usize>
+where
+    T: std::fmt::Debug,
+{
+    /// The polynomial's coefficients, from constant to leading term.
+    pub coef: [T; N],
+}
+
+impl<T, const N: usize> PolyN<T, N>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Creates a new fixed-size polynomial from the given coefficients.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if any of the coefficients is NaN or Infinity.
+    pub fn build(coef: [T; N]) -> Result<Self> {
+        check_finite_coef(coef)?;
+        Ok(PolyN { coef })
+    }
+
+    /// Evaluates the polynomial at `x`, using Horner's method as [`Polynomial::eval`] does.
+    pub fn eval(&self, x: T) -> T {
+        self.coef
+            .iter()
+            .rev()
+            .copied()
+            .reduce(|res, coef| coef + x * res)
+            .unwrap_or(T::zero())
+    }
+
+    /// Evaluates the polynomial's first `M` derivatives (including the 0-th derivative, i.e. the
+    /// polynomial's value) at `x`, like [`Polynomial::eval_derivs`] but into an array.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{PolyN, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = PolyN::build([1.0, 2.0, 3.0])?;
+    ///
+    /// assert_eq!(poly.eval_derivs::<4>(1.0), [6.0, 8.0, 6.0, 0.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_derivs<const M: usize>(&self, x: T) -> [T; M] {
+        let mut res = [T::zero(); M];
+        eval_derivs_into(&self.coef, x, &mut res);
+        res
+    }
+
+    /// Returns the derivative of the polynomial, keeping the same size with a trailing zero
+    /// coefficient.
+    pub fn derivative(&self) -> Self {
+        let mut coef = [T::zero(); N];
+        for (i, c) in coef.iter_mut().enumerate().take(N.saturating_sub(1)) {
+            *c = self.coef[i + 1] * T::from(i + 1).unwrap();
+        }
+        PolyN { coef }
+    }
+}
+
+impl<F> PolyN<F, 2>
+where
+    F: Float + std::fmt::Debug,
+{
+    /// Calculates the root of the linear polynomial, as in [`solve::linear`].
+    pub fn solve(&self) -> Result<F> {
+        let [b, a] = self.coef;
+        solve::linear(a, b)
+    }
+}
+
+impl<F> PolyN<F, 3>
+where
+    F: Float + std::fmt::Debug,
+{
+    /// Calculates the real roots of the quadratic polynomial, as in [`solve::quadratic`].
+    pub fn solve(&self) -> Result<QuadraticRoots<F>> {
+        let [c, b, a] = self.coef;
+        solve::quadratic(a, b, c)
+    }
+}
+
+impl<F> PolyN<F, 4>
+where
+    F: Float + FloatConst + std::fmt::Debug,
+{
+    /// Calculates the real roots of the cubic polynomial, as in [`solve::cubic`].
+    pub fn solve(&self) -> Result<CubicRoots<F>> {
+        let [d, c, b, a] = self.coef;
+        solve::cubic(a, b, c, d)
+    }
+}
+
+impl<F> PolyN<F, 5>
+where
+    F: Float + FloatConst + std::fmt::Debug,
+{
+    /// Calculates the real roots of the quartic polynomial, as in [`solve::quartic`].
+    pub fn solve(&self) -> Result<([F; 4], usize)> {
+        let [e, d, c, b, a] = self.coef;
+        solve::quartic(a, b, c, d, e)
+    }
+}
+
+impl<T, const N: usize> From<PolyN<T, N>> for Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    fn from(poly: PolyN<T, N>) -> Self {
+        match N {
+            0 => Polynomial::new(),
+            _ => Polynomial {
+                coef: poly.coef.to_vec(),
+            },
+        }
+    }
+}
+
+impl<T, const N: usize> TryFrom<&Polynomial<T>> for PolyN<T, N>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Error = PolyError;
+
+    /// Copies the polynomial's coefficients, padding them with zeros up to `N`.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the polynomial's order is N or higher.
+    fn try_from(poly: &Polynomial<T>) -> Result<Self> {
+        let len = poly
+            .coef
+            .iter()
+            .rposition(|c| !c.is_zero())
+            .map_or(0, |i| i + 1);
+        if len > N {
+            return Err(PolyError::IncorrectOrder {
+                expected: N.saturating_sub(1),
+                got: len - 1,
+            });
+        }
+
+        let mut coef = [T::zero(); N];
+        coef[..len].copy_from_slice(&poly.coef[..len]);
+        Ok(PolyN { coef })
+    }
+}
@@ -0,0 +1,83 @@
+//! Derivatives of the polynomial's value with respect to its coefficients, e.g. for
+//! Gauss-Newton or Levenberg-Marquardt iterations that fit the coefficients.
+
+use crate::{PolyError, Polynomial, Result};
+
+impl<T> Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Returns the gradient of P(x) with respect to the coefficients, i.e. ∂P/∂cᵢ = xⁱ, for
+    /// every coefficient of the polynomial (including trailing zeros).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 2.0, 3.0])?;
+    ///
+    /// assert_eq!(poly.eval_grad_coeffs(2.0), [1.0, 2.0, 4.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_grad_coeffs(&self, x: T) -> Vec<T> {
+        let mut power = T::one();
+        self.coef
+            .iter()
+            .map(|_| {
+                let res = power;
+                power = power * x;
+                res
+            })
+            .collect()
+    }
+
+    /// Returns the Jacobian matrix of the values P(xⱼ) with respect to the coefficients, i.e. the
+    /// Vandermonde matrix with rows `[1, xⱼ, xⱼ², ...]`, with one row per point and one column
+    /// per coefficient.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[0.0, 0.0])?;
+    ///
+    /// assert_eq!(poly.jacobian_coeffs(&[2.0, 3.0]), [[1.0, 2.0], [1.0, 3.0]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn jacobian_coeffs(&self, xs: &[T]) -> Vec<Vec<T>> {
+        xs.iter().map(|x| self.eval_grad_coeffs(*x)).collect()
+    }
+
+    /// Returns the residuals rⱼ = P(xⱼ) − yⱼ along with their Jacobian matrix with respect to the
+    /// coefficients (see [`jacobian_coeffs`](Polynomial::jacobian_coeffs)), which is all a
+    /// Gauss-Newton step needs.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `xs` and `ys` have different lengths.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 1.0])?;
+    /// let (res, jac) = poly.residuals_and_jacobian(&[0.0, 2.0], &[1.5, 2.0])?;
+    ///
+    /// assert_eq!(res, [-0.5, 1.0]);
+    /// assert_eq!(jac, [[1.0, 0.0], [1.0, 2.0]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn residuals_and_jacobian(&self, xs: &[T], ys: &[T]) -> Result<(Vec<T>, Vec<Vec<T>>)> {
+        if xs.len() != ys.len() {
+            return Err(PolyError::LengthMismatch(xs.len(), ys.len()));
+        }
+        let residuals = xs.iter().zip(ys).map(|(x, y)| self.eval(*x) - *y).collect();
+        Ok((residuals, self.jacobian_coeffs(xs)))
+    }
+}
@@ -0,0 +1,308 @@
+//! Homotopy continuation, i.e. tracking the roots of a family of polynomials P(x; t) as the
+//! parameter t moves along a path.
+//!
+//! The roots are tracked with a predictor-corrector scheme: every step extrapolates the roots
+//! from the last two points of the path (secant predictor), and corrects them simultaneously with
+//! the Aberth-Ehrlich method. A step is accepted if every corrected root stays close to its
+//! prediction, relative to its distance from the other roots, which prevents the paths from
+//! jumping onto each other. Otherwise the step is halved. When the step becomes smaller than
+//! [`TrackOptions::min_step`], two roots have (nearly) collided, e.g. at a bifurcation where two
+//! real roots become complex; the collision is recorded and the step is taken anyway.
+
+use num::complex::Complex64;
+
+use crate::utils::{check_finite_coef, convert_to_complex64};
+use crate::{PolyError, Polynomial, Result, solve};
+
+/// Angle of the constant γ of the "gamma trick" of [`solve`], which keeps the paths away from
+/// singularities for almost all choices.
+const GAMMA_ANGLE: f64 = 0.754_877_666_246_692_7;
+
+/// Number of minimum steps within which the collisions of the same two roots are merged, since
+/// the roots may take a few steps to move apart after colliding.
+const COLLISION_STEPS: f64 = 16.0;
+
+/// Options of [`track`]. The steps are given as fractions of the length of the path, and must
+/// satisfy 0 < `min_step` ≤ `initial_step` ≤ `max_step` < ∞.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrackOptions {
+    /// The first step.
+    pub initial_step: f64,
+    /// The smallest step, under which two roots are considered to have collided.
+    pub min_step: f64,
+    /// The largest step.
+    pub max_step: f64,
+}
+
+impl Default for TrackOptions {
+    /// An initial step of 1%, a minimum step of 10⁻⁹ and a maximum step of 10%.
+    fn default() -> Self {
+        TrackOptions {
+            initial_step: 0.01,
+            min_step: 1e-9,
+            max_step: 0.1,
+        }
+    }
+}
+
+/// A (near) collision of two roots, found by [`track`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Collision {
+    /// The value of the parameter where the roots collided.
+    pub t: f64,
+    /// The indices of the two roots, in the roots of the path before the collision.
+    pub roots: [usize; 2],
+}
+
+/// The paths of the roots of a family of polynomials, created with [`track`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RootPaths {
+    /// The values of the parameter at every accepted step, from start to end.
+    pub t: Vec<f64>,
+    /// The roots at every value of `t`, where the i-th root continues the same path.
+    pub roots: Vec<Vec<Complex64>>,
+    /// The collisions of roots along the path, in the order they were found.
+    pub collisions: Vec<Collision>,
+}
+
+/// Tracks the roots of the polynomials `family(t)` as t moves from `t0` to `t1`.
+///
+/// The roots at `t0` are found with [`solve_complex`](Polynomial::solve_complex), and are then
+/// continued along the path as described in the [module](self) documentation. The order of the
+/// polynomials must not change along the path.
+///
+/// # Error
+///
+/// Returns an error if `t0` or `t1` are not finite, if the steps of `opts` are not ordered and
+/// positive as described in [`TrackOptions`], if `family(t)` has NaN or Infinity coefficients or a
+/// different order than `family(t0)` for some t, if `family(t0)` is constant, or if its roots
+/// could not be found.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{Polynomial, Result, homotopy};
+/// # fn main() -> Result<()> {
+/// // x² − t: two complex roots that collide at 0 and become real
+/// let family = |t: f64| Polynomial::build(&[-t, 0.0, 1.0]).unwrap();
+/// let paths = homotopy::track(family, -1.0, 1.0, homotopy::TrackOptions::default())?;
+///
+/// assert_eq!(paths.collisions.len(), 1);
+/// assert!(paths.collisions[0].t.abs() < 1e-6);
+/// let last = paths.roots.last().unwrap();
+/// assert!(last.iter().all(|x| (x.re.abs() - 1.0).abs() < 1e-12 && x.im.abs() < 1e-12));
+/// # Ok(())
+/// # }
+/// ```
+pub fn track<T, F>(family: F, t0: f64, t1: f64, opts: TrackOptions) -> Result<RootPaths>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+    F: Fn(f64) -> Polynomial<T>,
+{
+    if !(t0.is_finite() && t1.is_finite()) {
+        return Err(PolyError::InvalidInterval(t0, t1));
+    }
+    let TrackOptions {
+        initial_step,
+        min_step,
+        max_step,
+    } = opts;
+    let ordered = 0.0 < min_step && min_step <= initial_step && initial_step <= max_step;
+    if !(ordered && max_step.is_finite()) {
+        return Err(PolyError::InvalidData);
+    }
+
+    let start = family(t0);
+    check_finite_coef(start.coef.iter().copied())?;
+    let mut roots = start.solve_complex()?;
+    let n = roots.len();
+    let coef_at = |t: f64| -> Result<Vec<Complex64>> {
+        let poly = family(t).to_trimmed();
+        check_finite_coef(poly.coef.iter().copied())?;
+        match poly.coef.len() == n + 1 {
+            true => Ok(poly.coef.iter().map(|c| convert_to_complex64(*c)).collect()),
+            false => Err(PolyError::IncorrectOrder {
+                expected: n,
+                got: poly.coef.len().saturating_sub(1),
+            }),
+        }
+    };
+
+    let length = (t1 - t0).abs();
+    let dir = (t1 - t0).signum();
+    let (min_step, max_step) = (opts.min_step * length, opts.max_step * length);
+    let mut paths = RootPaths {
+        t: vec![t0],
+        roots: vec![roots.clone()],
+        collisions: Vec::new(),
+    };
+
+    let mut s = 0.0;
+    let mut h = opts.initial_step * length;
+    // The previous roots and step, for the secant predictor
+    let mut previous: Option<(Vec<Complex64>, f64)> = None;
+    while s < length {
+        h = h.min(length - s);
+        let t = t0 + dir * (s + h);
+        let coef = coef_at(t)?;
+
+        let predicted: Vec<Complex64> = match &previous {
+            Some((prev, h_prev)) => roots
+                .iter()
+                .zip(prev)
+                .map(|(x, p)| x + (x - p) * (h / h_prev))
+                .collect(),
+            None => roots.clone(),
+        };
+        let separation = separations(&roots);
+        let corrected = solve::refine_aberth(&coef, &predicted).ok();
+        let accepted = corrected.as_ref().is_some_and(|next| {
+            next.iter()
+                .zip(&predicted)
+                .zip(&separation)
+                .all(|((x, p), sep)| (x - p).norm() <= 0.25 * sep)
+        });
+
+        match (accepted, corrected) {
+            (true, Some(next)) => {
+                let small = next
+                    .iter()
+                    .zip(&predicted)
+                    .zip(&separation)
+                    .all(|((x, p), sep)| (x - p).norm() <= 0.05 * sep);
+                previous = Some((std::mem::replace(&mut roots, next), h));
+                s += h;
+                if small {
+                    h = (2.0 * h).min(max_step);
+                }
+            }
+            (_, corrected) if h <= min_step => {
+                // Step over the collision, without the secant predictor
+                let pair = closest_pair(&roots);
+                let repeated = paths.collisions.last().is_some_and(|c| {
+                    c.roots == pair && (c.t - t).abs() <= COLLISION_STEPS * min_step
+                });
+                if !repeated {
+                    paths.collisions.push(Collision { t, roots: pair });
+                }
+                let next = match corrected {
+                    Some(next) => next,
+                    None => {
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(t, "corrector failed at a collision, solving from scratch");
+                        match_nearest(&predicted, &Polynomial { coef }.solve_complex()?)
+                    }
+                };
+                roots = next;
+                previous = None;
+                s += h;
+                h = opts.initial_step * length;
+            }
+            _ => {
+                h *= 0.5;
+                continue;
+            }
+        }
+        paths.t.push(t);
+        paths.roots.push(roots.clone());
+    }
+    Ok(paths)
+}
+
+/// Finds all the complex roots of `poly` by continuation from the roots of unity.
+///
+/// The roots of the monic polynomial F are tracked along the linear homotopy
+/// (1−t)·γ·(xⁿ − 1) + t·F(x), from t = 0 to 1, where γ is a fixed complex constant that keeps
+/// the paths away from each other (the "gamma trick"). This is an alternative to
+/// [`solve_complex`](Polynomial::solve_complex) that does not depend on a good initial guess.
+///
+/// # Error
+///
+/// Returns an error if `poly` has NaN or Infinity coefficients, if it is constant, or if the
+/// roots could not be tracked.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{Polynomial, Result, homotopy};
+/// # fn main() -> Result<()> {
+/// let poly = Polynomial::build(&[-6.0, 11.0, -6.0, 1.0])?; // (x−1)(x−2)(x−3)
+/// let mut roots: Vec<f64> = homotopy::solve(&poly)?.iter().map(|x| x.re).collect();
+/// roots.sort_by(f64::total_cmp);
+///
+/// assert!(roots.iter().zip([1.0, 2.0, 3.0]).all(|(x, r)| (x - r).abs() < 1e-12));
+/// # Ok(())
+/// # }
+/// ```
+pub fn solve<T>(poly: &Polynomial<T>) -> Result<Vec<Complex64>>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    check_finite_coef(poly.coef.iter().copied())?;
+    let poly = poly.to_trimmed();
+    let n = poly.coef.len().saturating_sub(1);
+    if n == 0 {
+        return Err(PolyError::ConstantPoly);
+    }
+    let lead = convert_to_complex64(poly.coef[n]);
+    let target: Vec<Complex64> = poly
+        .coef
+        .iter()
+        .map(|c| convert_to_complex64(*c) / lead)
+        .collect();
+
+    let gamma = Complex64::from_polar(1.0, GAMMA_ANGLE);
+    let family = |t: f64| {
+        let mut coef: Vec<Complex64> = target.iter().map(|c| c * t).collect();
+        coef[0] -= gamma * (1.0 - t);
+        coef[n] += gamma * (1.0 - t);
+        Polynomial { coef }
+    };
+    let paths = track(family, 0.0, 1.0, TrackOptions::default())?;
+    Ok(paths.roots.last().cloned().unwrap_or_default())
+}
+
+/// Returns the distance of every root to its nearest other root.
+fn separations(roots: &[Complex64]) -> Vec<f64> {
+    roots
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            roots
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, y)| (x - y).norm())
+                .fold(f64::INFINITY, f64::min)
+        })
+        .collect()
+}
+
+/// Returns the indices of the two closest roots.
+fn closest_pair(roots: &[Complex64]) -> [usize; 2] {
+    let mut best = ([0, 0], f64::INFINITY);
+    for i in 0..roots.len() {
+        for j in i + 1..roots.len() {
+            let d = (roots[i] - roots[j]).norm();
+            if d < best.1 {
+                best = ([i, j], d);
+            }
+        }
+    }
+    best.0
+}
+
+/// Orders `roots` so that each one is the nearest remaining root to the corresponding `guess`.
+fn match_nearest(guess: &[Complex64], roots: &[Complex64]) -> Vec<Complex64> {
+    let mut remaining = roots.to_vec();
+    guess
+        .iter()
+        .filter_map(|g| {
+            let (i, _) = remaining
+                .iter()
+                .enumerate()
+                .min_by(|(_, x), (_, y)| (*x - g).norm().total_cmp(&(*y - g).norm()))?;
+            Some(remaining.swap_remove(i))
+        })
+        .collect()
+}
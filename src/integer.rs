@@ -0,0 +1,371 @@
+//! Exact arithmetic on polynomials with integer coefficients, `Polynomial<i64>` and
+//! `Polynomial<i128>`.
+//!
+//! The methods of the float polynomials are not available for integer coefficients, and the ones
+//! here are named differently (e.g. `eval_exact` instead of `eval`), since every
+//! operation is checked for overflow and returns [`PolyError::IntegerOverflow`] instead of
+//! wrapping or panicking.
+
+use num::{Integer, PrimInt, Signed};
+
+use crate::{PolyError, Polynomial, Result};
+
+/// Implements the exact integer methods for `Polynomial<$int>`.
+macro_rules! integer_poly {
+    ($int:ty) => {
+        impl Polynomial<$int> {
+            /// Creates a new Polynomial from the given integer coefficients, from constant to
+            /// leading term.
+            pub fn from_integers(coef: &[$int]) -> Self {
+                match coef.is_empty() {
+                    true => Polynomial { coef: vec![0] },
+                    false => Polynomial {
+                        coef: coef.to_vec(),
+                    },
+                }
+            }
+
+            /// Evaluates the polynomial at the integer `x` exactly, with Horner's method.
+            ///
+            /// # Error
+            ///
+            /// Returns an error if an intermediate result overflows.
+            ///
+            /// ## Example
+            ///
+            /// ```
+            /// # use rsl_polynomials::{Polynomial, Result};
+            /// # fn main() -> Result<()> {
+            #[doc = concat!("let poly = Polynomial::<", stringify!($int), ">::from_integers(&[1, 2, 3]);")]
+            ///
+            /// assert_eq!(poly.eval_exact(2)?, 17);
+            /// # Ok(())
+            /// # }
+            /// ```
+            pub fn eval_exact(&self, x: $int) -> Result<$int> {
+                eval_exact(&self.coef, x)
+            }
+
+            /// Adds two polynomials exactly.
+            ///
+            /// # Error
+            ///
+            /// Returns an error if a coefficient overflows.
+            pub fn checked_add(&self, other: &Self) -> Result<Self> {
+                zip_checked(&self.coef, &other.coef, |a, b| a.checked_add(*b))
+            }
+
+            /// Subtracts `other` from the polynomial exactly.
+            ///
+            /// # Error
+            ///
+            /// Returns an error if a coefficient overflows.
+            pub fn checked_sub(&self, other: &Self) -> Result<Self> {
+                zip_checked(&self.coef, &other.coef, |a, b| a.checked_sub(*b))
+            }
+
+            /// Multiplies two polynomials exactly.
+            ///
+            /// # Error
+            ///
+            /// Returns an error if a coefficient overflows.
+            pub fn checked_mul(&self, other: &Self) -> Result<Self> {
+                checked_mul(&self.coef, &other.coef)
+            }
+
+            /// Returns the content of the polynomial, i.e. the greatest common divisor of its
+            /// coefficients, with the sign of the leading coefficient. The zero polynomial has
+            /// content 0.
+            ///
+            /// ## Example
+            ///
+            /// ```
+            /// # use rsl_polynomials::Polynomial;
+            #[doc = concat!("let poly = Polynomial::<", stringify!($int), ">::from_integers(&[6, -4, -8]);")]
+            ///
+            /// assert_eq!(poly.content(), -2);
+            /// assert_eq!(poly.primitive_part().coef, [-3, 2, 4]);
+            /// ```
+            pub fn content(&self) -> $int {
+                content(&self.coef)
+            }
+
+            /// Divides the polynomial by its [`content`](Self::content), so that its
+            /// coefficients are coprime and its leading coefficient is positive. The zero
+            /// polynomial is returned as is.
+            pub fn primitive_part(&self) -> Self {
+                match self.content() {
+                    0 => self.clone(),
+                    content => Polynomial {
+                        coef: self.coef.iter().map(|a| a / content).collect(),
+                    },
+                }
+            }
+
+            /// Finds the distinct integer roots of the polynomial, in increasing order.
+            ///
+            /// By the rational root theorem, every non-zero integer root divides the lowest
+            /// non-zero coefficient, so the divisors up to the Cauchy bound of the roots are
+            /// tested exactly. This takes O(√|a|) steps, where a is that coefficient.
+            ///
+            /// # Error
+            ///
+            /// Returns an error if the polynomial is the zero polynomial (every integer is a
+            /// root), or if testing a candidate overflows.
+            ///
+            /// ## Example
+            ///
+            /// ```
+            /// # use rsl_polynomials::{Polynomial, Result};
+            /// # fn main() -> Result<()> {
+            /// // x(x−3)(x+2)(2x−1) = 2x⁴ − 3x³ − 11x² + 6x
+            #[doc = concat!("let poly = Polynomial::<", stringify!($int), ">::from_integers(&[0, 6, -11, -3, 2]);")]
+            ///
+            /// assert_eq!(poly.integer_roots()?, [-2, 0, 3]);
+            /// # Ok(())
+            /// # }
+            /// ```
+            pub fn integer_roots(&self) -> Result<Vec<$int>> {
+                integer_roots(&self.coef)
+            }
+
+            /// Finds the distinct rational roots of the polynomial, as pairs (p, q) of a
+            /// numerator and a positive denominator in lowest terms, in increasing order.
+            ///
+            /// By the rational root theorem, p divides the lowest non-zero coefficient and q
+            /// divides the leading one, so every such candidate up to the Cauchy bound of the
+            /// roots is tested exactly.
+            ///
+            /// # Error
+            ///
+            /// Returns an error if the polynomial is the zero polynomial, or if testing a
+            /// candidate overflows.
+            ///
+            /// ## Example
+            ///
+            /// ```
+            /// # use rsl_polynomials::{Polynomial, Result};
+            /// # fn main() -> Result<()> {
+            /// // (2x−1)(3x+2)(x²+1) = 6x⁴ + x³ + 4x² + x − 2
+            #[doc = concat!("let poly = Polynomial::<", stringify!($int), ">::from_integers(&[-2, 1, 4, 1, 6]);")]
+            ///
+            /// assert_eq!(poly.rational_roots()?, [(-2, 3), (1, 2)]);
+            /// # Ok(())
+            /// # }
+            /// ```
+            pub fn rational_roots(&self) -> Result<Vec<($int, $int)>> {
+                rational_roots(&self.coef)
+            }
+        }
+    };
+}
+
+integer_poly!(i64);
+integer_poly!(i128);
+
+/// Evaluates the polynomial with coefficients `coef` at `x` with checked Horner's method.
+fn eval_exact<T: PrimInt>(coef: &[T], x: T) -> Result<T> {
+    coef.iter().rev().try_fold(T::zero(), |res, c| {
+        res.checked_mul(&x)
+            .and_then(|r| r.checked_add(c))
+            .ok_or(PolyError::IntegerOverflow)
+    })
+}
+
+/// Combines the coefficients of two polynomials pairwise with `op`, padding the shorter one with
+/// zeros.
+fn zip_checked<T>(a: &[T], b: &[T], op: impl Fn(T, &T) -> Option<T>) -> Result<Polynomial<T>>
+where
+    T: PrimInt + std::fmt::Debug,
+{
+    let coef = (0..a.len().max(b.len()))
+        .map(|i| {
+            let x = a.get(i).copied().unwrap_or(T::zero());
+            op(x, b.get(i).unwrap_or(&T::zero())).ok_or(PolyError::IntegerOverflow)
+        })
+        .collect::<Result<Vec<T>>>()?;
+    Ok(Polynomial { coef })
+}
+
+/// Multiplies two polynomials with checked arithmetic.
+fn checked_mul<T>(a: &[T], b: &[T]) -> Result<Polynomial<T>>
+where
+    T: PrimInt + std::fmt::Debug,
+{
+    if a.is_empty() || b.is_empty() {
+        return Ok(Polynomial {
+            coef: vec![T::zero()],
+        });
+    }
+
+    let mut coef = vec![T::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            coef[i + j] = x
+                .checked_mul(y)
+                .and_then(|p| coef[i + j].checked_add(&p))
+                .ok_or(PolyError::IntegerOverflow)?;
+        }
+    }
+    Ok(Polynomial { coef })
+}
+
+/// Greatest common divisor of the coefficients, with the sign of the leading one.
+fn content<T: PrimInt + Integer + Signed>(coef: &[T]) -> T {
+    let gcd = coef.iter().fold(T::zero(), |g, a| g.gcd(a));
+    let lead = coef.iter().rev().find(|a| !a.is_zero());
+    lead.map_or(T::zero(), |a| gcd * a.signum())
+}
+
+/// Checks if p/q (in lowest terms, p ≠ 0, q > 0) is a root of the polynomial exactly, by
+/// dividing it by (qx − p), which succeeds only if every step is an exact division and the
+/// remainder is 0.
+///
+/// The division runs from the end whose divisor is the larger of |p| and q, i.e. from the
+/// constant term if |p| ≥ q, so that the intermediate values stay bounded by the sum of the
+/// coefficients' magnitudes, whether p/q is a root or not.
+fn is_root_exact<T: PrimInt + Integer + Signed>(coef: &[T], p: T, q: T) -> Result<bool> {
+    let overflow = || PolyError::IntegerOverflow;
+    let Some(n) = coef.iter().rposition(|a| !a.is_zero()) else {
+        return Ok(true);
+    };
+    let coef = &coef[..=n];
+
+    if p.abs() >= q {
+        // a₀ = −p·s₀, aₖ = q·sₖ₋₁ − p·sₖ, aₙ = q·sₙ₋₁
+        let mut s = T::zero();
+        for a in &coef[..n] {
+            let num = q
+                .checked_mul(&s)
+                .and_then(|x| x.checked_sub(a))
+                .ok_or_else(overflow)?;
+            if !num.is_multiple_of(&p) {
+                return Ok(false);
+            }
+            s = num / p;
+        }
+        Ok(q.checked_mul(&s).ok_or_else(overflow)? == coef[n])
+    } else {
+        // The same recurrence, from the leading term down
+        let mut s = T::zero();
+        for a in coef[1..].iter().rev() {
+            let num = p
+                .checked_mul(&s)
+                .and_then(|x| x.checked_add(a))
+                .ok_or_else(overflow)?;
+            if !num.is_multiple_of(&q) {
+                return Ok(false);
+            }
+            s = num / q;
+        }
+        Ok(p.checked_mul(&s).ok_or_else(overflow)? == -coef[0])
+    }
+}
+
+/// Returns the lowest non-zero coefficient and its index, along with the Cauchy bound
+/// 1 + max|aᵢ/aₙ| of the non-zero roots, rounded down.
+fn root_candidates<T: PrimInt + Integer + Signed>(coef: &[T]) -> Result<(usize, T, T)> {
+    let Some(lowest) = coef.iter().position(|a| !a.is_zero()) else {
+        return Err(PolyError::Trivial);
+    };
+    // |MIN| is not representable
+    if coef.contains(&T::min_value()) {
+        return Err(PolyError::IntegerOverflow);
+    }
+    let lead = coef.iter().rev().find(|a| !a.is_zero()).unwrap().abs();
+    let bound = coef
+        .iter()
+        .map(|a| a.abs() / lead)
+        .fold(T::zero(), |m, a| m.max(a))
+        .saturating_add(T::one());
+    Ok((lowest, coef[lowest], bound))
+}
+
+/// Calls `f` with every positive divisor d of |a| with d ≤ bound, in no particular order.
+fn for_each_divisor<T: PrimInt + Integer + Signed>(
+    a: T,
+    bound: T,
+    mut f: impl FnMut(T) -> Result<()>,
+) -> Result<()> {
+    let a = a.abs();
+    let mut d = T::one();
+    while d <= bound && d <= a / d {
+        if a.is_multiple_of(&d) {
+            f(d)?;
+            let pair = a / d;
+            if pair != d && pair <= bound {
+                f(pair)?;
+            }
+        }
+        d = d + T::one();
+    }
+    Ok(())
+}
+
+/// Finds the distinct integer roots of the polynomial, in increasing order.
+fn integer_roots<T>(coef: &[T]) -> Result<Vec<T>>
+where
+    T: PrimInt + Integer + Signed,
+{
+    let (lowest, a, bound) = root_candidates(coef)?;
+    let coef = &coef[lowest..];
+
+    let mut roots = Vec::new();
+    if lowest > 0 {
+        roots.push(T::zero());
+    }
+    for_each_divisor(a, bound, |d| {
+        for x in [d, -d] {
+            if is_root_exact(coef, x, T::one())? {
+                roots.push(x);
+            }
+        }
+        Ok(())
+    })?;
+
+    roots.sort();
+    Ok(roots)
+}
+
+/// Finds the distinct rational roots p/q of the polynomial (in lowest terms, with q > 0), in
+/// increasing order.
+fn rational_roots<T>(coef: &[T]) -> Result<Vec<(T, T)>>
+where
+    T: PrimInt + Integer + Signed,
+{
+    let (lowest, a, bound) = root_candidates(coef)?;
+    let coef = &coef[lowest..];
+    let lead = coef.iter().rev().find(|a| !a.is_zero()).unwrap().abs();
+
+    let mut denominators = Vec::new();
+    for_each_divisor(lead, lead, |q| {
+        denominators.push(q);
+        Ok(())
+    })?;
+
+    let mut roots = Vec::new();
+    if lowest > 0 {
+        roots.push((T::zero(), T::one()));
+    }
+    for q in denominators {
+        // |p/q| is at most the Cauchy bound
+        let p_bound = q
+            .checked_mul(&bound.saturating_add(T::one()))
+            .unwrap_or(T::max_value());
+        for_each_divisor(a, p_bound, |p| {
+            if !p.gcd(&q).is_one() {
+                return Ok(());
+            }
+            for p in [p, -p] {
+                if is_root_exact(coef, p, q)? {
+                    roots.push((p, q));
+                }
+            }
+            Ok(())
+        })?;
+    }
+
+    let value = |(p, q): &(T, T)| p.to_f64().unwrap_or(f64::NAN) / q.to_f64().unwrap_or(f64::NAN);
+    roots.sort_by(|x, y| value(x).total_cmp(&value(y)));
+    Ok(roots)
+}
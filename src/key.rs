@@ -0,0 +1,140 @@
+//! Bit-exact keys for using polynomials in hashed collections.
+
+use std::hash::{Hash, Hasher};
+
+use crate::Polynomial;
+
+impl<T> Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Returns a bit-exact representation of the polynomial, suitable as a key of a hashed
+    /// collection.
+    ///
+    /// The key contains the bits of the real and imaginary parts (as [`f64`]) of every
+    /// coefficient, with trailing zero coefficients removed. `-0.0` is stored as `0.0` and every
+    /// NaN as the same NaN, so that two polynomials that compare equal have the same key.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let p = Polynomial::build(&[1.0, 2.0, 0.0])?;
+    /// let q = Polynomial::build(&[1.0, 2.0])?;
+    ///
+    /// assert_eq!(p.canonical_key(), q.canonical_key());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn canonical_key(&self) -> Vec<u64> {
+        let len = self
+            .coef
+            .iter()
+            .rposition(|c| !c.is_zero())
+            .map_or(0, |i| i + 1);
+
+        self.coef[..len]
+            .iter()
+            .flat_map(|c| [canonical_bits(c.re()), canonical_bits(c.im())])
+            .collect()
+    }
+}
+
+/// Returns the bits of `x` as an [`f64`], with a single representation of zero and of NaN.
+fn canonical_bits<R: num::Float>(x: R) -> u64 {
+    match x.to_f64().unwrap_or(f64::NAN) {
+        x if x.is_nan() => f64::NAN.to_bits(),
+        0.0 => 0,
+        x => x.to_bits(),
+    }
+}
+
+/// A polynomial wrapper that implements [`Eq`] and [`Hash`] through its
+/// [`canonical_key`](Polynomial::canonical_key), so that it can be used as the key of a
+/// [`HashMap`](std::collections::HashMap) or the element of a
+/// [`HashSet`](std::collections::HashSet).
+///
+/// Two keys are equal if their polynomials have bit-exactly the same coefficients, ignoring
+/// trailing zeros. Unlike [`PartialEq`] on [`Polynomial`], polynomials with NaN coefficients are
+/// equal to themselves.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{PolyKey, Polynomial, Result};
+/// # use std::collections::HashMap;
+/// # fn main() -> Result<()> {
+/// let mut cache = HashMap::new();
+///
+/// let p = Polynomial::build(&[-1.0, 0.0, 1.0])?;
+/// cache.insert(PolyKey::new(p.clone()), p.solve_complex()?);
+///
+/// let q = Polynomial::build(&[-1.0, 0.0, 1.0, 0.0])?;
+/// assert!(cache.contains_key(&PolyKey::new(q)));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PolyKey<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    poly: Polynomial<T>,
+    key: Vec<u64>,
+}
+
+impl<T> PolyKey<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Wraps a polynomial, computing its canonical key.
+    pub fn new(poly: Polynomial<T>) -> Self {
+        let key = poly.canonical_key();
+        PolyKey { poly, key }
+    }
+
+    /// Returns the wrapped polynomial.
+    pub fn poly(&self) -> &Polynomial<T> {
+        &self.poly
+    }
+
+    /// Returns the canonical key of the wrapped polynomial.
+    pub fn key(&self) -> &[u64] {
+        &self.key
+    }
+
+    /// Unwraps the polynomial.
+    pub fn into_inner(self) -> Polynomial<T> {
+        self.poly
+    }
+}
+
+impl<T> From<Polynomial<T>> for PolyKey<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    fn from(poly: Polynomial<T>) -> Self {
+        PolyKey::new(poly)
+    }
+}
+
+impl<T> PartialEq for PolyKey<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<T> Eq for PolyKey<T> where T: num::complex::ComplexFloat + std::fmt::Debug {}
+
+impl<T> Hash for PolyKey<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+    }
+}
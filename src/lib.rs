@@ -1,16 +1,129 @@
 //! A re-write of [`GSL's Polynomial Routines`].
 //!
 //! [`GSL's Polynomial Routines`]: https://www.gnu.org/software/gsl/doc/html/poly.html
+//!
+//! ## Features
+//!
+//! - `ffi`: `extern "C"` functions with the symbols and signatures of GSL's polynomial routines.
+//! - `nalgebra`: evaluation of polynomials at `nalgebra` matrices, characteristic polynomials and
+//!   companion matrices, and the `NalgebraEigen` backend of the general solver.
+//! - `ndarray`: construction, evaluation and fitting with `ndarray` arrays.
+//! - `tracing`: `tracing` spans and events from the general solvers, with the algorithm chosen,
+//!   the iterations, the residuals of the roots and the fallbacks taken.
+//! - `wasm-bindgen`: JavaScript bindings of the evaluation and the solvers. The crate itself
+//!   builds for `wasm32-unknown-unknown` without any feature.
+//!
+//! ## Reproducibility
+//!
+//! Nothing in the crate is randomized: the initial values of the iterative solvers are fixed
+//! functions of the coefficients, and the samples of
+//! [`fit_ransac`](Polynomial::fit_ransac) come from a generator seeded by
+//! [`RansacOptions::seed`], so every result is the same from run to run on the same machine and
+//! build.
+//!
+//! Across platforms, the results are also bit-for-bit identical wherever they only depend on the
+//! basic arithmetic operations and `sqrt`, which IEEE 754 rounds exactly, e.g. evaluation, the
+//! arithmetic of polynomials, [`solve_real_quadratic`](Polynomial::solve_real_quadratic), and
+//! [`solve_complex`](Polynomial::solve_complex) with real coefficients, whose companion QR uses
+//! nothing else. Code paths that call transcendental functions (`cbrt`, `powf`, `acos`, `cos`,
+//! `hypot`, ...) get them from the platform's math library, whose last bits may differ, e.g.
+//! [`solve_real_cubic`](Polynomial::solve_real_cubic) and the Aberth iterations used for complex
+//! coefficients. The only exception to both are 32-bit x86 targets without SSE2, whose x87
+//! registers carry excess precision.
 
+pub mod approx;
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+pub mod arbitrary;
+mod arith;
+#[cfg(feature = "ndarray")]
+mod array;
+pub mod bases;
+mod basis;
+pub mod batch;
+mod bezier;
+mod bracket;
+mod calculus;
+mod check;
+pub mod compat;
+mod contour;
+mod decompose;
+mod distance;
+pub mod dsp;
 mod error;
+mod eval;
+mod exact;
+mod extrema;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod fit;
+mod fixed;
+mod gradient;
+pub mod homotopy;
+mod integer;
+mod key;
+mod linalg;
+mod lut;
+#[cfg(feature = "nalgebra")]
+mod matrix;
+mod monotone;
+mod multi;
+mod online;
+mod piecewise;
+mod polymatrix;
 mod polynomial;
-mod solve;
+mod pretty;
+pub mod quadrature;
+pub mod rational;
+mod robust;
+pub mod roots;
+mod sample;
+mod series;
+mod sign;
+pub mod solve;
+mod solver_state;
+mod sparse;
+mod special;
+mod spline;
+mod stats;
+mod tolerance;
+mod tracker;
+mod trig;
 mod utils;
+mod vieta;
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm;
 
 #[cfg(test)]
 mod test;
 
-pub use error::PolyError;
-pub use polynomial::Polynomial;
+pub use basis::BasisEvaluator;
+pub use bracket::BracketOptions;
+pub use check::SelfCheckReport;
+pub use distance::Norm;
+pub use error::{PolyError, PolyErrorKind};
+pub use eval::NanPolicy;
+pub use extrema::{CriticalPoint, StationaryKind};
+pub use fit::{DegreeCriterion, DegreeSelection, FitResult, OrthogonalFit};
+pub use fixed::PolyN;
+pub use key::PolyKey;
+pub use lut::{Lut, LutNodes};
+pub use multi::MultiPoly;
+pub use online::OnlinePolyFit;
+pub use piecewise::PiecewisePolynomial;
+pub use polymatrix::PolyMatrix;
+pub use polynomial::{Polynomial, SanitizePolicy};
+pub use pretty::FormatOptions;
+pub use robust::{RansacOptions, RobustLoss};
+pub use roots::{CLUSTER_TOL, CubicRoots, QuadraticRoots, Root};
+pub use sample::SampleOptions;
+pub use series::TruncatedSeries;
+pub use sign::{Sign, SignInterval};
+pub use solver_state::{IterationInfo, RootSolverState};
+pub use sparse::SparsePolynomial;
+pub use spline::SplineBoundary;
+pub use tolerance::Tolerance;
+pub use tracker::RootTracker;
+pub use trig::TrigPoly;
+pub use vieta::RootReport;
 
 pub type Result<T> = std::result::Result<T, error::PolyError>;
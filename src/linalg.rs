@@ -0,0 +1,213 @@
+//! Small dense linear algebra routines used internally.
+//!
+//! Matrices are stored as a [`Vec`] of rows.
+
+use crate::{PolyError, Result};
+
+/// Solves the linear least squares problem  min‖Ax − b‖₂  (A is m×n, m ≥ n) using Householder
+/// QR decomposition, returning the solution x and the matrix (AᵀA)⁻¹ = R⁻¹R⁻ᵀ.
+pub(crate) fn lstsq(a: &[Vec<f64>], b: &[f64]) -> Result<(Vec<f64>, Vec<Vec<f64>>)> {
+    let m = a.len();
+    let n = a.first().map_or(0, |row| row.len());
+    if (n == 0) | (m < n) {
+        return Err(PolyError::SingularMatrix);
+    }
+
+    let mut r = a.to_vec();
+    let mut qtb = b.to_vec();
+
+    for k in 0..n {
+        let norm = (k..m).map(|i| r[i][k].powi(2)).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            return Err(PolyError::SingularMatrix);
+        }
+
+        // Householder vector v = x − αe₁, with α chosen to avoid cancellation
+        let alpha = if r[k][k] > 0.0 { -norm } else { norm };
+        let mut v: Vec<f64> = (k..m).map(|i| r[i][k]).collect();
+        v[0] -= alpha;
+        let vnorm2: f64 = v.iter().map(|e| e * e).sum();
+
+        // sⱼ = 2vᵀrⱼ/‖v‖² for the trailing columns rⱼ, which are then updated to rⱼ − sⱼv
+        let mut s = vec![0.0; n - k];
+        for (row, vi) in r[k..].iter().zip(&v) {
+            s.iter_mut()
+                .zip(&row[k..])
+                .for_each(|(sj, rij)| *sj += vi * rij);
+        }
+        s.iter_mut().for_each(|sj| *sj = 2.0 * *sj / vnorm2);
+        for (row, vi) in r[k..].iter_mut().zip(&v) {
+            row[k..]
+                .iter_mut()
+                .zip(&s)
+                .for_each(|(rij, sj)| *rij -= sj * vi);
+        }
+        let s = 2.0 * v.iter().zip(&qtb[k..]).map(|(vi, bi)| vi * bi).sum::<f64>() / vnorm2;
+        qtb[k..]
+            .iter_mut()
+            .zip(&v)
+            .for_each(|(bi, vi)| *bi -= s * vi);
+    }
+
+    // Rank check
+    let rmax = (0..n).map(|i| r[i][i].abs()).fold(0.0, f64::max);
+    if (0..n).any(|i| r[i][i].abs() <= rmax * (n as f64) * f64::EPSILON) {
+        return Err(PolyError::SingularMatrix);
+    }
+
+    let x = solve_upper_triangular(&r, &qtb[..n]);
+
+    // R⁻¹, column by column
+    let mut rinv = vec![vec![0.0; n]; n];
+    for j in 0..n {
+        let mut e = vec![0.0; n];
+        e[j] = 1.0;
+        let col = solve_upper_triangular(&r, &e);
+        (0..n).for_each(|i| rinv[i][j] = col[i]);
+    }
+
+    let mut cov = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in 0..n {
+            cov[i][j] = (0..n).map(|k| rinv[i][k] * rinv[j][k]).sum();
+        }
+    }
+
+    Ok((x, cov))
+}
+
+/// Solves the linear least squares problem  min‖Ax − b‖₂  (A is m×n, of any shape and rank)
+/// with the singular value decomposition of A, found with one-sided Jacobi rotations. Singular
+/// values below `rcond` times the largest one are treated as 0, so that the minimum-norm solution
+/// is returned for rank-deficient problems, as LAPACK's `gelsd` does.
+pub(crate) fn lstsq_svd(a: &[Vec<f64>], b: &[f64], rcond: f64) -> Vec<f64> {
+    let n = a.first().map_or(0, |row| row.len());
+    // The columns of A, rotated in place into the columns of UΣ, and of V
+    let mut u: Vec<Vec<f64>> = (0..n)
+        .map(|j| a.iter().map(|row| row[j]).collect())
+        .collect();
+    let mut v: Vec<Vec<f64>> = (0..n)
+        .map(|j| (0..n).map(|i| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+    let dot = |x: &[f64], y: &[f64]| x.iter().zip(y).map(|(a, b)| a * b).sum::<f64>();
+
+    for _ in 0..60 {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let alpha = dot(&u[p], &u[p]);
+                let beta = dot(&u[q], &u[q]);
+                let gamma = dot(&u[p], &u[q]);
+                if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                let c = 1.0 / (1.0 + t * t).sqrt();
+                let s = c * t;
+                for cols in [&mut u, &mut v] {
+                    let (head, tail) = cols.split_at_mut(q);
+                    for (x, y) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+                        (*x, *y) = (c * *x - s * *y, s * *x + c * *y);
+                    }
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    // x = Σ vⱼ(uⱼ·b)/σⱼ², where the columns uⱼ of UΣ have norm σⱼ
+    let sigma2: Vec<f64> = u.iter().map(|col| dot(col, col)).collect();
+    let cutoff = rcond * rcond * sigma2.iter().copied().fold(0.0, f64::max);
+    let mut x = vec![0.0; n];
+    for ((uj, vj), s2) in u.iter().zip(&v).zip(&sigma2) {
+        if *s2 > cutoff && *s2 > 0.0 {
+            let k = dot(uj, b) / s2;
+            x.iter_mut().zip(vj).for_each(|(x, v)| *x += k * v);
+        }
+    }
+    x
+}
+
+/// Solves Rx = b by back substitution, where R is the upper triangular part of the leading n×n
+/// block of `r`.
+fn solve_upper_triangular(r: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n = b.len();
+    let mut x = vec![0.0; n];
+    for i in (0..n).rev() {
+        let s: f64 = ((i + 1)..n).map(|j| r[i][j] * x[j]).sum();
+        x[i] = (b[i] - s) / r[i][i];
+    }
+    x
+}
+
+/// Computes the Cholesky decomposition A = LLᵀ of a symmetric positive definite matrix, returning
+/// the lower triangular L.
+pub(crate) fn cholesky(a: &[Vec<f64>]) -> Result<Vec<Vec<f64>>> {
+    let n = a.len();
+    let mut l: Vec<Vec<f64>> = Vec::with_capacity(n);
+    for (i, ai) in a.iter().enumerate() {
+        let mut li = vec![0.0; n];
+        for (j, lj) in l.iter().enumerate() {
+            let s: f64 = li[..j].iter().zip(lj).map(|(x, y)| x * y).sum();
+            li[j] = (ai[j] - s) / lj[j];
+        }
+        let d = ai[i] - li[..i].iter().map(|x| x * x).sum::<f64>();
+        if d <= 0.0 {
+            return Err(PolyError::SingularMatrix);
+        }
+        li[i] = d.sqrt();
+        l.push(li);
+    }
+    Ok(l)
+}
+
+/// Solves Lx = b by forward substitution, where L is lower triangular.
+pub(crate) fn solve_lower_triangular(l: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n = b.len();
+    let mut x = vec![0.0; n];
+    for i in 0..n {
+        let s: f64 = (0..i).map(|j| l[i][j] * x[j]).sum();
+        x[i] = (b[i] - s) / l[i][i];
+    }
+    x
+}
+
+/// Solves Lᵀx = b by back substitution, where L is lower triangular.
+pub(crate) fn solve_lower_triangular_transposed(l: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n = b.len();
+    let mut x = vec![0.0; n];
+    for i in (0..n).rev() {
+        let s: f64 = ((i + 1)..n).map(|j| l[j][i] * x[j]).sum();
+        x[i] = (b[i] - s) / l[i][i];
+    }
+    x
+}
+
+/// Solves a tridiagonal system with the Thomas algorithm, where `sub`, `diag` and `sup` are the
+/// sub-, main and super-diagonals (`sub[0]` and `sup[n-1]` are unused).
+///
+/// The matrix must be diagonally dominant, so that no pivoting is needed.
+pub(crate) fn solve_tridiagonal(sub: &[f64], diag: &[f64], sup: &[f64], rhs: &[f64]) -> Vec<f64> {
+    let n = diag.len();
+    let mut c = vec![0.0; n];
+    let mut d = vec![0.0; n];
+
+    c[0] = sup[0] / diag[0];
+    d[0] = rhs[0] / diag[0];
+    for i in 1..n {
+        let m = diag[i] - sub[i] * c[i - 1];
+        c[i] = sup[i] / m;
+        d[i] = (rhs[i] - sub[i] * d[i - 1]) / m;
+    }
+
+    let mut x = vec![0.0; n];
+    x[n - 1] = d[n - 1];
+    for i in (0..n - 1).rev() {
+        x[i] = d[i] - c[i] * x[i + 1];
+    }
+    x
+}
@@ -0,0 +1,154 @@
+//! Conversion of polynomials to lookup tables for linear interpolation.
+
+use std::f64::consts::PI;
+
+use crate::{PolyError, Polynomial, Result};
+
+/// Number of fine grid cells per table node used to place the [`LutNodes::EqualError`] nodes.
+const DENSITY_SAMPLES: usize = 64;
+
+/// Number of points per table segment used to measure the reconstruction error.
+const ERROR_SAMPLES: usize = 16;
+
+/// Node placement strategy for [`Polynomial::to_lut`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LutNodes {
+    /// Equally spaced nodes.
+    Uniform,
+    /// Chebyshev extrema nodes, clustered towards the interval's endpoints.
+    Chebyshev,
+    /// Nodes placed so that every segment contributes roughly the same linear interpolation
+    /// error, i.e. denser where the polynomial's curvature is larger.
+    EqualError,
+}
+
+/// A lookup table of samples of a polynomial, reconstructed by linear interpolation.
+#[derive(Clone, Debug)]
+pub struct Lut {
+    /// The `(x, y)` samples, in increasing `x` order.
+    pub points: Vec<(f64, f64)>,
+    /// The maximum absolute reconstruction error over the table's interval.
+    pub max_error: f64,
+    /// The root mean square reconstruction error over the table's interval.
+    pub rms_error: f64,
+}
+
+impl Lut {
+    /// Evaluates the table at `x` by linear interpolation between the neighbouring samples.
+    ///
+    /// Values outside the table's interval are linearly extrapolated from the closest segment.
+    pub fn eval(&self, x: f64) -> f64 {
+        let i = self
+            .points
+            .partition_point(|(xi, _)| *xi <= x)
+            .clamp(1, self.points.len() - 1);
+        let (x0, y0) = self.points[i - 1];
+        let (x1, y1) = self.points[i];
+        y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+    }
+}
+
+impl Polynomial<f64> {
+    /// Samples the polynomial at `n` nodes in `[a, b]`, placed according to `nodes`, producing a
+    /// lookup table for linear interpolation along with its reconstruction error.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `n < 2` or if `[a, b]` is not a valid finite interval.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{LutNodes, Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[0.0, 0.0, 0.0, 1.0])?; // x³
+    ///
+    /// let uniform = poly.to_lut(0.0, 2.0, 16, LutNodes::Uniform)?;
+    /// let equal = poly.to_lut(0.0, 2.0, 16, LutNodes::EqualError)?;
+    ///
+    /// assert!(equal.max_error < uniform.max_error);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_lut(&self, a: f64, b: f64, n: usize, nodes: LutNodes) -> Result<Lut> {
+        if n < 2 {
+            return Err(PolyError::NotEnoughPoints(2));
+        }
+        if !(a.is_finite() && b.is_finite() && a < b) {
+            return Err(PolyError::InvalidInterval(a, b));
+        }
+
+        let xs: Vec<f64> = match nodes {
+            LutNodes::Uniform => (0..n)
+                .map(|k| a + (b - a) * k as f64 / (n - 1) as f64)
+                .collect(),
+            LutNodes::Chebyshev => (0..n)
+                .map(|k| 0.5 * (a + b) - 0.5 * (b - a) * (PI * k as f64 / (n - 1) as f64).cos())
+                .collect(),
+            LutNodes::EqualError => self.equal_error_nodes(a, b, n),
+        };
+
+        let points: Vec<(f64, f64)> = xs.iter().map(|x| (*x, self.eval(*x))).collect();
+
+        // Measure the reconstruction error inside every segment
+        let (mut max_error, mut sum_sq) = (0.0f64, 0.0);
+        for pair in points.windows(2) {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            for j in 1..ERROR_SAMPLES {
+                let t = j as f64 / ERROR_SAMPLES as f64;
+                let x = x0 + t * (x1 - x0);
+                let error = (self.eval(x) - (y0 + t * (y1 - y0))).abs();
+                max_error = max_error.max(error);
+                sum_sq += error.powi(2) * (x1 - x0) / ERROR_SAMPLES as f64;
+            }
+        }
+
+        Ok(Lut {
+            points,
+            max_error,
+            rms_error: (sum_sq / (b - a)).sqrt(),
+        })
+    }
+
+    /// Places `n` nodes so that they equidistribute √|P''|, which asymptotically equalizes the
+    /// linear interpolation error h²|P''|/8 of each segment.
+    fn equal_error_nodes(&self, a: f64, b: f64, n: usize) -> Vec<f64> {
+        let cells = DENSITY_SAMPLES * n;
+        let grid: Vec<f64> = (0..=cells)
+            .map(|k| a + (b - a) * k as f64 / cells as f64)
+            .collect();
+        let curvature: Vec<f64> = grid
+            .iter()
+            .map(|x| self.eval_derivs(*x, 3)[2].abs().sqrt())
+            .collect();
+
+        // Regularize the density, so that flat regions still get some nodes
+        let mean = curvature.iter().sum::<f64>() / curvature.len() as f64;
+        let floor = 0.01 * mean.max(f64::MIN_POSITIVE);
+        let density: Vec<f64> = curvature.iter().map(|c| c + floor).collect();
+
+        let mut cumulative = vec![0.0; cells + 1];
+        for k in 1..=cells {
+            cumulative[k] =
+                cumulative[k - 1] + 0.5 * (density[k - 1] + density[k]) * (grid[k] - grid[k - 1]);
+        }
+        let total = cumulative[cells];
+
+        let mut nodes: Vec<f64> = (0..n)
+            .map(|i| {
+                let target = total * i as f64 / (n - 1) as f64;
+                let k = cumulative.partition_point(|c| *c < target).clamp(1, cells);
+                let (c0, c1) = (cumulative[k - 1], cumulative[k]);
+                let t = if c1 > c0 {
+                    (target - c0) / (c1 - c0)
+                } else {
+                    0.0
+                };
+                grid[k - 1] + t * (grid[k] - grid[k - 1])
+            })
+            .collect();
+        nodes[0] = a;
+        nodes[n - 1] = b;
+        nodes
+    }
+}
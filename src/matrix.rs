@@ -0,0 +1,159 @@
+//! Polynomials of square [`nalgebra`] matrices, and the characteristic and companion matrices
+//! of polynomials.
+
+use nalgebra::{ComplexField, DMatrix};
+
+use crate::{PolyError, Polynomial, Result};
+
+impl<T> Polynomial<T>
+where
+    T: num::complex::ComplexFloat + ComplexField + std::fmt::Debug,
+{
+    /// Evaluates the polynomial at the square matrix `a`, i.e. calculates
+    /// P(A) = c₀I + c₁A + ... + cₙAⁿ.
+    ///
+    /// The Paterson–Stockmeyer scheme is used, which needs about 2√n matrix multiplications
+    /// instead of the n of Horner's method.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `a` is not square.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # use nalgebra::DMatrix;
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 0.0, 1.0])?; // 1+x²
+    /// let a = DMatrix::from_row_slice(2, 2, &[0.0, 1.0, -1.0, 0.0]); // A² = -I
+    ///
+    /// assert_eq!(poly.eval_matrix(&a)?, DMatrix::zeros(2, 2));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_matrix(&self, a: &DMatrix<T>) -> Result<DMatrix<T>> {
+        if !a.is_square() {
+            return Err(PolyError::NotSquareMatrix(a.nrows(), a.ncols()));
+        }
+        let dim = a.nrows();
+        if self.coef.is_empty() {
+            return Ok(DMatrix::zeros(dim, dim));
+        }
+
+        // P(A) = Σ Bₖ(A)·(Aˢ)ᵏ, where Bₖ(A) = Σ cₖₛ₊ⱼAʲ (j < s), evaluated with Horner's method in Aˢ
+        let s = ((self.coef.len() as f64).sqrt().ceil() as usize).max(1);
+        let mut powers = vec![DMatrix::identity(dim, dim)];
+        for j in 1..=s {
+            powers.push(&powers[j - 1] * a);
+        }
+
+        let block = |chunk: &[T]| {
+            chunk
+                .iter()
+                .zip(&powers)
+                .fold(DMatrix::zeros(dim, dim), |acc, (c, power)| acc + power * *c)
+        };
+
+        let mut chunks = self.coef.chunks(s).rev();
+        let leading = block(chunks.next().unwrap_or(&[]));
+        Ok(chunks.fold(leading, |acc, chunk| acc * &powers[s] + block(chunk)))
+    }
+
+    /// Returns the companion matrix of the polynomial, i.e. the n×n matrix with ones on the
+    /// subdiagonal and −cᵢ/cₙ on the last column, whose characteristic polynomial is the monic
+    /// version of the polynomial and whose eigenvalues are its roots.
+    ///
+    /// This is the matrix whose eigenvalues [`solve_complex`](Polynomial::solve_complex)
+    /// calculates, so it can be used with other eigenvalue solvers. Zero higher order terms are
+    /// ignored.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the polynomial is constant.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # use nalgebra::DMatrix;
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[6.0, -5.0, 1.0])?; // (x-2)(x-3)
+    /// let companion = poly.companion_matrix()?;
+    ///
+    /// assert_eq!(companion, DMatrix::from_row_slice(2, 2, &[0.0, -6.0, 1.0, 5.0]));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn companion_matrix(&self) -> Result<DMatrix<T>> {
+        let n = match self.coef.iter().rposition(|c| !c.is_zero()) {
+            Some(n) if n > 0 => n,
+            _ => return Err(PolyError::ConstantPoly),
+        };
+
+        let mut m = DMatrix::zeros(n, n);
+        for i in 1..n {
+            m[(i, i - 1)] = T::one();
+        }
+        for i in 0..n {
+            m[(i, n - 1)] = -self.coef[i] / self.coef[n];
+        }
+        Ok(m)
+    }
+
+    /// Calculates the characteristic polynomial det(xI − A) of the square matrix `a`.
+    ///
+    /// The matrix is first reduced to upper Hessenberg form H by unitary similarity
+    /// transformations, and the characteristic polynomial of H is then calculated with La Budde's
+    /// recurrence over its leading principal submatrices, which is much more stable than the
+    /// Faddeev–LeVerrier method.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `a` is not square.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # use nalgebra::DMatrix;
+    /// # fn main() -> Result<()> {
+    /// let a = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+    /// let charpoly = Polynomial::characteristic(&a)?; // x²-5x-2
+    ///
+    /// assert!(charpoly.approx_eq(&Polynomial::build(&[-2.0, -5.0, 1.0])?, 1e-14, 1e-14));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "charpoly")]
+    pub fn characteristic(a: &DMatrix<T>) -> Result<Polynomial<T>> {
+        if !a.is_square() {
+            return Err(PolyError::NotSquareMatrix(a.nrows(), a.ncols()));
+        }
+        let n = a.nrows();
+        let h = match n {
+            0 => a.clone(),
+            _ => a.clone().hessenberg().h(),
+        };
+
+        // pᵢ is the characteristic polynomial of the leading i×i submatrix of H:
+        // pᵢ = (x − hᵢᵢ)pᵢ₋₁ − Σₘ hᵢ₋ₘ,ᵢ·(hᵢ,ᵢ₋₁⋯hᵢ₋ₘ₊₁,ᵢ₋ₘ)·pᵢ₋ₘ₋₁ (1-based)
+        let mut p: Vec<Polynomial<T>> = vec![Polynomial {
+            coef: vec![T::one()],
+        }];
+        for i in 1..=n {
+            let linear = Polynomial {
+                coef: vec![-h[(i - 1, i - 1)], T::one()],
+            };
+            let mut next = &linear * &p[i - 1];
+            let mut subdiagonal = T::one();
+            for m in 1..i {
+                subdiagonal *= h[(i - m, i - m - 1)];
+                next.axpy(-h[(i - m - 1, i - 1)] * subdiagonal, &p[i - m - 1]);
+            }
+            p.push(next);
+        }
+
+        Ok(p.swap_remove(n))
+    }
+}
@@ -0,0 +1,180 @@
+//! Projection of polynomials onto monotone polynomials.
+
+use crate::utils::compose_linear;
+use crate::{PolyError, Polynomial, Result, linalg};
+
+/// Number of points per coefficient of the initial grid at which the monotonicity constraint is
+/// enforced.
+const CONSTRAINT_POINTS: usize = 16;
+
+/// Maximum number of constraint points added where the slope has the wrong sign.
+const MAX_REFINEMENTS: usize = 50;
+
+/// Tolerance of the slope's sign, relative to the magnitude of the derivative's coefficients.
+const SLOPE_TOL: f64 = 1e-10;
+
+/// Maximum number of sweeps over the constraints of Hildreth's method.
+const MAX_SWEEPS: usize = 100_000;
+
+impl Polynomial<f64> {
+    /// Returns the polynomial of the same order that is monotone on `[a, b]` and is closest to
+    /// this one in the least-squares sense, i.e. minimizes ∫(P − Q)²dx over the interval.
+    ///
+    /// The direction of monotonicity is the one of P(b) − P(a). The constraint Q' ≥ 0 (or ≤ 0) is
+    /// enforced on a dense grid of Chebyshev points in the interval, which is refined with the
+    /// minima of Q' until the constraint holds (up to rounding) on the whole interval. Each
+    /// quadratic program is solved with Hildreth's method. A polynomial that is already monotone
+    /// on the interval is returned unchanged.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `[a, b]` is not a valid finite interval, or if the optimization does
+    /// not converge.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[0.0, -0.2, 0.0, 1.0])?; // x³-0.2x, with a dip around 0
+    /// let monotone = poly.nearest_monotone_on(-1.0, 1.0)?;
+    ///
+    /// let deriv = monotone.derivative();
+    /// assert!((0..=100).all(|i| deriv.eval(-1.0 + 0.02 * i as f64) >= -1e-9));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn nearest_monotone_on(&self, a: f64, b: f64) -> Result<Polynomial<f64>> {
+        if !(a.is_finite() && b.is_finite() && a < b) {
+            return Err(PolyError::InvalidInterval(a, b));
+        }
+
+        // Work on t ∈ [-1, 1], where x = mid + half·t, to keep the problem well conditioned.
+        let (mid, half) = (0.5 * (a + b), 0.5 * (b - a));
+        let p = compose_linear(self, half, mid);
+        let n = p.coef.len();
+        if n <= 2 {
+            return Ok(self.clone());
+        }
+
+        let sign = if self.eval(b) >= self.eval(a) {
+            1.0
+        } else {
+            -1.0
+        };
+
+        // Constraint row at t: sign·Q'(t) = Σ sign·k·cₖ·tᵏ⁻¹ ≥ 0
+        let constraint = |t: f64| -> Vec<f64> {
+            (0..n)
+                .map(|k| match k {
+                    0 => 0.0,
+                    _ => sign * k as f64 * t.powi(k as i32 - 1),
+                })
+                .collect()
+        };
+
+        let (_, violation) = min_slope(&p, sign)?;
+        if violation >= 0.0 {
+            return Ok(self.clone());
+        }
+
+        // With the Gram matrix G = LLᵀ of the monomials on [-1, 1], the objective becomes
+        // ‖Lᵀ(c − p)‖², i.e. a Euclidean projection of y₀ = Lᵀp onto the cone {y: ÃLᵀc ≥ 0}.
+        let gram: Vec<Vec<f64>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| match (i + j) % 2 {
+                        0 => 2.0 / (i + j + 1) as f64,
+                        _ => 0.0,
+                    })
+                    .collect()
+            })
+            .collect();
+        let l = linalg::cholesky(&gram)?;
+        let y0: Vec<f64> = (0..n)
+            .map(|i| (i..n).map(|k| l[k][i] * p.coef[k]).sum())
+            .collect();
+
+        // Start from a Chebyshev grid, and keep adding the point where the slope is most
+        // negative until the constraint holds everywhere.
+        let m = CONSTRAINT_POINTS * n;
+        let mut rows: Vec<Vec<f64>> = (0..m)
+            .map(|j| -(std::f64::consts::PI * j as f64 / (m - 1) as f64).cos())
+            .map(|t| linalg::solve_lower_triangular(&l, &constraint(t)))
+            .collect();
+        let slope_scale = p.derivative().coef.iter().map(|c| c.abs()).sum::<f64>();
+
+        for _ in 0..MAX_REFINEMENTS {
+            let y = hildreth(&rows, &y0)?;
+            let q = Polynomial {
+                coef: linalg::solve_lower_triangular_transposed(&l, &y),
+            };
+
+            let (t, slope) = min_slope(&q, sign)?;
+            if slope >= -SLOPE_TOL * slope_scale {
+                return Ok(compose_linear(&q, 1.0 / half, -mid / half));
+            }
+            rows.push(linalg::solve_lower_triangular(&l, &constraint(t)));
+        }
+
+        Err(PolyError::NoConvergence)
+    }
+}
+
+/// Returns the point of [-1, 1] where sign·Q' is minimum, along with that minimum.
+fn min_slope(q: &Polynomial<f64>, sign: f64) -> Result<(f64, f64)> {
+    let deriv = q.derivative();
+    let mut candidates = vec![-1.0, 1.0];
+
+    // The real parts of all the critical points are checked, since the solver may move real
+    // ones slightly off the real axis.
+    let second = deriv.derivative().to_trimmed();
+    if second.coef.len() >= 2 {
+        candidates.extend(
+            second
+                .solve_complex()?
+                .iter()
+                .map(|z| z.re.clamp(-1.0, 1.0)),
+        );
+    }
+
+    Ok(candidates.iter().map(|t| (*t, sign * deriv.eval(*t))).fold(
+        (0.0, f64::INFINITY),
+        |min, cur| match cur.1 < min.1 {
+            true => cur,
+            false => min,
+        },
+    ))
+}
+
+/// Projects `y0` onto the polyhedral cone {y: aⱼᵀy ≥ 0} with Hildreth's dual coordinate ascent.
+fn hildreth(rows: &[Vec<f64>], y0: &[f64]) -> Result<Vec<f64>> {
+    let norms: Vec<f64> = rows.iter().map(|row| dot(row, row)).collect();
+    let scale = dot(y0, y0).sqrt();
+    let mut lambda = vec![0.0; rows.len()];
+    let mut y = y0.to_vec();
+
+    for _ in 0..MAX_SWEEPS {
+        let mut max_step = 0.0f64;
+        for (j, row) in rows.iter().enumerate() {
+            if norms[j] == 0.0 {
+                continue;
+            }
+            let delta = (-dot(row, &y) / norms[j]).max(-lambda[j]);
+            if delta != 0.0 {
+                lambda[j] += delta;
+                y.iter_mut().zip(row).for_each(|(yi, ai)| *yi += delta * ai);
+                max_step = max_step.max(delta.abs() * norms[j].sqrt());
+            }
+        }
+        if max_step <= 1e-15 * scale {
+            return Ok(y);
+        }
+    }
+
+    Err(PolyError::NoConvergence)
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
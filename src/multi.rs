@@ -0,0 +1,234 @@
+//! Multivariate polynomials in a small, fixed number of variables.
+
+use crate::utils::check_finite_coef;
+use crate::{PolyError, Polynomial, Result};
+
+/// Representation of a polynomial in `N` variables x₀, ..., xₙ₋₁, as a list of its non-zero
+/// `(exponents, coefficient)` terms, where `exponents[i]` is the exponent of xᵢ.
+///
+/// The terms are kept sorted by their exponents, with no repeated exponents and no zero
+/// coefficients.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultiPoly<T, const N: usize>
+where
+    T: std::fmt::Debug,
+{
+    terms: Vec<([usize; N], T)>,
+}
+
+impl<T, const N: usize> MultiPoly<T, N>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Creates a new multivariate polynomial with no terms (zero polynomial).
+    pub fn new() -> Self {
+        MultiPoly { terms: vec![] }
+    }
+
+    /// Creates a new multivariate polynomial from the given `(exponents, coefficient)` terms, in
+    /// any order. Coefficients of repeated exponents are summed.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if any of the coefficients is NaN or Infinity.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{MultiPoly, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = MultiPoly::build(&[([2, 0], 1.0), ([1, 1], 3.0), ([0, 0], -1.0)])?; // x²+3xy−1
+    ///
+    /// assert_eq!(poly.degree(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build(terms: &[([usize; N], T)]) -> Result<Self> {
+        check_finite_coef(terms.iter().map(|(_, c)| *c))?;
+        Ok(Self::from_unsorted(terms.to_vec()))
+    }
+
+    /// Sorts the terms, merges repeated exponents and drops zero coefficients.
+    fn from_unsorted(mut terms: Vec<([usize; N], T)>) -> Self {
+        terms.sort_by_key(|(exps, _)| *exps);
+
+        let mut merged: Vec<([usize; N], T)> = Vec::with_capacity(terms.len());
+        for (exps, c) in terms {
+            match merged.last_mut() {
+                Some(last) if last.0 == exps => last.1 = last.1 + c,
+                _ => merged.push((exps, c)),
+            }
+        }
+        merged.retain(|(_, c)| !c.is_zero());
+
+        MultiPoly { terms: merged }
+    }
+
+    /// Returns the non-zero `(exponents, coefficient)` terms.
+    pub fn terms(&self) -> &[([usize; N], T)] {
+        &self.terms
+    }
+
+    /// Returns the total degree of the polynomial, i.e. the highest sum of exponents of its
+    /// terms. The zero polynomial has degree 0.
+    pub fn degree(&self) -> usize {
+        self.terms
+            .iter()
+            .map(|(exps, _)| exps.iter().sum())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Evaluates the polynomial at the point `x`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{MultiPoly, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = MultiPoly::build(&[([2, 0], 1.0), ([1, 1], 3.0), ([0, 0], -1.0)])?; // x²+3xy−1
+    ///
+    /// assert_eq!(poly.eval([2.0, 1.0]), 9.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval(&self, x: [T; N]) -> T {
+        // Tables of the powers of each variable, up to the highest exponent used
+        let powers: Vec<Vec<T>> = (0..N)
+            .map(|i| {
+                let max_exp = self.terms.iter().map(|(e, _)| e[i]).max().unwrap_or(0);
+                std::iter::successors(Some(T::one()), |p| Some(*p * x[i]))
+                    .take(max_exp + 1)
+                    .collect()
+            })
+            .collect();
+
+        self.terms
+            .iter()
+            .map(|(exps, c)| {
+                exps.iter()
+                    .enumerate()
+                    .fold(*c, |res, (i, e)| res * powers[i][*e])
+            })
+            .fold(T::zero(), |res, term| res + term)
+    }
+
+    /// Returns the partial derivative ∂P/∂xᵥ, where v = `var`.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `var >= N`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{MultiPoly, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = MultiPoly::build(&[([2, 0], 1.0), ([1, 1], 3.0), ([0, 0], -1.0)])?; // x²+3xy−1
+    /// let dx = poly.partial_derivative(0)?; // 2x+3y
+    ///
+    /// assert_eq!(dx.terms(), [([0, 1], 3.0), ([1, 0], 2.0)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn partial_derivative(&self, var: usize) -> Result<Self> {
+        check_variable::<N>(var)?;
+
+        let terms = self
+            .terms
+            .iter()
+            .filter(|(exps, _)| exps[var] > 0)
+            .map(|(exps, c)| {
+                let mut new_exps = *exps;
+                new_exps[var] -= 1;
+                (new_exps, *c * T::from(exps[var]).unwrap())
+            })
+            .collect();
+        Ok(Self::from_unsorted(terms))
+    }
+
+    /// Substitutes the variable x_`var` with the univariate polynomial `poly` in the variable
+    /// x_`in_var`.
+    ///
+    /// For example, substituting y = p(x) in a bivariate polynomial P(x, y) gives the values of P
+    /// along the curve (x, p(x)), as a polynomial that only depends on x.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `var >= N` or `in_var >= N`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{MultiPoly, Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = MultiPoly::build(&[([2, 0], 1.0), ([0, 2], 1.0)])?; // x²+y²
+    /// let line = Polynomial::build(&[1.0, 2.0])?; // y = 1+2x
+    ///
+    /// let on_line = poly.substitute(1, &line, 0)?.to_univariate(0)?;
+    /// assert_eq!(on_line.coef, [1.0, 4.0, 5.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn substitute(&self, var: usize, poly: &Polynomial<T>, in_var: usize) -> Result<Self> {
+        check_variable::<N>(var)?;
+        check_variable::<N>(in_var)?;
+
+        let max_exp = self.terms.iter().map(|(e, _)| e[var]).max().unwrap_or(0);
+        let powers: Vec<Polynomial<T>> = std::iter::successors(
+            Some(Polynomial {
+                coef: vec![T::one()],
+            }),
+            |p| Some(p * poly),
+        )
+        .take(max_exp + 1)
+        .collect();
+
+        let mut terms = vec![];
+        for (exps, c) in self.terms.iter() {
+            for (k, pc) in powers[exps[var]].terms() {
+                let mut new_exps = *exps;
+                new_exps[var] = 0;
+                new_exps[in_var] += k;
+                terms.push((new_exps, *c * pc));
+            }
+        }
+        Ok(Self::from_unsorted(terms))
+    }
+
+    /// Converts the polynomial to a univariate [`Polynomial`] in the variable x_`var`.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `var >= N`, or if the polynomial depends on any other variable.
+    pub fn to_univariate(&self, var: usize) -> Result<Polynomial<T>> {
+        check_variable::<N>(var)?;
+
+        let depends_on_others = self
+            .terms
+            .iter()
+            .any(|(exps, _)| exps.iter().enumerate().any(|(i, e)| i != var && *e > 0));
+        if depends_on_others {
+            return Err(PolyError::NotUnivariate(var));
+        }
+
+        Ok(self.terms.iter().map(|(exps, c)| (exps[var], *c)).collect())
+    }
+}
+
+impl<T, const N: usize> Default for MultiPoly<T, N>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Checks that `var` is a valid variable index for a polynomial in `N` variables.
+fn check_variable<const N: usize>(var: usize) -> Result<()> {
+    match var < N {
+        true => Ok(()),
+        false => Err(PolyError::InvalidVariable(var, N)),
+    }
+}
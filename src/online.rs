@@ -0,0 +1,137 @@
+//! Streaming least-squares fitting of polynomials, in constant memory.
+
+use crate::{FitResult, PolyError, Polynomial, Result, linalg};
+
+/// An incremental least-squares polynomial fit, refreshed one data point at a time without
+/// storing the data.
+///
+/// The fitter keeps the triangular factor R of the QR decomposition of the Vandermonde matrix,
+/// along with Qᵀy and the sum of squared residuals, and updates them with Givens rotations for
+/// every new point. This takes O(degree²) memory and time per point, and has the numerical
+/// stability of QR, unlike accumulating the normal equations.
+///
+/// As with [`Polynomial::fit`], the powers of large abscissas, e.g. timestamps, are badly
+/// scaled: subtract a reference value from them first.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{OnlinePolyFit, Result};
+/// # fn main() -> Result<()> {
+/// let mut online = OnlinePolyFit::new(2);
+/// for i in 0..1000 {
+///     let t = i as f64 / 100.0;
+///     online.update(t, 1.0 + 0.5 * t - 0.1 * t * t)?;
+/// }
+///
+/// let fit = online.fit()?;
+/// assert!((fit.poly.coef[2] + 0.1).abs() < 1e-12);
+/// assert_eq!(fit.dof, 997);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct OnlinePolyFit {
+    /// The upper triangular factor R, (degree+1)×(degree+1).
+    r: Vec<Vec<f64>>,
+    /// The first degree+1 entries of Qᵀy.
+    qty: Vec<f64>,
+    /// The sum of squared residuals, i.e. the squared norm of the rest of Qᵀy.
+    chi2: f64,
+    /// The number of points so far.
+    count: usize,
+}
+
+impl OnlinePolyFit {
+    /// Creates a fitter of a polynomial of order `degree`, with no data.
+    pub fn new(degree: usize) -> Self {
+        OnlinePolyFit {
+            r: vec![vec![0.0; degree + 1]; degree + 1],
+            qty: vec![0.0; degree + 1],
+            chi2: 0.0,
+            count: 0,
+        }
+    }
+
+    /// Returns the order of the fitted polynomial.
+    pub fn degree(&self) -> usize {
+        self.qty.len() - 1
+    }
+
+    /// Returns the number of points added so far.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if no points have been added.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Adds the data point `(x, y)` to the fit.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `x` or `y` is NaN or Infinity, or if the powers of `x` overflow, in
+    /// which case the fit is left unchanged.
+    pub fn update(&mut self, x: f64, y: f64) -> Result<()> {
+        let n = self.qty.len();
+        let mut row: Vec<f64> = std::iter::successors(Some(1.0), |p| Some(p * x))
+            .take(n)
+            .collect();
+        if !y.is_finite() || row.iter().any(|v| !v.is_finite()) {
+            return Err(PolyError::InvalidData);
+        }
+
+        // Rotate the new row into R, one column at a time
+        let mut b = y;
+        for k in 0..n {
+            if row[k] == 0.0 {
+                continue;
+            }
+            let h = self.r[k][k].hypot(row[k]);
+            let (c, s) = (self.r[k][k] / h, row[k] / h);
+            self.r[k][k] = h;
+            for (rkj, aj) in self.r[k][k + 1..].iter_mut().zip(&mut row[k + 1..]) {
+                (*rkj, *aj) = (c * *rkj + s * *aj, c * *aj - s * *rkj);
+            }
+            let qk = self.qty[k];
+            self.qty[k] = c * qk + s * b;
+            b = c * b - s * qk;
+        }
+        self.chi2 += b * b;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Returns the least-squares fit of the points added so far, as [`Polynomial::fit`] would
+    /// for the same data, with the covariance of the coefficients scaled by χ²/dof.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if fewer than `degree+1` points have been added, or if their abscissas do
+    /// not determine a unique polynomial.
+    pub fn fit(&self) -> Result<FitResult> {
+        let n = self.qty.len();
+        if self.count < n {
+            return Err(PolyError::NotEnoughPoints(n));
+        }
+        let (coef, mut covariance) = linalg::lstsq(&self.r, &self.qty)?;
+
+        let dof = self.count - n;
+        let s2 = match dof {
+            0 => f64::NAN,
+            _ => self.chi2 / dof as f64,
+        };
+        covariance.iter_mut().flatten().for_each(|c| *c *= s2);
+
+        Ok(FitResult {
+            poly: Polynomial::build(&coef)?,
+            covariance,
+            chi2: self.chi2,
+            dof,
+            residual_variance: s2,
+            covariance_scale: 1.0,
+        })
+    }
+}
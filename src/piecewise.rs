@@ -0,0 +1,202 @@
+//! Piecewise polynomials, defined by breakpoints and one polynomial per interval.
+
+use crate::roots::{REAL_TOL, real_roots_in};
+use crate::{PolyError, Polynomial, Result};
+
+/// Representation of a piecewise polynomial on the breakpoints x₀ < x₁ < ... < xₙ:
+///
+/// S(x) = Pᵢ(x − xᵢ),  for xᵢ ≤ x < xᵢ₊₁
+///
+/// Each piece is expressed in terms of the local coordinate x − xᵢ, which keeps the
+/// coefficients well scaled far from the origin. Outside of [x₀, xₙ], the first and last pieces
+/// are extrapolated.
+#[derive(Clone, Debug)]
+pub struct PiecewisePolynomial {
+    breaks: Vec<f64>,
+    pieces: Vec<Polynomial<f64>>,
+}
+
+impl PiecewisePolynomial {
+    /// Creates a new piecewise polynomial from its breakpoints and the polynomial of each
+    /// interval, in the local coordinate x − xᵢ.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if there are fewer than 2 breakpoints, if the number of pieces is not one
+    /// less than the number of breakpoints, or if the breakpoints are not finite and strictly
+    /// increasing.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{PiecewisePolynomial, Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// // |x| on [-1, 1]
+    /// let abs = PiecewisePolynomial::build(
+    ///     &[-1.0, 0.0, 1.0],
+    ///     &[Polynomial::build(&[1.0, -1.0])?, Polynomial::build(&[0.0, 1.0])?],
+    /// )?;
+    ///
+    /// assert_eq!(abs.eval(-0.5), 0.5);
+    /// assert_eq!(abs.eval(0.25), 0.25);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build(breaks: &[f64], pieces: &[Polynomial<f64>]) -> Result<Self> {
+        if breaks.len() < 2 {
+            return Err(PolyError::NotEnoughPoints(2));
+        }
+        if breaks.len() != pieces.len() + 1 {
+            return Err(PolyError::LengthMismatch(breaks.len(), pieces.len() + 1));
+        }
+        if let Some(w) = breaks
+            .windows(2)
+            .find(|w| !(w[0].is_finite() && w[1].is_finite() && w[0] < w[1]))
+        {
+            return Err(PolyError::InvalidInterval(w[0], w[1]));
+        }
+
+        Ok(PiecewisePolynomial {
+            breaks: breaks.to_vec(),
+            pieces: pieces.to_vec(),
+        })
+    }
+
+    /// Returns the breakpoints.
+    pub fn breaks(&self) -> &[f64] {
+        &self.breaks
+    }
+
+    /// Returns the polynomials of each interval, in the local coordinate x − xᵢ.
+    pub fn pieces(&self) -> &[Polynomial<f64>] {
+        &self.pieces
+    }
+
+    /// Returns the index of the piece that `x` falls in.
+    fn piece_index(&self, x: f64) -> usize {
+        self.breaks
+            .partition_point(|b| *b <= x)
+            .saturating_sub(1)
+            .min(self.pieces.len() - 1)
+    }
+
+    /// Evaluates the piecewise polynomial at `x`.
+    pub fn eval(&self, x: f64) -> f64 {
+        let i = self.piece_index(x);
+        self.pieces[i].eval(x - self.breaks[i])
+    }
+
+    /// Returns the derivative of the piecewise polynomial, on the same breakpoints.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{PiecewisePolynomial, Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let abs = PiecewisePolynomial::build(
+    ///     &[-1.0, 0.0, 1.0],
+    ///     &[Polynomial::build(&[1.0, -1.0])?, Polynomial::build(&[0.0, 1.0])?],
+    /// )?;
+    /// let sign = abs.derivative();
+    ///
+    /// assert_eq!(sign.eval(-0.5), -1.0);
+    /// assert_eq!(sign.eval(0.5), 1.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn derivative(&self) -> Self {
+        PiecewisePolynomial {
+            breaks: self.breaks.clone(),
+            pieces: self.pieces.iter().map(|p| p.derivative()).collect(),
+        }
+    }
+
+    /// Calculates the definite integral of the piecewise polynomial from `a` to `b`.
+    ///
+    /// If `a > b`, the result is negative.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `a` or `b` are not finite.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{PiecewisePolynomial, Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let abs = PiecewisePolynomial::build(
+    ///     &[-1.0, 0.0, 1.0],
+    ///     &[Polynomial::build(&[1.0, -1.0])?, Polynomial::build(&[0.0, 1.0])?],
+    /// )?;
+    ///
+    /// assert_eq!(abs.integrate(-1.0, 1.0)?, 1.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn integrate(&self, a: f64, b: f64) -> Result<f64> {
+        if !(a.is_finite() && b.is_finite()) {
+            return Err(PolyError::InvalidInterval(a, b));
+        }
+        if a > b {
+            return Ok(-self.integrate(b, a)?);
+        }
+
+        let (first, last) = (self.piece_index(a), self.piece_index(b));
+        let mut total = 0.0;
+        for i in first..=last {
+            let lo = if i == first { a } else { self.breaks[i] };
+            let hi = if i == last { b } else { self.breaks[i + 1] };
+            let integral = self.pieces[i].antiderivative();
+            total += integral.eval(hi - self.breaks[i]) - integral.eval(lo - self.breaks[i]);
+        }
+        Ok(total)
+    }
+
+    /// Calculates the real roots of the piecewise polynomial in [x₀, xₙ], in increasing order.
+    ///
+    /// The roots of each piece are found from its complex roots, keeping the (nearly) real ones
+    /// in its interval. Repeated roots are listed as many times as their multiplicity, and roots
+    /// on a breakpoint that are found by both neighbouring pieces are only reported once. Pieces
+    /// that are identically zero have no roots.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the roots of a piece could not be found.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{PiecewisePolynomial, Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let pw = PiecewisePolynomial::build(
+    ///     &[0.0, 1.0, 3.0],
+    ///     &[Polynomial::build(&[-0.5, 1.0])?, Polynomial::build(&[0.5, 0.0, -1.0])?],
+    /// )?;
+    /// let roots = pw.roots()?;
+    ///
+    /// assert_eq!(roots.len(), 2);
+    /// assert!((roots[0] - 0.5).abs() < 1e-15);
+    /// assert!((roots[1] - (1.0 + 0.5f64.sqrt())).abs() < 1e-15);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn roots(&self) -> Result<Vec<f64>> {
+        let mut roots: Vec<f64> = vec![];
+        // Number of roots of the previous piece on its right end
+        let mut at_previous_end = 0;
+
+        for (i, piece) in self.pieces.iter().enumerate() {
+            let (start, width) = (self.breaks[i], self.breaks[i + 1] - self.breaks[i]);
+            let slack = REAL_TOL * width;
+            let piece_roots = real_roots_in(piece, 0.0, width)?;
+
+            // Roots on the common breakpoint of two pieces are found by both
+            let at_start = piece_roots.iter().filter(|r| **r <= slack).count();
+            let skip = at_start.min(at_previous_end);
+
+            at_previous_end = piece_roots.iter().filter(|r| **r >= width - slack).count();
+            roots.extend(piece_roots.iter().skip(skip).map(|r| start + r));
+        }
+        Ok(roots)
+    }
+}
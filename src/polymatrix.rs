@@ -0,0 +1,294 @@
+//! Matrices with polynomial entries, e.g. the numerator and denominator matrices of the polynomial
+//! matrix fraction descriptions of multivariable systems.
+
+use std::ops::{Index, IndexMut};
+
+use num::{Float, Zero};
+
+use crate::{PolyError, Polynomial, Result, Tolerance};
+
+/// Returns whether every coefficient of `poly` is 0.
+fn is_zero<T: num::complex::ComplexFloat + std::fmt::Debug>(poly: &Polynomial<T>) -> bool {
+    poly.coef.iter().all(|c| c.is_zero())
+}
+
+/// Returns the order of `poly`, ignoring zero leading terms, or `None` for the zero polynomial.
+fn degree<T: num::complex::ComplexFloat + std::fmt::Debug>(poly: &Polynomial<T>) -> Option<usize> {
+    poly.coef.iter().rposition(|c| !c.is_zero())
+}
+
+/// A matrix of polynomials, stored in row-major order.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{PolyMatrix, Polynomial, Result};
+/// # fn main() -> Result<()> {
+/// // [s+1  1 ]
+/// // [ 0  s+2]
+/// let m = PolyMatrix::from_rows(vec![
+///     vec![Polynomial::build(&[1.0, 1.0])?, Polynomial::build(&[1.0])?],
+///     vec![Polynomial::build(&[0.0])?, Polynomial::build(&[2.0, 1.0])?],
+/// ])?;
+///
+/// assert_eq!(m.determinant()?.coef, [2.0, 3.0, 1.0]);
+/// assert_eq!(m.eval(-1.0), [[0.0, 1.0], [0.0, 1.0]]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolyMatrix<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    rows: usize,
+    cols: usize,
+    entries: Vec<Polynomial<T>>,
+}
+
+impl<T> PolyMatrix<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Creates a `rows`×`cols` matrix of zero polynomials.
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        PolyMatrix {
+            rows,
+            cols,
+            entries: vec![Polynomial::new(); rows * cols],
+        }
+    }
+
+    /// Creates the `n`×`n` identity matrix.
+    pub fn identity(n: usize) -> Self {
+        let mut m = Self::zeros(n, n);
+        for i in 0..n {
+            m[(i, i)] = Polynomial {
+                coef: vec![T::one()],
+            };
+        }
+        m
+    }
+
+    /// Creates a matrix from its rows.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the rows have different lengths.
+    pub fn from_rows(rows: Vec<Vec<Polynomial<T>>>) -> Result<Self> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if let Some(row) = rows.iter().find(|row| row.len() != cols) {
+            return Err(PolyError::LengthMismatch(cols, row.len()));
+        }
+        Ok(PolyMatrix {
+            rows: rows.len(),
+            cols,
+            entries: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Returns the number of rows.
+    pub fn nrows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn ncols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the transposed matrix.
+    pub fn transpose(&self) -> Self {
+        let entries = (0..self.cols)
+            .flat_map(|j| (0..self.rows).map(move |i| (i, j)))
+            .map(|ij| self[ij].clone())
+            .collect();
+        PolyMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            entries,
+        }
+    }
+
+    /// Evaluates every entry at `x`, returning the numeric matrix as a vector of rows.
+    pub fn eval(&self, x: T) -> Vec<Vec<T>> {
+        self.entries
+            .chunks(self.cols.max(1))
+            .take(self.rows)
+            .map(|row| row.iter().map(|p| p.eval(x)).collect())
+            .collect()
+    }
+
+    /// Returns the matrix product `self·rhs`.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the number of columns of `self` differs from the number of rows of
+    /// `rhs`.
+    pub fn matmul(&self, rhs: &PolyMatrix<T>) -> Result<Self> {
+        if self.cols != rhs.rows {
+            return Err(PolyError::LengthMismatch(self.cols, rhs.rows));
+        }
+        let mut res = Self::zeros(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for j in 0..rhs.cols {
+                res[(i, j)] = (0..self.cols).fold(Polynomial::new(), |acc, k| {
+                    &acc + &(&self[(i, k)] * &rhs[(k, j)])
+                });
+            }
+        }
+        Ok(res)
+    }
+
+    /// Calculates the determinant, with the fraction-free Bareiss elimination, whose exact
+    /// divisions keep the intermediate entries polynomials of bounded order.
+    ///
+    /// The determinant of the 0×0 matrix is 1. Zero leading terms are trimmed.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the matrix is not square.
+    pub fn determinant(&self) -> Result<Polynomial<T>> {
+        if self.rows != self.cols {
+            return Err(PolyError::NotSquareMatrix(self.rows, self.cols));
+        }
+        let n = self.rows;
+        if n == 0 {
+            return Ok(Polynomial {
+                coef: vec![T::one()],
+            });
+        }
+
+        let mut m: Vec<Vec<Polynomial<T>>> =
+            self.entries.chunks(n).map(|row| row.to_vec()).collect();
+        let mut prev = Polynomial {
+            coef: vec![T::one()],
+        };
+        let mut negate = false;
+        for k in 0..n - 1 {
+            if is_zero(&m[k][k]) {
+                match (k + 1..n).find(|i| !is_zero(&m[*i][k])) {
+                    Some(i) => {
+                        m.swap(k, i);
+                        negate = !negate;
+                    }
+                    None => return Ok(Polynomial::new()),
+                }
+            }
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    let num = &(&m[i][j] * &m[k][k]) - &(&m[i][k] * &m[k][j]);
+                    m[i][j] = num.div_rem(&prev)?.0;
+                }
+            }
+            prev = m[k][k].clone();
+        }
+
+        let det = Polynomial::from_raw_unchecked(m[n - 1][n - 1].to_trimmed().coef);
+        Ok(if negate { -det } else { det })
+    }
+
+    /// Returns the row degrees, i.e. the largest order of the entries of every row, or `None` for
+    /// a zero row.
+    pub fn row_degrees(&self) -> Vec<Option<usize>> {
+        (0..self.rows)
+            .map(|i| (0..self.cols).filter_map(|j| degree(&self[(i, j)])).max())
+            .collect()
+    }
+
+    /// Returns the column degrees, i.e. the largest order of the entries of every column, or
+    /// `None` for a zero column.
+    pub fn column_degrees(&self) -> Vec<Option<usize>> {
+        (0..self.cols)
+            .map(|j| (0..self.rows).filter_map(|i| degree(&self[(i, j)])).max())
+            .collect()
+    }
+
+    /// Returns the highest-column-degree coefficient matrix Γ, whose column j holds the
+    /// coefficients of s^dⱼ of column j, where dⱼ is its column degree. Zero columns are zero.
+    pub fn leading_column_matrix(&self) -> Vec<Vec<T>> {
+        let degrees = self.column_degrees();
+        (0..self.rows)
+            .map(|i| {
+                (0..self.cols)
+                    .map(|j| match degrees[j] {
+                        Some(d) => self[(i, j)].coef.get(d).copied().unwrap_or(T::zero()),
+                        None => T::zero(),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns whether the square matrix is column reduced, i.e. whether the order of its
+    /// determinant is the sum of its column degrees, or equivalently whether its
+    /// [`leading_column_matrix`](PolyMatrix::leading_column_matrix) is nonsingular. That
+    /// coefficient of the determinant is considered 0 if it is negligible compared to its largest
+    /// coefficient.
+    ///
+    /// A column-reduced denominator makes a matrix fraction description N·D⁻¹ proper exactly when
+    /// every column degree of N is at most that of D.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the matrix is not square.
+    pub fn is_column_reduced(&self, tol: Tolerance) -> Result<bool> {
+        let det = self.determinant()?;
+        let Some(sum) = self
+            .column_degrees()
+            .into_iter()
+            .try_fold(0, |sum, d| d.map(|d| sum + d))
+        else {
+            return Ok(false);
+        };
+        let scale = det
+            .coef
+            .iter()
+            .map(|c| c.abs())
+            .fold(T::Real::zero(), T::Real::max);
+        Ok(det
+            .coef
+            .get(sum)
+            .is_some_and(|c| !tol.is_negligible(c.abs(), scale)))
+    }
+
+    /// Returns whether the square matrix is unimodular, i.e. whether its determinant is a
+    /// nonzero constant, so that its inverse is a polynomial matrix as well. The non-constant
+    /// terms of the determinant are considered 0 if they are negligible compared to its constant
+    /// term.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the matrix is not square.
+    pub fn is_unimodular(&self, tol: Tolerance) -> Result<bool> {
+        let det = self.determinant()?;
+        let constant = det.coef[0].abs();
+        Ok(!constant.is_zero()
+            && det.coef[1..]
+                .iter()
+                .all(|c| tol.is_negligible(c.abs(), constant)))
+    }
+}
+
+impl<T> Index<(usize, usize)> for PolyMatrix<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = Polynomial<T>;
+
+    /// Returns the entry at row `i` and column `j`.
+    fn index(&self, (i, j): (usize, usize)) -> &Polynomial<T> {
+        assert!(i < self.rows && j < self.cols, "index out of bounds");
+        &self.entries[i * self.cols + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for PolyMatrix<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Polynomial<T> {
+        assert!(i < self.rows && j < self.cols, "index out of bounds");
+        &mut self.entries[i * self.cols + j]
+    }
+}
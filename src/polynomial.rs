@@ -1,10 +1,17 @@
 //! Methods for evaluating a polynomial and its derivatives on a certain point.
 
-use num::Zero;
+use num::complex::Complex64;
+use num::{Float, Zero};
 
 use crate::{
-    PolyError, Result, solve,
-    utils::{check_if_correct_order, check_if_real_coefficients, convert_complex_to_real},
+    CubicRoots, PolyError, QuadraticRoots, Result, Tolerance,
+    roots::{CLUSTER_TOL, Root, cluster_roots, condition_number},
+    solve::{self, BuiltinQr, EigenBackend},
+    utils::{
+        check_finite_coef, check_if_correct_order, check_if_real_coefficients,
+        convert_complex_to_real, convert_to_complex64, convert_to_real_array, eval_derivs_into,
+        order,
+    },
 };
 
 #[allow(rustdoc::broken_intra_doc_links)]
@@ -17,6 +24,7 @@ use crate::{
 ///
 /// [`Vec`]: std::vec::Vec
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Polynomial<T>
 where
     T: std::fmt::Debug,
@@ -25,6 +33,21 @@ where
     pub coef: Vec<T>,
 }
 
+/// What to do with NaN or Infinity coefficients in [`Polynomial::build_sanitized`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SanitizePolicy {
+    /// Return [`PolyError::InvalidCoefficients`], like [`Polynomial::build`].
+    #[default]
+    Error,
+    /// Remove the offending coefficients from the sequence, as corrupted samples of a coefficient
+    /// stream. This is not the same as dropping their terms: every following coefficient moves
+    /// down one order per removed sample, e.g. `[1, NaN, 3]` becomes 1+3x, not 1+3x². Use
+    /// [`Zero`](SanitizePolicy::Zero) to keep the order of every term.
+    DropSample,
+    /// Replace the offending coefficients with 0, keeping the order of every other term.
+    Zero,
+}
+
 impl<T> Polynomial<T>
 where
     T: num::complex::ComplexFloat + std::fmt::Debug,
@@ -52,11 +75,97 @@ where
             return Ok(Polynomial::new());
         }
 
-        match coef.iter().any(|x| x.is_nan() | x.is_infinite()) {
-            true => Err(PolyError::InvalidCoefficients),
-            false => Ok(Polynomial {
-                coef: coef.to_vec(),
-            }),
+        check_finite_coef(coef.iter().copied())?;
+        Ok(Polynomial {
+            coef: coef.to_vec(),
+        })
+    }
+
+    /// Creates a new Polynomial from the given coefficients, handling NaN or Infinity coefficients
+    /// according to `policy` instead of always failing.
+    ///
+    /// # Error
+    ///
+    /// Returns an error only with [`SanitizePolicy::Error`], if any of the coefficients is NaN or
+    /// Infinity.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result, SanitizePolicy};
+    /// # fn main() -> Result<()> {
+    /// let coef = [1.0, f64::NAN, 3.0];
+    ///
+    /// let zero = Polynomial::build_sanitized(&coef, SanitizePolicy::Zero)?;
+    /// let drop = Polynomial::build_sanitized(&coef, SanitizePolicy::DropSample)?;
+    ///
+    /// assert_eq!(zero.coef, [1.0, 0.0, 3.0]); // 1+3x²
+    /// assert_eq!(drop.coef, [1.0, 3.0]); // 1+3x
+    /// assert!(Polynomial::build_sanitized(&coef, SanitizePolicy::Error).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build_sanitized(coef: &[T], policy: SanitizePolicy) -> Result<Self> {
+        let coef: Vec<T> = match policy {
+            SanitizePolicy::Error => return Self::build(coef),
+            SanitizePolicy::DropSample => coef.iter().copied().filter(|c| c.is_finite()).collect(),
+            SanitizePolicy::Zero => coef
+                .iter()
+                .map(|&c| if c.is_finite() { c } else { T::zero() })
+                .collect(),
+        };
+        Ok(Self::from_raw_unchecked(coef))
+    }
+
+    /// Same as [`build`](Polynomial::build), but takes the coefficients in descending power order,
+    /// from leading to constant term, as MATLAB's and NumPy's `polyval` do.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if any of the coefficients is NaN or Infinity.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build_desc(&[3.0, 4.0, 1.0])?; // 3x²+4x+1
+    ///
+    /// assert_eq!(poly.coef, [1.0, 4.0, 3.0]);
+    /// assert_eq!(poly.coef_desc(), [3.0, 4.0, 1.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build_desc(coef: &[T]) -> Result<Self> {
+        let mut poly = Self::build(coef)?;
+        poly.coef.reverse();
+        Ok(poly)
+    }
+
+    /// Returns a copy of the coefficients in descending power order, from leading to constant
+    /// term, as MATLAB's and NumPy's `polyval` take them.
+    pub fn coef_desc(&self) -> Vec<T> {
+        self.coef.iter().rev().copied().collect()
+    }
+
+    /// Creates a new Polynomial from the given coefficients without checking them, for trusted
+    /// hot paths where the validation of [`build`](Polynomial::build) is redundant.
+    ///
+    /// The coefficients are moved, not copied. They must be finite: NaN or Infinity coefficients
+    /// do not cause undefined behavior, but the results of every method are then unspecified.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::Polynomial;
+    /// let poly = Polynomial::from_raw_unchecked(vec![1.0, 2.0, 3.0]);
+    ///
+    /// assert_eq!(poly.eval(1.0), 6.0);
+    /// ```
+    pub fn from_raw_unchecked(coef: Vec<T>) -> Self {
+        match coef.is_empty() {
+            true => Polynomial::new(),
+            false => Polynomial { coef },
         }
     }
 
@@ -93,6 +202,55 @@ where
         Polynomial { coef: new_coeffs }
     }
 
+    /// Checks if two polynomials are approximately equal, i.e. if each pair of coefficients
+    /// satisfies |a − b| ≤ max(rel_tol·max(|a|, |b|), abs_tol). Missing higher order terms are
+    /// treated as 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let p = Polynomial::build(&[1.0, 0.1 + 0.2])?;
+    /// let q = Polynomial::build(&[1.0, 0.3, 1e-20])?;
+    ///
+    /// assert!(p != q);
+    /// assert!(p.approx_eq(&q, 1e-12, 1e-15));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn approx_eq(&self, other: &Polynomial<T>, rel_tol: T::Real, abs_tol: T::Real) -> bool {
+        let len = self.coef.len().max(other.coef.len());
+        (0..len).all(|i| {
+            let a = self.coef.get(i).copied().unwrap_or(T::zero());
+            let b = other.coef.get(i).copied().unwrap_or(T::zero());
+            (a - b).abs() <= Float::max(rel_tol * Float::max(a.abs(), b.abs()), abs_tol)
+        })
+    }
+
+    /// Returns an iterator over the polynomial's `(exponent, coefficient)` terms, in increasing
+    /// exponent order, skipping the zero coefficients.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 0.0, 0.0, 2.0])?; // 1+2x³
+    /// let terms: Vec<(usize, f64)> = poly.terms().collect();
+    ///
+    /// assert_eq!(terms, [(0, 1.0), (3, 2.0)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn terms(&self) -> impl Iterator<Item = (usize, T)> + '_ {
+        self.coef
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, c)| !c.is_zero())
+    }
+
     /// Converts a general polynomial to a [`monic`] polynomial:
     /// ax³ + bx² + cx + d  −>  x³ + a'x² + b'x + c'
     ///
@@ -187,153 +345,1141 @@ where
             .unwrap_or(T::zero())
     }
 
-    /// Evaluates the polynomials first `n` derivatives (including the 0-th derivative, i.e. the
-    /// polynomial's value) for the value `x`.
-    ///
-    /// The result is a vector holding the calculated derivatives:
+    /// Evaluates the polynomial at `x` with Estrin's scheme, which has more instruction-level
+    /// parallelism than Horner's method, and can be faster for orders of about 8 or more.
     ///
-    /// [d⁰/dx⁰, d¹/dx¹, d²/dx², ..., dⁿ/dxⁿ]
+    /// The coefficients are split into blocks of 8, each of which is evaluated as a tree of
+    /// independent products with x, x² and x⁴, and the blocks are then combined with Horner's
+    /// method in x⁸. Polynomials with fewer than 8 coefficients are evaluated with
+    /// [`eval`](Polynomial::eval). The rounding errors are comparable to those of Horner's
+    /// method, but for |x| large enough for x⁸ to overflow, the result may be NaN instead of
+    /// Infinity.
     ///
     /// ## Example
     ///
     /// ```
     /// # use rsl_polynomials::{Polynomial, Result};
     /// # fn main() -> Result<()> {
-    /// let poly = Polynomial::build(&[1.0, 2.0, 3.0])?;
+    /// let poly = Polynomial::build(&[1.0; 10])?;
     ///
-    /// assert_eq!(poly.eval_derivs(1.0, 4), &[6.0, 8.0, 6.0, 0.0]);
+    /// assert_eq!(poly.eval_estrin(2.0), 1023.0);
     /// # Ok(())
     /// # }
     /// ```
-    #[doc(alias = "gsl_poly_eval_derivs")]
-    pub fn eval_derivs(&self, x: T, n: usize) -> Vec<T> {
-        let mut res: Vec<T> = vec![T::zero(); n];
+    pub fn eval_estrin(&self, x: T) -> T {
+        if self.coef.len() < 8 {
+            return self.eval(x);
+        }
 
-        let last_idx = self.coef.len() - 1;
-        let nmax = self.coef.len().min(res.len()) - 1;
+        let x2 = x * x;
+        let x4 = x2 * x2;
+        let x8 = x4 * x4;
+        let block = |c: &[T]| {
+            let at = |i: usize| c.get(i).copied().unwrap_or(T::zero());
+            let (p01, p23) = (at(0) + at(1) * x, at(2) + at(3) * x);
+            let (p45, p67) = (at(4) + at(5) * x, at(6) + at(7) * x);
+            (p01 + p23 * x2) + (p45 + p67 * x2) * x4
+        };
+
+        self.coef
+            .chunks(8)
+            .rev()
+            .map(block)
+            .reduce(|res, b| b + res * x8)
+            .unwrap_or(T::zero())
+    }
 
-        // Partially fill res with the dominant term's coefficient
-        res.iter_mut()
-            .take(nmax + 1)
-            .for_each(|e| *e = *self.coef.last().unwrap());
+    /// Evaluates the polynomial at the `count` equally spaced points x₀ + i·step, with a
+    /// forward-difference table: after the first n+1 points, which are evaluated with
+    /// [`eval`](Polynomial::eval), every point costs only n additions, where n is the order.
+    ///
+    /// The rounding errors of the table accumulate along the progression, roughly like
+    /// count^n·ε for an order n polynomial, so this suits dense curves of low order polynomials,
+    /// e.g. for plotting, rather than long progressions of high order ones.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 0.0, 1.0])?; // 1+x²
+    ///
+    /// assert_eq!(poly.eval_arithmetic_progression(0.0, 0.5, 5), [1.0, 1.25, 2.0, 3.25, 5.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_arithmetic_progression(&self, x0: T, step: T, count: usize) -> Vec<T> {
+        let n = order(&self.coef);
+        let mut values: Vec<T> = (0..count.min(n + 1))
+            .map(|i| self.eval(x0 + T::from(i).unwrap() * step))
+            .collect();
+        if count <= n + 1 {
+            return values;
+        }
 
-        for i in 0..last_idx {
-            let k = last_idx - i;
-            res[0] = x * res[0] + self.coef[k - 1];
-            let jmax = if nmax < k { nmax } else { k - 1 };
-            for j in 1..=jmax {
-                res[j] = x * res[j] + res[j - 1];
+        // back[k] = ∇ᵏP at the last point, where ∇ⁿP is constant along the progression
+        let mut table = values.clone();
+        let mut back = vec![values[n]];
+        for k in 1..=n {
+            for i in 0..=n - k {
+                table[i] = table[i + 1] - table[i];
             }
+            back.push(table[n - k]);
         }
 
-        // Mutliply each term by the corresponding exponents
-        let mut f = T::one();
-        for (i, d) in res.iter_mut().enumerate().take(nmax + 1).skip(2) {
-            f = f * T::from(i).unwrap();
-            *d = *d * f;
+        values.reserve(count - n - 1);
+        for _ in n + 1..count {
+            for k in (0..n).rev() {
+                back[k] = back[k] + back[k + 1];
+            }
+            values.push(back[0]);
         }
+        values
+    }
 
-        res
+    /// Lazily evaluates the polynomial at every value of `xs`, with
+    /// [`eval`](Polynomial::eval), so that the values can be streamed into a consumer, e.g. a
+    /// plotter or an encoder, without collecting them into a buffer first.
+    ///
+    /// The iterator borrows the polynomial and evaluates each value only when it is pulled.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 0.0, 1.0])?; // 1+x²
+    ///
+    /// let sum: f64 = poly.eval_map((0..4).map(f64::from)).sum();
+    /// assert_eq!(sum, 18.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_map<I: IntoIterator<Item = T>>(&self, xs: I) -> impl Iterator<Item = T> {
+        xs.into_iter().map(move |x| self.eval(x))
     }
 
-    /// Calculates the **real** roots af a quadratic equation `ax²+bx+c`.
+    /// Evaluates the polynomial for a value `x` of any type that the coefficients convert into
+    /// and that supports addition and multiplication, with Horner's method as
+    /// [`eval`](Polynomial::eval).
     ///
-    /// # Error
+    /// This makes it possible to evaluate the polynomial with automatic differentiation types,
+    /// e.g. the dual numbers of the `num-dual` crate, which give P(x) and P'(x) from a single
+    /// evaluation, or at complex points for real coefficients.
     ///
-    /// Returns an error in 3 cases:
-    /// 1. the Polynomial is not of order 2
-    /// 2. one of the coefficients is not real
-    /// 3. the Polynomial is constant, i.e. a=b=0
+    /// ## Example
     ///
-    /// # Example
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # use num::complex::Complex64;
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 0.0, 1.0])?; // 1+x²
+    ///
+    /// assert_eq!(poly.eval_generic(Complex64::new(0.0, 1.0)), Complex64::new(0.0, 0.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_generic<U>(&self, x: U) -> U
+    where
+        U: From<T> + Clone + std::ops::Add<Output = U> + std::ops::Mul<Output = U>,
+    {
+        self.coef
+            .iter()
+            .rev()
+            .map(|c| U::from(*c))
+            .reduce(|res, coef| coef + x.clone() * res)
+            .unwrap_or(U::from(T::zero()))
+    }
+
+    /// Evaluates two polynomials at the same `x`, e.g. the numerator and denominator of a
+    /// rational function, running their Horner recurrences in lockstep.
+    ///
+    /// ## Example
     ///
     /// ```
     /// # use rsl_polynomials::{Polynomial, Result};
-    /// #
     /// # fn main() -> Result<()> {
-    /// let poly = Polynomial::build(&[-20.0, 0.0, 5.0])?; // 5x²-20
-    /// let y = poly.solve_real_quadratic()?;
-    /// let expected = [2.0, -2.0];
+    /// let p = Polynomial::build(&[1.0, 2.0, 3.0])?;
+    /// let q = Polynomial::build(&[4.0, 5.0])?;
     ///
-    /// assert_eq!(y, expected);
+    /// assert_eq!(Polynomial::eval_pair(&p, &q, 2.0), (17.0, 14.0));
     /// # Ok(())
     /// # }
     /// ```
-    #[doc(alias = "gsl_poly_solve_quadratic")]
-    pub fn solve_real_quadratic(&self) -> Result<Vec<f64>> {
-        check_if_correct_order(&self.coef, 2)?;
-        check_if_real_coefficients(&self.coef)?;
+    pub fn eval_pair(p: &Polynomial<T>, q: &Polynomial<T>, x: T) -> (T, T) {
+        let [p, q] = Self::eval_many([p, q], x);
+        (p, q)
+    }
 
-        let mut reals = Vec::<f64>::new();
-        for c in self.coef.iter() {
-            reals.push(convert_complex_to_real(*c)?);
-        }
+    /// Evaluates `N` polynomials at the same `x`, running their Horner recurrences in lockstep.
+    ///
+    /// Interleaving the independent recurrences, instead of evaluating each polynomial after the
+    /// other, lets the CPU overlap their multiply-add chains. Every recurrence is seeded with its
+    /// own leading coefficient and joins in at its own length, exactly like
+    /// [`eval`](Polynomial::eval), so the results are the same even at x = ±∞.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let p = Polynomial::build(&[1.0, 2.0, 3.0])?;
+    /// let q = Polynomial::build(&[4.0, 5.0])?;
+    /// let r = Polynomial::build(&[6.0])?;
+    ///
+    /// assert_eq!(Polynomial::eval_many([&p, &q, &r], 2.0), [17.0, 14.0, 6.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_many<const N: usize>(polys: [&Polynomial<T>; N], x: T) -> [T; N] {
+        let len = polys.iter().map(|p| p.coef.len()).max().unwrap_or(0);
 
-        solve::solve_real_quadratic(reals[2], reals[1], reals[0])
+        let mut res = [T::zero(); N];
+        for (r, p) in res.iter_mut().zip(polys.iter()) {
+            *r = p.coef.last().copied().unwrap_or(T::zero());
+        }
+        for k in (0..len.saturating_sub(1)).rev() {
+            for (r, p) in res.iter_mut().zip(polys.iter()) {
+                if k + 1 < p.coef.len() {
+                    *r = p.coef[k] + x * *r;
+                }
+            }
+        }
+        res
     }
 
-    /// Calculates the **real** roots af a quadratic equation `ax³+bx²+cx+d`.
+    /// Evaluates the polynomial's value and its first `n − 1` derivatives for the value `x`.
     ///
-    /// The roots are returned in increasing order.
+    /// The result is a vector of length `n` holding the calculated derivatives:
     ///
-    /// ## Note
+    /// [d⁰/dx⁰, d¹/dx¹, d²/dx², ..., dⁿ⁻¹/dxⁿ⁻¹]
     ///
-    /// Due to finite precision, some double roots may be missed, and considered to be a
-    /// pair of complex roots z = x ± i*EPSILON close to the real axis.
+    /// Derivatives of order higher than the polynomial's degree are 0. See
+    /// [`eval_derivs_into`](Polynomial::eval_derivs_into) for a version that does not allocate.
     ///
-    /// # Error
+    /// ## Example
     ///
-    /// Returns an error in 3 cases:
-    /// 1. the Polynomial is not of order 3
-    /// 2. one of the coefficients is not real
-    /// 3. the Polynomial is constant, i.e. a=b=c=0
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 2.0, 3.0])?;
     ///
-    /// # Example
+    /// assert_eq!(poly.eval_derivs(1.0, 4), &[6.0, 8.0, 6.0, 0.0]);
+    /// assert!(poly.eval_derivs(1.0, 0).is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "gsl_poly_eval_derivs")]
+    pub fn eval_derivs(&self, x: T, n: usize) -> Vec<T> {
+        let mut res: Vec<T> = vec![T::zero(); n];
+        self.eval_derivs_into(x, &mut res);
+        res
+    }
+
+    /// Same as [`eval_derivs`](Polynomial::eval_derivs), but writes the value and the first
+    /// `out.len() − 1` derivatives into the caller-provided `out`, so that it can be reused.
+    ///
+    /// ## Example
     ///
     /// ```
     /// # use rsl_polynomials::{Polynomial, Result};
-    /// #
     /// # fn main() -> Result<()> {
-    /// let poly = Polynomial::build(&[-6.0, 11.0, -6.0, 1.0])?; // x³-6x²+11x-6
-    /// let y = poly.solve_real_cubic()?;
-    /// let expected = [1.0, 2.0, 3.0];
+    /// let poly = Polynomial::build(&[1.0, 2.0, 3.0])?;
+    /// let mut out = [0.0; 3];
     ///
-    /// assert_eq!(y, expected);
+    /// poly.eval_derivs_into(1.0, &mut out);
+    /// assert_eq!(out, [6.0, 8.0, 6.0]);
     /// # Ok(())
     /// # }
     /// ```
-    #[doc(alias = "gsl_poly_solve_cubic")]
-    pub fn solve_real_cubic(&self) -> Result<Vec<f64>> {
-        check_if_correct_order(&self.coef, 3)?;
-        check_if_real_coefficients(&self.coef)?;
+    pub fn eval_derivs_into(&self, x: T, out: &mut [T]) {
+        eval_derivs_into(&self.coef, x, out);
+    }
 
-        let monic = self.to_monic();
+    /// Evaluates the polynomial and its first derivative for the value `x`, returning
+    /// (P(x), P'(x)) without allocating.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 2.0, 3.0])?;
+    ///
+    /// assert_eq!(poly.eval_value_and_deriv(1.0), (6.0, 8.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_value_and_deriv(&self, x: T) -> (T, T) {
+        let mut iter = self.coef.iter().rev().copied();
+        let Some(mut value) = iter.next() else {
+            return (T::zero(), T::zero());
+        };
+        let Some(c) = iter.next() else {
+            return (value, T::zero());
+        };
 
-        let mut reals = Vec::<f64>::new();
-        for c in monic.coef.iter() {
-            reals.push(convert_complex_to_real(*c)?);
+        // Seed the derivative's recurrence with the leading coefficient, as in `eval`
+        let mut deriv = value;
+        value = c + x * value;
+        for c in iter {
+            deriv = value + x * deriv;
+            value = c + x * value;
         }
+        (value, deriv)
+    }
 
-        solve::solve_real_cubic(reals[2], reals[1], reals[0])
+    /// Evaluates the polynomial's first derivative for the value `x`, without allocating.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 2.0, 3.0])?;
+    ///
+    /// assert_eq!(poly.eval_deriv1(1.0), 8.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_deriv1(&self, x: T) -> T {
+        self.eval_value_and_deriv(x).1
     }
-}
 
-impl<T> Default for Polynomial<T>
-where
-    T: num::complex::ComplexFloat + std::fmt::Debug,
-{
-    fn default() -> Self {
-        Self::new()
+    /// Runs Horner's method at `x₀`, returning all the intermediate values bᵢ of the synthetic
+    /// division of the polynomial by (x − x₀), from b₀ to bₙ.
+    ///
+    /// b₀ is the value P(x₀), while b₁, ..., bₙ are the coefficients (from constant to leading
+    /// term) of the deflated polynomial Q, where P(x) = (x − x₀)Q(x) + P(x₀).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-6.0, 11.0, -6.0, 1.0])?; // (x−1)(x−2)(x−3)
+    ///
+    /// let b = poly.horner_expansion(1.0);
+    /// assert_eq!(b, [0.0, 6.0, -5.0, 1.0]); // P(1) = 0, Q(x) = 6−5x+x²
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn horner_expansion(&self, x: T) -> Vec<T> {
+        let mut res = self.coef.clone();
+        for k in (0..res.len().saturating_sub(1)).rev() {
+            res[k] = res[k] + x * res[k + 1];
+        }
+        res
     }
-}
 
-impl<T> std::fmt::Debug for Polynomial<T>
-where
-    T: std::fmt::Debug,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Polynomial")
-            .field("Coefficients", &self.coef)
-            .finish()
+    /// Splits the polynomial into its even and odd parts, E and O, such that P(x) = E(x²) + x·O(x²).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 2.0, 3.0, 4.0, 5.0])?;
+    ///
+    /// let (even, odd) = poly.split_even_odd();
+    /// assert_eq!(even.coef, [1.0, 3.0, 5.0]);
+    /// assert_eq!(odd.coef, [2.0, 4.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn split_even_odd(&self) -> (Polynomial<T>, Polynomial<T>) {
+        let part = |skip| {
+            let coef: Vec<T> = self.coef.iter().skip(skip).step_by(2).copied().collect();
+            match coef.is_empty() {
+                true => Polynomial::new(),
+                false => Polynomial { coef },
+            }
+        };
+        (part(0), part(1))
+    }
+
+    /// Evaluates the polynomial at both `x` and `−x`, returning (P(x), P(−x)).
+    ///
+    /// The even and odd parts of P(x) = E(x²) + x·O(x²) are evaluated once, at x², so this costs
+    /// about as much as a single [`eval`](Polynomial::eval). At x = ±∞ the two parts may both be
+    /// infinite, in which case one of the results is NaN instead of ±∞.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 2.0, 3.0])?;
+    ///
+    /// assert_eq!(poly.eval_even_odd(2.0), (17.0, 9.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_even_odd(&self, x: T) -> (T, T) {
+        let x2 = x * x;
+        let horner = |skip| {
+            self.coef
+                .iter()
+                .skip(skip)
+                .step_by(2)
+                .rev()
+                .copied()
+                .reduce(|res, coef| coef + x2 * res)
+                .unwrap_or(T::zero())
+        };
+        let odd = match self.coef.len() > 1 {
+            true => x * horner(1),
+            false => T::zero(),
+        };
+        let even = horner(0);
+        (even + odd, even - odd)
+    }
+
+    /// Calculates the **real** roots af a quadratic equation `ax²+bx+c`.
+    ///
+    /// The roots are returned in increasing order. They are calculated in the real type of the
+    /// coefficients, e.g. `f32` for `f32` and `Complex32` coefficients, and `f64` for `f64` and
+    /// `Complex64` ones. The formula avoids the cancellation of the smaller root when b² ≫ 4ac.
+    ///
+    /// # Error
+    ///
+    /// Returns an error in 3 cases:
+    /// 1. the Polynomial is not of order 2
+    /// 2. one of the coefficients is not real
+    /// 3. the Polynomial is constant, i.e. a=b=0
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// #
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-20.0, 0.0, 5.0])?; // 5x²-20
+    /// let y = poly.solve_real_quadratic()?;
+    /// let expected = [-2.0, 2.0];
+    ///
+    /// assert_eq!(y, expected);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "gsl_poly_solve_quadratic")]
+    pub fn solve_real_quadratic(&self) -> Result<Vec<T::Real>> {
+        let [c, b, a] = convert_to_real_array(&self.coef)?;
+        solve::solve_real_quadratic(a, b, c)
+    }
+
+    /// Calculates the **real** roots af a quadratic equation `ax³+bx²+cx+d`.
+    ///
+    /// The roots are returned in increasing order, in the real type of the coefficients.
+    ///
+    /// ## Note
+    ///
+    /// Due to finite precision, some double roots may be missed, and considered to be a
+    /// pair of complex roots z = x ± i*EPSILON close to the real axis.
+    ///
+    /// # Error
+    ///
+    /// Returns an error in 2 cases:
+    /// 1. the Polynomial is not of order 3, or its leading coefficient is 0
+    /// 2. one of the coefficients is not real
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// #
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-6.0, 11.0, -6.0, 1.0])?; // x³-6x²+11x-6
+    /// let y = poly.solve_real_cubic()?;
+    /// let expected = [1.0, 2.0, 3.0];
+    ///
+    /// assert_eq!(y, expected);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "gsl_poly_solve_cubic")]
+    pub fn solve_real_cubic(&self) -> Result<Vec<T::Real>> {
+        let [d, c, b, a] = convert_to_real_array(&self.coef)?;
+        if a.is_zero() {
+            return Err(PolyError::IncorrectOrder {
+                expected: 3,
+                got: order(&[d, c, b]),
+            });
+        }
+        solve::solve_real_cubic(b / a, c / a, d / a)
+    }
+
+    /// Same as [`solve_real_quadratic`](Polynomial::solve_real_quadratic), but returns the
+    /// structure of the roots instead of a Vec, so that the absence of real roots, a double root
+    /// and a degenerate (linear) equation can be told apart.
+    ///
+    /// # Error
+    ///
+    /// Returns an error in 3 cases:
+    /// 1. the Polynomial is not of order 2
+    /// 2. one of the coefficients is not real
+    /// 3. the Polynomial is constant, i.e. a=b=0
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, QuadraticRoots, Result};
+    /// #
+    /// # fn main() -> Result<()> {
+    /// let double = Polynomial::build(&[25.0, -20.0, 4.0])?; // (2x-5)²
+    /// let none = Polynomial::build(&[26.0, -20.0, 4.0])?;
+    ///
+    /// assert_eq!(double.solve_real_quadratic_structured()?, QuadraticRoots::Double(2.5));
+    /// assert_eq!(none.solve_real_quadratic_structured()?, QuadraticRoots::None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "gsl_poly_solve_quadratic")]
+    pub fn solve_real_quadratic_structured(&self) -> Result<QuadraticRoots<T::Real>> {
+        let [c, b, a] = convert_to_real_array(&self.coef)?;
+        solve::quadratic(a, b, c)
+    }
+
+    /// Same as
+    /// [`solve_real_quadratic_structured`](Polynomial::solve_real_quadratic_structured), but
+    /// treats a leading coefficient with |a| ≤ tol·max(|a|, |b|, |c|) as 0, so that a nearly
+    /// degenerate equation is solved as a linear one.
+    ///
+    /// # Error
+    ///
+    /// Returns an error in 3 cases:
+    /// 1. the Polynomial is not of order 2
+    /// 2. one of the coefficients is not real
+    /// 3. the Polynomial is (nearly) constant
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, QuadraticRoots, Result, Tolerance};
+    /// #
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-2.0, 1.0, 1e-300])?;
+    /// let roots = poly.solve_real_quadratic_structured_with_tol(Tolerance::new(1e-12))?;
+    ///
+    /// assert_eq!(roots, QuadraticRoots::Linear(2.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn solve_real_quadratic_structured_with_tol(
+        &self,
+        tol: Tolerance,
+    ) -> Result<QuadraticRoots<T::Real>> {
+        let [c, b, a] = convert_to_real_array(&self.coef)?;
+        solve::quadratic_with_tol(a, b, c, tol)
+    }
+
+    /// Same as [`solve_real_cubic`](Polynomial::solve_real_cubic), but returns the structure of
+    /// the roots instead of a Vec, so that a single real root and a triple root can be told
+    /// apart.
+    ///
+    /// # Error
+    ///
+    /// Returns an error in 2 cases:
+    /// 1. the Polynomial is not of order 3, or its leading coefficient is 0
+    /// 2. one of the coefficients is not real
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{CubicRoots, Polynomial, Result};
+    /// #
+    /// # fn main() -> Result<()> {
+    /// let single = Polynomial::build(&[-27.0, 0.0, 0.0, 1.0])?; // x³-27
+    /// let triple = Polynomial::build(&[-27.0, 27.0, -9.0, 1.0])?; // (x-3)³
+    ///
+    /// assert_eq!(single.solve_real_cubic_structured()?, CubicRoots::One(3.0));
+    /// assert_eq!(triple.solve_real_cubic_structured()?, CubicRoots::TripleRepeated(3.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "gsl_poly_solve_cubic")]
+    pub fn solve_real_cubic_structured(&self) -> Result<CubicRoots<T::Real>> {
+        let [d, c, b, a] = convert_to_real_array(&self.coef)?;
+        solve::cubic(a, b, c, d)
+    }
+
+    /// Same as [`solve_real_quadratic`](Polynomial::solve_real_quadratic), but returns the roots
+    /// in a fixed-size array along with their number, so that no allocation takes place.
+    ///
+    /// The unused slots of the array are filled with NaN. If the equation has no real roots, the
+    /// number of roots is 0 instead of an error.
+    ///
+    /// # Error
+    ///
+    /// Returns an error in 3 cases:
+    /// 1. the Polynomial is not of order 2
+    /// 2. one of the coefficients is not real
+    /// 3. the Polynomial is constant, i.e. a=b=0
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// #
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-20.0, 0.0, 5.0])?; // 5x²-20
+    /// let (roots, n) = poly.solve_real_quadratic_arr()?;
+    ///
+    /// assert_eq!(&roots[..n], [-2.0, 2.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "gsl_poly_solve_quadratic")]
+    pub fn solve_real_quadratic_arr(&self) -> Result<([T::Real; 2], usize)> {
+        let nan = T::Real::nan();
+        Ok(match self.solve_real_quadratic_structured()? {
+            QuadraticRoots::None => ([nan; 2], 0),
+            QuadraticRoots::Linear(x) | QuadraticRoots::Double(x) => ([x, nan], 1),
+            QuadraticRoots::Two(x1, x2) => ([x1, x2], 2),
+        })
+    }
+
+    /// Calculates the **real** roots of a cubic equation `ax³+bx²+cx+d`, returning them in a
+    /// fixed-size array along with their number, so that no allocation takes place.
+    ///
+    /// The roots are returned in increasing order, with repeated roots listed as many times as
+    /// their multiplicity, like [`CubicRoots::to_vec`]. The unused slots of the array are filled
+    /// with NaN.
+    ///
+    /// # Error
+    ///
+    /// Returns an error in 2 cases:
+    /// 1. the Polynomial is not of order 3
+    /// 2. one of the coefficients is not real
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// #
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-1.0, 0.0, 0.0, 1.0])?; // x³-1
+    /// let (roots, n) = poly.solve_real_cubic_arr()?;
+    ///
+    /// assert_eq!(&roots[..n], [1.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "gsl_poly_solve_cubic")]
+    pub fn solve_real_cubic_arr(&self) -> Result<([T::Real; 3], usize)> {
+        let nan = T::Real::nan();
+        Ok(match self.solve_real_cubic_structured()? {
+            CubicRoots::One(x) => ([x, nan, nan], 1),
+            CubicRoots::Three(roots) => (roots, 3),
+            CubicRoots::TripleRepeated(x) => ([x; 3], 3),
+        })
+    }
+
+    /// Calculates the 2 complex roots of a quadratic equation `az²+bz+c` whose coefficients may
+    /// be complex, sorted by their real and then imaginary part.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the Polynomial is not of order 2.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use num::complex::Complex64;
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// #
+    /// # fn main() -> Result<()> {
+    /// let i = Complex64::i();
+    /// let one = Complex64::new(1.0, 0.0);
+    /// let poly = Polynomial::build(&[i - one, -one - 2.0 * i, one])?; // (z−i)(z−1−i)
+    /// let roots = poly.solve_complex_quadratic()?;
+    ///
+    /// assert!((roots[0] - i).norm() < 1e-15);
+    /// assert!((roots[1] - one - i).norm() < 1e-15);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "gsl_poly_complex_solve_quadratic")]
+    pub fn solve_complex_quadratic(&self) -> Result<[Complex64; 2]> {
+        check_if_correct_order(&self.coef, 2)?;
+        let [c, b, a] = [0, 1, 2].map(|i| convert_to_complex64(self.coef[i]));
+        solve::solve_complex_quadratic(a, b, c)
+    }
+
+    /// Calculates the 3 complex roots of a cubic equation `az³+bz²+cz+d` whose coefficients may
+    /// be complex, sorted by their real and then imaginary part.
+    ///
+    /// Repeated roots are listed as many times as their multiplicity.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the Polynomial is not of order 3.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use num::complex::Complex64;
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// #
+    /// # fn main() -> Result<()> {
+    /// let i = Complex64::i();
+    /// let one = Complex64::new(1.0, 0.0);
+    /// let poly = Polynomial::build(&[i, -one, -i, one])?; // z³−iz²−z+i = (z−i)(z−1)(z+1)
+    /// let roots = poly.solve_complex_cubic()?;
+    ///
+    /// assert!((roots[0] + one).norm() < 1e-14);
+    /// assert!((roots[1] - i).norm() < 1e-14);
+    /// assert!((roots[2] - one).norm() < 1e-14);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "gsl_poly_complex_solve_cubic")]
+    pub fn solve_complex_cubic(&self) -> Result<[Complex64; 3]> {
+        check_if_correct_order(&self.coef, 3)?;
+        let [d, c, b, a] = [0, 1, 2, 3].map(|i| convert_to_complex64(self.coef[i]));
+        solve::solve_complex_cubic(a, b, c, d)
+    }
+
+    /// Calculates all the (complex) roots of a polynomial of any order.
+    ///
+    /// For real coefficients, the roots are found as the eigenvalues of the polynomial's balanced
+    /// companion matrix, using the QR algorithm as in GSL. Polynomials with complex coefficients
+    /// are solved with the Aberth-Ehrlich method.
+    ///
+    /// The roots are returned in no particular order.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the (trimmed) Polynomial is constant, or if the iterations fail to
+    /// converge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// #
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 0.0, 1.0])?; // x²+1
+    /// let roots = poly.solve_complex()?;
+    ///
+    /// assert_eq!(roots.len(), 2);
+    /// assert!(roots.iter().all(|z| (z.norm() - 1.0).abs() < 1e-15 && z.re.abs() < 1e-15));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "gsl_poly_complex_solve")]
+    pub fn solve_complex(&self) -> Result<Vec<Complex64>> {
+        self.solve_complex_with(&BuiltinQr)
+    }
+
+    /// Calculates all the (complex) roots of a polynomial of any order, like
+    /// [`solve_complex`](Polynomial::solve_complex), but finds the eigenvalues of the companion
+    /// matrix with the given [`EigenBackend`].
+    ///
+    /// Polynomials with complex coefficients are still solved with the Aberth-Ehrlich method.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the (trimmed) Polynomial is constant, or if the backend fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # use rsl_polynomials::solve::BuiltinQr;
+    /// #
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 0.0, 1.0])?; // x²+1
+    /// let roots = poly.solve_complex_with(&BuiltinQr)?;
+    ///
+    /// assert_eq!(roots.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn solve_complex_with<B>(&self, backend: &B) -> Result<Vec<Complex64>>
+    where
+        B: EigenBackend + ?Sized,
+    {
+        let poly = self.to_trimmed();
+        if poly.coef.len() < 2 {
+            return Err(PolyError::ConstantPoly);
+        }
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("solve_complex", order = poly.coef.len() - 1).entered();
+
+        let roots = match check_if_real_coefficients(&poly.coef) {
+            Ok(()) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(algorithm = "companion", "real coefficients");
+                let mut reals = Vec::<f64>::new();
+                for c in poly.coef.iter() {
+                    reals.push(convert_complex_to_real(*c)?);
+                }
+                solve::solve_complex_companion(&reals, backend)?
+            }
+            Err(_) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(algorithm = "aberth", "complex coefficients");
+                let complex: Vec<Complex64> =
+                    poly.coef.iter().map(|c| convert_to_complex64(*c)).collect();
+                solve::solve_complex_aberth(&complex)?
+            }
+        };
+
+        #[cfg(feature = "tracing")]
+        if tracing::enabled!(tracing::Level::DEBUG) {
+            let complex: Vec<Complex64> =
+                poly.coef.iter().map(|c| convert_to_complex64(*c)).collect();
+            let residual = solve::max_backward_error(&complex, &roots);
+            tracing::debug!(residual, "found {} roots", roots.len());
+        }
+        Ok(roots)
+    }
+
+    /// Calculates the n complex roots of a binomial a·xⁿ + b, as the n-th roots of −b/a, without
+    /// going through the general solver.
+    ///
+    /// The roots are returned in increasing argument order, starting from the principal root.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the (trimmed) Polynomial is constant, or if it has any terms other
+    /// than the leading and constant ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// #
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-16.0, 0.0, 0.0, 0.0, 1.0])?; // x⁴−16
+    /// let roots = poly.solve_binomial()?;
+    ///
+    /// assert_eq!(roots.len(), 4);
+    /// assert!((roots[0].re - 2.0).abs() < 1e-15 && roots[0].im == 0.0);
+    /// assert!((roots[1].im - 2.0).abs() < 1e-15 && roots[1].re.abs() < 1e-15);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn solve_binomial(&self) -> Result<Vec<Complex64>> {
+        let poly = self.to_trimmed();
+        let n = match poly.coef.len() {
+            0 | 1 => return Err(PolyError::ConstantPoly),
+            len => len - 1,
+        };
+        if poly.coef[1..n].iter().any(|c| !c.is_zero()) {
+            return Err(PolyError::NotBinomial);
+        }
+
+        let c = -convert_to_complex64(poly.coef[0]) / convert_to_complex64(poly.coef[n]);
+        let (r, theta) = c.to_polar();
+        let r = r.powf(1.0 / n as f64);
+        let turns = theta / (2.0 * std::f64::consts::PI);
+
+        Ok((0..n)
+            .map(|k| r * unit_from_turns((turns + k as f64) / n as f64))
+            .collect())
+    }
+
+    /// Calculates the **real** roots of a polynomial of order 1 to 3, merging roots that lie
+    /// within `tol` of each other (see [`CLUSTER_TOL`]) into a single [`Root`] with the
+    /// corresponding multiplicity.
+    ///
+    /// The roots are returned in increasing order.
+    ///
+    /// # Error
+    ///
+    /// Returns an error in 4 cases:
+    /// 1. the (trimmed) Polynomial is not of order 1, 2 or 3
+    /// 2. one of the coefficients is not real
+    /// 3. the Polynomial is constant
+    /// 4. the Polynomial is quadratic with no real roots
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{CLUSTER_TOL, Polynomial, Result};
+    /// #
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-4913.0, 867.0, -51.0, 1.0])?; // (x-17)³
+    /// let roots = poly.solve_real_clustered(CLUSTER_TOL)?;
+    ///
+    /// assert_eq!(roots.len(), 1);
+    /// assert_eq!(roots[0].value, 17.0);
+    /// assert_eq!(roots[0].multiplicity, 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn solve_real_clustered(&self, tol: f64) -> Result<Vec<Root>> {
+        let poly = self.to_trimmed();
+        check_if_real_coefficients(&poly.coef)?;
+
+        let mut reals = Vec::<f64>::new();
+        for c in poly.coef.iter() {
+            reals.push(convert_complex_to_real(*c)?);
+        }
+
+        // An all-zero polynomial is trimmed to no coefficients at all
+        if reals.len() <= 1 {
+            return Err(PolyError::ConstantPoly);
+        }
+
+        let values = match reals.len() - 1 {
+            1 => vec![solve::solve_real_linear(reals[1], reals[0])?],
+            2 => match solve::solve_real_quadratic_structured(reals[2], reals[1], reals[0])? {
+                QuadraticRoots::None => return Err(PolyError::NoRealRoots),
+                roots => roots.to_vec(),
+            },
+            3 => {
+                let a = reals[3];
+                solve::solve_real_cubic_structured(reals[2] / a, reals[1] / a, reals[0] / a)
+                    .to_vec()
+            }
+            got => {
+                return Err(PolyError::IncorrectOrder { expected: 3, got });
+            }
+        };
+
+        Ok(cluster_roots(&values, tol))
+    }
+
+    /// Same as [`solve_real_clustered`](Polynomial::solve_real_clustered) with the default
+    /// [`CLUSTER_TOL`], but also estimates each root's condition number
+    ///
+    /// κ = P̃(|r|) / (|r|·|P'(r)|),   where P̃(x) = Σ|cᵢ|xⁱ
+    ///
+    /// which bounds the relative change of the root caused by relative perturbations of the
+    /// coefficients. For a root at 0 the absolute condition number P̃(0)/|P'(0)| is returned
+    /// instead. Multiple roots are infinitely ill-conditioned, so expect huge values for them.
+    ///
+    /// # Error
+    ///
+    /// Same as [`solve_real_clustered`](Polynomial::solve_real_clustered).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// #
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-6.0, 11.0, -6.0, 1.0])?; // (x-1)(x-2)(x-3)
+    /// let roots = poly.solve_with_diagnostics()?;
+    ///
+    /// // Well separated simple roots are well-conditioned
+    /// assert!(roots.iter().all(|r| r.condition.unwrap() < 100.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn solve_with_diagnostics(&self) -> Result<Vec<Root>> {
+        let mut roots = self.solve_real_clustered(CLUSTER_TOL)?;
+
+        let mut reals = Vec::<f64>::new();
+        for c in self.to_trimmed().coef.iter() {
+            reals.push(convert_complex_to_real(*c)?);
+        }
+
+        roots
+            .iter_mut()
+            .for_each(|root| root.condition = Some(condition_number(&reals, root.value)));
+
+        Ok(roots)
+    }
+}
+
+impl<F> Polynomial<F>
+where
+    F: Float + std::fmt::Debug,
+{
+    /// Evaluates the polynomial at `x` with Horner's method, computing every step c + x·res with
+    /// a single fused multiply-add ([`f64::mul_add`]).
+    ///
+    /// Every step is rounded once instead of twice, so the rounding error is up to about half of
+    /// that of [`eval`](Polynomial::eval), which matters most near the roots, where the terms
+    /// cancel out. It is also faster, but only when the target supports FMA instructions, e.g.
+    /// when compiling for x86-64 with `-C target-feature=+fma` (or `-C target-cpu=native`);
+    /// otherwise `mul_add` is emulated in software, which is several times slower than `eval`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, 2.0, 3.0])?;
+    ///
+    /// assert_eq!(poly.eval_fma(1.0), 6.0);
+    /// assert_eq!(poly.eval_fma(-1.0), 2.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_fma(&self, x: F) -> F {
+        self.coef
+            .iter()
+            .rev()
+            .copied()
+            .reduce(|res, coef| res.mul_add(x, coef))
+            .unwrap_or(F::zero())
+    }
+}
+
+impl<T> Default for Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Two polynomials are equal if their coefficients are equal, ignoring any trailing zeros, so
+/// that e.g. `[1, 2, 0] == [1, 2]`.
+impl<T> PartialEq for Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let len = self.coef.len().max(other.coef.len());
+        (0..len).all(|i| {
+            self.coef.get(i).copied().unwrap_or(T::zero())
+                == other.coef.get(i).copied().unwrap_or(T::zero())
+        })
+    }
+}
+
+/// Builds a polynomial from `(exponent, coefficient)` terms, in any order. Coefficients of repeated
+/// exponents are summed.
+///
+/// Unlike [`Polynomial::build`], the coefficients are not checked for NaN or Infinity.
+///
+/// # Example
+///
+/// ```
+/// # use rsl_polynomials::Polynomial;
+/// let poly: Polynomial<f64> = [(100, 1.0), (0, 1.0)].into_iter().collect(); // x¹⁰⁰+1
+///
+/// assert_eq!(poly.coef.len(), 101);
+/// assert_eq!(poly.eval(1.0), 2.0);
+/// ```
+impl<T> FromIterator<(usize, T)> for Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    fn from_iter<I: IntoIterator<Item = (usize, T)>>(iter: I) -> Self {
+        let mut coef = vec![T::zero()];
+        for (exp, c) in iter {
+            if exp >= coef.len() {
+                coef.resize(exp + 1, T::zero());
+            }
+            coef[exp] = coef[exp] + c;
+        }
+        Polynomial { coef }
+    }
+}
+
+/// Creates a polynomial from an array of coefficients, from constant to leading term, like
+/// [`Polynomial::build`].
+///
+/// # Example
+///
+/// ```
+/// # use rsl_polynomials::{Polynomial, Result};
+/// # fn main() -> Result<()> {
+/// let poly = Polynomial::try_from([1.0, 4.0, 3.0])?; // 1+4x+3x²
+///
+/// assert!(Polynomial::try_from([1.0, f64::NAN]).is_err());
+/// # Ok(())
+/// # }
+/// ```
+impl<T, const N: usize> TryFrom<[T; N]> for Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Error = PolyError;
+
+    fn try_from(coef: [T; N]) -> Result<Self> {
+        Polynomial::build(&coef)
+    }
+}
+
+/// Creates a polynomial from a slice of coefficients, from constant to leading term, like
+/// [`Polynomial::build`].
+impl<T> TryFrom<&[T]> for Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Error = PolyError;
+
+    fn try_from(coef: &[T]) -> Result<Self> {
+        Polynomial::build(coef)
+    }
+}
+
+/// Creates a polynomial from a vector of coefficients, from constant to leading term, like
+/// [`Polynomial::build`], without copying them.
+impl<T> TryFrom<Vec<T>> for Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Error = PolyError;
+
+    fn try_from(coef: Vec<T>) -> Result<Self> {
+        check_finite_coef(coef.iter().copied())?;
+        match coef.is_empty() {
+            true => Ok(Polynomial::new()),
+            false => Ok(Polynomial { coef }),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Polynomial<T>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Polynomial")
+            .field("Coefficients", &self.coef)
+            .finish()
+    }
+}
+
+/// Creates a [`Polynomial`] from its coefficients, from constant to leading term.
+///
+/// `poly![]` creates the zero polynomial.
+///
+/// # Panics
+///
+/// Panics if any of the coefficients is NaN or Infinity. Use [`Polynomial::build`] or
+/// [`TryFrom`] to handle invalid coefficients.
+///
+/// # Example
+///
+/// ```
+/// # use rsl_polynomials::{poly, Polynomial};
+/// let poly = poly![1.0, 4.0, 3.0]; // 1+4x+3x²
+///
+/// assert_eq!(poly.eval(1.0), 8.0);
+/// ```
+#[macro_export]
+macro_rules! poly {
+    () => {
+        $crate::Polynomial::new()
+    };
+    ($($c:expr),+ $(,)?) => {
+        $crate::Polynomial::build(&[$($c),+]).expect("polynomial coefficients must be finite")
+    };
+}
+
+/// Returns the point of the unit circle at the given number of turns, e^(2πi·turns).
+///
+/// The turns are reduced to the nearest quarter-turn, which is applied exactly, so that e.g. the
+/// roots of unity on the axes have exactly 0 real or imaginary parts.
+fn unit_from_turns(turns: f64) -> Complex64 {
+    let turns = turns - turns.floor();
+    let quarters = (4.0 * turns).round();
+    let (sin, cos) = (2.0 * std::f64::consts::PI * (turns - quarters / 4.0)).sin_cos();
+    match quarters as u8 % 4 {
+        0 => Complex64::new(cos, sin),
+        1 => Complex64::new(-sin, cos),
+        2 => Complex64::new(-cos, -sin),
+        _ => Complex64::new(sin, -cos),
     }
 }
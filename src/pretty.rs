@@ -0,0 +1,150 @@
+//! Human-readable formatting of polynomials, as Unicode text or LaTeX math.
+
+use std::fmt::Display;
+
+use num::Float;
+
+use crate::Polynomial;
+
+/// Options of [`Polynomial::to_unicode`] and [`Polynomial::to_latex`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormatOptions {
+    /// The name of the variable, e.g. `"t"`, or `"\\omega"` in LaTeX.
+    pub variable: String,
+    /// The number of decimal places of the coefficients, or `None` for the shortest
+    /// representation that round-trips.
+    pub precision: Option<usize>,
+    /// Whether the terms are written from the leading term down to the constant, as usual in
+    /// print, or from the constant up, like [`Polynomial::coef`].
+    pub descending: bool,
+}
+
+impl Default for FormatOptions {
+    /// The variable `x`, the shortest representation of the coefficients and descending order.
+    fn default() -> Self {
+        FormatOptions {
+            variable: "x".into(),
+            precision: None,
+            descending: true,
+        }
+    }
+}
+
+/// The syntax of the formatted polynomial.
+#[derive(Clone, Copy)]
+enum Syntax {
+    Unicode,
+    Latex,
+}
+
+/// Returns `n` in Unicode superscript digits.
+fn superscript(n: usize) -> String {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    n.to_string()
+        .chars()
+        .map(|d| DIGITS[d.to_digit(10).unwrap_or(0) as usize])
+        .collect()
+}
+
+impl<F> Polynomial<F>
+where
+    F: Float + Display + std::fmt::Debug,
+{
+    /// Formats the polynomial as Unicode text, with superscript exponents and proper minus
+    /// signs, e.g. `3x² − 4x + 1`.
+    ///
+    /// Zero terms are omitted, and so are unit coefficients other than the constant's. The zero
+    /// polynomial is `0`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{FormatOptions, Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0, -4.0, 0.0, 3.0])?;
+    ///
+    /// assert_eq!(poly.to_unicode(&FormatOptions::default()), "3x³ − 4x + 1");
+    ///
+    /// let opts = FormatOptions {
+    ///     variable: "t".into(),
+    ///     precision: Some(2),
+    ///     descending: false,
+    /// };
+    /// assert_eq!(poly.to_unicode(&opts), "1.00 − 4.00t + 3.00t³");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_unicode(&self, opts: &FormatOptions) -> String {
+        self.format_terms(opts, Syntax::Unicode)
+    }
+
+    /// Formats the polynomial as LaTeX math, without the surrounding delimiters, e.g.
+    /// `3x^{2} - 4x + 1`.
+    ///
+    /// Zero terms are omitted, and so are unit coefficients other than the constant's. The zero
+    /// polynomial is `0`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{FormatOptions, Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[0.5, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 2.0])?;
+    ///
+    /// assert_eq!(poly.to_latex(&FormatOptions::default()), "2x^{10} - x^{2} + 0.5");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_latex(&self, opts: &FormatOptions) -> String {
+        self.format_terms(opts, Syntax::Latex)
+    }
+
+    /// Joins the non-zero terms in the order of `opts`, with the signs and powers of `syntax`.
+    fn format_terms(&self, opts: &FormatOptions, syntax: Syntax) -> String {
+        let minus = match syntax {
+            Syntax::Unicode => "−",
+            Syntax::Latex => "-",
+        };
+        let mut terms: Vec<(usize, F)> = self
+            .coef
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, c)| !c.is_zero())
+            .collect();
+        if opts.descending {
+            terms.reverse();
+        }
+        if terms.is_empty() {
+            return "0".into();
+        }
+
+        let mut out = String::new();
+        for (i, (power, c)) in terms.into_iter().enumerate() {
+            match (i, c.is_sign_negative()) {
+                (0, false) => {}
+                (0, true) => out.push_str(minus),
+                (_, false) => out.push_str(" + "),
+                (_, true) => out.push_str(&format!(" {minus} ")),
+            }
+
+            let abs = c.abs();
+            if power == 0 || abs != F::one() {
+                match opts.precision {
+                    Some(p) => out.push_str(&format!("{abs:.p$}")),
+                    None => out.push_str(&format!("{abs}")),
+                }
+            }
+            match (power, syntax) {
+                (0, _) => {}
+                (1, _) => out.push_str(&opts.variable),
+                (_, Syntax::Unicode) => {
+                    out.push_str(&opts.variable);
+                    out.push_str(&superscript(power));
+                }
+                (_, Syntax::Latex) => out.push_str(&format!("{}^{{{power}}}", opts.variable)),
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,499 @@
+//! Rational functions, i.e. ratios of polynomials P(x)/Q(x).
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num::complex::Complex64;
+
+use crate::arith::forward_owned_binop;
+use crate::linalg::lstsq;
+use crate::solve::solve_complex_aberth_from;
+use crate::utils::check_finite_coef;
+use crate::{CLUSTER_TOL, PolyError, Polynomial, Result};
+
+/// Cancels the (nearly) common roots of `p` and `q`, returning the reduced numerator and
+/// denominator.
+///
+/// Two roots are considered common when they are within `tol` of each other, relative to their
+/// magnitude (or absolutely, for roots smaller than 1). Complex conjugate pairs are cancelled
+/// together, so the reduced polynomials still have real coefficients.
+///
+/// # Error
+///
+/// Returns an error if `q` is the zero polynomial, or if the roots of `p` or `q` could not be
+/// found.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{Polynomial, Result, rational};
+/// # fn main() -> Result<()> {
+/// let p = Polynomial::build(&[-2.0, 1.0, 1.0])?; // (x−1)(x+2)
+/// let q = Polynomial::build(&[3.0, -4.0, 1.0])?; // (x−1)(x−3)
+/// let (p, q) = rational::cancel_common_roots(&p, &q, 1e-8)?;
+///
+/// assert!((p.coef[0] - 2.0).abs() < 1e-14 && (p.coef[1] - 1.0).abs() < 1e-14);
+/// assert!((q.coef[0] + 3.0).abs() < 1e-14 && (q.coef[1] - 1.0).abs() < 1e-14);
+/// # Ok(())
+/// # }
+/// ```
+pub fn cancel_common_roots(
+    p: &Polynomial<f64>,
+    q: &Polynomial<f64>,
+    tol: f64,
+) -> Result<(Polynomial<f64>, Polynomial<f64>)> {
+    let (p_trim, q_trim) = (p.to_trimmed(), q.to_trimmed());
+    if q_trim.coef.last().is_none_or(|c| *c == 0.0) {
+        return Err(PolyError::DivisionByZero);
+    }
+    if (p_trim.coef.len() < 2) | (q_trim.coef.len() < 2) {
+        return Ok((p.clone(), q.clone()));
+    }
+
+    // Only match roots in the upper half plane; their conjugates are cancelled along with them.
+    let upper = |roots: Vec<Complex64>| -> Vec<Complex64> {
+        roots.into_iter().filter(|z| z.im >= 0.0).collect()
+    };
+    let p_roots = upper(p_trim.solve_complex()?);
+    let mut q_roots: Vec<Option<Complex64>> = upper(q_trim.solve_complex()?)
+        .into_iter()
+        .map(Some)
+        .collect();
+
+    let mut common = Polynomial { coef: vec![1.0] };
+    for rp in p_roots {
+        let closest = q_roots
+            .iter()
+            .enumerate()
+            .filter_map(|(i, rq)| rq.map(|rq| (i, rq, (rq - rp).norm())))
+            .min_by(|a, b| a.2.total_cmp(&b.2));
+
+        if let Some((i, rq, dist)) = closest
+            && dist <= tol * rp.norm().max(1.0)
+        {
+            q_roots[i] = None;
+            let r = (rp + rq) / 2.0;
+            let factor = match r.im {
+                0.0 => Polynomial {
+                    coef: vec![-r.re, 1.0],
+                },
+                _ => Polynomial {
+                    coef: vec![r.norm_sqr(), -2.0 * r.re, 1.0],
+                },
+            };
+            common = &common * &factor;
+        }
+    }
+
+    if common.coef.len() == 1 {
+        return Ok((p.clone(), q.clone()));
+    }
+    Ok((p_trim.div_rem(&common)?.0, q_trim.div_rem(&common)?.0))
+}
+
+/// Evaluates P(x)/Q(x), after cancelling the nearly common roots of P and Q with
+/// [`cancel_common_roots`] and a tolerance of [`CLUSTER_TOL`].
+///
+/// This avoids the 0/0 (or otherwise catastrophically inaccurate) evaluation near removable
+/// singularities. Since the roots of both polynomials are calculated on every call, for repeated
+/// evaluations it is preferable to cancel the common roots once and evaluate the reduced
+/// polynomials.
+///
+/// # Error
+///
+/// Returns an error if `q` is the zero polynomial, or if the roots of `p` or `q` could not be
+/// found.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{Polynomial, Result, rational};
+/// # fn main() -> Result<()> {
+/// let p = Polynomial::build(&[-2.0, 1.0, 1.0])?; // (x−1)(x+2)
+/// let q = Polynomial::build(&[3.0, -4.0, 1.0])?; // (x−1)(x−3)
+///
+/// assert!(p.eval(1.0) / q.eval(1.0) != p.eval(1.0) / q.eval(1.0)); // NaN
+/// assert!((rational::eval_stable(&p, &q, 1.0)? + 1.5).abs() < 1e-14);
+/// # Ok(())
+/// # }
+/// ```
+pub fn eval_stable(p: &Polynomial<f64>, q: &Polynomial<f64>, x: f64) -> Result<f64> {
+    let (p, q) = cancel_common_roots(p, q, CLUSTER_TOL)?;
+    let (num, den) = Polynomial::eval_pair(&p, &q, x);
+    Ok(num / den)
+}
+
+/// Constructs the [m/n] Padé approximant of a power series, i.e. the rational function P/Q, with P
+/// of order `m` and Q of order `n`, whose Taylor series matches the given one up to the term
+/// xᵐ⁺ⁿ.
+///
+/// `series` contains the Taylor coefficients, constant term first, and must contain at least
+/// `m+n+1` terms; any further terms are ignored. The denominator is normalized so that Q(0) = 1.
+///
+/// # Error
+///
+/// Returns an error if there are not enough (finite) series coefficients, or if the approximant
+/// does not exist, i.e. the linear system for the denominator is singular.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{Result, rational};
+/// # fn main() -> Result<()> {
+/// // eˣ = 1 + x + x²/2 + x³/6 + ...
+/// let series = [1.0, 1.0, 1.0 / 2.0, 1.0 / 6.0, 1.0 / 24.0];
+/// let exp = rational::pade(&series, 2, 2)?;
+///
+/// assert!((exp.eval(0.5) - 0.5f64.exp()).abs() < 1e-4);
+/// assert!((exp.den.coef[1] + 0.5).abs() < 1e-15);
+/// # Ok(())
+/// # }
+/// ```
+pub fn pade(series: &[f64], m: usize, n: usize) -> Result<RationalFunction<f64>> {
+    if series.len() < m + n + 1 {
+        return Err(PolyError::NotEnoughCoefficients(m + n + 1));
+    }
+    check_finite_coef(series[..=m + n].iter().copied())?;
+    // Coefficient of xⁱ, with cᵢ = 0 for i < 0
+    let c = |i: isize| if i < 0 { 0.0 } else { series[i as usize] };
+
+    // Σⱼ qⱼ·cₖ₋ⱼ = −cₖ for k = m+1..=m+n, with q₀ = 1
+    let mut q = vec![1.0];
+    if n > 0 {
+        let a: Vec<Vec<f64>> = (1..=n)
+            .map(|i| (1..=n).map(|j| c((m + i) as isize - j as isize)).collect())
+            .collect();
+        let b: Vec<f64> = (1..=n).map(|i| -c((m + i) as isize)).collect();
+        q.extend(lstsq(&a, &b)?.0);
+    }
+
+    let p: Vec<f64> = (0..=m)
+        .map(|k| (0..=k.min(n)).map(|j| q[j] * c((k - j) as isize)).sum())
+        .collect();
+
+    Ok(RationalFunction {
+        num: Polynomial { coef: p },
+        den: Polynomial { coef: q },
+    })
+}
+
+/// A rational function P(x)/Q(x), e.g. the transfer function of a linear system.
+///
+/// The arithmetic operations do not cancel common factors, so the orders of the numerator and
+/// denominator grow with every operation; use [`simplify`](RationalFunction::simplify) to reduce
+/// them.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{Polynomial, Result};
+/// # use rsl_polynomials::rational::RationalFunction;
+/// # fn main() -> Result<()> {
+/// let f = RationalFunction::new(
+///     Polynomial::build(&[1.0])?,
+///     Polynomial::build(&[1.0, 1.0])?, // 1/(x+1)
+/// )?;
+/// let g = RationalFunction::new(
+///     Polynomial::build(&[0.0, 1.0])?,
+///     Polynomial::build(&[1.0, 1.0])?, // x/(x+1)
+/// )?;
+///
+/// assert_eq!((&f + &g).eval(2.0), 1.0);
+/// assert_eq!((&f * &g).eval(1.0), 0.25);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RationalFunction<T = f64>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// The numerator P.
+    pub num: Polynomial<T>,
+    /// The denominator Q.
+    pub den: Polynomial<T>,
+}
+
+impl<T> RationalFunction<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Creates the rational function `num/den`.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `den` is the zero polynomial.
+    pub fn new(num: Polynomial<T>, den: Polynomial<T>) -> Result<Self> {
+        if den.coef.iter().all(|c| c.is_zero()) {
+            return Err(PolyError::DivisionByZero);
+        }
+        Ok(Self { num, den })
+    }
+
+    /// Evaluates P(x)/Q(x).
+    ///
+    /// Follows IEEE 754 at the poles, i.e. returns ±∞ where only Q(x) vanishes and NaN where both
+    /// P(x) and Q(x) vanish. See [`eval_checked`](RationalFunction::eval_checked) for an
+    /// alternative.
+    pub fn eval(&self, x: T) -> T {
+        let (num, den) = Polynomial::eval_pair(&self.num, &self.den, x);
+        num / den
+    }
+
+    /// Returns the reciprocal Q(x)/P(x).
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the numerator is the zero polynomial.
+    pub fn recip(&self) -> Result<Self> {
+        Self::new(self.den.clone(), self.num.clone())
+    }
+
+    /// Returns the (complex) zeros of the rational function, i.e. the roots of its numerator.
+    ///
+    /// Common roots of the numerator and denominator are included; call
+    /// [`simplify`](RationalFunction::simplify) first to exclude them.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the roots could not be found.
+    pub fn zeros(&self) -> Result<Vec<Complex64>> {
+        roots_or_empty(&self.num)
+    }
+
+    /// Returns the (complex) poles of the rational function, i.e. the roots of its denominator.
+    ///
+    /// Common roots of the numerator and denominator are included; call
+    /// [`simplify`](RationalFunction::simplify) first to exclude them.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the roots could not be found.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # use rsl_polynomials::rational::RationalFunction;
+    /// # fn main() -> Result<()> {
+    /// let f = RationalFunction::new(
+    ///     Polynomial::build(&[1.0])?,
+    ///     Polynomial::build(&[1.0, 0.0, 1.0])?, // 1/(x²+1)
+    /// )?;
+    /// let poles = f.poles()?;
+    ///
+    /// assert_eq!(poles.len(), 2);
+    /// assert!(poles.iter().all(|p| (p.norm() - 1.0).abs() < 1e-14 && p.re.abs() < 1e-14));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn poles(&self) -> Result<Vec<Complex64>> {
+        roots_or_empty(&self.den)
+    }
+}
+
+impl RationalFunction<f64> {
+    /// Evaluates P(x)/Q(x), returning an error at the poles.
+    ///
+    /// Where both P(x) and Q(x) vanish, the common roots are cancelled (see [`eval_stable`]), so
+    /// removable singularities are evaluated to their limit.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if x is a pole of the (simplified) rational function.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{PolyError, Polynomial, Result};
+    /// # use rsl_polynomials::rational::RationalFunction;
+    /// # fn main() -> Result<()> {
+    /// let f = RationalFunction::new(
+    ///     Polynomial::build(&[-2.0, 1.0, 1.0])?, // (x−1)(x+2)
+    ///     Polynomial::build(&[3.0, -4.0, 1.0])?, // (x−1)(x−3)
+    /// )?;
+    ///
+    /// assert!((f.eval_checked(1.0)? + 1.5).abs() < 1e-14);
+    /// assert!(matches!(f.eval_checked(3.0), Err(PolyError::Pole(_))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_checked(&self, x: f64) -> Result<f64> {
+        let (num, den) = Polynomial::eval_pair(&self.num, &self.den, x);
+        if den != 0.0 {
+            return Ok(num / den);
+        }
+        if num == 0.0 {
+            let value = eval_stable(&self.num, &self.den, x)?;
+            if value.is_finite() {
+                return Ok(value);
+            }
+        }
+        Err(PolyError::Pole(x))
+    }
+
+    /// Returns the rational function with the (nearly) common roots of its numerator and
+    /// denominator cancelled, i.e. divided by their greatest common divisor.
+    ///
+    /// See [`cancel_common_roots`] for the meaning of `tol`.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the roots of the numerator or the denominator could not be found.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # use rsl_polynomials::rational::RationalFunction;
+    /// # fn main() -> Result<()> {
+    /// let f = RationalFunction::new(
+    ///     Polynomial::build(&[-2.0, 1.0, 1.0])?, // (x−1)(x+2)
+    ///     Polynomial::build(&[3.0, -4.0, 1.0])?, // (x−1)(x−3)
+    /// )?;
+    /// let g = f.simplify(1e-8)?; // (x+2)/(x−3)
+    ///
+    /// assert_eq!((g.num.coef.len(), g.den.coef.len()), (2, 2));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn simplify(&self, tol: f64) -> Result<Self> {
+        let (num, den) = cancel_common_roots(&self.num, &self.den, tol)?;
+        Ok(Self { num, den })
+    }
+
+    /// Computes the root locus of the rational function G = P/Q, i.e. the closed-loop poles,
+    /// the roots of Q + k·P (1 + k·G = 0), for every gain k in `gains`.
+    ///
+    /// The poles of every gain are found with the Aberth-Ehrlich method, starting from the poles
+    /// of the previous gain, which takes only a few iterations for a fine sweep. As a result, the
+    /// i-th pole of every gain continues the same branch of the locus, as long as the number of
+    /// poles does not change. The first gain (or any gain where the number of poles changes)
+    /// starts from scratch.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if a gain is NaN or Infinity, if Q + k·P is a constant polynomial for
+    /// some gain, or if the poles could not be found.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # use rsl_polynomials::rational::RationalFunction;
+    /// # fn main() -> Result<()> {
+    /// // G = 1/(s(s+2)), with closed-loop poles −1 ± √(1−k)
+    /// let g = RationalFunction::new(
+    ///     Polynomial::build(&[1.0])?,
+    ///     Polynomial::build(&[0.0, 2.0, 1.0])?,
+    /// )?;
+    /// let locus = g.root_locus(&[0.0, 0.75, 2.0])?;
+    ///
+    /// let mut poles = locus[1].clone();
+    /// poles.sort_by(|a, b| a.re.total_cmp(&b.re));
+    /// assert!((poles[0].re + 1.5).abs() < 1e-12 && (poles[1].re + 0.5).abs() < 1e-12);
+    /// for p in &locus[2] {
+    ///     assert!((p.re + 1.0).abs() < 1e-12 && (p.im.abs() - 1.0).abs() < 1e-12);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn root_locus(&self, gains: &[f64]) -> Result<Vec<Vec<Complex64>>> {
+        check_finite_coef(gains.iter().copied())?;
+        let to_complex = |c: &f64| Complex64::new(*c, 0.0);
+        let num: Vec<Complex64> = self.num.coef.iter().map(to_complex).collect();
+        let den: Vec<Complex64> = self.den.coef.iter().map(to_complex).collect();
+
+        let mut locus: Vec<Vec<Complex64>> = Vec::with_capacity(gains.len());
+        for k in gains {
+            let len = num.len().max(den.len());
+            let mut coef: Vec<Complex64> = (0..len)
+                .map(|i| {
+                    let p = num.get(i).copied().unwrap_or_default();
+                    let q = den.get(i).copied().unwrap_or_default();
+                    q + p * k
+                })
+                .collect();
+            match coef.iter().rposition(|c| *c != Complex64::default()) {
+                None | Some(0) => return Err(PolyError::ConstantPoly),
+                Some(i) => coef.truncate(i + 1),
+            }
+            let guess = locus.last().map_or(&[][..], |p| &p[..]);
+            locus.push(solve_complex_aberth_from(&coef, guess)?);
+        }
+        Ok(locus)
+    }
+}
+
+/// Returns the roots of a polynomial, or no roots if it is constant.
+fn roots_or_empty<T>(poly: &Polynomial<T>) -> Result<Vec<Complex64>>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    match poly.coef.iter().rposition(|c| !c.is_zero()) {
+        None | Some(0) => Ok(Vec::new()),
+        Some(_) => poly.solve_complex(),
+    }
+}
+
+impl<T> Add for &RationalFunction<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = RationalFunction<T>;
+
+    fn add(self, rhs: Self) -> RationalFunction<T> {
+        match self.den == rhs.den {
+            true => RationalFunction {
+                num: &self.num + &rhs.num,
+                den: self.den.clone(),
+            },
+            false => RationalFunction {
+                num: &(&self.num * &rhs.den) + &(&rhs.num * &self.den),
+                den: &self.den * &rhs.den,
+            },
+        }
+    }
+}
+
+impl<T> Sub for &RationalFunction<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = RationalFunction<T>;
+
+    fn sub(self, rhs: Self) -> RationalFunction<T> {
+        self + &(-rhs)
+    }
+}
+
+impl<T> Mul for &RationalFunction<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = RationalFunction<T>;
+
+    fn mul(self, rhs: Self) -> RationalFunction<T> {
+        RationalFunction {
+            num: &self.num * &rhs.num,
+            den: &self.den * &rhs.den,
+        }
+    }
+}
+
+impl<T> Neg for &RationalFunction<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = RationalFunction<T>;
+
+    fn neg(self) -> RationalFunction<T> {
+        RationalFunction {
+            num: -&self.num,
+            den: self.den.clone(),
+        }
+    }
+}
+
+forward_owned_binop!(RationalFunction, Add, add);
+forward_owned_binop!(RationalFunction, Sub, sub);
+forward_owned_binop!(RationalFunction, Mul, mul);
@@ -0,0 +1,296 @@
+//! Robust least-squares fitting of polynomials to data with outliers.
+
+use crate::{FitResult, PolyError, Polynomial, Result, stats};
+
+/// Ratio of the standard deviation to the median absolute deviation of normal data.
+const MAD_TO_SIGMA: f64 = 1.482_602_218_505_602;
+
+/// A robust loss function of [`Polynomial::fit_robust`], with its tuning constant k, in units of
+/// the estimated standard deviation of the data.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RobustLoss {
+    /// Huber's loss: quadratic for residuals up to k, linear beyond, so that outliers still pull,
+    /// but less. k = 1.345 gives 95% efficiency on normal data.
+    Huber(f64),
+    /// Tukey's biweight: residuals beyond k are ignored entirely. k = 4.685 gives 95% efficiency
+    /// on normal data.
+    Tukey(f64),
+}
+
+impl RobustLoss {
+    /// Returns the weight of a residual `u`, in units of the estimated standard deviation.
+    fn weight(&self, u: f64) -> f64 {
+        match *self {
+            RobustLoss::Huber(k) => match u.abs() <= k {
+                true => 1.0,
+                false => k / u.abs(),
+            },
+            RobustLoss::Tukey(k) => match u.abs() < k {
+                true => (1.0 - (u / k).powi(2)).powi(2),
+                false => 0.0,
+            },
+        }
+    }
+}
+
+/// Options of [`Polynomial::fit_ransac`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RansacOptions {
+    /// The largest absolute residual of an inlier, or `None` to use 2.5 times the standard
+    /// deviation estimated from the residuals of a [`RobustLoss::Tukey`] fit of all the data.
+    pub threshold: Option<f64>,
+    /// The number of random samples of `degree+1` points.
+    pub iterations: usize,
+    /// The seed of the generator of the samples. The same seed always gives the same result.
+    pub seed: u64,
+}
+
+impl Default for RansacOptions {
+    /// An estimated threshold, 500 samples and a seed of 0.
+    fn default() -> Self {
+        RansacOptions {
+            threshold: None,
+            iterations: 500,
+            seed: 0,
+        }
+    }
+}
+
+/// The SplitMix64 generator, which is enough to draw the samples of RANSAC.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `0..n`. The modulo bias is negligible for the sizes of data sets.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+impl Polynomial<f64> {
+    /// Fits a polynomial of order `degree` to the data points `(xs[i], ys[i])`, minimizing a
+    /// robust `loss` of the residuals instead of their squares, so that outliers don't drag the
+    /// fit towards them.
+    ///
+    /// The fit uses iteratively reweighted least squares, starting from the ordinary fit: in
+    /// every iteration, the standard deviation of the data is estimated from the median absolute
+    /// residual, and the data are refitted with the weights of the loss, until the coefficients
+    /// settle. Tukey's loss, which is not convex, starts from the Huber fit with k = 1.345
+    /// instead.
+    ///
+    /// The returned [`FitResult`] is that of the last weighted fit, with its weights.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the slices have different lengths, contain NaN or Infinity, contain
+    /// fewer than `degree+1` points, if the tuning constant is not positive and finite, if the
+    /// points with non-zero weights do not determine a unique polynomial, or if the iterations do
+    /// not converge.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result, RobustLoss};
+    /// # fn main() -> Result<()> {
+    /// let xs = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+    /// let ys = [1.02, 2.99, 5.01, 30.0, 8.98, 11.03, 12.97, 15.01]; // 2x + 1, with an outlier
+    ///
+    /// let plain = Polynomial::fit(&xs, &ys, 1)?;
+    /// let robust = Polynomial::fit_robust(&xs, &ys, 1, RobustLoss::Tukey(4.685))?;
+    ///
+    /// assert!((plain.poly.coef[0] - 1.0).abs() > 1.0);
+    /// assert!((robust.poly.coef[0] - 1.0).abs() < 0.05);
+    /// assert!((robust.poly.coef[1] - 2.0).abs() < 0.01);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fit_robust(
+        xs: &[f64],
+        ys: &[f64],
+        degree: usize,
+        loss: RobustLoss,
+    ) -> Result<FitResult> {
+        let (RobustLoss::Huber(k) | RobustLoss::Tukey(k)) = loss;
+        if !(k.is_finite() && k > 0.0) {
+            return Err(PolyError::InvalidTuning(k));
+        }
+        let start = Self::fit(xs, ys, degree)?;
+        match loss {
+            RobustLoss::Huber(_) => irls(xs, ys, degree, loss, start),
+            RobustLoss::Tukey(_) => {
+                let huber = irls(xs, ys, degree, RobustLoss::Huber(1.345), start)?;
+                irls(xs, ys, degree, loss, huber)
+            }
+        }
+    }
+
+    /// Fits a polynomial of order `degree` to the data points `(xs[i], ys[i])` with RANSAC, which
+    /// tolerates any fraction of outliers as long as the inliers are the largest consistent set.
+    ///
+    /// Polynomials are interpolated through random samples of `degree+1` points, and the one
+    /// with the most points within the threshold, or the smallest sum of their squared
+    /// residuals on a tie, is refitted by least squares to its inliers. The samples are drawn
+    /// from a generator seeded by [`RansacOptions::seed`], so that the result is reproducible.
+    ///
+    /// Returns the fit of the inliers and which points are inliers.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the slices have different lengths, contain NaN or Infinity, contain
+    /// fewer than `degree+1` points, if the threshold is not positive and finite, or if no
+    /// sample determines a unique polynomial.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, RansacOptions, Result};
+    /// # fn main() -> Result<()> {
+    /// let xs: Vec<f64> = (0..20).map(|i| i as f64).collect();
+    /// let mut ys: Vec<f64> = xs.iter().map(|x| 0.5 * x * x - x + 2.0).collect();
+    /// for i in [1, 4, 5, 9, 13, 14, 18] {
+    ///     ys[i] = 100.0 - ys[i];
+    /// }
+    ///
+    /// let opts = RansacOptions {
+    ///     threshold: Some(0.1),
+    ///     ..Default::default()
+    /// };
+    /// let (fit, inliers) = Polynomial::fit_ransac(&xs, &ys, 2, opts)?;
+    ///
+    /// assert_eq!(inliers.iter().filter(|i| **i).count(), 13);
+    /// assert!((fit.poly.coef[2] - 0.5).abs() < 1e-10);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fit_ransac(
+        xs: &[f64],
+        ys: &[f64],
+        degree: usize,
+        opts: RansacOptions,
+    ) -> Result<(FitResult, Vec<bool>)> {
+        if xs.len() != ys.len() {
+            return Err(PolyError::LengthMismatch(xs.len(), ys.len()));
+        }
+        if xs.iter().chain(ys).any(|v| !v.is_finite()) {
+            return Err(PolyError::InvalidData);
+        }
+        if xs.len() < degree + 1 {
+            return Err(PolyError::NotEnoughPoints(degree + 1));
+        }
+        let threshold = match opts.threshold {
+            Some(threshold) if threshold.is_finite() && threshold > 0.0 => threshold,
+            Some(threshold) => return Err(PolyError::InvalidTuning(threshold)),
+            None => {
+                let tukey = Self::fit_robust(xs, ys, degree, RobustLoss::Tukey(4.685))?;
+                let residuals: Vec<f64> = xs
+                    .iter()
+                    .zip(ys)
+                    .map(|(x, y)| (y - tukey.poly.eval(*x)).abs())
+                    .collect();
+                let sigma = MAD_TO_SIGMA * stats::median(&residuals);
+                2.5 * sigma.max(f64::EPSILON * max_abs(ys))
+            }
+        };
+
+        let n = xs.len();
+        let mut rng = SplitMix64(opts.seed);
+        let mut indices: Vec<usize> = (0..n).collect();
+        let mut best: Option<(usize, f64, Polynomial<f64>)> = None;
+        for _ in 0..opts.iterations {
+            // A partial Fisher–Yates shuffle
+            for i in 0..=degree {
+                let j = i + rng.below(n - i);
+                indices.swap(i, j);
+            }
+            let sample = &indices[..=degree];
+            let sx: Vec<f64> = sample.iter().map(|i| xs[*i]).collect();
+            let sy: Vec<f64> = sample.iter().map(|i| ys[*i]).collect();
+            let Ok(candidate) = Self::fit(&sx, &sy, degree) else {
+                continue;
+            };
+
+            let (count, sum_sq) = xs
+                .iter()
+                .zip(ys)
+                .map(|(x, y)| (y - candidate.poly.eval(*x)).abs())
+                .filter(|r| *r <= threshold)
+                .fold((0, 0.0), |(count, sum), r| (count + 1, sum + r * r));
+            let better = match &best {
+                None => true,
+                Some((c, s, _)) => count > *c || (count == *c && sum_sq < *s),
+            };
+            if better {
+                best = Some((count, sum_sq, candidate.poly));
+            }
+        }
+        let Some((_, _, poly)) = best else {
+            return Err(PolyError::NoConvergence);
+        };
+
+        let inliers: Vec<bool> = xs
+            .iter()
+            .zip(ys)
+            .map(|(x, y)| (y - poly.eval(*x)).abs() <= threshold)
+            .collect();
+        let (ix, iy): (Vec<f64>, Vec<f64>) = xs
+            .iter()
+            .zip(ys)
+            .zip(&inliers)
+            .filter(|(_, inlier)| **inlier)
+            .map(|((x, y), _)| (*x, *y))
+            .unzip();
+        Ok((Self::fit(&ix, &iy, degree)?, inliers))
+    }
+}
+
+/// Returns the largest absolute value.
+fn max_abs(values: &[f64]) -> f64 {
+    values.iter().fold(0.0, |acc: f64, v| acc.max(v.abs()))
+}
+
+/// Iteratively reweighted least squares with `loss`, starting from the fit `start`.
+fn irls(
+    xs: &[f64],
+    ys: &[f64],
+    degree: usize,
+    loss: RobustLoss,
+    start: FitResult,
+) -> Result<FitResult> {
+    const MAX_ITER: usize = 100;
+    let floor = f64::EPSILON * max_abs(ys);
+    let mut fit = start;
+    for _ in 0..MAX_ITER {
+        let residuals: Vec<f64> = xs
+            .iter()
+            .zip(ys)
+            .map(|(x, y)| y - fit.poly.eval(*x))
+            .collect();
+        let abs: Vec<f64> = residuals.iter().map(|r| r.abs()).collect();
+        // An exact fit of most of the data leaves the rest with (practically) no weight
+        let sigma = (MAD_TO_SIGMA * stats::median(&abs))
+            .max(floor)
+            .max(f64::MIN_POSITIVE);
+        let weights: Vec<f64> = residuals.iter().map(|r| loss.weight(r / sigma)).collect();
+
+        let next = Polynomial::fit_weighted(xs, ys, &weights, degree)?;
+        let scale = max_abs(&next.poly.coef).max(f64::MIN_POSITIVE);
+        let change = next
+            .poly
+            .coef
+            .iter()
+            .zip(&fit.poly.coef)
+            .fold(0.0, |acc: f64, (a, b)| acc.max((a - b).abs()));
+        fit = next;
+        if change <= 1e-12 * scale {
+            return Ok(fit);
+        }
+    }
+    Err(PolyError::NoConvergence)
+}
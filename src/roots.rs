@@ -0,0 +1,308 @@
+//! Representation and post-processing of computed roots, e.g. of the output of
+//! [`Polynomial::solve_complex`].
+
+use std::cmp::Ordering;
+
+use num::complex::Complex64;
+
+use crate::{PolyError, Polynomial, Result};
+
+/// Default relative tolerance under which two computed roots are considered to be the same root.
+///
+/// A root of multiplicity `m` can only be computed to about `EPSILON^(1/m)` relative accuracy, so
+/// this is loose enough to merge double and triple roots.
+pub const CLUSTER_TOL: f64 = 1e-5;
+
+/// A real root of a polynomial.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Root {
+    /// The root's value.
+    pub value: f64,
+    /// The number of times the root is repeated.
+    pub multiplicity: usize,
+    /// The root's condition number, if it has been estimated.
+    pub condition: Option<f64>,
+}
+
+/// The real roots of a quadratic equation ax²+bx+c = 0, of type `F` ([`f64`] by default).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum QuadraticRoots<F = f64> {
+    /// No real roots (the roots are a complex conjugate pair).
+    None,
+    /// The equation is degenerate (a = 0), with the single root of bx+c = 0.
+    Linear(F),
+    /// A double root.
+    Double(F),
+    /// Two distinct real roots, in increasing order.
+    Two(F, F),
+}
+
+impl<F: Copy> QuadraticRoots<F> {
+    /// Returns the roots, with repeated roots listed as many times as their multiplicity.
+    pub fn to_vec(&self) -> Vec<F> {
+        match *self {
+            QuadraticRoots::None => vec![],
+            QuadraticRoots::Linear(x) => vec![x],
+            QuadraticRoots::Double(x) => vec![x, x],
+            QuadraticRoots::Two(x1, x2) => vec![x1, x2],
+        }
+    }
+}
+
+/// The real roots of a cubic equation x³+ax²+bx+c = 0, of type `F` ([`f64`] by default).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CubicRoots<F = f64> {
+    /// A single real root (the other two are a complex conjugate pair).
+    One(F),
+    /// Three real roots in increasing order, two of which may coincide.
+    Three([F; 3]),
+    /// A triple root.
+    TripleRepeated(F),
+}
+
+impl<F: Copy> CubicRoots<F> {
+    /// Returns the real roots, with repeated roots listed as many times as their multiplicity.
+    pub fn to_vec(&self) -> Vec<F> {
+        match *self {
+            CubicRoots::One(x) => vec![x],
+            CubicRoots::Three(roots) => roots.to_vec(),
+            CubicRoots::TripleRepeated(x) => vec![x; 3],
+        }
+    }
+}
+
+/// Merges nearly-equal roots into a single [`Root`] with the corresponding multiplicity.
+///
+/// Roots are considered equal when they are within `tol` of the cluster's first root, relative to
+/// their magnitude (or absolutely, for roots smaller than 1). The merged value is the cluster's
+/// mean. The result is sorted in increasing order.
+pub(crate) fn cluster_roots(values: &[f64], tol: f64) -> Vec<Root> {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+
+    let mut clusters: Vec<Vec<f64>> = Vec::new();
+    for x in sorted {
+        match clusters.last_mut() {
+            Some(cluster) if (x - cluster[0]).abs() <= tol * x.abs().max(1.0) => cluster.push(x),
+            _ => clusters.push(vec![x]),
+        }
+    }
+
+    clusters
+        .iter()
+        .map(|cluster| Root {
+            value: cluster.iter().sum::<f64>() / cluster.len() as f64,
+            multiplicity: cluster.len(),
+            condition: None,
+        })
+        .collect()
+}
+
+/// Estimates the condition number of the root `r` of the polynomial with real coefficients `coef`.
+///
+/// See [`Polynomial::solve_with_diagnostics`](crate::Polynomial::solve_with_diagnostics).
+pub(crate) fn condition_number(coef: &[f64], r: f64) -> f64 {
+    let abs_eval = coef
+        .iter()
+        .rev()
+        .fold(0.0, |res, c| c.abs() + r.abs() * res);
+    let deriv = coef
+        .iter()
+        .enumerate()
+        .skip(1)
+        .rev()
+        .fold(0.0, |res, (i, c)| i as f64 * c + r * res);
+
+    match r {
+        0.0 => abs_eval / deriv.abs(),
+        _ => abs_eval / (r.abs() * deriv.abs()),
+    }
+}
+
+/// Maximum imaginary part of a computed root, relative to its magnitude (or absolute, for roots
+/// smaller than 1), for it to be considered real.
+///
+/// Multiple real roots are computed as clusters of complex roots with imaginary parts of about
+/// `EPSILON^(1/m)`, so this is the same as [`CLUSTER_TOL`].
+pub(crate) const REAL_TOL: f64 = CLUSTER_TOL;
+
+/// Finds the real roots of a polynomial with real coefficients, in increasing order, by keeping
+/// the (nearly) real roots of all its complex roots.
+///
+/// Repeated roots are listed as many times as their multiplicity. The zero polynomial has no
+/// roots.
+pub(crate) fn real_roots(poly: &Polynomial<f64>) -> Result<Vec<f64>> {
+    let trimmed = poly.to_trimmed();
+    if trimmed.coef.len() < 2 {
+        return Ok(vec![]);
+    }
+
+    let mut roots = filter_real(&trimmed.solve_complex()?, REAL_TOL);
+    roots.sort_by(f64::total_cmp);
+    Ok(roots)
+}
+
+/// Finds the real roots of a polynomial with real coefficients in `[a, b]`, in increasing order,
+/// like [`real_roots`].
+///
+/// Roots within `REAL_TOL·(b − a)` outside the interval are clamped into it.
+pub(crate) fn real_roots_in(poly: &Polynomial<f64>, a: f64, b: f64) -> Result<Vec<f64>> {
+    let slack = REAL_TOL * (b - a);
+    Ok(real_roots(poly)?
+        .into_iter()
+        .filter(|x| (*x >= a - slack) & (*x <= b + slack))
+        .map(|x| x.clamp(a, b))
+        .collect())
+}
+
+/// Returns whether the imaginary part of `z` is at most `tol` relative to its magnitude, or
+/// absolute if it is smaller than 1.
+fn is_real(z: &Complex64, tol: f64) -> bool {
+    z.im.abs() <= tol * z.norm().max(1.0)
+}
+
+/// Orders complex numbers by their real and then their imaginary part.
+fn cmp_re_im(a: &Complex64, b: &Complex64) -> Ordering {
+    a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im))
+}
+
+/// Returns the real parts of the (nearly) real `roots`, in their original order, i.e. of the
+/// ones whose imaginary part is at most `tol` relative to their magnitude, or absolute for roots
+/// smaller than 1.
+///
+/// Multiple real roots are computed as clusters with imaginary parts of about `EPSILON^(1/m)`,
+/// so e.g. [`CLUSTER_TOL`] keeps double and triple roots.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{Polynomial, Result, roots};
+/// # fn main() -> Result<()> {
+/// let poly = Polynomial::build(&[-2.0, 2.0, -1.0, 1.0])?; // (x − 1)(x² + 2)
+/// let real = roots::filter_real(&poly.solve_complex()?, 1e-12);
+///
+/// assert_eq!(real.len(), 1);
+/// assert!((real[0] - 1.0).abs() < 1e-14);
+/// # Ok(())
+/// # }
+/// ```
+pub fn filter_real(roots: &[Complex64], tol: f64) -> Vec<f64> {
+    roots
+        .iter()
+        .filter(|z| is_real(z, tol))
+        .map(|z| z.re)
+        .collect()
+}
+
+/// Sorts `roots` by increasing magnitude, breaking ties by their real and then imaginary part.
+pub fn sort_by_magnitude(roots: &mut [Complex64]) {
+    roots.sort_by(|a, b| a.norm().total_cmp(&b.norm()).then(cmp_re_im(a, b)));
+}
+
+/// Sorts `roots` with the (nearly) real ones first, as decided by [`filter_real`] with `tol`,
+/// and then the complex ones, each by their real and then imaginary part.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::roots::sort_real_then_complex;
+/// # use num::complex::Complex64;
+/// let mut roots = [
+///     Complex64::new(0.0, 1.0),
+///     Complex64::new(2.0, 0.0),
+///     Complex64::new(0.0, -1.0),
+///     Complex64::new(-3.0, 0.0),
+/// ];
+/// sort_real_then_complex(&mut roots, 1e-12);
+///
+/// assert_eq!(roots.map(|z| z.re), [-3.0, 2.0, 0.0, 0.0]);
+/// assert_eq!(roots.map(|z| z.im), [0.0, 0.0, -1.0, 1.0]);
+/// ```
+pub fn sort_real_then_complex(roots: &mut [Complex64], tol: f64) {
+    roots.sort_by(|a, b| {
+        (!is_real(a, tol))
+            .cmp(&!is_real(b, tol))
+            .then(cmp_re_im(a, b))
+    });
+}
+
+/// The roots of a polynomial with real coefficients, split into real roots and complex
+/// conjugate pairs, created with [`pair_conjugates`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConjugatePairs {
+    /// The real roots, in increasing order.
+    pub real: Vec<f64>,
+    /// The root with positive imaginary part of every conjugate pair, by increasing real and then
+    /// imaginary part.
+    pub pairs: Vec<Complex64>,
+}
+
+impl ConjugatePairs {
+    /// Returns all the roots, with the real ones first and every pair as z, z̄, so that they are
+    /// exactly real or exactly conjugate.
+    pub fn roots(&self) -> Vec<Complex64> {
+        let mut roots: Vec<Complex64> = self.real.iter().map(|r| Complex64::new(*r, 0.0)).collect();
+        roots.extend(self.pairs.iter().flat_map(|z| [*z, z.conj()]));
+        roots
+    }
+}
+
+/// Splits the computed `roots` of a polynomial with real coefficients into real roots and
+/// complex conjugate pairs, as decided by [`filter_real`] with `tol`.
+///
+/// Every root with positive imaginary part is matched with the remaining root closest to its
+/// conjugate, and the pair is replaced by their average, which removes the rounding errors that
+/// break the conjugate symmetry.
+///
+/// # Error
+///
+/// Returns an error if a complex root has no conjugate within `tol` relative to its magnitude
+/// (or absolute, if it is smaller than 1), e.g. if the polynomial has complex coefficients.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{Polynomial, Result, roots};
+/// # fn main() -> Result<()> {
+/// let poly = Polynomial::build(&[6.0, 1.0, 1.0, 1.0])?; // (x + 2)(x² − x + 3)
+/// let split = roots::pair_conjugates(&poly.solve_complex()?, 1e-12)?;
+///
+/// assert!((split.real[0] + 2.0).abs() < 1e-14);
+/// assert!((split.pairs[0].re - 0.5).abs() < 1e-14);
+/// assert!(split.pairs[0].im > 0.0);
+/// # Ok(())
+/// # }
+/// ```
+pub fn pair_conjugates(roots: &[Complex64], tol: f64) -> Result<ConjugatePairs> {
+    let mut real = filter_real(roots, tol);
+    real.sort_by(f64::total_cmp);
+
+    let mut lower: Vec<Complex64> = roots
+        .iter()
+        .filter(|z| !is_real(z, tol) && z.im < 0.0)
+        .copied()
+        .collect();
+    let mut pairs = Vec::new();
+    for z in roots.iter().filter(|z| !is_real(z, tol) && z.im > 0.0) {
+        let nearest = lower
+            .iter()
+            .enumerate()
+            .map(|(i, w)| (i, (z - w.conj()).norm()))
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        match nearest {
+            Some((i, dist)) if dist <= tol * z.norm().max(1.0) => {
+                pairs.push(0.5 * (z + lower.swap_remove(i).conj()));
+            }
+            _ => return Err(PolyError::UnpairedRoot(*z)),
+        }
+    }
+    if let Some(w) = lower.first() {
+        return Err(PolyError::UnpairedRoot(*w));
+    }
+    pairs.sort_by(cmp_re_im);
+
+    Ok(ConjugatePairs { real, pairs })
+}
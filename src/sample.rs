@@ -0,0 +1,106 @@
+//! Adaptive sampling of polynomials for plotting.
+
+use crate::{PolyError, Polynomial, Result};
+
+/// Options of [`Polynomial::sample`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SampleOptions {
+    /// The number of equally spaced points the interval starts with, at least 2.
+    pub initial: usize,
+    /// The largest deviation of the drawn line segments from the curve, relative to the range of
+    /// the initial samples.
+    pub tol: f64,
+    /// The maximum number of points, after which no segment is refined any further.
+    pub max_points: usize,
+}
+
+impl Default for SampleOptions {
+    /// 17 initial points, a tolerance of 1e-3 and up to 10000 points.
+    fn default() -> Self {
+        SampleOptions {
+            initial: 17,
+            tol: 1e-3,
+            max_points: 10_000,
+        }
+    }
+}
+
+impl Polynomial<f64> {
+    /// Samples the polynomial on `[a, b]` for plotting, returning the points `(x, P(x))` in
+    /// increasing order, with both ends included.
+    ///
+    /// Starting from equally spaced points, every segment is halved while the line between its
+    /// ends deviates from the curve by more than `tol` times the range of the initial samples.
+    /// The deviation is estimated with h²|P''|/8 at the midpoint, where h is the width of the
+    /// segment, and with the distance of the midpoint from the line, which also catches segments
+    /// around inflection points. The points thus concentrate where the curvature is high.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if `[a, b]` is not a valid finite interval.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result, SampleOptions};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0])?; // x⁸
+    /// let points = poly.sample(-1.0, 1.0, SampleOptions::default())?;
+    ///
+    /// // Denser near the steep ends than around the flat middle
+    /// let near = |c: f64| points.iter().filter(|(x, _)| (x - c).abs() < 0.2).count();
+    /// assert!(near(0.9) > 2 * near(0.0));
+    /// assert_eq!(points[0], (-1.0, 1.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sample(&self, a: f64, b: f64, opts: SampleOptions) -> Result<Vec<(f64, f64)>> {
+        if !(a.is_finite() && b.is_finite() && a < b) {
+            return Err(PolyError::InvalidInterval(a, b));
+        }
+
+        let n = opts.initial.max(2);
+        let initial: Vec<(f64, f64)> = (0..n)
+            .map(|i| match i == n - 1 {
+                true => b,
+                false => a + (b - a) * i as f64 / (n - 1) as f64,
+            })
+            .map(|x| (x, self.eval(x)))
+            .collect();
+
+        let (lo, hi) = initial
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), (_, y)| {
+                (lo.min(*y), hi.max(*y))
+            });
+        let scale = match hi - lo {
+            range if range > 0.0 => range,
+            _ => hi.abs().max(1.0),
+        };
+        let threshold = opts.tol * scale;
+
+        let mut points = vec![initial[0]];
+        let mut count = n;
+        let mut stack = Vec::new();
+        for segment in initial.windows(2).rev() {
+            stack.push((segment[0], segment[1]));
+        }
+        let mut derivs = [0.0; 3];
+        while let Some(((x0, y0), (x1, y1))) = stack.pop() {
+            let mid = 0.5 * (x0 + x1);
+            self.eval_derivs_into(mid, &mut derivs);
+            let h = x1 - x0;
+            let deviation =
+                (0.125 * h * h * derivs[2].abs()).max((derivs[0] - 0.5 * (y0 + y1)).abs());
+
+            if deviation > threshold && count < opts.max_points && x0 < mid && mid < x1 {
+                count += 1;
+                stack.push(((mid, derivs[0]), (x1, y1)));
+                stack.push(((x0, y0), (mid, derivs[0])));
+            } else {
+                points.push((x1, y1));
+            }
+        }
+        Ok(points)
+    }
+}
@@ -0,0 +1,349 @@
+//! Polynomials as truncated power series: multiplicative and compositional inverses, and the
+//! [`TruncatedSeries`] type.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::arith::forward_owned_binop;
+use crate::{PolyError, Polynomial, Result};
+
+/// Returns the first `n` coefficients of the product of the series `a` and `b`.
+fn mul_trunc<T: num::complex::ComplexFloat>(a: &[T], b: &[T], n: usize) -> Vec<T> {
+    let mut res = vec![T::zero(); n];
+    for (i, x) in a.iter().enumerate().take(n) {
+        for (j, y) in b.iter().enumerate().take(n - i) {
+            res[i + j] = res[i + j] + *x * *y;
+        }
+    }
+    res
+}
+
+impl<T> Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Returns the reciprocal 1/P of the polynomial as a power series truncated mod xⁿ, i.e. its
+    /// first `n` coefficients, with Newton's iteration G ← G(2 − PG), which doubles the number of
+    /// correct terms at every step.
+    ///
+    /// For n=0 the zero polynomial is returned.
+    ///
+    /// # Error
+    ///
+    /// Returns [`PolyError::NotInvertibleSeries`] if P(0)=0.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// // 1/(1−x−x²) generates the Fibonacci numbers
+    /// let poly = Polynomial::build(&[1.0, -1.0, -1.0])?;
+    ///
+    /// assert_eq!(poly.series_inverse(8)?.coef, [1.0, 1.0, 2.0, 3.0, 5.0, 8.0, 13.0, 21.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn series_inverse(&self, n: usize) -> Result<Polynomial<T>> {
+        let c0 = self.coef.first().copied().unwrap_or(T::zero());
+        if c0.is_zero() {
+            return Err(PolyError::NotInvertibleSeries);
+        }
+        if n == 0 {
+            return Ok(Polynomial::new());
+        }
+
+        let two = T::one() + T::one();
+        let mut inv = vec![T::one() / c0];
+        while inv.len() < n {
+            let len = (2 * inv.len()).min(n);
+            let mut residual = mul_trunc(&self.coef, &inv, len);
+            residual.iter_mut().for_each(|r| *r = -*r);
+            residual[0] = residual[0] + two;
+            inv = mul_trunc(&inv, &residual, len);
+        }
+        Ok(Polynomial { coef: inv })
+    }
+
+    /// Returns the compositional inverse Q of the polynomial as a power series truncated mod xⁿ,
+    /// i.e. the series with P(Q(x)) = Q(P(x)) = x, with Lagrange inversion:
+    /// [xᵏ]Q = [xᵏ⁻¹](x/P)ᵏ / k.
+    ///
+    /// For n=0 the zero polynomial is returned.
+    ///
+    /// # Error
+    ///
+    /// Returns [`PolyError::NotInvertibleSeries`] unless P(0)=0 and P'(0)≠0.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// // The inverse of x−x² generates the Catalan numbers
+    /// let poly = Polynomial::build(&[0.0, 1.0, -1.0])?;
+    ///
+    /// assert_eq!(poly.compositional_inverse(6)?.coef, [0.0, 1.0, 1.0, 2.0, 5.0, 14.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn compositional_inverse(&self, n: usize) -> Result<Polynomial<T>> {
+        let c0 = self.coef.first().copied().unwrap_or(T::zero());
+        let c1 = self.coef.get(1).copied().unwrap_or(T::zero());
+        if !c0.is_zero() || c1.is_zero() {
+            return Err(PolyError::NotInvertibleSeries);
+        }
+        if n == 0 {
+            return Ok(Polynomial::new());
+        }
+
+        // x/P, whose powers hold the coefficients of Q
+        let h = Polynomial {
+            coef: self.coef[1..].to_vec(),
+        }
+        .series_inverse(n)?;
+
+        let mut coef = vec![T::zero(); n];
+        let mut power = vec![T::one()];
+        for (k, c) in coef.iter_mut().enumerate().skip(1) {
+            power = mul_trunc(&power, &h.coef, n);
+            *c = power[k - 1] / T::from(k).unwrap();
+        }
+        Ok(Polynomial { coef })
+    }
+}
+
+/// A power series truncated to a fixed number of terms, i.e. known mod xⁿ.
+///
+/// The result of an operation between series of different lengths has the shorter length, since
+/// the higher terms of the other are unknown.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{Polynomial, Result, TruncatedSeries};
+/// # fn main() -> Result<()> {
+/// let x = TruncatedSeries::new(&Polynomial::build(&[0.0, 1.0])?, 5);
+/// let exp = x.exp();
+///
+/// assert_eq!(exp.coef(), [1.0, 1.0, 0.5, 1.0 / 6.0, 1.0 / 24.0]);
+/// assert_eq!(exp.log()?, x);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct TruncatedSeries<T> {
+    coef: Vec<T>,
+}
+
+impl<T> TruncatedSeries<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Creates the series of `poly` truncated to `n` terms, padding it with zeros if needed.
+    pub fn new(poly: &Polynomial<T>, n: usize) -> Self {
+        let mut coef: Vec<T> = poly.coef.iter().copied().take(n).collect();
+        coef.resize(n, T::zero());
+        TruncatedSeries { coef }
+    }
+
+    /// Returns the coefficients, from the constant term up to the term of xⁿ⁻¹.
+    pub fn coef(&self) -> &[T] {
+        &self.coef
+    }
+
+    /// Returns the number of terms n.
+    pub fn len(&self) -> usize {
+        self.coef.len()
+    }
+
+    /// Returns whether the series has no terms, i.e. is known mod x⁰ only.
+    pub fn is_empty(&self) -> bool {
+        self.coef.is_empty()
+    }
+
+    /// Returns the polynomial with the coefficients of the series.
+    pub fn to_polynomial(&self) -> Polynomial<T> {
+        Polynomial::from_raw_unchecked(self.coef.clone())
+    }
+
+    /// Returns the constant term, or 0 for a series with no terms.
+    fn constant(&self) -> T {
+        self.coef.first().copied().unwrap_or(T::zero())
+    }
+
+    /// Returns the reciprocal 1/f.
+    ///
+    /// # Error
+    ///
+    /// Returns [`PolyError::NotInvertibleSeries`] if the constant term is 0.
+    pub fn inverse(&self) -> Result<Self> {
+        let mut one = vec![T::zero(); self.len()];
+        if let Some(c) = one.first_mut() {
+            *c = T::one();
+        }
+        TruncatedSeries { coef: one }.divide(self)
+    }
+
+    /// Returns the quotient f/g.
+    ///
+    /// # Error
+    ///
+    /// Returns [`PolyError::NotInvertibleSeries`] if the constant term of `rhs` is 0.
+    pub fn divide(&self, rhs: &TruncatedSeries<T>) -> Result<Self> {
+        let g0 = rhs.constant();
+        if g0.is_zero() {
+            return Err(PolyError::NotInvertibleSeries);
+        }
+        let n = self.len().min(rhs.len());
+        let mut coef: Vec<T> = Vec::with_capacity(n);
+        for k in 0..n {
+            let sum = (1..=k).fold(T::zero(), |sum, j| sum + rhs.coef[j] * coef[k - j]);
+            coef.push((self.coef[k] - sum) / g0);
+        }
+        Ok(TruncatedSeries { coef })
+    }
+
+    /// Returns the exponential exp(f), from the recurrence of g′ = f′g.
+    pub fn exp(&self) -> Self {
+        let n = self.len();
+        let mut coef: Vec<T> = Vec::with_capacity(n);
+        if n > 0 {
+            coef.push(self.constant().exp());
+        }
+        for k in 1..n {
+            let sum = (1..=k).fold(T::zero(), |sum, j| {
+                sum + T::from(j).unwrap() * self.coef[j] * coef[k - j]
+            });
+            coef.push(sum / T::from(k).unwrap());
+        }
+        TruncatedSeries { coef }
+    }
+
+    /// Returns the natural logarithm log(f), from the recurrence of fg′ = f′.
+    ///
+    /// # Error
+    ///
+    /// Returns [`PolyError::InvalidConstantTerm`] if the logarithm of the constant term is not
+    /// finite, i.e. if it is 0 or, for real coefficients, negative.
+    pub fn log(&self) -> Result<Self> {
+        let n = self.len();
+        let f0 = self.constant();
+        let log0 = f0.ln();
+        if n > 0 && !log0.is_finite() {
+            return Err(PolyError::InvalidConstantTerm);
+        }
+        let mut coef: Vec<T> = Vec::with_capacity(n);
+        if n > 0 {
+            coef.push(log0);
+        }
+        for k in 1..n {
+            let sum = (1..k).fold(T::zero(), |sum, j| {
+                sum + T::from(j).unwrap() * coef[j] * self.coef[k - j]
+            });
+            let kf = T::from(k).unwrap();
+            coef.push((kf * self.coef[k] - sum) / (kf * f0));
+        }
+        Ok(TruncatedSeries { coef })
+    }
+
+    /// Returns the principal square root √f, from the recurrence of g² = f.
+    ///
+    /// # Error
+    ///
+    /// Returns [`PolyError::InvalidConstantTerm`] if the constant term is 0, where the square root
+    /// is not a power series in general, or if its square root is not finite, e.g. for a negative
+    /// real constant term.
+    pub fn sqrt(&self) -> Result<Self> {
+        let n = self.len();
+        let g0 = self.constant().sqrt();
+        if n > 0 && (g0.is_zero() || !g0.is_finite()) {
+            return Err(PolyError::InvalidConstantTerm);
+        }
+        let mut coef: Vec<T> = Vec::with_capacity(n);
+        if n > 0 {
+            coef.push(g0);
+        }
+        for k in 1..n {
+            let sum = (1..k).fold(T::zero(), |sum, j| sum + coef[j] * coef[k - j]);
+            coef.push((self.coef[k] - sum) / (g0 + g0));
+        }
+        Ok(TruncatedSeries { coef })
+    }
+}
+
+impl<T> Add for &TruncatedSeries<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = TruncatedSeries<T>;
+
+    fn add(self, rhs: Self) -> TruncatedSeries<T> {
+        TruncatedSeries {
+            coef: self
+                .coef
+                .iter()
+                .zip(&rhs.coef)
+                .map(|(a, b)| *a + *b)
+                .collect(),
+        }
+    }
+}
+
+impl<T> Sub for &TruncatedSeries<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = TruncatedSeries<T>;
+
+    fn sub(self, rhs: Self) -> TruncatedSeries<T> {
+        TruncatedSeries {
+            coef: self
+                .coef
+                .iter()
+                .zip(&rhs.coef)
+                .map(|(a, b)| *a - *b)
+                .collect(),
+        }
+    }
+}
+
+impl<T> Mul for &TruncatedSeries<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = TruncatedSeries<T>;
+
+    fn mul(self, rhs: Self) -> TruncatedSeries<T> {
+        TruncatedSeries {
+            coef: mul_trunc(&self.coef, &rhs.coef, self.len().min(rhs.len())),
+        }
+    }
+}
+
+impl<T> Neg for &TruncatedSeries<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = TruncatedSeries<T>;
+
+    fn neg(self) -> TruncatedSeries<T> {
+        TruncatedSeries {
+            coef: self.coef.iter().map(|c| -*c).collect(),
+        }
+    }
+}
+
+forward_owned_binop!(TruncatedSeries, Add, add);
+forward_owned_binop!(TruncatedSeries, Sub, sub);
+forward_owned_binop!(TruncatedSeries, Mul, mul);
+
+impl<T> Neg for TruncatedSeries<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = TruncatedSeries<T>;
+
+    fn neg(self) -> TruncatedSeries<T> {
+        -&self
+    }
+}
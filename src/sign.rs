@@ -0,0 +1,99 @@
+//! Sign analysis of polynomials over the real line.
+
+use crate::roots::{CLUSTER_TOL, cluster_roots, real_roots};
+use crate::{Polynomial, Result};
+
+/// The sign of a polynomial on an interval.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sign {
+    /// P(x) < 0.
+    Negative,
+    /// P(x) = 0, only for the zero polynomial.
+    Zero,
+    /// P(x) > 0.
+    Positive,
+}
+
+/// An open interval where a polynomial has a constant sign, created by
+/// [`Polynomial::sign_changes`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SignInterval {
+    /// The interval's start, a root of the polynomial or −∞.
+    pub start: f64,
+    /// The interval's end, a root of the polynomial or +∞.
+    pub end: f64,
+    /// The polynomial's sign inside the interval.
+    pub sign: Sign,
+}
+
+impl Polynomial<f64> {
+    /// Splits the real line at the distinct real roots of the polynomial, returning the open
+    /// intervals between them, in increasing order, along with the polynomial's sign in each one.
+    ///
+    /// The sign changes at roots of odd multiplicity, and is kept at roots of even multiplicity,
+    /// where the polynomial touches 0. The signs of the unbounded intervals are given by the
+    /// leading coefficient and the order, and the others are found by evaluating the polynomial
+    /// at the interval's midpoint. Nearly equal roots are merged (see
+    /// [`CLUSTER_TOL`](crate::CLUSTER_TOL)).
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the complex solver fails to converge.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result, Sign};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-1.0, 0.0, 1.0])?; // x²-1
+    /// let signs: Vec<Sign> = poly.sign_changes()?.iter().map(|i| i.sign).collect();
+    ///
+    /// assert_eq!(signs, [Sign::Positive, Sign::Negative, Sign::Positive]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sign_changes(&self) -> Result<Vec<SignInterval>> {
+        let order = match self.coef.iter().rposition(|c| *c != 0.0) {
+            Some(order) => order,
+            None => {
+                return Ok(vec![SignInterval {
+                    start: f64::NEG_INFINITY,
+                    end: f64::INFINITY,
+                    sign: Sign::Zero,
+                }]);
+            }
+        };
+        let sign_of = |value: f64| match value > 0.0 {
+            true => Sign::Positive,
+            false => Sign::Negative,
+        };
+
+        let mut bounds = vec![f64::NEG_INFINITY];
+        bounds.extend(
+            cluster_roots(&real_roots(self)?, CLUSTER_TOL)
+                .iter()
+                .map(|root| root.value),
+        );
+        bounds.push(f64::INFINITY);
+
+        let leading = self.coef[order];
+        let last = bounds.len() - 2;
+        Ok(bounds
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                let sign = match i {
+                    _ if i == last => sign_of(leading),
+                    0 if order % 2 == 1 => sign_of(-leading),
+                    0 => sign_of(leading),
+                    _ => sign_of(self.eval(0.5 * (pair[0] + pair[1]))),
+                };
+                SignInterval {
+                    start: pair[0],
+                    end: pair[1],
+                    sign,
+                }
+            })
+            .collect())
+    }
+}
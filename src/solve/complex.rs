@@ -0,0 +1,130 @@
+use num::Zero;
+use num::complex::Complex64;
+
+use crate::utils::order;
+use crate::{PolyError, Result};
+
+/// Newton steps used to polish the roots of the cubic against the original equation.
+const POLISH_STEPS: usize = 2;
+
+/// Solves a quadratic equation az²+bz+c = 0 with complex coefficients, returning its 2 complex
+/// roots, sorted by their real and then imaginary part.
+///
+/// The coefficients are scaled by a power of 2 close to the largest one, as in the real solver.
+/// The roots are calculated as q/a and c/q, where q = −(b + s√Δ)/2 and the sign s is chosen so
+/// that b and s√Δ do not cancel each other out.
+pub(crate) fn solve_complex_quadratic(
+    a: Complex64,
+    b: Complex64,
+    c: Complex64,
+) -> Result<[Complex64; 2]> {
+    if a.is_zero() {
+        return Err(PolyError::IncorrectOrder {
+            expected: 2,
+            got: order(&[c, b]),
+        });
+    }
+
+    let scale = a.norm().max(b.norm()).max(c.norm());
+    let exponent = scale.log2().round() as i32;
+    let [f1, f2] = [-exponent / 2, -exponent - (-exponent / 2)].map(|e| 2f64.powi(e));
+    let (a, b, c) = (a * f1 * f2, b * f1 * f2, c * f1 * f2);
+
+    let sqrt_det = (b * b - 4.0 * a * c).sqrt();
+    let sqrt_det = match (b.conj() * sqrt_det).re < 0.0 {
+        true => -sqrt_det,
+        false => sqrt_det,
+    };
+    let q = -0.5 * (b + sqrt_det);
+
+    let mut roots = match q.is_zero() {
+        // b = 0 and Δ = 0, i.e. b = c = 0
+        true => [Complex64::zero(); 2],
+        false => [q / a, c / q],
+    };
+    sort(&mut roots);
+    Ok(roots)
+}
+
+/// Solves a cubic equation az³+bz²+cz+d = 0 with complex coefficients with Cardano's method,
+/// returning its 3 complex roots, sorted by their real and then imaginary part.
+///
+/// The monic cubic is depressed to t³+pt+q = 0 (z = t−b/3a), whose roots are u·ωᵏ − p/(3u·ωᵏ),
+/// where u³ = −q/2 ± √Δ takes the sign of larger magnitude and ω is a cube root of unity. The
+/// roots are then polished with a few Newton steps on the original equation.
+pub(crate) fn solve_complex_cubic(
+    a: Complex64,
+    b: Complex64,
+    c: Complex64,
+    d: Complex64,
+) -> Result<[Complex64; 3]> {
+    if a.is_zero() {
+        return Err(PolyError::IncorrectOrder {
+            expected: 3,
+            got: order(&[d, c, b]),
+        });
+    }
+    let (b, c, d) = (b / a, c / a, d / a);
+
+    let shift = b / 3.0;
+    let p = c - b * b / 3.0;
+    let q = 2.0 * b * b * b / 27.0 - b * c / 3.0 + d;
+
+    let sqrt_det = (q * q / 4.0 + p * p * p / 27.0).sqrt();
+    let (u1, u2) = (-0.5 * q + sqrt_det, -0.5 * q - sqrt_det);
+    let u = match u1.norm() >= u2.norm() {
+        true => u1,
+        false => u2,
+    }
+    .cbrt();
+
+    let omega = Complex64::from_polar(1.0, 2.0 * std::f64::consts::FRAC_PI_3);
+    let mut roots = [Complex64::zero(); 3];
+    let mut uk = u;
+    for root in roots.iter_mut() {
+        let t = match uk.is_zero() {
+            // p = q = 0: triple root
+            true => Complex64::zero(),
+            false => uk - p / (3.0 * uk),
+        };
+        *root = polish(t - shift, [d, c, b, Complex64::new(1.0, 0.0)]);
+        uk *= omega;
+    }
+    sort(&mut roots);
+    Ok(roots)
+}
+
+/// Applies a few Newton steps to a root of the polynomial with coefficients `coef` (constant term
+/// first), keeping only the steps that decrease the residual.
+fn polish<const N: usize>(z: Complex64, coef: [Complex64; N]) -> Complex64 {
+    let eval = |z: Complex64| {
+        coef.iter()
+            .rev()
+            .fold((Complex64::zero(), Complex64::zero()), |(p, dp), c| {
+                (p * z + c, dp * z + p)
+            })
+    };
+
+    let mut z = z;
+    for _ in 0..POLISH_STEPS {
+        let (p, dp) = eval(z);
+        if dp.is_zero() {
+            break;
+        }
+        let next = z - p / dp;
+        match next.is_finite() && eval(next).0.norm() < p.norm() {
+            true => z = next,
+            false => break,
+        }
+    }
+    z
+}
+
+/// Sorts complex roots by their real and then imaginary part.
+fn sort(roots: &mut [Complex64]) {
+    roots.sort_by(|x, y| {
+        (x.re, x.im)
+            .partial_cmp(&(y.re, y.im))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+}
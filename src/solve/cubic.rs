@@ -1,61 +1,77 @@
-use std::f64::consts::PI;
+use num::Float;
+use num::traits::FloatConst;
 
-use crate::Result;
+use crate::utils::cast;
+use crate::{CubicRoots, Result};
 
 /// Solves a **depressed** cubic equation  t³+pt+q=0,  where t=x−b/3, awith real coefficients,
 /// returning a Vec with the found 0-3 real roots.
 ///
 /// a, b, c correspond to a polynomial x³ + ax² + bx + c.
-pub(crate) fn solve_real_cubic(a: f64, b: f64, c: f64) -> Result<Vec<f64>> {
-    let q = a.powi(2) - 3.0 * b;
-    let r = 2.0 * a.powi(3) - 9.0 * a * b + 27.0 * c;
+pub(crate) fn solve_real_cubic<F: Float + FloatConst>(a: F, b: F, c: F) -> Result<Vec<F>> {
+    // Keep padding the single real root, as callers expect exactly 3 values.
+    match solve_real_cubic_structured(a, b, c) {
+        CubicRoots::One(x) | CubicRoots::TripleRepeated(x) => Ok(vec![x; 3]),
+        CubicRoots::Three(roots) => Ok(roots.to_vec()),
+    }
+}
+
+/// Same as [`solve_real_cubic`], but returns the structure of the real roots.
+pub(crate) fn solve_real_cubic_structured<F: Float + FloatConst>(
+    a: F,
+    b: F,
+    c: F,
+) -> CubicRoots<F> {
+    let [two, three, nine] = [2.0, 3.0, 9.0].map(cast::<F>);
+    let zero = F::zero();
+    let pi = F::PI();
+
+    let q = a.powi(2) - three * b;
+    let r = two * a.powi(3) - nine * a * b + cast::<F>(27.0) * c;
 
-    let q_cap = q / 9.0;
-    let r_cap = r / 54.0;
+    let q_cap = q / nine;
+    let r_cap = r / cast(54.0);
 
     let q_cap3 = q_cap.powi(3);
     let r_cap2 = r_cap.powi(2);
 
-    let cq_cap3 = 2916.0 * q.powi(3);
-    let cr_cap2 = 729.0 * r.powi(2);
+    let cq_cap3 = cast::<F>(2916.0) * q.powi(3);
+    let cr_cap2 = cast::<F>(729.0) * r.powi(2);
 
-    let mut ans: Vec<f64> = vec![f64::NAN; 3];
+    let mut ans = [zero; 3];
 
     // NOTE: This test is actually `r_cap2==q_cap3`, written in a form suitable for exact
     // computation with integers
-    if (r_cap == 0.0) & (q_cap == 0.0) {
-        let x = -a / 3.0;
-        ans.fill(x);
-        return Ok(vec![x, x, x]);
+    if (r_cap == zero) & (q_cap == zero) {
+        return CubicRoots::TripleRepeated(-a / three);
     } else if cr_cap2 == cq_cap3 {
         let sqrtq = q_cap.sqrt();
 
-        if r > 0.0 {
-            ans[0] = -2.0 * sqrtq - a / 3.0;
-            ans[1] = sqrtq - a / 3.0;
-            ans[2] = sqrtq - a / 3.0;
+        if r > zero {
+            ans[0] = -two * sqrtq - a / three;
+            ans[1] = sqrtq - a / three;
+            ans[2] = sqrtq - a / three;
         } else {
-            ans[0] = -sqrtq - a / 3.0;
-            ans[1] = -sqrtq - a / 3.0;
-            ans[2] = 2.0 * sqrtq - a / 3.0;
+            ans[0] = -sqrtq - a / three;
+            ans[1] = -sqrtq - a / three;
+            ans[2] = two * sqrtq - a / three;
         }
     } else if r_cap2 < q_cap3 {
         let sgnr = r.signum();
         let ratio = sgnr * (r_cap2 / q_cap3).sqrt();
         let theta = ratio.acos();
-        let norm = -2.0 * q_cap.sqrt();
+        let norm = -two * q_cap.sqrt();
 
-        ans[0] = norm * (theta / 3.0).cos() - a / 3.0;
-        ans[1] = norm * ((theta + 2.0 * PI) / 3.0).cos() - a / 3.0;
-        ans[2] = norm * ((theta - 2.0 * PI) / 3.0).cos() - a / 3.0;
+        ans[0] = norm * (theta / three).cos() - a / three;
+        ans[1] = norm * ((theta + two * pi) / three).cos() - a / three;
+        ans[2] = norm * ((theta - two * pi) / three).cos() - a / three;
     } else {
         let sgnr = r.signum();
-        let a_cap = -sgnr * (r_cap.abs() + (r_cap2 - q_cap3).sqrt()).powf(1.0 / 3.0);
-        let b_cap = q / a_cap;
-        let x = a_cap + b_cap - a / 3.0;
-        ans.fill(x);
+        let a_cap = -sgnr * (r_cap.abs() + (r_cap2 - q_cap3).sqrt()).powf(F::one() / three);
+        let b_cap = q_cap / a_cap;
+        return CubicRoots::One(a_cap + b_cap - a / three);
     }
 
-    ans.sort_by(|a, b| a.partial_cmp(b).unwrap());
-    Ok(ans)
+    ans.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    CubicRoots::Three(ans)
 }
@@ -0,0 +1,73 @@
+//! Eigenvalue solvers for the companion matrices of the general polynomial solver.
+
+use num::complex::Complex64;
+
+#[cfg(feature = "nalgebra")]
+use crate::PolyError;
+use crate::Result;
+
+/// Maximum number of QR iterations per eigenvalue of the `nalgebra` backend, as in GSL.
+#[cfg(feature = "nalgebra")]
+const MAX_ITER_PER_EIGENVALUE: usize = 60;
+
+/// An eigenvalue solver, used by
+/// [`Polynomial::solve_complex_with`](crate::Polynomial::solve_complex_with) to calculate the
+/// roots of a polynomial with real coefficients as the eigenvalues of its companion matrix.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{Polynomial, Result};
+/// # use rsl_polynomials::solve::{BuiltinQr, EigenBackend};
+/// # use num::complex::Complex64;
+/// # use std::cell::Cell;
+/// /// Counts the matrices solved by the built-in solver.
+/// struct Counting(Cell<usize>);
+///
+/// impl EigenBackend for Counting {
+///     fn hessenberg_eigenvalues(&self, h: &mut [f64], n: usize) -> Result<Vec<Complex64>> {
+///         self.0.set(self.0.get() + 1);
+///         BuiltinQr.hessenberg_eigenvalues(h, n)
+///     }
+/// }
+///
+/// # fn main() -> Result<()> {
+/// let backend = Counting(Cell::new(0));
+/// let roots = Polynomial::build(&[-1.0, 0.0, 0.0, 1.0])?.solve_complex_with(&backend)?;
+///
+/// assert_eq!(roots.len(), 3);
+/// assert_eq!(backend.0.get(), 1);
+/// # Ok(())
+/// # }
+/// ```
+pub trait EigenBackend {
+    /// Calculates all the eigenvalues of the n×n upper Hessenberg matrix `h`, stored in
+    /// row-major order. The matrix can be overwritten.
+    fn hessenberg_eigenvalues(&self, h: &mut [f64], n: usize) -> Result<Vec<Complex64>>;
+}
+
+/// The built-in shifted double-step QR algorithm, as in GSL. This is the backend used by
+/// [`Polynomial::solve_complex`](crate::Polynomial::solve_complex).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BuiltinQr;
+
+impl EigenBackend for BuiltinQr {
+    fn hessenberg_eigenvalues(&self, h: &mut [f64], n: usize) -> Result<Vec<Complex64>> {
+        super::general::qr_companion(h, n)
+    }
+}
+
+/// The real Schur decomposition of `nalgebra`.
+#[cfg(feature = "nalgebra")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NalgebraEigen;
+
+#[cfg(feature = "nalgebra")]
+impl EigenBackend for NalgebraEigen {
+    fn hessenberg_eigenvalues(&self, h: &mut [f64], n: usize) -> Result<Vec<Complex64>> {
+        let m = nalgebra::DMatrix::from_row_slice(n, n, h);
+        let schur = nalgebra::Schur::try_new(m, f64::EPSILON, MAX_ITER_PER_EIGENVALUE * n)
+            .ok_or(PolyError::NoConvergence)?;
+        Ok(schur.complex_eigenvalues().iter().copied().collect())
+    }
+}
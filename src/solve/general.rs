@@ -0,0 +1,443 @@
+use num::complex::Complex64;
+use num::{One, Zero};
+
+use crate::{PolyError, Result, solve::EigenBackend};
+
+/// Maximum number of iterations of the Aberth-Ehrlich method.
+pub(crate) const ABERTH_MAX_ITER: usize = 500;
+
+/// Finds all the roots of a polynomial with real coefficients (from constant to leading term), by
+/// calculating the eigenvalues of its balanced companion matrix with `backend`.
+///
+/// The leading coefficient must be non-zero.
+pub(crate) fn solve_complex_companion<B>(coef: &[f64], backend: &B) -> Result<Vec<Complex64>>
+where
+    B: EigenBackend + ?Sized,
+{
+    let nc = coef.len() - 1;
+    let mut m = companion_matrix(coef);
+    balance_companion_matrix(&mut m, nc);
+    backend.hessenberg_eigenvalues(&mut m, nc)
+}
+
+/// Builds the nc×nc companion matrix (row-major) of a polynomial of order nc.
+fn companion_matrix(a: &[f64]) -> Vec<f64> {
+    let nc = a.len() - 1;
+    let mut m = vec![0.0; nc * nc];
+
+    for i in 1..nc {
+        m[i * nc + i - 1] = 1.0;
+    }
+    for i in 0..nc {
+        m[i * nc + nc - 1] = -a[i] / a[nc];
+    }
+    m
+}
+
+/// Balances the companion matrix by similarity transformations with powers of 2, so that its
+/// rows and columns have comparable norms.
+fn balance_companion_matrix(m: &mut [f64], nc: usize) {
+    const RADIX: f64 = 2.0;
+    const RADIX2: f64 = RADIX * RADIX;
+
+    let idx = |i: usize, j: usize| i * nc + j;
+    let mut not_converged = true;
+
+    while not_converged {
+        not_converged = false;
+
+        for i in 0..nc {
+            // Column norm, excluding the diagonal
+            let mut col_norm = match i != nc - 1 {
+                true => m[idx(i + 1, i)].abs(),
+                false => (0..nc - 1).map(|j| m[idx(j, nc - 1)].abs()).sum(),
+            };
+
+            // Row norm, excluding the diagonal
+            let row_norm = if i == 0 {
+                m[idx(0, nc - 1)].abs()
+            } else if i == nc - 1 {
+                m[idx(i, i - 1)].abs()
+            } else {
+                m[idx(i, i - 1)].abs() + m[idx(i, nc - 1)].abs()
+            };
+
+            if (col_norm == 0.0) | (row_norm == 0.0) {
+                continue;
+            }
+
+            let mut g = row_norm / RADIX;
+            let mut f = 1.0;
+            let s = col_norm + row_norm;
+
+            while col_norm < g {
+                f *= RADIX;
+                col_norm *= RADIX2;
+            }
+
+            g = row_norm * RADIX;
+
+            while col_norm > g {
+                f /= RADIX;
+                col_norm /= RADIX2;
+            }
+
+            if (row_norm + col_norm) < 0.95 * s * f {
+                not_converged = true;
+                g = 1.0 / f;
+
+                if i == 0 {
+                    m[idx(0, nc - 1)] *= g;
+                } else {
+                    m[idx(i, i - 1)] *= g;
+                    m[idx(i, nc - 1)] *= g;
+                }
+
+                if i == nc - 1 {
+                    (0..nc).for_each(|j| m[idx(j, i)] *= f);
+                } else {
+                    m[idx(i + 1, i)] *= f;
+                }
+            }
+        }
+    }
+}
+
+/// Finds the eigenvalues of an upper Hessenberg matrix (row-major, nc×nc) with the shifted
+/// double-step QR algorithm.
+pub(crate) fn qr_companion(h: &mut [f64], nc: usize) -> Result<Vec<Complex64>> {
+    // NOTE: GSL (and EISPACK's hqr which it is based on) uses 1-based indexing, which is kept here
+    // to make the algorithm easier to compare against the original.
+    let f = |i: usize, j: usize| (i - 1) * nc + (j - 1);
+
+    let mut zroot = vec![Complex64::zero(); nc];
+    let mut t = 0.0;
+    let mut n = nc;
+
+    'next_root: while n > 0 {
+        let mut iterations = 0;
+
+        loop {
+            // Look for a single small sub-diagonal element
+            let mut e = n;
+            while e >= 2 {
+                let a1 = h[f(e, e - 1)].abs();
+                let a2 = h[f(e - 1, e - 1)].abs();
+                let a3 = h[f(e, e)].abs();
+                if a1 <= f64::EPSILON * (a2 + a3) {
+                    break;
+                }
+                e -= 1;
+            }
+
+            let mut x = h[f(n, n)];
+
+            if e == n {
+                // One real root
+                zroot[n - 1] = Complex64::new(x + t, 0.0);
+                #[cfg(feature = "tracing")]
+                tracing::trace!(iterations, remaining = n - 1, "found a real root");
+                n -= 1;
+                continue 'next_root;
+            }
+
+            let mut y = h[f(n - 1, n - 1)];
+            let mut w = h[f(n - 1, n)] * h[f(n, n - 1)];
+
+            if e == n - 1 {
+                let p = (y - x) / 2.0;
+                let q = p * p + w;
+                y = q.abs().sqrt();
+                x += t;
+
+                if q > 0.0 {
+                    // Two real roots
+                    if p < 0.0 {
+                        y = -y;
+                    }
+                    y += p;
+                    zroot[n - 1] = Complex64::new(x - w / y, 0.0);
+                    zroot[n - 2] = Complex64::new(x + y, 0.0);
+                } else {
+                    // Complex conjugate pair
+                    zroot[n - 1] = Complex64::new(x + p, -y);
+                    zroot[n - 2] = Complex64::new(x + p, y);
+                }
+                #[cfg(feature = "tracing")]
+                tracing::trace!(iterations, remaining = n - 2, "found a pair of roots");
+                n -= 2;
+                continue 'next_root;
+            }
+
+            // No more roots found yet, do another iteration
+            if iterations == 120 {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(iterations, remaining = n, "QR iterations did not converge");
+                return Err(PolyError::NoConvergence);
+            }
+
+            if (iterations % 10 == 0) & (iterations > 0) {
+                // Use an exceptional shift
+                t += x;
+                (1..=n).for_each(|i| h[f(i, i)] -= x);
+                let s = h[f(n, n - 1)].abs() + h[f(n - 1, n - 2)].abs();
+                y = 0.75 * s;
+                x = y;
+                w = -0.4375 * s * s;
+            }
+
+            iterations += 1;
+
+            // Look for two consecutive small sub-diagonal elements
+            let (mut p, mut q, mut r);
+            let mut m = n - 2;
+            loop {
+                let z = h[f(m, m)];
+                r = x - z;
+                let s = y - z;
+                p = h[f(m, m + 1)] + (r * s - w) / h[f(m + 1, m)];
+                q = h[f(m + 1, m + 1)] - z - r - s;
+                r = h[f(m + 2, m + 1)];
+                let s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+
+                if m == e {
+                    break;
+                }
+
+                let a1 = h[f(m, m - 1)].abs();
+                let a2 = h[f(m - 1, m - 1)].abs();
+                let a3 = h[f(m + 1, m + 1)].abs();
+                if a1 * (q.abs() + r.abs()) <= f64::EPSILON * p.abs() * (a2 + a3) {
+                    break;
+                }
+                m -= 1;
+            }
+
+            ((m + 2)..=n).for_each(|i| h[f(i, i - 2)] = 0.0);
+            ((m + 3)..=n).for_each(|i| h[f(i, i - 3)] = 0.0);
+
+            // Double QR step
+            for k in m..n {
+                let notlast = k != n - 1;
+
+                if k != m {
+                    p = h[f(k, k - 1)];
+                    q = h[f(k + 1, k - 1)];
+                    r = if notlast { h[f(k + 2, k - 1)] } else { 0.0 };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x == 0.0 {
+                        continue;
+                    }
+                    p /= x;
+                    q /= x;
+                    r /= x;
+                }
+
+                let mut s = (p * p + q * q + r * r).sqrt();
+                if p < 0.0 {
+                    s = -s;
+                }
+
+                if k != m {
+                    h[f(k, k - 1)] = -s * x;
+                } else if e != m {
+                    h[f(k, k - 1)] *= -1.0;
+                }
+
+                p += s;
+                x = p / s;
+                y = q / s;
+                let z = r / s;
+                q /= p;
+                r /= p;
+
+                // Row modifications
+                for j in k..=n {
+                    let mut pp = h[f(k, j)] + q * h[f(k + 1, j)];
+                    if notlast {
+                        pp += r * h[f(k + 2, j)];
+                        h[f(k + 2, j)] -= pp * z;
+                    }
+                    h[f(k + 1, j)] -= pp * y;
+                    h[f(k, j)] -= pp * x;
+                }
+
+                // Column modifications
+                let j = if k + 3 < n { k + 3 } else { n };
+                for i in e..=j {
+                    let mut pp = x * h[f(i, k)] + y * h[f(i, k + 1)];
+                    if notlast {
+                        pp += z * h[f(i, k + 2)];
+                        h[f(i, k + 2)] -= pp * r;
+                    }
+                    h[f(i, k + 1)] -= pp * q;
+                    h[f(i, k)] -= pp;
+                }
+            }
+        }
+    }
+
+    Ok(zroot)
+}
+
+/// Finds all the roots of a polynomial with complex coefficients (from constant to leading term)
+/// with the Aberth-Ehrlich method.
+///
+/// The leading coefficient must be non-zero.
+pub(crate) fn solve_complex_aberth(coef: &[Complex64]) -> Result<Vec<Complex64>> {
+    solve_complex_aberth_from(coef, &[])
+}
+
+/// Like [`solve_complex_aberth`], but starts the iterations from the approximate roots `guess`,
+/// e.g. the roots of a nearby polynomial. The roots are returned in the order of their initial
+/// guesses.
+///
+/// The guesses are ignored if they are not as many as the roots (excluding the ones at 0), or if
+/// they are not finite and distinct. If the iterations do not converge from them, the solver
+/// starts again from its default initial values.
+pub(crate) fn solve_complex_aberth_from(
+    coef: &[Complex64],
+    guess: &[Complex64],
+) -> Result<Vec<Complex64>> {
+    // Deflate the roots at 0 first, since they cannot be detected by a relative criterion
+    let zeros = coef.iter().take_while(|c| c.is_zero()).count();
+    let coef = &coef[zeros..];
+    let n = coef.len() - 1;
+
+    let mut roots = vec![Complex64::zero(); zeros];
+    if n == 0 {
+        return Ok(roots);
+    }
+
+    match refine_aberth(coef, guess) {
+        Ok(z) => {
+            roots.extend(z);
+            return Ok(roots);
+        }
+        #[cfg(feature = "tracing")]
+        Err(_) if !guess.is_empty() => tracing::debug!(
+            guesses = guess.len(),
+            "refining the guesses failed, falling back to the default initial values"
+        ),
+        Err(_) => {}
+    }
+
+    roots.extend(aberth_iterations(coef, aberth_initial(coef))?);
+    Ok(roots)
+}
+
+/// Refines the approximate roots `guess` of a polynomial with complex coefficients (from constant
+/// to leading term) with the Aberth-Ehrlich method, returning them in the same order.
+///
+/// # Error
+///
+/// Returns [`PolyError::NoConvergence`] if the guesses are not as many as the roots, if they are
+/// not finite and distinct, or if the iterations do not converge.
+pub(crate) fn refine_aberth(coef: &[Complex64], guess: &[Complex64]) -> Result<Vec<Complex64>> {
+    let distinct = guess
+        .iter()
+        .enumerate()
+        .all(|(k, z)| z.is_finite() && guess[..k].iter().all(|w| w != z));
+    match guess.len() + 1 == coef.len() && distinct {
+        true => aberth_iterations(coef, guess.to_vec()),
+        false => Err(PolyError::NoConvergence),
+    }
+}
+
+/// Runs the Aberth-Ehrlich iterations on the approximate roots `z` of a polynomial without roots
+/// at 0, until they all converge.
+fn aberth_iterations(coef: &[Complex64], mut z: Vec<Complex64>) -> Result<Vec<Complex64>> {
+    let mut converged = vec![false; z.len()];
+    for _iteration in 0..ABERTH_MAX_ITER {
+        if aberth_sweep(coef, &mut z, &mut converged) {
+            #[cfg(feature = "tracing")]
+            tracing::trace!(iterations = _iteration + 1, "Aberth iterations converged");
+            return Ok(z);
+        }
+    }
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        iterations = ABERTH_MAX_ITER,
+        unconverged = converged.iter().filter(|c| !**c).count(),
+        "Aberth iterations did not converge"
+    );
+    Err(PolyError::NoConvergence)
+}
+
+/// Returns the largest relative backward error |P(z)|/P̃(|z|) of the `roots` of a polynomial with
+/// complex coefficients (from constant to leading term), where P̃(x) = Σ|cᵢ|xⁱ.
+#[cfg(feature = "tracing")]
+pub(crate) fn max_backward_error(coef: &[Complex64], roots: &[Complex64]) -> f64 {
+    roots
+        .iter()
+        .map(|z| {
+            let (p, abs_p) = coef
+                .iter()
+                .rev()
+                .fold((Complex64::zero(), 0.0), |(p, a), c| {
+                    (p * z + c, a * z.norm() + c.norm())
+                });
+            if abs_p == 0.0 { 0.0 } else { p.norm() / abs_p }
+        })
+        .fold(0.0, f64::max)
+}
+
+/// Returns the default initial values of the Aberth-Ehrlich iterations for a polynomial without
+/// roots at 0.
+pub(crate) fn aberth_initial(coef: &[Complex64]) -> Vec<Complex64> {
+    // Start on a circle with radius equal to the geometric mean of the roots' moduli, slightly
+    // rotated to avoid symmetric configurations.
+    let n = coef.len() - 1;
+    let radius = (coef[0].norm() / coef[n].norm()).powf(1.0 / n as f64);
+    (0..n)
+        .map(|k| {
+            let angle = 2.0 * std::f64::consts::PI * k as f64 / n as f64 + 0.4;
+            Complex64::from_polar(radius, angle)
+        })
+        .collect()
+}
+
+/// Runs a single Aberth-Ehrlich iteration on the approximate roots `z` that have not
+/// `converged` yet, returning whether they all have converged.
+pub(crate) fn aberth_sweep(
+    coef: &[Complex64],
+    z: &mut [Complex64],
+    converged: &mut [bool],
+) -> bool {
+    let n = z.len();
+    for k in 0..n {
+        if converged[k] {
+            continue;
+        }
+
+        // Horner for the value and the first derivative, along with the polynomial with
+        // absolute coefficients for the backward error estimate.
+        let (mut p, mut dp, mut abs_p) = (coef[n], Complex64::zero(), coef[n].norm());
+        for c in coef[..n].iter().rev() {
+            dp = dp * z[k] + p;
+            p = p * z[k] + c;
+            abs_p = abs_p * z[k].norm() + c.norm();
+        }
+
+        if p.norm() <= 4.0 * n as f64 * f64::EPSILON * abs_p {
+            converged[k] = true;
+            continue;
+        }
+
+        let ratio = p / dp;
+        let sum: Complex64 = (0..n)
+            .filter(|j| *j != k)
+            .map(|j| (z[k] - z[j]).inv())
+            .sum();
+        let step = ratio / (Complex64::one() - ratio * sum);
+        z[k] -= step;
+
+        if step.norm() <= f64::EPSILON * z[k].norm() {
+            converged[k] = true;
+        }
+    }
+    converged.iter().all(|c| *c)
+}
@@ -0,0 +1,100 @@
+use crate::solve::{cubic, linear::solve_real_linear, quadratic};
+use crate::utils::check_finite_coef;
+use crate::{CubicRoots, PolyError, QuadraticRoots, Result};
+
+/// Maximum value of the polynomial on a critical point, relative to the polynomial with absolute
+/// coefficients, for the critical point to be considered a grazing (double root) contact.
+const GRAZING_TOL: f64 = 16.0 * f64::EPSILON;
+
+/// Returns the earliest time `t` in (0, t_max] at which at³+bt²+ct+d = 0, or `None` if there is
+/// no such time.
+///
+/// This is the typical time-of-impact query of a physics engine, where the cubic is e.g. the
+/// signed distance (or the triple product of the relative positions) of two moving objects:
+///
+/// - The sign of the coefficients does not matter, i.e. a negative (or zero) leading coefficient
+///   does not change the result.
+/// - If a=0 (or a=b=0), the lower order equation is solved instead. A constant polynomial has no
+///   isolated roots, so `None` is returned even if it is identically zero.
+/// - Grazing contacts, where the curve touches zero without crossing it, are double roots which
+///   rounding errors may turn into a pair of complex roots. They are detected separately, by
+///   checking whether the polynomial vanishes (up to rounding) on its critical points.
+/// - A root at exactly t=0 (objects already in contact) is not reported, so that a contact that
+///   was just resolved is not detected again.
+///
+/// No allocation takes place.
+///
+/// # Error
+///
+/// Returns an error if any of the coefficients is NaN or Infinity, or if `t_max` is not a
+/// positive finite number.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{Result, solve};
+/// # fn main() -> Result<()> {
+/// // (t-1)(t-2)(t+1)
+/// assert_eq!(solve::earliest_impact(1.0, -2.0, -1.0, 2.0, 5.0)?, Some(1.0));
+/// assert_eq!(solve::earliest_impact(1.0, -2.0, -1.0, 2.0, 0.5)?, None);
+///
+/// // Grazing contact at t=1: (t-1)²
+/// assert_eq!(solve::earliest_impact(0.0, 1.0, -2.0, 1.0, 5.0)?, Some(1.0));
+/// # Ok(())
+/// # }
+/// ```
+pub fn earliest_impact(a: f64, b: f64, c: f64, d: f64, t_max: f64) -> Result<Option<f64>> {
+    check_finite_coef([a, b, c, d])?;
+    if !(t_max.is_finite() && t_max > 0.0) {
+        return Err(PolyError::InvalidInterval(0.0, t_max));
+    }
+
+    let in_range = |t: f64| (t > 0.0) & (t <= t_max);
+    let mut earliest: Option<f64> = None;
+    let mut consider = |t: f64| {
+        if in_range(t) && earliest.is_none_or(|e| t < e) {
+            earliest = Some(t);
+        }
+    };
+
+    // Crossing roots
+    if a != 0.0 {
+        match cubic(a, b, c, d)? {
+            CubicRoots::One(t) | CubicRoots::TripleRepeated(t) => consider(t),
+            CubicRoots::Three(roots) => roots.into_iter().for_each(&mut consider),
+        }
+    } else if b != 0.0 {
+        match quadratic(b, c, d)? {
+            QuadraticRoots::None => (),
+            QuadraticRoots::Linear(t) | QuadraticRoots::Double(t) => consider(t),
+            QuadraticRoots::Two(t1, t2) => {
+                consider(t1);
+                consider(t2);
+            }
+        }
+    } else if c != 0.0 {
+        consider(solve_real_linear(c, d)?);
+    } else {
+        return Ok(None);
+    }
+
+    // Grazing contacts, on the critical points 3at²+2bt+c = 0
+    let mut graze = |t: f64| {
+        let value = ((a * t + b) * t + c) * t + d;
+        let scale = ((a.abs() * t.abs() + b.abs()) * t.abs() + c.abs()) * t.abs() + d.abs();
+        if value.abs() <= GRAZING_TOL * scale {
+            consider(t);
+        }
+    };
+    match quadratic(3.0 * a, 2.0 * b, c) {
+        Ok(QuadraticRoots::Linear(t) | QuadraticRoots::Double(t)) => graze(t),
+        Ok(QuadraticRoots::Two(t1, t2)) => {
+            graze(t1);
+            graze(t2);
+        }
+        // No critical points
+        Ok(QuadraticRoots::None) | Err(_) => (),
+    }
+
+    Ok(earliest)
+}
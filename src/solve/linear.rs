@@ -1,10 +1,18 @@
-use crate::{PolyError, Result};
+use num::Float;
+
+use crate::{PolyError, Result, Tolerance};
 
 /// Solves a linear equation ax+b = 0, with real coefficients, returning a Vec with the
 /// found 0-2 real roots.
-pub(crate) fn solve_real_linear(a: f64, b: f64) -> Result<f64> {
-    match a {
-        0.0 => Err(PolyError::ConstantPoly),
-        _ => Ok(-b / a),
+pub(crate) fn solve_real_linear<F: Float>(a: F, b: F) -> Result<F> {
+    solve_real_linear_tol(a, b, Tolerance::EXACT)
+}
+
+/// Same as [`solve_real_linear`], but treats a negligible `a` (relative to the coefficients'
+/// magnitude) as 0.
+pub(crate) fn solve_real_linear_tol<F: Float>(a: F, b: F, tol: Tolerance) -> Result<F> {
+    match tol.is_negligible(a, a.abs().max(b.abs())) {
+        true => Err(PolyError::ConstantPoly),
+        false => Ok(-b / a),
     }
 }
@@ -1,6 +1,194 @@
+//! Solvers that operate directly on plain coefficients, without constructing a [`Polynomial`].
+//!
+//! The linear, quadratic, cubic and quartic solvers are generic over the float type, so that
+//! [`f32`] coefficients can be solved natively, without converting them to [`f64`].
+//!
+//! [`Polynomial`]: crate::Polynomial
+
+pub(crate) mod complex;
 pub(crate) mod cubic;
+pub(crate) mod eigen;
+pub(crate) mod general;
+pub(crate) mod impact;
 pub(crate) mod linear;
 pub(crate) mod quadratic;
+pub(crate) mod quartic;
+
+pub(crate) use complex::{solve_complex_cubic, solve_complex_quadratic};
+pub(crate) use cubic::{solve_real_cubic, solve_real_cubic_structured};
+#[cfg(feature = "nalgebra")]
+pub use eigen::NalgebraEigen;
+pub use eigen::{BuiltinQr, EigenBackend};
+#[cfg(feature = "tracing")]
+pub(crate) use general::max_backward_error;
+pub(crate) use general::{
+    ABERTH_MAX_ITER, aberth_initial, aberth_sweep, refine_aberth, solve_complex_aberth,
+    solve_complex_aberth_from, solve_complex_companion,
+};
+pub use impact::earliest_impact;
+pub(crate) use linear::{solve_real_linear, solve_real_linear_tol};
+pub(crate) use quadratic::{
+    solve_real_quadratic, solve_real_quadratic_structured, solve_real_quadratic_structured_tol,
+};
+pub(crate) use quartic::solve_real_quartic_arr;
+
+use num::Float;
+use num::traits::FloatConst;
+
+use crate::utils::order;
+use crate::{CubicRoots, PolyError, QuadraticRoots, Result, Tolerance};
+
+/// Calculates the root of the linear equation `ax+b = 0`.
+///
+/// # Error
+///
+/// Returns an error if the coefficients are NaN or Infinity, or if the equation is constant, i.e.
+/// a=0.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{Result, solve};
+/// # fn main() -> Result<()> {
+/// assert_eq!(solve::linear(2.0f32, -3.0)?, 1.5f32);
+/// # Ok(())
+/// # }
+/// ```
+pub fn linear<F: Float>(a: F, b: F) -> Result<F> {
+    check_finite(&[a, b])?;
+    solve_real_linear(a, b)
+}
+
+/// Same as [`linear`], but considers the equation constant if |a| ≤ tol·max(|a|, |b|).
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{PolyError, Tolerance, solve};
+/// assert!(solve::linear(1e-20, 1.0).is_ok());
+/// assert!(matches!(
+///     solve::linear_with_tol(1e-20, 1.0, Tolerance::new(1e-15)),
+///     Err(PolyError::ConstantPoly)
+/// ));
+/// ```
+pub fn linear_with_tol<F: Float>(a: F, b: F, tol: Tolerance) -> Result<F> {
+    check_finite(&[a, b])?;
+    solve_real_linear_tol(a, b, tol)
+}
+
+/// Calculates the **real** roots of the quadratic equation `ax²+bx+c = 0`.
+///
+/// In the case of a=0, the single root of the linear equation `bx+c = 0` is returned.
+///
+/// # Error
+///
+/// Returns an error if the coefficients are NaN or Infinity, or if the equation is constant, i.e.
+/// a=b=0.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{QuadraticRoots, Result, solve};
+/// # fn main() -> Result<()> {
+/// assert_eq!(solve::quadratic(5.0, 0.0, -20.0)?, QuadraticRoots::Two(-2.0, 2.0));
+/// assert_eq!(solve::quadratic(4.0, -20.0, 25.0)?, QuadraticRoots::Double(2.5));
+/// assert_eq!(solve::quadratic(1.0f32, -3.0, 2.0)?, QuadraticRoots::Two(1.0f32, 2.0));
+/// # Ok(())
+/// # }
+/// ```
+#[doc(alias = "gsl_poly_solve_quadratic")]
+pub fn quadratic<F: Float>(a: F, b: F, c: F) -> Result<QuadraticRoots<F>> {
+    check_finite(&[a, b, c])?;
+    solve_real_quadratic_structured(a, b, c)
+}
+
+/// Same as [`quadratic`], but solves the equation as a linear one if
+/// |a| ≤ tol·max(|a|, |b|, |c|), and considers it constant if |b| is negligible as well.
+///
+/// See [`Tolerance`] for an example.
+///
+/// # Error
+///
+/// Returns an error if the coefficients are NaN or Infinity, or if the equation is (nearly)
+/// constant.
+pub fn quadratic_with_tol<F: Float>(a: F, b: F, c: F, tol: Tolerance) -> Result<QuadraticRoots<F>> {
+    check_finite(&[a, b, c])?;
+    solve_real_quadratic_structured_tol(a, b, c, tol)
+}
+
+/// Calculates the **real** roots of the cubic equation `ax³+bx²+cx+d = 0`.
+///
+/// # Error
+///
+/// Returns an error if the coefficients are NaN or Infinity, or if a=0.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{CubicRoots, Result, solve};
+/// # fn main() -> Result<()> {
+/// assert_eq!(solve::cubic(1.0, -6.0, 11.0, -6.0)?, CubicRoots::Three([1.0, 2.0, 3.0]));
+/// assert_eq!(solve::cubic(2.0, 0.0, 0.0, -2.0)?, CubicRoots::One(1.0));
+/// assert_eq!(solve::cubic(1.0f32, -6.0, 11.0, -6.0)?, CubicRoots::Three([1.0f32, 2.0, 3.0]));
+/// # Ok(())
+/// # }
+/// ```
+#[doc(alias = "gsl_poly_solve_cubic")]
+pub fn cubic<F: Float + FloatConst>(a: F, b: F, c: F, d: F) -> Result<CubicRoots<F>> {
+    check_finite(&[a, b, c, d])?;
+    if a.is_zero() {
+        return Err(PolyError::IncorrectOrder {
+            expected: 3,
+            got: order(&[d, c, b]),
+        });
+    }
+    Ok(solve_real_cubic_structured(b / a, c / a, d / a))
+}
+
+/// Calculates the **real** roots of the quartic equation `ax⁴+bx³+cx²+dx+e = 0`, returning them
+/// in increasing order in a fixed-size array, along with their number.
+///
+/// Repeated roots are listed as many times as their multiplicity, and the unused slots of the
+/// array are filled with NaN.
+///
+/// # Error
+///
+/// Returns an error if the coefficients are NaN or Infinity, or if a=0.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{Result, solve};
+/// # fn main() -> Result<()> {
+/// // (x−1)(x−2)(x−3)(x−4)
+/// let (roots, n) = solve::quartic(1.0f64, -10.0, 35.0, -50.0, 24.0)?;
+/// assert_eq!(n, 4);
+/// assert!(roots.iter().zip([1.0, 2.0, 3.0, 4.0]).all(|(x, r)| (x - r).abs() < 1e-12));
+///
+/// // x⁴+1 has no real roots
+/// assert_eq!(solve::quartic(1.0, 0.0, 0.0, 0.0, 1.0)?.1, 0);
+/// # Ok(())
+/// # }
+/// ```
+pub fn quartic<F: Float + FloatConst>(a: F, b: F, c: F, d: F, e: F) -> Result<([F; 4], usize)> {
+    check_finite(&[a, b, c, d, e])?;
+    if a.is_zero() {
+        return Err(PolyError::IncorrectOrder {
+            expected: 4,
+            got: order(&[e, d, c, b]),
+        });
+    }
+    Ok(solve_real_quartic_arr(b / a, c / a, d / a, e / a))
+}
 
-pub(crate) use cubic::solve_real_cubic;
-pub(crate) use quadratic::solve_real_quadratic;
+/// Checks that the coefficients passed to the free solvers, which are not validated by
+/// [`Polynomial::build`](crate::Polynomial::build), are finite.
+fn check_finite<F: Float>(coef: &[F]) -> Result<()> {
+    match coef.iter().position(|c| !c.is_finite()) {
+        Some(index) => Err(PolyError::InvalidCoefficients {
+            index,
+            value: format!("{:?}", coef[index].to_f64().unwrap_or(f64::NAN)).into(),
+        }),
+        None => Ok(()),
+    }
+}
@@ -1,33 +1,87 @@
 use std::cmp::Ordering;
 
-use crate::solve::linear::solve_real_linear;
-use crate::{PolyError, Result};
+use num::Float;
+
+use crate::solve::linear::solve_real_linear_tol;
+use crate::utils::cast;
+use crate::{PolyError, QuadraticRoots, Result, Tolerance};
 
 /// Solves a quadratic equation ax²+bx+c = 0 with real coefficients, returning a Vec with the found 0-2
 /// real roots. In the case of a=0, solving is passed to the linear equation solver.
-pub(crate) fn solve_real_quadratic(a: f64, b: f64, c: f64) -> Result<Vec<f64>> {
-    if a == 0.0 {
-        return Ok(vec![solve_real_linear(b, c)?]);
+pub(crate) fn solve_real_quadratic<F: Float>(a: F, b: F, c: F) -> Result<Vec<F>> {
+    match solve_real_quadratic_structured(a, b, c)? {
+        QuadraticRoots::None => Err(PolyError::NoRealRoots),
+        QuadraticRoots::Linear(x) | QuadraticRoots::Double(x) => Ok(vec![x]),
+        QuadraticRoots::Two(x1, x2) => Ok(vec![x1, x2]),
+    }
+}
+
+/// Solves a quadratic equation ax²+bx+c = 0 with real coefficients, returning the structure of its
+/// real roots. In the case of a=0, solving is passed to the linear equation solver.
+///
+/// The coefficients are scaled by a power of 2 before forming the discriminant, so that it does
+/// not overflow for large coefficients.
+///
+/// Two distinct roots are returned in increasing order. As in GSL, they are calculated as
+/// q/a and c/q, where q = −(b + sgn(b)√Δ)/2, which avoids the catastrophic cancellation of
+/// (−b ± √Δ)/2a for the smaller root when b² ≫ 4ac.
+pub(crate) fn solve_real_quadratic_structured<F: Float>(
+    a: F,
+    b: F,
+    c: F,
+) -> Result<QuadraticRoots<F>> {
+    solve_real_quadratic_structured_tol(a, b, c, Tolerance::EXACT)
+}
+
+/// Same as [`solve_real_quadratic_structured`], but treats negligible leading coefficients
+/// (relative to the largest coefficient) as 0.
+pub(crate) fn solve_real_quadratic_structured_tol<F: Float>(
+    a: F,
+    b: F,
+    c: F,
+    tol: Tolerance,
+) -> Result<QuadraticRoots<F>> {
+    let scale = a.abs().max(b.abs()).max(c.abs());
+    if tol.is_negligible(a, scale) {
+        if tol.is_negligible(b, scale) {
+            return Err(PolyError::ConstantPoly);
+        }
+        return Ok(QuadraticRoots::Linear(solve_real_linear_tol(b, c, tol)?));
     }
 
-    let det = b.powi(2) - 4.0 * a * c;
+    // Scale the coefficients by a power of 2 close to the largest one, so that the discriminant
+    // neither overflows nor underflows, without introducing rounding errors. The factor is
+    // applied in two halves, since 2⁻ᵉ alone overflows for subnormal coefficients.
+    let exponent = scale.log2().round().to_i32().unwrap_or(0);
+    let [f1, f2] = [-exponent / 2, -exponent - (-exponent / 2)].map(|e| cast::<F>(2.0).powi(e));
+    let (a, b, c) = (a * f1 * f2, b * f1 * f2, c * f1 * f2);
 
-    let ordering = match det.partial_cmp(&0.0) {
+    let half: F = cast(0.5);
+    let det = b.powi(2) - cast::<F>(4.0) * a * c;
+
+    let ordering = match det.partial_cmp(&F::zero()) {
         Some(det) => det,
-        None => unreachable!("NaN discriminant"),
+        None => return Err(PolyError::NanDiscriminant),
     };
 
     match ordering {
-        Ordering::Less => Err(PolyError::NoRealRoots),
+        Ordering::Less => Ok(QuadraticRoots::None),
         Ordering::Equal => {
-            let x = -b / (2.0 * a);
-            Ok(vec![x])
+            let x = -half * b / a;
+            Ok(QuadraticRoots::Double(x))
+        }
+        Ordering::Greater if b.is_zero() => {
+            let r = (half * det.sqrt() / a).abs();
+            Ok(QuadraticRoots::Two(-r, r))
         }
         Ordering::Greater => {
-            let x1 = (-b + det.sqrt()) / (2.0 * a);
-            let x2 = (-b - det.sqrt()) / (2.0 * a);
+            let q = -half * (b + b.signum() * det.sqrt());
+            let (x1, x2) = (q / a, c / q);
 
-            Ok(vec![x1, x2])
+            match x1 < x2 {
+                true => Ok(QuadraticRoots::Two(x1, x2)),
+                false => Ok(QuadraticRoots::Two(x2, x1)),
+            }
         }
     }
 }
@@ -0,0 +1,99 @@
+use num::Float;
+use num::traits::FloatConst;
+
+use crate::solve::{solve_real_cubic_structured, solve_real_quadratic_structured};
+use crate::utils::cast;
+use crate::{CubicRoots, QuadraticRoots};
+
+/// Newton steps used to polish the roots of the depressed quartic against the original one.
+const POLISH_STEPS: usize = 2;
+
+/// Solves the quartic equation x⁴+bx³+cx²+dx+e = 0 with real coefficients with Ferrari's
+/// method, returning the real roots in increasing order, with repeated roots listed as many
+/// times as their multiplicity, along with their number. The unused slots are filled with NaN.
+///
+/// The quartic is depressed to y⁴+py²+qy+r = 0 (x = y−b/4), which is factored into two
+/// quadratics with the help of the largest root of its resolvent cubic. The roots are then
+/// polished with a few Newton steps on the original equation.
+pub(crate) fn solve_real_quartic_arr<F: Float + FloatConst>(
+    b: F,
+    c: F,
+    d: F,
+    e: F,
+) -> ([F; 4], usize) {
+    let [two, three, four, eight] = [2.0, 3.0, 4.0, 8.0].map(cast::<F>);
+    let half: F = cast(0.5);
+    let zero = F::zero();
+
+    let shift = b / four;
+    let b2 = b * b;
+    let p = c - cast::<F>(0.375) * b2;
+    let q = d - half * b * c + b2 * b / eight;
+    let r = e - b * d / four + b2 * c / cast(16.0) - three * b2 * b2 / cast(256.0);
+
+    let mut roots = Vec::with_capacity(4);
+    let mut push_squares = |quad: QuadraticRoots<F>| roots.extend(quad.to_vec());
+
+    if q == zero {
+        // Biquadratic: z² + pz + r = 0, with z = y²
+        let z = match solve_real_quadratic_structured(F::one(), p, r) {
+            Ok(z) => z.to_vec(),
+            Err(_) => Vec::new(),
+        };
+        for z in z.into_iter().filter(|z| *z >= zero) {
+            let y = z.sqrt();
+            push_squares(QuadraticRoots::Two(-y, y));
+        }
+    } else {
+        // Resolvent cubic m³ + pm² + (p²/4 − r)m − q²/8 = 0, which has a positive root since
+        // q ≠ 0
+        let m = match solve_real_cubic_structured(p, p * p / four - r, -q * q / eight) {
+            CubicRoots::One(m) | CubicRoots::TripleRepeated(m) => m,
+            CubicRoots::Three(m) => m[2],
+        };
+        let s = (two * m).sqrt();
+
+        // y⁴+py²+qy+r = (y² + sy + p/2 + m − q/2s)(y² − sy + p/2 + m + q/2s)
+        for sign in [F::one(), -F::one()] {
+            let constant = half * p + m - sign * q / (two * s);
+            if let Ok(quad) = solve_real_quadratic_structured(F::one(), sign * s, constant) {
+                push_squares(quad);
+            }
+        }
+    }
+
+    let mut res = [F::nan(); 4];
+    let n = roots.len().min(4);
+    for (slot, y) in res.iter_mut().zip(roots) {
+        *slot = polish(y - shift, [e, d, c, b, F::one()]);
+    }
+    res[..n].sort_by(|x, y| x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal));
+    (res, n)
+}
+
+/// Applies a few Newton steps to a root of the polynomial with coefficients `coef` (constant term
+/// first), keeping only the steps that decrease the residual.
+fn polish<F: Float>(x: F, coef: [F; 5]) -> F {
+    let eval = |x: F| {
+        coef.iter()
+            .rev()
+            .fold((F::zero(), F::zero()), |(p, dp), c| {
+                (p * x + *c, dp * x + p)
+            })
+    };
+
+    let mut x = x;
+    let (mut value, mut deriv) = eval(x);
+    for _ in 0..POLISH_STEPS {
+        if deriv == F::zero() || !value.is_finite() {
+            break;
+        }
+        let candidate = x - value / deriv;
+        let (new_value, new_deriv) = eval(candidate);
+        if new_value.abs() >= value.abs() {
+            break;
+        }
+        (x, value, deriv) = (candidate, new_value, new_deriv);
+    }
+    x
+}
@@ -0,0 +1,253 @@
+//! Resumable root finding, for long solves that must survive interruptions, with progress
+//! callbacks and cancellation.
+
+use std::ops::ControlFlow;
+
+use num::complex::Complex64;
+
+use crate::utils::{check_finite_coef, convert_to_complex64};
+use crate::{PolyError, Polynomial, Result, solve};
+
+/// The progress of an iterative root solve, passed to its callback after every iteration.
+#[derive(Clone, Copy, Debug)]
+pub struct IterationInfo<'a> {
+    /// The number of iterations run so far.
+    pub iteration: usize,
+    /// The number of roots that have converged.
+    pub converged: usize,
+    /// The current approximations of all the roots, converged or not.
+    pub roots: &'a [Complex64],
+}
+
+/// The state of an Aberth-Ehrlich solve of all the complex roots of a polynomial, which can be
+/// advanced a few iterations at a time.
+///
+/// With the `serde` feature, the state can be serialized between runs, e.g. to snapshot a
+/// high-order solve on a preemptible machine and resume it later from the same iteration. A
+/// resumed solve produces exactly the same roots as an uninterrupted one, as long as the format
+/// stores the floats losslessly (e.g. `serde_json` with its `float_roundtrip` feature).
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{Polynomial, Result, RootSolverState};
+/// # fn main() -> Result<()> {
+/// let poly = Polynomial::build(&[-6.0, 11.0, -6.0, 1.0])?;
+/// let mut state = RootSolverState::new(&poly)?;
+///
+/// while !state.run(5) {
+///     // Snapshot the state here
+/// }
+/// let roots = state.into_roots()?;
+/// assert_eq!(roots.len(), 3);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawState"))]
+pub struct RootSolverState {
+    /// The coefficients with the roots at 0 deflated, from constant to leading term.
+    coef: Vec<Complex64>,
+    /// The current approximations, starting with the roots at 0.
+    roots: Vec<Complex64>,
+    /// Whether every approximation has converged.
+    converged: Vec<bool>,
+    /// The number of iterations run so far.
+    iterations: usize,
+}
+
+/// The unvalidated fields of a deserialized [`RootSolverState`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawState {
+    coef: Vec<Complex64>,
+    roots: Vec<Complex64>,
+    converged: Vec<bool>,
+    iterations: usize,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawState> for RootSolverState {
+    type Error = PolyError;
+
+    fn try_from(raw: RawState) -> Result<Self> {
+        let n = raw.coef.len();
+        let valid = n >= 1
+            && raw.coef.first().is_some_and(|c| *c != Complex64::default())
+            && raw.coef.last().is_some_and(|c| *c != Complex64::default())
+            && raw.roots.len() >= n - 1
+            && raw.roots.len() == raw.converged.len();
+        if !valid {
+            return Err(PolyError::InvalidData);
+        }
+        check_finite_coef(raw.coef.iter().chain(&raw.roots).copied())?;
+        Ok(RootSolverState {
+            coef: raw.coef,
+            roots: raw.roots,
+            converged: raw.converged,
+            iterations: raw.iterations,
+        })
+    }
+}
+
+impl RootSolverState {
+    /// Starts solving `poly`, with the same initial approximations as
+    /// [`solve_complex`](Polynomial::solve_complex) uses for complex coefficients.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if any of the coefficients is NaN or Infinity, or if the (trimmed)
+    /// Polynomial is constant.
+    pub fn new<T>(poly: &Polynomial<T>) -> Result<Self>
+    where
+        T: num::complex::ComplexFloat + std::fmt::Debug,
+    {
+        check_finite_coef(poly.coef.iter().copied())?;
+        let poly = poly.to_trimmed();
+        if poly.coef.len() < 2 {
+            return Err(PolyError::ConstantPoly);
+        }
+
+        // Deflate the roots at 0 first, since they cannot be detected by a relative criterion
+        let zeros = poly.coef.iter().take_while(|c| c.is_zero()).count();
+        let coef: Vec<Complex64> = poly.coef[zeros..]
+            .iter()
+            .map(|c| convert_to_complex64(*c))
+            .collect();
+
+        let mut roots = vec![Complex64::default(); zeros];
+        let mut converged = vec![true; zeros];
+        if coef.len() > 1 {
+            let initial = solve::aberth_initial(&coef);
+            converged.extend(initial.iter().map(|_| false));
+            roots.extend(initial);
+        }
+
+        Ok(RootSolverState {
+            coef,
+            roots,
+            converged,
+            iterations: 0,
+        })
+    }
+
+    /// Runs at most `max_iter` more iterations, returning whether every root has converged.
+    pub fn run(&mut self, max_iter: usize) -> bool {
+        let zeros = self.roots.len() + 1 - self.coef.len();
+        for _ in 0..max_iter {
+            if self.is_converged() {
+                break;
+            }
+            solve::aberth_sweep(
+                &self.coef,
+                &mut self.roots[zeros..],
+                &mut self.converged[zeros..],
+            );
+            self.iterations += 1;
+        }
+        self.is_converged()
+    }
+
+    /// Same as [`run`](RootSolverState::run), but calls `callback` after every iteration, and
+    /// stops early, returning [`ControlFlow::Break`], if it breaks. The state is left consistent,
+    /// so that the solve can still be resumed.
+    pub fn run_with_progress<F>(
+        &mut self,
+        max_iter: usize,
+        mut callback: F,
+    ) -> ControlFlow<(), bool>
+    where
+        F: FnMut(&IterationInfo) -> ControlFlow<()>,
+    {
+        for _ in 0..max_iter {
+            if self.is_converged() {
+                break;
+            }
+            self.run(1);
+            callback(&IterationInfo {
+                iteration: self.iterations,
+                converged: self.converged.iter().filter(|c| **c).count(),
+                roots: &self.roots,
+            })?;
+        }
+        ControlFlow::Continue(self.is_converged())
+    }
+
+    /// Returns whether every root has converged.
+    pub fn is_converged(&self) -> bool {
+        self.converged.iter().all(|c| *c)
+    }
+
+    /// Returns the number of iterations run so far.
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Returns the current approximations of the roots, converged or not.
+    pub fn roots(&self) -> &[Complex64] {
+        &self.roots
+    }
+
+    /// Returns the roots, once they have all converged.
+    ///
+    /// # Error
+    ///
+    /// Returns [`PolyError::NoConvergence`] if some of the roots have not converged yet.
+    pub fn into_roots(self) -> Result<Vec<Complex64>> {
+        match self.is_converged() {
+            true => Ok(self.roots),
+            false => Err(PolyError::NoConvergence),
+        }
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Calculates all the (complex) roots with the Aberth-Ehrlich method, like
+    /// [`solve_complex`](Polynomial::solve_complex) does for complex coefficients, calling
+    /// `callback` after every iteration, e.g. to report the progress of a high order solve in a
+    /// GUI, or to cancel it by breaking.
+    ///
+    /// The roots are returned in no particular order.
+    ///
+    /// # Error
+    ///
+    /// Returns [`PolyError::Cancelled`] if the callback breaks, and otherwise the same errors as
+    /// [`solve_complex`](Polynomial::solve_complex).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use std::ops::ControlFlow;
+    /// # use rsl_polynomials::{PolyError, Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[1.0; 30])?;
+    ///
+    /// let roots = poly.solve_complex_with_progress(|info| {
+    ///     println!("{}: {}/29 converged", info.iteration, info.converged);
+    ///     ControlFlow::Continue(())
+    /// })?;
+    /// assert_eq!(roots.len(), 29);
+    ///
+    /// let cancelled = poly.solve_complex_with_progress(|info| match info.iteration {
+    ///     3 => ControlFlow::Break(()),
+    ///     _ => ControlFlow::Continue(()),
+    /// });
+    /// assert!(matches!(cancelled, Err(PolyError::Cancelled)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn solve_complex_with_progress<F>(&self, callback: F) -> Result<Vec<Complex64>>
+    where
+        F: FnMut(&IterationInfo) -> ControlFlow<()>,
+    {
+        let mut state = RootSolverState::new(self)?;
+        match state.run_with_progress(solve::ABERTH_MAX_ITER, callback) {
+            ControlFlow::Break(()) => Err(PolyError::Cancelled),
+            ControlFlow::Continue(_) => state.into_roots(),
+        }
+    }
+}
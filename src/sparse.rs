@@ -0,0 +1,207 @@
+//! Sparse representation of polynomials with few non-zero terms.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::arith::forward_owned_binop;
+use crate::utils::check_finite_coef;
+use crate::{Polynomial, Result};
+
+/// Representation of a polynomial as a list of its non-zero `(exponent, coefficient)` terms.
+///
+/// Unlike [`Polynomial`], the storage and evaluation cost depends on the number of non-zero
+/// terms rather than on the degree, which makes it suitable for polynomials like x⁵⁰⁰⁰ − 1.
+///
+/// The terms are kept sorted by increasing exponent, with no repeated exponents and no zero
+/// coefficients.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparsePolynomial<T>
+where
+    T: std::fmt::Debug,
+{
+    terms: Vec<(usize, T)>,
+}
+
+impl<T> SparsePolynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Creates a new sparse polynomial with no terms (zero polynomial).
+    pub fn new() -> Self {
+        SparsePolynomial { terms: vec![] }
+    }
+
+    /// Creates a new sparse polynomial from the given `(exponent, coefficient)` terms, in any
+    /// order. Coefficients of repeated exponents are summed.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if any of the coefficients is NaN or Infinity.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{SparsePolynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = SparsePolynomial::build(&[(5000, 1.0), (0, -1.0)])?; // x⁵⁰⁰⁰−1
+    ///
+    /// assert_eq!(poly.degree(), 5000);
+    /// assert_eq!(poly.terms(), [(0, -1.0), (5000, 1.0)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build(terms: &[(usize, T)]) -> Result<Self> {
+        check_finite_coef(terms.iter().map(|(_, c)| *c))?;
+        Ok(Self::from_unsorted(terms.to_vec()))
+    }
+
+    /// Sorts the terms, merges repeated exponents and drops zero coefficients.
+    fn from_unsorted(mut terms: Vec<(usize, T)>) -> Self {
+        terms.sort_by_key(|(exp, _)| *exp);
+
+        let mut merged: Vec<(usize, T)> = Vec::with_capacity(terms.len());
+        for (exp, c) in terms {
+            match merged.last_mut() {
+                Some(last) if last.0 == exp => last.1 = last.1 + c,
+                _ => merged.push((exp, c)),
+            }
+        }
+        merged.retain(|(_, c)| !c.is_zero());
+
+        SparsePolynomial { terms: merged }
+    }
+
+    /// Returns the non-zero `(exponent, coefficient)` terms, in increasing exponent order.
+    pub fn terms(&self) -> &[(usize, T)] {
+        &self.terms
+    }
+
+    /// Returns the degree of the polynomial, i.e. its highest exponent with a non-zero
+    /// coefficient. The zero polynomial has degree 0.
+    pub fn degree(&self) -> usize {
+        self.terms.last().map_or(0, |(exp, _)| *exp)
+    }
+
+    /// Evaluates the polynomial at `x`.
+    ///
+    /// The powers of `x` are calculated incrementally, raising the previous power to the gap
+    /// between consecutive exponents by binary exponentiation.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{SparsePolynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = SparsePolynomial::build(&[(5000, 1.0), (0, -1.0)])?; // x⁵⁰⁰⁰−1
+    ///
+    /// assert_eq!(poly.eval(1.0), 0.0);
+    /// assert_eq!(poly.eval(-1.0), 0.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval(&self, x: T) -> T {
+        let mut res = T::zero();
+        let (mut power, mut last_exp) = (T::one(), 0);
+        for (exp, c) in self.terms.iter() {
+            power = power * num::pow(x, exp - last_exp);
+            last_exp = *exp;
+            res = res + *c * power;
+        }
+        res
+    }
+}
+
+impl<T> Default for SparsePolynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Polynomial<T>> for SparsePolynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    fn from(poly: Polynomial<T>) -> Self {
+        SparsePolynomial {
+            terms: poly.terms().collect(),
+        }
+    }
+}
+
+impl<T> From<SparsePolynomial<T>> for Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    fn from(sparse: SparsePolynomial<T>) -> Self {
+        sparse.terms.into_iter().collect()
+    }
+}
+
+impl<T> Add for &SparsePolynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = SparsePolynomial<T>;
+
+    fn add(self, rhs: Self) -> SparsePolynomial<T> {
+        SparsePolynomial::from_unsorted([self.terms.as_slice(), &rhs.terms].concat())
+    }
+}
+
+impl<T> Sub for &SparsePolynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = SparsePolynomial<T>;
+
+    fn sub(self, rhs: Self) -> SparsePolynomial<T> {
+        self + &(-rhs)
+    }
+}
+
+impl<T> Mul for &SparsePolynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = SparsePolynomial<T>;
+
+    fn mul(self, rhs: Self) -> SparsePolynomial<T> {
+        let mut terms = Vec::with_capacity(self.terms.len() * rhs.terms.len());
+        for (e1, c1) in self.terms.iter() {
+            for (e2, c2) in rhs.terms.iter() {
+                terms.push((e1 + e2, *c1 * *c2));
+            }
+        }
+        SparsePolynomial::from_unsorted(terms)
+    }
+}
+
+impl<T> Neg for &SparsePolynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = SparsePolynomial<T>;
+
+    fn neg(self) -> SparsePolynomial<T> {
+        SparsePolynomial {
+            terms: self.terms.iter().map(|(exp, c)| (*exp, -*c)).collect(),
+        }
+    }
+}
+
+forward_owned_binop!(SparsePolynomial, Add, add);
+forward_owned_binop!(SparsePolynomial, Sub, sub);
+forward_owned_binop!(SparsePolynomial, Mul, mul);
+
+impl<T> Neg for SparsePolynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    type Output = SparsePolynomial<T>;
+
+    fn neg(self) -> SparsePolynomial<T> {
+        -&self
+    }
+}
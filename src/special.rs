@@ -0,0 +1,258 @@
+//! Constructors of special families of polynomials.
+
+use crate::{PolyError, Polynomial, Result};
+
+impl Polynomial<f64> {
+    /// Returns the n-th cyclotomic polynomial Φₙ(x), the monic polynomial whose roots are the
+    /// primitive n-th roots of unity.
+    ///
+    /// It is calculated with the Möbius inversion formula Φₙ(x) = ∏(xᵈ − 1)^μ(n/d), with the
+    /// product taken over the divisors d of n. The coefficients are integers, and are exact as
+    /// long as the intermediate products are exactly representable.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if n = 0.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// assert_eq!(Polynomial::cyclotomic(1)?.coef, [-1.0, 1.0]);
+    /// assert_eq!(Polynomial::cyclotomic(6)?.coef, [1.0, -1.0, 1.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cyclotomic(n: usize) -> Result<Self> {
+        if n == 0 {
+            return Err(PolyError::InvalidIndex(0));
+        }
+        // xᵈ − 1
+        let binomial = |d: usize| {
+            let mut coef = vec![0.0; d + 1];
+            coef[0] = -1.0;
+            coef[d] = 1.0;
+            Polynomial { coef }
+        };
+
+        let divisors: Vec<usize> = (1..=n).filter(|d| n.is_multiple_of(*d)).collect();
+        let mut res: Polynomial<f64> = Polynomial { coef: vec![1.0] };
+        for d in divisors.iter().filter(|d| mobius(n / **d) == 1) {
+            res = &res * &binomial(*d);
+        }
+        // Every division is exact, since the product of all the denominators divides the
+        // numerator
+        for d in divisors.iter().filter(|d| mobius(n / **d) == -1) {
+            res = res.div_rem(&binomial(*d))?.0;
+            res.coef.iter_mut().for_each(|c| *c = c.round());
+        }
+        Ok(res)
+    }
+
+    /// Returns Wilkinson's polynomial of order n, i.e. (x − 1)(x − 2)⋯(x − n).
+    ///
+    /// Its roots are notoriously ill-conditioned with respect to the (large) coefficients, which
+    /// makes it a standard stress test for root finders. For n > 18, the coefficients are not
+    /// exactly representable as [`f64`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::Polynomial;
+    /// assert_eq!(Polynomial::wilkinson(3).coef, [-6.0, 11.0, -6.0, 1.0]);
+    /// ```
+    pub fn wilkinson(n: usize) -> Self {
+        let roots: Vec<f64> = (1..=n).map(|k| k as f64).collect();
+        Polynomial::from_roots(&roots)
+    }
+
+    /// Returns the Bernstein basis polynomial bᵢ,ₙ(x) = C(n, i)·xⁱ(1 − x)ⁿ⁻ⁱ, in the monomial
+    /// basis.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if i > n.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// assert_eq!(Polynomial::bernstein(1, 2)?.coef, [0.0, 2.0, -2.0]); // 2x(1−x)
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bernstein(i: usize, n: usize) -> Result<Self> {
+        if i > n {
+            return Err(PolyError::InvalidIndex(i));
+        }
+        let scale = binomial(n, i);
+        let mut coef = vec![0.0; n + 1];
+        for (j, c) in coef[i..].iter_mut().enumerate() {
+            let sign = if j.is_multiple_of(2) { 1.0 } else { -1.0 };
+            *c = sign * scale * binomial(n - i, j);
+        }
+        Ok(Polynomial { coef })
+    }
+
+    /// Returns the Taylor polynomial of order n of eˣ at 0, Σ xᵏ/k!.
+    ///
+    /// Every coefficient is the correctly rounded value of 1/k!, so the polynomials are exact
+    /// test cases, e.g. for comparing against [`f64::exp`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::Polynomial;
+    /// assert_eq!(Polynomial::taylor_exp(3).coef, [1.0, 1.0, 0.5, 1.0 / 6.0]);
+    /// assert!((Polynomial::taylor_exp(20).eval(1.0) - 1f64.exp()).abs() < 1e-15);
+    /// ```
+    pub fn taylor_exp(n: usize) -> Self {
+        Polynomial {
+            coef: (0..=n).map(recip_factorial).collect(),
+        }
+    }
+
+    /// Returns the Taylor polynomial of order n of sin(x) at 0, Σ (−1)ᵏx²ᵏ⁺¹/(2k+1)!, with
+    /// correctly rounded coefficients. The even powers have zero coefficients.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::Polynomial;
+    /// assert_eq!(Polynomial::taylor_sin(4).coef, [0.0, 1.0, 0.0, -1.0 / 6.0, 0.0]);
+    /// ```
+    pub fn taylor_sin(n: usize) -> Self {
+        Polynomial {
+            coef: (0..=n)
+                .map(|k| match k % 4 {
+                    1 => recip_factorial(k),
+                    3 => -recip_factorial(k),
+                    _ => 0.0,
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the Taylor polynomial of order n of cos(x) at 0, Σ (−1)ᵏx²ᵏ/(2k)!, with correctly
+    /// rounded coefficients. The odd powers have zero coefficients.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::Polynomial;
+    /// assert_eq!(Polynomial::taylor_cos(4).coef, [1.0, 0.0, -0.5, 0.0, 1.0 / 24.0]);
+    /// ```
+    pub fn taylor_cos(n: usize) -> Self {
+        Polynomial {
+            coef: (0..=n)
+                .map(|k| match k % 4 {
+                    0 => recip_factorial(k),
+                    2 => -recip_factorial(k),
+                    _ => 0.0,
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the Taylor polynomial of order n of ln(1 + x) at 0, Σ (−1)ᵏ⁺¹xᵏ/k, with correctly
+    /// rounded coefficients. It converges to ln(1 + x) only for −1 < x ≤ 1.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::Polynomial;
+    /// assert_eq!(Polynomial::taylor_ln1p(3).coef, [0.0, 1.0, -0.5, 1.0 / 3.0]);
+    /// ```
+    pub fn taylor_ln1p(n: usize) -> Self {
+        Polynomial {
+            coef: (0..=n)
+                .map(|k| match k {
+                    0 => 0.0,
+                    _ if k % 2 == 1 => 1.0 / k as f64,
+                    _ => -1.0 / k as f64,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Returns 1/k!, correctly rounded to the nearest [`f64`].
+///
+/// Up to 22!, the factorial itself is exact, so a single division is correctly rounded. Beyond
+/// that, floor(2ˢ/k!) is computed exactly in multi-precision, with s large enough to hold 64
+/// more bits than a mantissa, and rounded with the knowledge that 1/k! is never a tie, since k!
+/// has odd prime factors.
+fn recip_factorial(k: usize) -> f64 {
+    // 1/178! < 2⁻¹⁰⁷⁵ underflows
+    if k >= 178 {
+        return 0.0;
+    }
+    if k <= 22 {
+        return 1.0 / (2..=k).map(|j| j as f64).product::<f64>();
+    }
+
+    // floor(2ˢ/k!), as 32-bit limbs from the least significant, dividing by every factor
+    let log2 = (2..=k).map(|j| (j as f64).log2()).sum::<f64>();
+    let s = log2.ceil() as usize + 64;
+    let mut limbs = vec![0u32; s / 32 + 1];
+    limbs[s / 32] = 1 << (s % 32);
+    for j in 2..=k as u64 {
+        let mut rem = 0u64;
+        for limb in limbs.iter_mut().rev() {
+            let cur = (rem << 32) | u64::from(*limb);
+            *limb = (cur / j) as u32;
+            rem = cur % j;
+        }
+    }
+    let bit = |i: usize| (limbs[i / 32] >> (i % 32)) & 1 == 1;
+    let Some(top) = (0..limbs.len() * 32).rev().find(|i| bit(*i)) else {
+        return 0.0;
+    };
+
+    // The leading bit is worth 2^(top − s); below 2⁻¹⁰²² the mantissa loses precision
+    let exponent = top as i64 - s as i64;
+    let precision = 53 - (-1022 - exponent).max(0);
+    if precision < 0 {
+        return 0.0;
+    }
+    let last = top + 1 - precision as usize;
+    let mut mantissa = (last..=top)
+        .rev()
+        .fold(0u64, |m, i| (m << 1) | u64::from(bit(i)));
+    // Round half up, as the rest is never exactly a half
+    if last > 0 && bit(last - 1) {
+        mantissa += 1;
+    }
+    (mantissa as f64) * 2f64.powi(last as i32 - s as i32 + 600) * 2f64.powi(-600)
+}
+
+/// The binomial coefficient C(n, k), rounded to the nearest [`f64`].
+fn binomial(n: usize, k: usize) -> f64 {
+    let k = k.min(n - k);
+    (0..k)
+        .fold(1.0, |res, j| res * (n - j) as f64 / (j + 1) as f64)
+        .round()
+}
+
+/// The Möbius function μ(n).
+fn mobius(n: usize) -> i32 {
+    let mut n = n;
+    let mut res = 1;
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            n /= p;
+            if n.is_multiple_of(p) {
+                return 0;
+            }
+            res = -res;
+        }
+        p += 1;
+    }
+    if n > 1 {
+        res = -res;
+    }
+    res
+}
@@ -0,0 +1,110 @@
+//! Cubic spline interpolation.
+
+use crate::{PiecewisePolynomial, PolyError, Polynomial, Result, linalg};
+
+/// The boundary condition of a cubic spline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SplineBoundary {
+    /// Zero second derivative at both ends.
+    Natural,
+    /// The given first derivatives at the first and last point.
+    Clamped(f64, f64),
+}
+
+impl PiecewisePolynomial {
+    /// Constructs the cubic spline interpolating the data points `(xs[i], ys[i])`, i.e. the
+    /// piecewise cubic polynomial with continuous first and second derivatives that passes
+    /// through all the points, with the given boundary condition.
+    ///
+    /// The breakpoints of the result are the abscissas `xs`.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the slices have different lengths, contain NaN or Infinity, contain
+    /// fewer than 2 points, or if the abscissas are not strictly increasing.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{PiecewisePolynomial, Result, SplineBoundary};
+    /// # fn main() -> Result<()> {
+    /// let xs = [0.0, 1.0, 2.0, 3.0];
+    /// let ys = [0.0, 1.0, 0.0, 1.0];
+    /// let spline = PiecewisePolynomial::cubic_spline(&xs, &ys, SplineBoundary::Natural)?;
+    ///
+    /// assert_eq!(spline.eval(2.0), 0.0);
+    /// assert!(spline.derivative().derivative().eval(0.0).abs() < 1e-15);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "gsl_interp_cspline")]
+    pub fn cubic_spline(xs: &[f64], ys: &[f64], boundary: SplineBoundary) -> Result<Self> {
+        if xs.len() != ys.len() {
+            return Err(PolyError::LengthMismatch(xs.len(), ys.len()));
+        }
+        let boundary_values = match boundary {
+            SplineBoundary::Natural => vec![],
+            SplineBoundary::Clamped(s0, sn) => vec![s0, sn],
+        };
+        if xs
+            .iter()
+            .chain(ys)
+            .chain(&boundary_values)
+            .any(|v| !v.is_finite())
+        {
+            return Err(PolyError::InvalidData);
+        }
+        if xs.len() < 2 {
+            return Err(PolyError::NotEnoughPoints(2));
+        }
+        if let Some(w) = xs.windows(2).find(|w| w[0] >= w[1]) {
+            return Err(PolyError::InvalidInterval(w[0], w[1]));
+        }
+
+        let n = xs.len() - 1;
+        let h: Vec<f64> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+        let slopes: Vec<f64> = (0..n).map(|i| (ys[i + 1] - ys[i]) / h[i]).collect();
+
+        // Tridiagonal system for the second derivatives Mᵢ at the data points
+        let (mut sub, mut diag, mut sup, mut rhs) = (
+            vec![0.0; n + 1],
+            vec![0.0; n + 1],
+            vec![0.0; n + 1],
+            vec![0.0; n + 1],
+        );
+        for i in 1..n {
+            sub[i] = h[i - 1];
+            diag[i] = 2.0 * (h[i - 1] + h[i]);
+            sup[i] = h[i];
+            rhs[i] = 6.0 * (slopes[i] - slopes[i - 1]);
+        }
+        match boundary {
+            SplineBoundary::Natural => {
+                diag[0] = 1.0;
+                diag[n] = 1.0;
+            }
+            SplineBoundary::Clamped(s0, sn) => {
+                diag[0] = 2.0 * h[0];
+                sup[0] = h[0];
+                rhs[0] = 6.0 * (slopes[0] - s0);
+                sub[n] = h[n - 1];
+                diag[n] = 2.0 * h[n - 1];
+                rhs[n] = 6.0 * (sn - slopes[n - 1]);
+            }
+        }
+        let m = linalg::solve_tridiagonal(&sub, &diag, &sup, &rhs);
+
+        let pieces: Vec<Polynomial<f64>> = (0..n)
+            .map(|i| Polynomial {
+                coef: vec![
+                    ys[i],
+                    slopes[i] - h[i] * (2.0 * m[i] + m[i + 1]) / 6.0,
+                    m[i] / 2.0,
+                    (m[i + 1] - m[i]) / (6.0 * h[i]),
+                ],
+            })
+            .collect();
+
+        PiecewisePolynomial::build(xs, &pieces)
+    }
+}
@@ -0,0 +1,173 @@
+//! Statistical distribution functions used internally.
+
+use std::f64::consts::PI;
+
+/// Lanczos approximation coefficients (g=7, n=9).
+const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// Natural logarithm of the Gamma function, for x > 0.
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+    if x < 0.5 {
+        // Reflection formula
+        return (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let sum = LANCZOS[1..]
+        .iter()
+        .enumerate()
+        .fold(LANCZOS[0], |acc, (i, c)| acc + c / (x + (i + 1) as f64));
+
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+/// Regularized incomplete beta function Iₓ(a, b), for 0 ≤ x ≤ 1.
+pub(crate) fn incomplete_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let ln_front = ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln();
+
+    // The continued fraction converges fast only for x < (a+1)/(a+b+2)
+    if x < (a + 1.0) / (a + b + 2.0) {
+        ln_front.exp() * beta_continued_fraction(x, a, b) / a
+    } else {
+        1.0 - ln_front.exp() * beta_continued_fraction(1.0 - x, b, a) / b
+    }
+}
+
+/// Evaluates the continued fraction of the incomplete beta function with the modified Lentz
+/// method.
+fn beta_continued_fraction(x: f64, a: f64, b: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+    const MAX_ITER: usize = 300;
+
+    let mut c = 1.0;
+    let mut d = 1.0 - (a + b) * x / (a + 1.0);
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+
+    for m in 1..=MAX_ITER {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+
+        // Even step
+        let num = m * (b - m) * x / ((a + m2 - 1.0) * (a + m2));
+        d = 1.0 + num * d;
+        c = 1.0 + num / c;
+        d = 1.0 / if d.abs() < TINY { TINY } else { d };
+        c = if c.abs() < TINY { TINY } else { c };
+        h *= d * c;
+
+        // Odd step
+        let num = -(a + m) * (a + b + m) * x / ((a + m2) * (a + m2 + 1.0));
+        d = 1.0 + num * d;
+        c = 1.0 + num / c;
+        d = 1.0 / if d.abs() < TINY { TINY } else { d };
+        c = if c.abs() < TINY { TINY } else { c };
+        let delta = d * c;
+        h *= delta;
+
+        if (delta - 1.0).abs() < f64::EPSILON {
+            break;
+        }
+    }
+    h
+}
+
+/// Cumulative distribution function of Student's t-distribution with `nu` degrees of freedom.
+pub(crate) fn student_t_cdf(t: f64, nu: f64) -> f64 {
+    let tail = 0.5 * incomplete_beta(nu / (nu + t * t), 0.5 * nu, 0.5);
+    if t > 0.0 { 1.0 - tail } else { tail }
+}
+
+/// Quantile (inverse CDF) of Student's t-distribution with `nu` degrees of freedom, for
+/// 0 < p < 1.
+pub(crate) fn student_t_quantile(p: f64, nu: f64) -> f64 {
+    if p == 0.5 {
+        return 0.0;
+    }
+    if p < 0.5 {
+        return -student_t_quantile(1.0 - p, nu);
+    }
+
+    // Bracket the quantile, then bisect down to machine precision
+    let mut lo = 0.0;
+    let mut hi = 1.0;
+    while student_t_cdf(hi, nu) < p {
+        lo = hi;
+        hi *= 2.0;
+    }
+    while hi - lo > f64::EPSILON * hi {
+        let mid = 0.5 * (lo + hi);
+        if student_t_cdf(mid, nu) < p {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    0.5 * (lo + hi)
+}
+
+/// Median of `values`, or NaN if there are none.
+pub(crate) fn median(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let n = sorted.len();
+    match n {
+        0 => f64::NAN,
+        _ if n % 2 == 1 => sorted[n / 2],
+        _ => 0.5 * (sorted[n / 2 - 1] + sorted[n / 2]),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use is_close::is_close;
+
+    #[test]
+    fn test_ln_gamma() {
+        assert!(is_close!(ln_gamma(5.0), 24f64.ln(), rel_tol = 1e-13));
+        assert!(is_close!(ln_gamma(0.5), PI.sqrt().ln(), rel_tol = 1e-13));
+    }
+
+    #[test]
+    fn test_student_t_quantile() {
+        // Cauchy distribution
+        let expected = (PI * 0.475).tan();
+        assert!(is_close!(
+            student_t_quantile(0.975, 1.0),
+            expected,
+            rel_tol = 1e-12
+        ));
+        assert!(is_close!(
+            student_t_quantile(0.975, 10.0),
+            2.228138851986273,
+            rel_tol = 1e-12
+        ));
+        assert!(is_close!(
+            student_t_quantile(0.025, 10.0),
+            -2.228138851986273,
+            rel_tol = 1e-12
+        ));
+    }
+}
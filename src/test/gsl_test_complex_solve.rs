@@ -0,0 +1,111 @@
+use crate::Polynomial;
+use is_close::is_close;
+use num::complex::Complex64;
+
+// GSL's tests use this tolerance
+const EPS: f64 = 100.0 * f64::EPSILON;
+
+/// Sorts roots by their real and then imaginary part, as GSL's tests do.
+fn sorted(mut z: Vec<Complex64>) -> Vec<Complex64> {
+    z.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+    z
+}
+
+#[test]
+/// Source: gsl/poly/test.c
+fn test_gsl_complex_solve1() {
+    // x⁵-1
+    let p = Polynomial::build(&[-1.0, 0.0, 0.0, 0.0, 0.0, 1.0]).unwrap();
+    let z = sorted(p.solve_complex().unwrap());
+
+    let c = |k: f64| Complex64::from_polar(1.0, 2.0 * std::f64::consts::PI * k / 5.0);
+    let expected = [c(3.0), c(2.0), c(4.0), c(1.0), c(0.0)];
+
+    for (z, e) in z.iter().zip(expected) {
+        assert!(is_close!(z.re, e.re, rel_tol = EPS, abs_tol = EPS));
+        assert!(is_close!(z.im, e.im, rel_tol = EPS, abs_tol = EPS));
+    }
+}
+
+#[test]
+/// Source: gsl/poly/test.c
+fn test_gsl_complex_solve2() {
+    // (x-1)(x-2)(x-3)(x-4)(x-5)
+    let p = Polynomial::build(&[-120.0, 274.0, -225.0, 85.0, -15.0, 1.0]).unwrap();
+    let z = sorted(p.solve_complex().unwrap());
+
+    for (i, z) in z.iter().enumerate() {
+        assert!(is_close!(z.re, (i + 1) as f64, rel_tol = 1e-9));
+        assert!(is_close!(z.im, 0.0, abs_tol = EPS));
+    }
+}
+
+#[test]
+/// Source: gsl/poly/test.c
+fn test_gsl_complex_solve3() {
+    // x⁸+x⁴+1
+    let p = Polynomial::build(&[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]).unwrap();
+    let z = sorted(p.solve_complex().unwrap());
+
+    let (s, c) = (0.5, 3f64.sqrt() / 2.0);
+    let expected = [
+        (-c, -s),
+        (-c, s),
+        (-s, -c),
+        (-s, c),
+        (s, -c),
+        (s, c),
+        (c, -s),
+        (c, s),
+    ];
+
+    for (z, (re, im)) in z.iter().zip(expected) {
+        assert!(is_close!(z.re, re, rel_tol = EPS));
+        assert!(is_close!(z.im, im, rel_tol = EPS));
+    }
+}
+
+#[test]
+/// Source: gsl/poly/test.c
+fn test_gsl_complex_solve_quadratic() {
+    // 4x²-20x+26
+    let p = Polynomial::build(&[26.0, -20.0, 4.0]).unwrap();
+    let [z0, z1] = p.solve_complex_quadratic().unwrap();
+
+    assert!(is_close!(z0.re, 2.5, rel_tol = EPS));
+    assert!(is_close!(z0.im, -0.5, rel_tol = EPS));
+    assert!(is_close!(z1.re, 2.5, rel_tol = EPS));
+    assert!(is_close!(z1.im, 0.5, rel_tol = EPS));
+
+    // 4x²-20x+25
+    let p = Polynomial::build(&[25.0, -20.0, 4.0]).unwrap();
+    let [z0, z1] = p.solve_complex_quadratic().unwrap();
+
+    assert!(is_close!(z0.re, 2.5, rel_tol = EPS));
+    assert!(is_close!(z1.re, 2.5, rel_tol = EPS));
+    assert_eq!((z0.im, z1.im), (0.0, 0.0));
+}
+
+#[test]
+/// Source: gsl/poly/test.c
+fn test_gsl_complex_solve_cubic() {
+    // x³-27
+    let p = Polynomial::build(&[-27.0, 0.0, 0.0, 1.0]).unwrap();
+    let [z0, z1, z2] = p.solve_complex_cubic().unwrap();
+    let s = 3.0 * 3f64.sqrt() / 2.0;
+
+    assert!(is_close!(z0.re, -1.5, rel_tol = EPS));
+    assert!(is_close!(z0.im, -s, rel_tol = EPS));
+    assert!(is_close!(z1.re, -1.5, rel_tol = EPS));
+    assert!(is_close!(z1.im, s, rel_tol = EPS));
+    assert!(is_close!(z2.re, 3.0, rel_tol = EPS));
+    assert!(is_close!(z2.im, 0.0, abs_tol = EPS));
+
+    // (x+1)(x-1)(x-1) = x³-x²-x+1
+    let p = Polynomial::build(&[1.0, -1.0, -1.0, 1.0]).unwrap();
+    let z = p.solve_complex_cubic().unwrap();
+    for (z, e) in z.iter().zip([-1.0, 1.0, 1.0]) {
+        assert!(is_close!(z.re, e, rel_tol = 1e-7));
+        assert!(z.im.abs() < 1e-7);
+    }
+}
@@ -31,7 +31,7 @@ fn test_gsl_quadratic2() {
 fn test_gsl_quadratic3() {
     let p = Polynomial::build(&[21.0, -20.0, 4.0]).unwrap();
     let y = p.solve_real_quadratic().unwrap();
-    let expected = [3.5, 1.5];
+    let expected = [1.5, 3.5];
 
     assert!(is_close!(y[0], expected[0], rel_tol = EPS));
     assert!(is_close!(y[1], expected[1], rel_tol = EPS));
@@ -42,7 +42,7 @@ fn test_gsl_quadratic3() {
 fn test_gsl_quadratic4() {
     let p = Polynomial::build(&[0.0, 7.0, 4.0]).unwrap();
     let y = p.solve_real_quadratic().unwrap();
-    let expected = [0.0, -1.75];
+    let expected = [-1.75, 0.0];
 
     assert!(is_close!(y[0], expected[0], rel_tol = EPS));
     assert!(is_close!(y[1], expected[1], rel_tol = EPS));
@@ -53,7 +53,7 @@ fn test_gsl_quadratic4() {
 fn test_gsl_quadratic5() {
     let p = Polynomial::build(&[-20.0, 0.0, 5.0]).unwrap();
     let y = p.solve_real_quadratic().unwrap();
-    let expected = [2.0, -2.0];
+    let expected = [-2.0, 2.0];
 
     assert!(is_close!(y[0], expected[0], rel_tol = EPS));
     assert!(is_close!(y[1], expected[1], rel_tol = EPS));
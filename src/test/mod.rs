@@ -1,6 +1,67 @@
+mod gsl_test_complex_solve;
 mod gsl_test_cubic;
 mod gsl_test_eval;
 mod gsl_test_quadratic;
 
+mod test_approx;
+#[cfg(feature = "proptest")]
+mod test_arbitrary;
+mod test_arith;
+#[cfg(feature = "ndarray")]
+mod test_array;
+mod test_bases;
+mod test_basis;
+mod test_batch;
+mod test_bezier;
+mod test_bracket;
+mod test_check;
+mod test_compat;
+mod test_complex_roots;
+mod test_contour;
+mod test_decompose;
+mod test_distance;
+mod test_dsp;
+mod test_eigen;
+mod test_eval_clamped;
+mod test_exact;
+mod test_extrema;
+#[cfg(feature = "ffi")]
+mod test_ffi;
+mod test_fit;
+mod test_fixed;
+mod test_gradient;
+mod test_homotopy;
+mod test_impact;
+mod test_integer;
+mod test_key;
+mod test_lut;
+#[cfg(feature = "nalgebra")]
+mod test_matrix;
+mod test_monotone;
+mod test_multi;
+mod test_online;
+mod test_piecewise;
+mod test_polymatrix;
 mod test_polynomial;
+mod test_pretty;
 mod test_quadratic;
+mod test_quadrature;
+mod test_rational;
+mod test_reproducible;
+mod test_robust;
+mod test_roots;
+mod test_sample;
+mod test_series;
+mod test_sign;
+mod test_solver_state;
+mod test_sparse;
+mod test_special;
+mod test_spline;
+mod test_structured;
+#[cfg(feature = "tracing")]
+mod test_tracing;
+mod test_tracker;
+mod test_trig;
+mod test_vieta;
+#[cfg(feature = "wasm-bindgen")]
+mod test_wasm;
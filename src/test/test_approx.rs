@@ -0,0 +1,217 @@
+use is_close::is_close;
+
+use crate::approx::{RemezOptions, remez};
+use crate::{PolyErrorKind, Polynomial};
+
+#[test]
+fn test_remez_exp_linear() {
+    // The best line equioscillates at 0, ln(e − 1) and 1
+    let e = std::f64::consts::E;
+    let xi = (e - 1.0).ln();
+    let expected = 0.5 * (2.0 - e + (e - 1.0) * xi);
+
+    let res = remez(f64::exp, 0.0, 1.0, 1, RemezOptions::default()).unwrap();
+    assert!(is_close!(res.error, expected, rel_tol = 1e-8));
+    assert!(is_close!(res.poly.coef[1], e - 1.0, rel_tol = 1e-8));
+    assert!(is_close!(res.reference[1], xi, abs_tol = 1e-6));
+    assert_eq!(res.reference[0], 0.0);
+    assert_eq!(res.reference[2], 1.0);
+}
+
+#[test]
+fn test_remez_equioscillation() {
+    let (a, b) = (-1.0, 2.0);
+    let f = |x: f64| (x * x + 1.0).sqrt() * x.sin();
+    let res = remez(f, a, b, 6, RemezOptions::default()).unwrap();
+
+    assert_eq!(res.poly.coef.len(), 7);
+    assert_eq!(res.reference.len(), 8);
+    assert!(res.levelled_error <= res.error);
+    assert!(res.error - res.levelled_error <= 1e-8 * res.error);
+
+    // Alternating signs with the levelled magnitude on the reference
+    let errors: Vec<f64> = res
+        .reference
+        .iter()
+        .map(|x| f(*x) - res.poly.eval(*x))
+        .collect();
+    for pair in errors.windows(2) {
+        assert!(pair[0] * pair[1] < 0.0);
+    }
+    for err in &errors {
+        assert!(is_close!(err.abs(), res.error, rel_tol = 1e-6));
+    }
+
+    // ... and nowhere larger
+    for i in 0..=3000 {
+        let x = a + (b - a) * i as f64 / 3000.0;
+        assert!((f(x) - res.poly.eval(x)).abs() <= res.error * (1.0 + 1e-6));
+    }
+}
+
+#[test]
+fn test_remez_abs() {
+    // x² + 1/8, with error 1/8 and 5 alternations
+    let res = remez(f64::abs, -1.0, 1.0, 3, RemezOptions::default()).unwrap();
+    assert!(is_close!(res.error, 0.125, rel_tol = 1e-8));
+    assert!(is_close!(res.poly.coef[0], 0.125, abs_tol = 1e-8));
+    assert!(is_close!(res.poly.coef[1], 0.0, abs_tol = 1e-8));
+    assert!(is_close!(res.poly.coef[2], 1.0, rel_tol = 1e-8));
+    assert!(is_close!(res.poly.coef[3], 0.0, abs_tol = 1e-8));
+}
+
+#[test]
+fn test_remez_polynomial() {
+    // A polynomial of lower order is reproduced exactly
+    let poly = Polynomial::build(&[1.0, 2.0, -1.0]).unwrap();
+    let res = remez(|x| poly.eval(x), 2.0, 5.0, 4, RemezOptions::default()).unwrap();
+    assert!(res.error < 1e-13);
+    for (c, expected) in res.poly.coef.iter().zip([1.0, 2.0, -1.0, 0.0, 0.0]) {
+        assert!(is_close!(*c, expected, abs_tol = 1e-10));
+    }
+
+    let res = remez(|_| 3.0, -1.0, 1.0, 0, RemezOptions::default()).unwrap();
+    assert_eq!(res.poly.coef.len(), 1);
+    assert!(is_close!(res.poly.coef[0], 3.0));
+}
+
+#[test]
+fn test_remez_errors() {
+    let opts = RemezOptions::default();
+    let kind = |res: crate::Result<_>| {
+        res.map(|_: crate::approx::MinimaxResult| ())
+            .unwrap_err()
+            .kind()
+    };
+
+    assert_eq!(
+        kind(remez(f64::exp, 1.0, 1.0, 2, opts)),
+        PolyErrorKind::InvalidInterval
+    );
+    assert_eq!(
+        kind(remez(f64::exp, 0.0, f64::NAN, 2, opts)),
+        PolyErrorKind::InvalidInterval
+    );
+    assert_eq!(
+        kind(remez(f64::ln, -1.0, 1.0, 2, opts)),
+        PolyErrorKind::InvalidData
+    );
+
+    let opts = RemezOptions {
+        max_iter: 1,
+        ..opts
+    };
+    assert_eq!(
+        kind(remez(f64::exp, -1.0, 1.0, 5, opts)),
+        PolyErrorKind::NoConvergence
+    );
+}
+
+#[test]
+fn test_chebyshev_fit() {
+    let f = |x: f64| 1.0 / (1.0 + 25.0 * x * x);
+    let fit = Polynomial::chebyshev_fit(f, -1.0, 1.0, 40).unwrap();
+    assert_eq!(fit.coef.len(), 41);
+
+    // Interpolates at the nodes, and converges for Runge's function
+    let node = (std::f64::consts::PI * 3.5 / 41.0).cos();
+    assert!(is_close!(fit.eval(node), f(node), abs_tol = 1e-14));
+    for i in 0..=200 {
+        let x = -1.0 + i as f64 / 100.0;
+        assert!((fit.eval(x) - f(x)).abs() < 1e-3);
+    }
+
+    // The odd coefficients of an even function vanish
+    assert!(fit.coef.iter().skip(1).step_by(2).all(|c| c.abs() < 1e-15));
+}
+
+#[test]
+fn test_chebyshev_fit_polynomial() {
+    // A polynomial of order n is reproduced, on any interval
+    let poly = Polynomial::build(&[2.0, -1.0, 0.5, 3.0]).unwrap();
+    let fit = Polynomial::chebyshev_fit(|x| poly.eval(x), 1.0, 4.0, 3).unwrap();
+    let monomial = fit.to_monomial();
+    for (c, expected) in monomial.coef.iter().zip(&poly.coef) {
+        assert!(is_close!(*c, *expected, rel_tol = 1e-11, abs_tol = 1e-11));
+    }
+
+    // T₀, ..., T₃ of x on [-1, 1]
+    let fit = Polynomial::chebyshev_fit(|x| x * x * x, -1.0, 1.0, 3).unwrap();
+    for (c, expected) in fit.coef.iter().zip([0.0, 0.75, 0.0, 0.25]) {
+        assert!(is_close!(*c, expected, abs_tol = 1e-15));
+    }
+
+    let fit = Polynomial::chebyshev_fit(|_| 2.5, 0.0, 1.0, 0).unwrap();
+    assert_eq!(fit.coef, vec![2.5]);
+}
+
+#[test]
+fn test_chebyshev_fit_near_minimax() {
+    let fit = Polynomial::chebyshev_fit(f64::exp, 0.0, 1.0, 5).unwrap();
+    let best = remez(f64::exp, 0.0, 1.0, 5, RemezOptions::default()).unwrap();
+    let error = (0..=1000)
+        .map(|i| i as f64 / 1000.0)
+        .map(|x| (fit.eval(x) - x.exp()).abs())
+        .fold(0.0, f64::max);
+    assert!(error >= best.error && error < 2.0 * best.error);
+}
+
+#[test]
+fn test_chebyshev_fit_errors() {
+    let kind = |res: crate::Result<crate::approx::ChebyshevFit>| res.unwrap_err().kind();
+    assert_eq!(
+        kind(Polynomial::chebyshev_fit(f64::exp, 2.0, 1.0, 3)),
+        PolyErrorKind::InvalidInterval
+    );
+    assert_eq!(
+        kind(Polynomial::chebyshev_fit(f64::ln, -1.0, 1.0, 3)),
+        PolyErrorKind::InvalidData
+    );
+}
+
+#[test]
+fn test_chebyshev_calculus() {
+    let (a, b) = (-2.0, 5.0);
+    let fit = Polynomial::chebyshev_fit(f64::cos, a, b, 50).unwrap();
+    let derivative = fit.derivative();
+    let integral = fit.integral();
+    assert_eq!(derivative.coef.len(), 50);
+    assert_eq!(integral.coef.len(), 52);
+    assert_eq!((integral.a, integral.b), (a, b));
+
+    for i in 0..=70 {
+        let x = a + (b - a) * i as f64 / 70.0;
+        assert!(is_close!(derivative.eval(x), -x.sin(), abs_tol = 1e-11));
+        assert!(is_close!(
+            integral.eval(x),
+            x.sin() - a.sin(),
+            abs_tol = 1e-13
+        ));
+    }
+    assert!(is_close!(integral.eval(a), 0.0, abs_tol = 1e-15));
+
+    // Integration and differentiation are inverse
+    let back = integral.derivative();
+    for (c, expected) in back.coef.iter().zip(&fit.coef) {
+        assert!(is_close!(*c, *expected, abs_tol = 1e-15));
+    }
+}
+
+#[test]
+fn test_chebyshev_calculus_monomial() {
+    // Agrees with the monomial calculus at low order
+    let poly = Polynomial::build(&[1.0, -3.0, 0.5, 2.0]).unwrap();
+    let fit = Polynomial::chebyshev_fit(|x| poly.eval(x), 0.0, 2.0, 3).unwrap();
+    let expected = [-3.0, 1.0, 6.0];
+    for (c, e) in fit.derivative().to_monomial().coef.iter().zip(expected) {
+        assert!(is_close!(*c, e, abs_tol = 1e-12));
+    }
+    let expected = [0.0, 1.0, -1.5, 0.5 / 3.0, 0.5];
+    for (c, e) in fit.integral().to_monomial().coef.iter().zip(expected) {
+        assert!(is_close!(*c, e, abs_tol = 1e-12));
+    }
+
+    let constant = Polynomial::chebyshev_fit(|_| 2.0, 1.0, 3.0, 0).unwrap();
+    assert_eq!(constant.derivative().coef, vec![0.0]);
+    assert!(is_close!(constant.integral().eval(3.0), 4.0));
+}
@@ -0,0 +1,54 @@
+use num::complex::Complex64;
+use proptest::prelude::*;
+
+use crate::{Polynomial, arbitrary};
+
+proptest! {
+    #[test]
+    fn test_arbitrary_is_finite(poly in any::<Polynomial<f64>>()) {
+        prop_assert!(!poly.coef.is_empty() && poly.coef.len() <= 9);
+        prop_assert!(poly.coef.iter().all(|c| c.is_finite() && c.abs() <= 100.0));
+    }
+
+    #[test]
+    fn test_with_roots_are_roots((poly, roots) in arbitrary::with_roots(3, 2)) {
+        prop_assert_eq!(poly.coef.len(), 8);
+        let complex = Polynomial {
+            coef: poly.coef.iter().map(|c| Complex64::new(*c, 0.0)).collect(),
+        };
+        prop_assert!(complex.verify_roots(&roots).max_relative_residual < 1e-12);
+    }
+
+    #[test]
+    fn test_solve_reconstruct_round_trip((poly, _) in arbitrary::with_roots(4, 2)) {
+        let roots = poly.solve_complex().unwrap();
+        let complex = Polynomial {
+            coef: poly.coef.iter().map(|c| Complex64::new(*c, 0.0)).collect(),
+        };
+        let report = complex.verify_roots(&roots);
+        prop_assert!(report.count_matches);
+        prop_assert!(report.max_relative_residual < 1e-10, "{:?}", report);
+    }
+
+    #[test]
+    fn test_factor_real_round_trip((poly, _) in arbitrary::with_roots(3, 2)) {
+        let (factors, _) = poly.factor_real().unwrap();
+        let product = factors
+            .iter()
+            .fold(Polynomial::build(&[1.0]).unwrap(), |acc, f| &acc * f);
+        let scale = poly.coef.iter().fold(0.0, |m: f64, c| m.max(c.abs()));
+        prop_assert_eq!(product.coef.len(), poly.coef.len());
+        for (a, b) in product.coef.iter().zip(&poly.coef) {
+            prop_assert!((a - b).abs() <= 1e-8 * scale, "{:?} vs {:?}", product, poly);
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+#[test]
+fn test_quickcheck_arbitrary() {
+    fn finite(poly: Polynomial<f64>) -> bool {
+        !poly.coef.is_empty() && poly.coef.iter().all(|c| c.is_finite() && c.abs() <= 100.0)
+    }
+    quickcheck::quickcheck(finite as fn(Polynomial<f64>) -> bool);
+}
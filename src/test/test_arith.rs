@@ -0,0 +1,186 @@
+use is_close::is_close;
+use num::complex::Complex64;
+
+use crate::Polynomial;
+
+#[test]
+fn test_add_sub() {
+    let p = Polynomial::build(&[1.0, 2.0, 3.0]).unwrap();
+    let q = Polynomial::build(&[4.0, 5.0]).unwrap();
+
+    assert_eq!((&p + &q).coef, [5.0, 7.0, 3.0]);
+    assert_eq!((&q + &p).coef, [5.0, 7.0, 3.0]);
+    assert_eq!((&p - &q).coef, [-3.0, -3.0, 3.0]);
+    assert_eq!((&q - &p).coef, [3.0, 3.0, -3.0]);
+    assert_eq!((-&p).coef, [-1.0, -2.0, -3.0]);
+
+    // Leading terms cancelling out are not trimmed
+    assert_eq!((&p - &p).coef, [0.0, 0.0, 0.0]);
+    assert_eq!((p + q).coef, [5.0, 7.0, 3.0]);
+}
+
+#[test]
+fn test_mul() {
+    let p = Polynomial::build(&[1.0, 1.0]).unwrap();
+    let q = Polynomial::build(&[-1.0, 1.0]).unwrap();
+
+    assert_eq!((&p * &q).coef, [-1.0, 0.0, 1.0]);
+    assert_eq!((&p * &p).coef, [1.0, 2.0, 1.0]);
+    assert_eq!((&p * &Polynomial::new()).coef, [0.0, 0.0]);
+
+    // Trimmed zero polynomials have no coefficients
+    let empty: Polynomial<f64> = Polynomial { coef: vec![] };
+    assert_eq!((&empty * &empty).coef, [0.0]);
+    assert_eq!((&p * &empty).coef, [0.0]);
+
+    let i = Polynomial::build(&[Complex64::new(0.0, 1.0)]).unwrap();
+    assert_eq!((&i * &i).coef, [Complex64::new(-1.0, 0.0)]);
+}
+
+#[test]
+fn test_derivative_antiderivative() {
+    let poly = Polynomial::build(&[1.0, 4.0, 3.0, 8.0]).unwrap();
+
+    assert_eq!(poly.derivative().coef, [4.0, 6.0, 24.0]);
+    assert_eq!(poly.antiderivative().coef, [0.0, 1.0, 2.0, 1.0, 2.0]);
+    assert_eq!(poly.antiderivative().derivative().coef, poly.coef);
+    assert_eq!(Polynomial::build(&[5.0]).unwrap().derivative().coef, [0.0]);
+
+    let x = 0.7;
+    assert!(is_close!(
+        poly.derivative().eval(x),
+        poly.eval_derivs(x, 2)[1],
+        rel_tol = 1e-15
+    ));
+}
+
+#[test]
+fn test_div_rem() {
+    let p = Polynomial::build(&[1.0, 2.0, 0.0, 3.0, 4.0]).unwrap();
+    let d = Polynomial::build(&[1.0, 0.0, 2.0]).unwrap();
+    let (q, r) = p.div_rem(&d).unwrap();
+
+    assert_eq!(q.coef.len(), 3);
+    assert_eq!(r.coef.len(), 2);
+    let back = &(&q * &d) + &r;
+    for (a, b) in back.coef.iter().zip(&p.coef) {
+        assert!(is_close!(*a, *b, abs_tol = 1e-14));
+    }
+
+    // Lower order dividend
+    let (q, r) = d.div_rem(&p).unwrap();
+    assert_eq!(q.coef, [0.0]);
+    assert_eq!(r.coef, d.coef);
+
+    // Constant divisor, with trailing zeros
+    let c = Polynomial::build(&[2.0, 0.0]).unwrap();
+    let (q, r) = p.div_rem(&c).unwrap();
+    assert_eq!(q.coef, [0.5, 1.0, 0.0, 1.5, 2.0]);
+    assert_eq!(r.coef, [0.0]);
+
+    assert!(matches!(
+        p.div_rem(&Polynomial::new()).unwrap_err(),
+        crate::PolyError::DivisionByZero
+    ));
+}
+
+#[test]
+fn test_axpy() {
+    let mut poly = Polynomial::build(&[1.0, 2.0, 3.0]).unwrap();
+    let short = Polynomial::build(&[1.0, 1.0]).unwrap();
+    let long = Polynomial::build(&[0.0, 0.0, 0.0, 1.0]).unwrap();
+
+    poly.axpy(-2.0, &short);
+    assert_eq!(poly.coef, [-1.0, 0.0, 3.0]);
+
+    poly.axpy(0.5, &long);
+    assert_eq!(poly.coef, [-1.0, 0.0, 3.0, 0.5]);
+
+    // Linear combination of the monomial basis
+    let mut combination = Polynomial::new();
+    for (k, alpha) in [2.0, -1.0, 4.0].iter().enumerate() {
+        let mut coef = vec![0.0; k + 1];
+        coef[k] = 1.0;
+        combination.axpy(*alpha, &Polynomial::build(&coef).unwrap());
+    }
+    assert_eq!(combination.coef, [2.0, -1.0, 4.0]);
+
+    let mut complex = Polynomial::build(&[Complex64::new(1.0, 0.0)]).unwrap();
+    complex.axpy(
+        Complex64::new(0.0, 1.0),
+        &Polynomial::build(&[Complex64::new(0.0, 1.0)]).unwrap(),
+    );
+    assert_eq!(complex.coef, [Complex64::new(0.0, 0.0)]);
+}
+
+#[test]
+fn test_scalar_ops() {
+    let p: Polynomial<f64> = Polynomial::build(&[1.0, 2.0, 3.0]).unwrap();
+
+    assert_eq!((&p + 1.0).coef, [2.0, 2.0, 3.0]);
+    assert_eq!((&p - 1.0).coef, [0.0, 2.0, 3.0]);
+    assert_eq!((&p * 2.0).coef, [2.0, 4.0, 6.0]);
+    assert_eq!((&p / 2.0).coef, [0.5, 1.0, 1.5]);
+
+    assert_eq!((1.0 + &p).coef, [2.0, 2.0, 3.0]);
+    assert_eq!((1.0 - &p).coef, [0.0, -2.0, -3.0]);
+    assert_eq!((2.0 * &p).coef, [2.0, 4.0, 6.0]);
+
+    assert_eq!((2.0 * p.clone() + 1.0).coef, [3.0, 4.0, 6.0]);
+    assert_eq!((p / 4.0 - 0.25).coef, [0.0, 0.5, 0.75]);
+
+    // Trimmed zero polynomials have no coefficients
+    let zero: Polynomial<f64> = Polynomial::build(&[0.0, 0.0]).unwrap().to_trimmed();
+    assert_eq!((&zero + 1.0).coef, [1.0]);
+    assert_eq!((1.0 - &zero).coef, [1.0]);
+    assert_eq!((&zero * 2.0).coef, [0.0]);
+
+    let i = Complex64::new(0.0, 1.0);
+    let pc = Polynomial::build(&[Complex64::new(1.0, 0.0), i]).unwrap();
+    assert_eq!((i * &pc).coef, [i, Complex64::new(-1.0, 0.0)]);
+
+    let pf = Polynomial::build(&[1.0f32, 2.0]).unwrap();
+    assert_eq!((3.0f32 - pf).coef, [2.0, -2.0]);
+}
+
+#[test]
+fn test_mul_mod_pow_mod() {
+    let modulus = Polynomial::build(&[2.0, -1.0, 0.5, 1.0]).unwrap();
+    let p = Polynomial::build(&[0.5, -1.0, 0.25]).unwrap();
+
+    let mut naive = Polynomial::build(&[1.0]).unwrap();
+    for k in 0..8u64 {
+        let expected = naive.div_rem(&modulus).unwrap().1;
+        let res = p.pow_mod(k, &modulus).unwrap();
+        assert!(res.approx_eq(&expected, 1e-12, 1e-12), "{k}: {res:?}");
+        naive = &naive * &p;
+    }
+
+    let q = Polynomial::build(&[1.0, 1.0, 1.0, 1.0, 1.0]).unwrap();
+    let expected = (&p * &q).div_rem(&modulus).unwrap().1;
+    assert!(
+        p.mul_mod(&q, &modulus)
+            .unwrap()
+            .approx_eq(&expected, 1e-12, 1e-12)
+    );
+}
+
+#[test]
+fn test_pow_mod_cyclic() {
+    // i·x in C[x]/(x⁴−1): (ix)⁴ = x⁴ = 1
+    let modulus =
+        Polynomial::build(&[-1.0, 0.0, 0.0, 0.0, 1.0].map(|c| Complex64::new(c, 0.0))).unwrap();
+    let p = Polynomial::build(&[Complex64::new(0.0, 0.0), Complex64::new(0.0, 1.0)]).unwrap();
+    let res = p.pow_mod(4, &modulus).unwrap();
+    assert!(res.approx_eq(
+        &Polynomial::build(&[Complex64::new(1.0, 0.0)]).unwrap(),
+        0.0,
+        0.0
+    ));
+
+    // Reduction modulo a constant is always 0
+    let constant = Polynomial::build(&[2.0]).unwrap();
+    let p = Polynomial::build(&[1.0, 2.0]).unwrap();
+    assert_eq!(p.pow_mod(3, &constant).unwrap().coef, [0.0]);
+    assert!(p.pow_mod(3, &Polynomial::build(&[0.0]).unwrap()).is_err());
+}
@@ -0,0 +1,63 @@
+use ndarray::{Array1, array};
+use num::complex::Complex64;
+
+use crate::{PolyError, Polynomial};
+
+#[test]
+fn test_from_array() {
+    let coef = array![1.0, 2.0, 3.0];
+    let poly = Polynomial::from_array(coef.view()).unwrap();
+    assert_eq!(poly.coef, [1.0, 2.0, 3.0]);
+    assert_eq!(poly.to_array(), coef);
+
+    // Non-contiguous views are copied
+    let matrix = array![[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]];
+    let poly = Polynomial::from_array(matrix.column(0)).unwrap();
+    assert_eq!(poly.coef, [1.0, 2.0, 3.0]);
+
+    let empty = Array1::<f64>::zeros(0);
+    assert_eq!(Polynomial::from_array(empty.view()).unwrap().coef, [0.0]);
+
+    let nan = array![1.0, f64::NAN];
+    assert!(matches!(
+        Polynomial::from_array(nan.view()),
+        Err(PolyError::InvalidCoefficients { index: 1, .. })
+    ));
+}
+
+#[test]
+fn test_eval_array() {
+    let poly = Polynomial::build(&[1.0, 2.0, 3.0]).unwrap();
+    let xs = Array1::linspace(-2.0, 2.0, 9);
+    let ys = poly.eval_array(xs.view());
+    assert_eq!(ys.len(), 9);
+    xs.iter()
+        .zip(&ys)
+        .for_each(|(x, y)| assert_eq!(*y, poly.eval(*x)));
+
+    let poly = Polynomial::build(&[Complex64::new(0.0, 1.0), Complex64::new(1.0, 0.0)]).unwrap();
+    let zs = array![[Complex64::new(1.0, 0.0)], [Complex64::new(0.0, -1.0)]];
+    let res = poly.eval_array(zs.view());
+    assert_eq!(res.shape(), &[2, 1]);
+    assert_eq!(res[[1, 0]], Complex64::default());
+}
+
+#[test]
+fn test_fit_array() {
+    let xs = Array1::linspace(0.0, 3.0, 10);
+    let ys = xs.mapv(|x| 1.0 - 2.0 * x + 0.5 * x * x);
+    let fit = Polynomial::fit_array(xs.view(), ys.view(), 2).unwrap();
+    let expected = Polynomial::fit(xs.as_slice().unwrap(), ys.as_slice().unwrap(), 2).unwrap();
+    assert_eq!(fit.poly, expected.poly);
+
+    // Strided views give the same fit
+    let data = ndarray::stack![ndarray::Axis(1), xs, ys];
+    let weights = Array1::from_elem(10, 1.0);
+    let fit = Polynomial::fit_weighted_array(data.column(0), data.column(1), weights.view(), 2);
+    assert!(fit.unwrap().poly.approx_eq(&expected.poly, 1e-12, 1e-12));
+
+    assert!(matches!(
+        Polynomial::fit_array(xs.view(), ys.slice(ndarray::s![..5]), 2),
+        Err(PolyError::LengthMismatch(10, 5))
+    ));
+}
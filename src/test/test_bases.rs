@@ -0,0 +1,180 @@
+use is_close::is_close;
+
+use crate::approx::ChebyshevFit;
+use crate::bases::{BernsteinPoly, LegendreSeries, NewtonPoly, PolynomialBasis};
+use crate::{PolyErrorKind, Polynomial};
+
+const INTERVAL: (f64, f64) = (-1.0, 3.0);
+
+/// Points of the interval at which the forms are compared.
+fn points() -> impl Iterator<Item = f64> {
+    (0..=20).map(|i| -1.0 + i as f64 / 5.0)
+}
+
+fn nodes() -> Vec<f64> {
+    vec![-1.0, 0.5, 3.0, 1.0, -0.25, 2.0]
+}
+
+/// Converts `source` to `B`, and checks the values and derivatives.
+fn check_convert<A: PolynomialBasis, B: PolynomialBasis>(source: &A, params: &B::Params) {
+    let target: B = source.convert(params).unwrap();
+    assert_eq!(target.degree(), source.degree());
+    for x in points() {
+        assert!(is_close!(
+            target.eval(x),
+            source.eval(x),
+            rel_tol = 1e-12,
+            abs_tol = 1e-12
+        ));
+        let (dt, ds) = (target.derivative().eval(x), source.derivative().eval(x));
+        assert!(is_close!(dt, ds, rel_tol = 1e-11, abs_tol = 1e-11));
+    }
+}
+
+/// Checks the conversions of `source` to every form.
+fn check_all<A: PolynomialBasis>(source: &A) {
+    check_convert::<A, Polynomial<f64>>(source, &());
+    check_convert::<A, ChebyshevFit>(source, &INTERVAL);
+    check_convert::<A, LegendreSeries>(source, &INTERVAL);
+    check_convert::<A, BernsteinPoly>(source, &INTERVAL);
+    check_convert::<A, NewtonPoly>(source, &nodes());
+}
+
+#[test]
+fn test_convert_every_pair() {
+    let poly = Polynomial::build(&[0.5, -2.0, 1.0, 0.75, -0.25, 0.125]).unwrap();
+    check_all(&poly);
+    check_all(&poly.convert::<ChebyshevFit>(&INTERVAL).unwrap());
+    check_all(&poly.convert::<LegendreSeries>(&INTERVAL).unwrap());
+    check_all(&poly.convert::<BernsteinPoly>(&INTERVAL).unwrap());
+    check_all(&poly.convert::<NewtonPoly>(&nodes()).unwrap());
+}
+
+#[test]
+fn test_known_coefficients() {
+    // x² = P₀/3 + 2P₂/3
+    let legendre = LegendreSeries::interpolate(|x| x * x, 2, &(-1.0, 1.0)).unwrap();
+    for (c, expected) in legendre.coef.iter().zip([1.0 / 3.0, 0.0, 2.0 / 3.0]) {
+        assert!(is_close!(*c, expected, abs_tol = 1e-15));
+    }
+
+    // The control points of x on [0, 1] are equally spaced
+    let bernstein = BernsteinPoly::interpolate(|x| x, 3, &(0.0, 1.0)).unwrap();
+    for (c, expected) in bernstein.coef.iter().zip([0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0]) {
+        assert!(is_close!(*c, expected, abs_tol = 1e-15));
+    }
+
+    // x² = 0 + 1·x + 1·x(x − 1)
+    let newton = NewtonPoly::interpolate(|x| x * x, 2, &vec![0.0, 1.0, 2.0, 5.0]).unwrap();
+    assert_eq!(newton.coef, vec![0.0, 1.0, 1.0]);
+    assert_eq!(newton.nodes, vec![0.0, 1.0, 2.0]);
+}
+
+#[test]
+fn test_derivative_in_basis() {
+    // Against the derivative of the function, at high order
+    for x in points() {
+        let legendre = LegendreSeries::interpolate(f64::sin, 30, &INTERVAL).unwrap();
+        assert!(is_close!(
+            legendre.derivative().eval(x),
+            x.cos(),
+            abs_tol = 1e-12
+        ));
+    }
+
+    // Constants
+    let bernstein = BernsteinPoly::interpolate(|_| 2.0, 0, &INTERVAL).unwrap();
+    assert_eq!(bernstein.derivative().coef, vec![0.0]);
+    let legendre = LegendreSeries::interpolate(|_| 2.0, 0, &INTERVAL).unwrap();
+    assert_eq!(legendre.derivative().coef, vec![0.0]);
+    let newton = NewtonPoly::interpolate(|_| 2.0, 0, &vec![1.0]).unwrap();
+    assert_eq!(newton.derivative().eval(0.0), 0.0);
+    assert_eq!(newton.derivative().degree(), 0);
+}
+
+#[test]
+fn test_integral_in_basis() {
+    // Against the monomial antiderivative, shifted to vanish at a
+    let poly = Polynomial::build(&[0.5, -2.0, 1.0, 0.75, -0.25, 0.125]).unwrap();
+    let antiderivative = poly.antiderivative();
+    let (a, _) = INTERVAL;
+    let expected = |x: f64| antiderivative.eval(x) - antiderivative.eval(a);
+
+    let legendre = poly
+        .convert::<LegendreSeries>(&INTERVAL)
+        .unwrap()
+        .integral();
+    let bernstein = poly.convert::<BernsteinPoly>(&INTERVAL).unwrap().integral();
+    assert_eq!(legendre.degree(), poly.degree() + 1);
+    assert_eq!(bernstein.degree(), poly.degree() + 1);
+    assert_eq!(bernstein.coef[0], 0.0);
+    for x in points() {
+        assert!(is_close!(legendre.eval(x), expected(x), abs_tol = 1e-11));
+        assert!(is_close!(bernstein.eval(x), expected(x), abs_tol = 1e-11));
+    }
+    assert!(is_close!(legendre.eval(a), 0.0, abs_tol = 1e-14));
+
+    // The derivative of the integral is the original form
+    let legendre = poly.convert::<LegendreSeries>(&INTERVAL).unwrap();
+    let bernstein = poly.convert::<BernsteinPoly>(&INTERVAL).unwrap();
+    for x in points() {
+        let (dl, l) = (legendre.integral().derivative().eval(x), legendre.eval(x));
+        assert!(is_close!(dl, l, rel_tol = 1e-12, abs_tol = 1e-12));
+        let (db, b) = (bernstein.integral().derivative().eval(x), bernstein.eval(x));
+        assert!(is_close!(db, b, rel_tol = 1e-12, abs_tol = 1e-12));
+    }
+
+    // Empty and constant forms
+    let empty = LegendreSeries {
+        coef: vec![],
+        a: 0.0,
+        b: 1.0,
+    };
+    assert_eq!(empty.integral().coef, vec![0.0]);
+    let empty = BernsteinPoly {
+        coef: vec![],
+        a: 0.0,
+        b: 1.0,
+    };
+    assert_eq!(empty.integral().coef, vec![0.0]);
+    let constant = LegendreSeries::interpolate(|_| 2.0, 0, &INTERVAL).unwrap();
+    assert!(is_close!(
+        constant.integral().eval(3.0),
+        8.0,
+        rel_tol = 1e-15
+    ));
+    let constant = BernsteinPoly::interpolate(|_| 2.0, 0, &INTERVAL).unwrap();
+    assert!(is_close!(
+        constant.integral().eval(3.0),
+        8.0,
+        rel_tol = 1e-15
+    ));
+}
+
+#[test]
+fn test_bases_errors() {
+    let kind = |res: crate::Result<LegendreSeries>| res.unwrap_err().kind();
+    assert_eq!(
+        kind(LegendreSeries::interpolate(f64::exp, 3, &(1.0, 1.0))),
+        PolyErrorKind::InvalidInterval
+    );
+    assert_eq!(
+        kind(LegendreSeries::interpolate(f64::ln, 3, &(-1.0, 1.0))),
+        PolyErrorKind::InvalidData
+    );
+    assert_eq!(
+        BernsteinPoly::interpolate(f64::exp, 3, &(0.0, f64::NAN))
+            .unwrap_err()
+            .kind(),
+        PolyErrorKind::InvalidInterval
+    );
+
+    let newton = |nodes: Vec<f64>| {
+        NewtonPoly::interpolate(f64::exp, 2, &nodes)
+            .unwrap_err()
+            .kind()
+    };
+    assert_eq!(newton(vec![0.0, 1.0]), PolyErrorKind::NotEnoughPoints);
+    assert_eq!(newton(vec![0.0, 1.0, 0.0]), PolyErrorKind::SingularMatrix);
+    assert_eq!(newton(vec![0.0, f64::NAN, 1.0]), PolyErrorKind::InvalidData);
+}
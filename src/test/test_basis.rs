@@ -0,0 +1,65 @@
+use is_close::is_close;
+
+use crate::{BasisEvaluator, PolyError, Polynomial};
+
+/// The first Legendre polynomials
+fn legendre() -> Vec<Polynomial<f64>> {
+    vec![
+        Polynomial::build(&[1.0]).unwrap(),
+        Polynomial::build(&[0.0, 1.0]).unwrap(),
+        Polynomial::build(&[-0.5, 0.0, 1.5]).unwrap(),
+        Polynomial::build(&[0.0, -1.5, 0.0, 2.5]).unwrap(),
+    ]
+}
+
+#[test]
+fn test_eval_in_basis() {
+    let basis = legendre();
+    let coeffs = [0.5, -1.0, 2.0, 0.25];
+
+    // Compare with the expansion converted to monomial form
+    let mut monomial = Polynomial::new();
+    for (c, phi) in coeffs.iter().zip(&basis) {
+        monomial.axpy(*c, phi);
+    }
+
+    for x in [-1.0, -0.3, 0.0, 0.8] {
+        let value = Polynomial::eval_in_basis(&coeffs, &basis, x).unwrap();
+        assert!(is_close!(value, monomial.eval(x), abs_tol = 1e-15));
+    }
+
+    assert!(matches!(
+        Polynomial::eval_in_basis(&coeffs[..2], &basis, 0.0).unwrap_err(),
+        PolyError::LengthMismatch(2, 4)
+    ));
+}
+
+#[test]
+fn test_basis_evaluator() {
+    let basis = legendre();
+    let evaluator = BasisEvaluator::new(&basis);
+    assert_eq!(evaluator.len(), 4);
+
+    let mut values = [0.0; 4];
+    for x in [-1.0, -0.3, 0.0, 0.8, 1.0] {
+        evaluator.values(x, &mut values).unwrap();
+        for (v, phi) in values.iter().zip(&basis) {
+            assert!(is_close!(*v, phi.eval(x), abs_tol = 1e-15));
+        }
+
+        let coeffs = [0.5, -1.0, 2.0, 0.25];
+        assert!(is_close!(
+            evaluator.eval(&coeffs, x).unwrap(),
+            Polynomial::eval_in_basis(&coeffs, &basis, x).unwrap(),
+            abs_tol = 1e-15
+        ));
+    }
+
+    // Legendre polynomials are 1 at x=1
+    evaluator.values(1.0, &mut values).unwrap();
+    assert_eq!(values, [1.0; 4]);
+
+    assert!(evaluator.values(0.0, &mut [0.0; 3]).is_err());
+    assert!(evaluator.eval(&[1.0], 0.0).is_err());
+    assert!(BasisEvaluator::<f64>::new(&[]).is_empty());
+}
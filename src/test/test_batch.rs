@@ -0,0 +1,125 @@
+use crate::{CubicRoots, QuadraticRoots, batch};
+use crate::{PolyError, solve};
+
+/// Coefficients of (x − r₀)(x − r₁)⋯ scaled by `a`, leading coefficient first.
+fn expand<const N: usize>(a: f64, roots: &[f64]) -> [f64; N] {
+    let mut coef = vec![a];
+    for r in roots {
+        coef.push(0.0);
+        for k in (1..coef.len()).rev() {
+            coef[k] -= r * coef[k - 1];
+        }
+    }
+    coef.try_into().unwrap()
+}
+
+#[test]
+fn test_quadratic_batch_matches_single() {
+    let coefs: Vec<[f64; 3]> = (0..37)
+        .map(|i| {
+            let t = i as f64;
+            [1.0 + 0.1 * t, -3.0 + 0.25 * t, 2.0 - 0.5 * t]
+        })
+        .collect();
+    let batch = batch::solve_real_quadratic_batch(&coefs);
+
+    assert_eq!(batch.len(), coefs.len());
+    for (roots, [a, b, c]) in batch.iter().zip(&coefs) {
+        let single = solve::quadratic(*a, *b, *c).unwrap().to_vec();
+        let roots = roots.as_ref().unwrap().to_vec();
+        assert_eq!(roots.len(), single.len());
+        for (x, y) in roots.iter().zip(&single) {
+            assert!((x - y).abs() <= 1e-14 * y.abs().max(1.0));
+        }
+    }
+}
+
+#[test]
+fn test_cubic_batch() {
+    let coefs: Vec<[f64; 4]> = (0..20)
+        .map(|i| expand(2.0 + i as f64, &[-1.0, 0.5 * i as f64, 3.0 + i as f64]))
+        .collect();
+
+    for (i, roots) in batch::solve_real_cubic_batch(&coefs).iter().enumerate() {
+        let CubicRoots::Three(x) = roots.as_ref().unwrap() else {
+            panic!("{roots:?}")
+        };
+        let expected = [-1.0, 0.5 * i as f64, 3.0 + i as f64];
+        for (x, r) in x.iter().zip(expected) {
+            assert!((x - r).abs() < 1e-9 * r.abs().max(1.0), "{x} {r}");
+        }
+    }
+}
+
+#[test]
+fn test_quartic() {
+    let cases: [&[f64]; 4] = [
+        &[-3.0, -1.0, 0.5, 2.0],
+        &[1.0, 1.0, 2.0, 5.0],
+        &[-2.0, -2.0, 2.0, 2.0],
+        &[0.0, 1e-3, 7.0, 100.0],
+    ];
+    for roots in cases {
+        let [a, b, c, d, e] = expand::<5>(1.5, roots);
+        let (x, n) = solve::quartic(a, b, c, d, e).unwrap();
+        assert_eq!(n, 4, "{roots:?}: {x:?}");
+        for (x, r) in x.iter().zip(roots) {
+            assert!((x - r).abs() < 1e-6 * r.abs().max(1.0), "{roots:?}: {x:?}");
+        }
+    }
+
+    // (x² + 1)(x − 1)(x + 3): two real roots
+    let (x, n) = solve::quartic(1.0f64, 2.0, -2.0, 2.0, -3.0).unwrap();
+    assert_eq!(n, 2);
+    assert!((x[0] + 3.0).abs() < 1e-12 && (x[1] - 1.0).abs() < 1e-12);
+    assert!(x[2].is_nan() && x[3].is_nan());
+
+    // Biquadratic (x² − 1)(x² + 4)
+    let (x, n) = solve::quartic(1.0, 0.0, 3.0, 0.0, -4.0).unwrap();
+    assert_eq!((n, x[0], x[1]), (2, -1.0, 1.0));
+
+    assert!(matches!(
+        solve::quartic(0.0, 1.0, 1.0, 1.0, 1.0),
+        Err(PolyError::IncorrectOrder { expected: 4, .. })
+    ));
+
+    let (x, n) = solve::quartic(1.0f32, -10.0, 35.0, -50.0, 24.0).unwrap();
+    assert_eq!(n, 4);
+    assert!((x[3] - 4.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_quartic_batch() {
+    let coefs: Vec<[f64; 5]> = (0..13)
+        .map(|i| expand(1.0, &[-2.0, -0.5, 1.0 + i as f64, 4.0 + i as f64]))
+        .collect();
+
+    for (roots, coef) in batch::solve_real_quartic_batch(&coefs).iter().zip(&coefs) {
+        let [a, b, c, d, e] = *coef;
+        let single = solve::quartic(a, b, c, d, e).unwrap();
+        assert_eq!(roots.as_ref().unwrap(), &single);
+    }
+}
+
+#[test]
+fn test_batch_invalid_lanes() {
+    let roots = batch::solve_real_quadratic_batch(&[
+        [1.0, -3.0, 2.0],
+        [0.0, 1.0, 1.0],
+        [f64::NAN, 1.0, 1.0],
+        [1.0, 0.0, -4.0],
+    ]);
+
+    assert_eq!(roots[0].as_ref().unwrap(), &QuadraticRoots::Two(1.0, 2.0));
+    assert!(matches!(
+        roots[1],
+        Err(PolyError::IncorrectOrder { expected: 2, .. })
+    ));
+    assert!(matches!(
+        roots[2],
+        Err(PolyError::InvalidCoefficients { .. })
+    ));
+    assert_eq!(roots[3].as_ref().unwrap(), &QuadraticRoots::Two(-2.0, 2.0));
+
+    assert!(batch::solve_real_cubic_batch(&[]).is_empty());
+}
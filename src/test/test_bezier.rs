@@ -0,0 +1,95 @@
+use crate::{PolyError, Polynomial};
+
+/// Checks that every root lies in exactly one interval, and that every interval is narrow.
+fn check_enclosures(intervals: &[(f64, f64)], roots: &[f64], tol: f64) {
+    assert_eq!(intervals.len(), roots.len(), "{intervals:?}");
+    for (interval, root) in intervals.iter().zip(roots) {
+        assert!(
+            interval.0 <= *root && *root <= interval.1,
+            "{interval:?} {root}"
+        );
+        assert!(interval.1 - interval.0 <= tol, "{intervals:?}");
+    }
+}
+
+#[test]
+fn test_bezier_roots() {
+    let roots = [-2.0, -0.5, 0.1, 0.3, 1.7];
+    let poly = Polynomial::from_roots(&roots);
+
+    let intervals = poly.roots_in_interval_bezier(-3.0, 3.0, 1e-12).unwrap();
+    check_enclosures(&intervals, &roots, 1e-8);
+
+    // Only the roots inside the interval
+    let intervals = poly.roots_in_interval_bezier(0.0, 1.0, 1e-12).unwrap();
+    check_enclosures(&intervals, &[0.1, 0.3], 1e-8);
+
+    // Roots at the endpoints
+    let intervals = poly.roots_in_interval_bezier(-0.5, 0.1, 1e-12).unwrap();
+    check_enclosures(&intervals, &[-0.5, 0.1], 1e-8);
+}
+
+#[test]
+fn test_bezier_no_roots() {
+    let poly = Polynomial::build(&[1.0, 0.0, 1.0]).unwrap(); // x²+1
+    assert!(
+        poly.roots_in_interval_bezier(-10.0, 10.0, 1e-12)
+            .unwrap()
+            .is_empty()
+    );
+
+    let poly = Polynomial::build(&[2.0]).unwrap();
+    assert!(
+        poly.roots_in_interval_bezier(-1.0, 1.0, 1e-12)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_bezier_wilkinson() {
+    let poly = Polynomial::wilkinson(12);
+    let roots: Vec<f64> = (1..=12).map(|k| k as f64).collect();
+
+    // The roots are ill-conditioned, so the enclosures are limited by the rounding errors of
+    // the coefficients rather than by the tolerance
+    let intervals = poly.roots_in_interval_bezier(0.5, 12.5, 1e-9).unwrap();
+    check_enclosures(&intervals, &roots, 1e-3);
+}
+
+#[test]
+fn test_bezier_double_root() {
+    // (x−1)²(x+1): the double root is enclosed, even though there is no sign change
+    let poly = Polynomial::from_roots(&[1.0, 1.0, -1.0]);
+    let intervals = poly.roots_in_interval_bezier(-2.0, 2.0, 1e-8).unwrap();
+
+    assert_eq!(intervals.len(), 2);
+    assert!(intervals[0].0 <= -1.0 && -1.0 <= intervals[0].1);
+    assert!(intervals[1].0 <= 1.0 && 1.0 <= intervals[1].1);
+    assert!(intervals[1].1 - intervals[1].0 < 1e-4);
+}
+
+#[test]
+fn test_bezier_errors() {
+    let poly = Polynomial::build(&[-1.0, 1.0]).unwrap();
+    assert!(matches!(
+        poly.roots_in_interval_bezier(1.0, 0.0, 1e-12),
+        Err(PolyError::InvalidInterval(_, _))
+    ));
+    assert!(poly.roots_in_interval_bezier(0.0, 1.0, 0.0).is_err());
+    assert!(matches!(
+        Polynomial::build(&[0.0])
+            .unwrap()
+            .roots_in_interval_bezier(0.0, 1.0, 1e-12),
+        Err(PolyError::Trivial)
+    ));
+    for bad in [f64::NAN, f64::INFINITY] {
+        let poly = Polynomial {
+            coef: vec![-1.0, bad, 1.0],
+        };
+        assert!(matches!(
+            poly.roots_in_interval_bezier(0.0, 1.0, 1e-12),
+            Err(PolyError::InvalidCoefficients { index: 1, .. })
+        ));
+    }
+}
@@ -0,0 +1,72 @@
+use is_close::is_close;
+
+use crate::{BracketOptions, PolyError, Polynomial};
+
+#[test]
+fn test_root_in_bracket() {
+    let poly = Polynomial::build(&[-6.0, 11.0, -6.0, 1.0]).unwrap(); // (x-1)(x-2)(x-3)
+    let opts = BracketOptions::default();
+
+    for (a, b, root) in [
+        (0.0, 1.5, 1.0),
+        (1.5, 2.2, 2.0),
+        (2.5, 10.0, 3.0),
+        (-5.0, 1.7, 1.0),
+    ] {
+        let x = poly.root_in_bracket(a, b, opts).unwrap();
+        assert!(is_close!(x, root, rel_tol = 1e-14));
+    }
+}
+
+#[test]
+fn test_root_in_bracket_endpoints() {
+    let poly = Polynomial::build(&[-1.0, 1.0]).unwrap();
+    let opts = BracketOptions::default();
+
+    assert_eq!(poly.root_in_bracket(1.0, 3.0, opts).unwrap(), 1.0);
+    assert_eq!(poly.root_in_bracket(-3.0, 1.0, opts).unwrap(), 1.0);
+}
+
+#[test]
+fn test_root_in_bracket_hard() {
+    // Newton's method alone cycles on x³-2x+2 starting from 0
+    let poly = Polynomial::build(&[2.0, -2.0, 0.0, 1.0]).unwrap();
+    let x = poly
+        .root_in_bracket(-2.0, 2.0, BracketOptions::default())
+        .unwrap();
+    assert!(poly.eval(x).abs() < 1e-14);
+
+    // Odd multiple root, where Newton's method converges only linearly
+    let poly = Polynomial::build(&[-1.0, 3.0, -3.0, 1.0]).unwrap(); // (x-1)³
+    let opts = BracketOptions {
+        xtol: 1e-10,
+        ..Default::default()
+    };
+    let x = poly.root_in_bracket(0.0, 3.0, opts).unwrap();
+    assert!((x - 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_root_in_bracket_errors() {
+    let poly = Polynomial::build(&[1.0, 0.0, 1.0]).unwrap();
+    let opts = BracketOptions::default();
+
+    assert!(matches!(
+        poly.root_in_bracket(-1.0, 1.0, opts),
+        Err(PolyError::RootNotBracketed(-1.0, 1.0))
+    ));
+    assert!(matches!(
+        poly.root_in_bracket(1.0, -1.0, opts),
+        Err(PolyError::InvalidInterval(..))
+    ));
+
+    let poly = Polynomial::build(&[-2.0, 0.0, 1.0]).unwrap();
+    let opts = BracketOptions {
+        max_iter: 1,
+        ..Default::default()
+    };
+    assert!(matches!(
+        poly.root_in_bracket(0.0, 100.0, opts),
+        Err(PolyError::NoConvergence)
+    ));
+}
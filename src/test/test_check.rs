@@ -0,0 +1,48 @@
+use num::complex::Complex64;
+
+use crate::{PolyErrorKind, Polynomial};
+
+#[test]
+fn test_self_check_passes() {
+    let poly = Polynomial::from_roots(&[-3.0, 0.5, 1.0, 2.5, 4.0]) * 2.0;
+    let report = poly.self_check().unwrap();
+    assert!(report.passes(1e-13));
+    assert_eq!(report.roots.unwrap().residuals.len(), 5);
+
+    let i = Complex64::i();
+    let poly = Polynomial::build(&[1.0 + i, -2.0 * i, 0.5 + 0.0 * i, 3.0 - i]).unwrap();
+    assert!(poly.self_check().unwrap().passes(1e-13));
+}
+
+#[test]
+fn test_self_check_constant() {
+    let report = Polynomial::build(&[3.0]).unwrap().self_check().unwrap();
+    assert_eq!(report.roots, Err(PolyErrorKind::ConstantPoly));
+    assert_eq!(report.monic_round_trip, 0.0);
+    assert!(report.passes(0.0));
+
+    let report = Polynomial::<f64>::new().self_check().unwrap();
+    assert!(report.passes(0.0));
+}
+
+#[test]
+fn test_self_check_clustered_roots() {
+    // The roots of (x−1)⁸ are found far from 1, but they are backward stable, which is what the
+    // residuals measure
+    let poly = Polynomial::from_roots(&[1.0; 8]);
+    let report = poly.self_check().unwrap();
+    let roots = poly.solve_complex().unwrap();
+    assert!(roots.iter().any(|z| (z - 1.0).norm() > 1e-3));
+    assert!(report.passes(1e-12));
+}
+
+#[test]
+fn test_self_check_non_finite() {
+    let poly = Polynomial {
+        coef: vec![1.0, f64::NAN],
+    };
+    assert_eq!(
+        poly.self_check().unwrap_err().kind(),
+        PolyErrorKind::InvalidCoefficients
+    );
+}
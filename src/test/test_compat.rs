@@ -0,0 +1,97 @@
+use num::complex::Complex64;
+
+use crate::compat::{polyfit, polyfit_rcond, polyval, roots};
+use crate::{PolyErrorKind, Polynomial};
+
+#[test]
+fn test_polyval() {
+    assert_eq!(polyval(&[1.0, -2.0, 0.0, 5.0], 2.0), 5.0);
+    assert_eq!(polyval::<f64>(&[], 2.0), 0.0);
+
+    let i = Complex64::i();
+    assert_eq!(
+        polyval(
+            &[
+                Complex64::new(1.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                1.0 + 0.0 * i
+            ],
+            i
+        ),
+        0.0 * i
+    );
+}
+
+#[test]
+fn test_polyfit_matches_fit() {
+    let xs: Vec<f64> = (0..20).map(|i| i as f64 * 0.3 - 2.0).collect();
+    let ys: Vec<f64> = xs.iter().map(|x| x.sin() + 0.1 * x * x).collect();
+
+    let p = polyfit(&xs, &ys, 4).unwrap();
+    let fit = Polynomial::fit(&xs, &ys, 4).unwrap();
+    assert_eq!(p.len(), 5);
+    for (a, b) in p.iter().zip(fit.poly.coef_desc()) {
+        assert!((a - b).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_polyfit_keeps_shape() {
+    // The leading coefficients of an exact lower order fit are (almost) 0, but still returned
+    let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+    let ys = [1.0, 3.0, 5.0, 7.0, 9.0];
+    let p = polyfit(&xs, &ys, 3).unwrap();
+    assert_eq!(p.len(), 4);
+    assert!(p[0].abs() < 1e-12 && p[1].abs() < 1e-12);
+    assert!((p[2] - 2.0).abs() < 1e-12 && (p[3] - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_polyfit_rank_deficient() {
+    // Fewer points than coefficients: an exact, minimum-norm interpolant
+    let xs = [-1.0, 1.0];
+    let ys = [0.0, 2.0];
+    let p = polyfit_rcond(&xs, &ys, 3, 1e-10).unwrap();
+    assert_eq!(p.len(), 4);
+    for (x, y) in xs.iter().zip(ys) {
+        assert!((polyval(&p, *x) - y).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_polyfit_errors() {
+    assert_eq!(
+        polyfit(&[1.0], &[1.0, 2.0], 1).unwrap_err().kind(),
+        PolyErrorKind::LengthMismatch
+    );
+    assert_eq!(
+        polyfit(&[], &[], 1).unwrap_err().kind(),
+        PolyErrorKind::NotEnoughPoints
+    );
+    assert_eq!(
+        polyfit(&[1.0, f64::NAN], &[1.0, 2.0], 1)
+            .unwrap_err()
+            .kind(),
+        PolyErrorKind::InvalidData
+    );
+}
+
+#[test]
+fn test_roots() {
+    let mut r = roots(&[1.0, -6.0, 11.0, -6.0]).unwrap();
+    r.sort_by(|a, b| a.re.total_cmp(&b.re));
+    for (z, expected) in r.iter().zip([1.0, 2.0, 3.0]) {
+        assert!((z.re - expected).abs() < 1e-12 && z.im.abs() < 1e-12);
+    }
+
+    // Leading zeros are dropped, trailing zeros are roots at 0, appended last
+    let r = roots(&[0.0, 0.0, 1.0, 0.0, -4.0, 0.0, 0.0]).unwrap();
+    assert_eq!(r.len(), 4);
+    assert_eq!(&r[2..], [Complex64::new(0.0, 0.0); 2]);
+
+    assert!(roots(&[3.0]).unwrap().is_empty());
+    assert!(roots(&[0.0, 0.0]).unwrap().is_empty());
+    assert!(roots::<f64>(&[]).unwrap().is_empty());
+    assert_eq!(roots(&[0.0, 2.0, 0.0]).unwrap(), [Complex64::new(0.0, 0.0)]);
+    assert!(roots(&[1.0, f64::INFINITY]).is_err());
+}
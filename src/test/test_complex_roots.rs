@@ -0,0 +1,151 @@
+use num::complex::Complex64;
+
+use crate::{PolyError, Polynomial};
+
+/// Coefficients of a·(z − r₀)(z − r₁)⋯, from constant to leading term.
+fn expand(a: Complex64, roots: &[Complex64]) -> Vec<Complex64> {
+    let mut coef = vec![a];
+    for r in roots {
+        coef.insert(0, Complex64::new(0.0, 0.0));
+        for k in 0..coef.len() - 1 {
+            let next = coef[k + 1];
+            coef[k] -= r * next;
+        }
+    }
+    coef
+}
+
+fn assert_roots(found: &[Complex64], expected: &[Complex64], tol: f64) {
+    for (z, e) in found.iter().zip(expected) {
+        assert!((z - e).norm() < tol, "{z} != {e}");
+    }
+}
+
+#[test]
+fn test_complex_quadratic() {
+    let i = Complex64::i();
+    let roots = [Complex64::new(-1.5, 2.0), Complex64::new(0.5, -3.0)];
+    let poly = Polynomial::build(&expand(2.0 - i, &roots)).unwrap();
+
+    assert_roots(&poly.solve_complex_quadratic().unwrap(), &roots, 1e-14);
+
+    // Roots far apart in magnitude, where (−b ± √Δ)/2a cancels
+    let roots = [Complex64::new(1e-9, 1e-9), Complex64::new(1e8, -1e8)];
+    let poly = Polynomial::build(&expand(i, &roots)).unwrap();
+    let found = poly.solve_complex_quadratic().unwrap();
+
+    assert!((found[0] - roots[0]).norm() < 1e-15 * roots[0].norm() * 10.0);
+    assert!((found[1] - roots[1]).norm() < 1e-15 * roots[1].norm() * 10.0);
+
+    // Large coefficients do not overflow the discriminant
+    let poly = Polynomial::build(&[1e300 * i, 1e300 + 0.0 * i, 1e300 + 0.0 * i]).unwrap();
+    assert!(
+        poly.solve_complex_quadratic()
+            .unwrap()
+            .iter()
+            .all(|z| z.is_finite())
+    );
+
+    let zero = Complex64::new(0.0, 0.0);
+    let poly = Polynomial::build(&[zero, zero, i]).unwrap();
+    assert_eq!(poly.solve_complex_quadratic().unwrap(), [zero; 2]);
+}
+
+#[test]
+fn test_complex_cubic() {
+    let i = Complex64::i();
+    let roots = [
+        Complex64::new(-2.0, 1.0),
+        Complex64::new(0.25, -0.5),
+        Complex64::new(3.0, 0.0),
+    ];
+    let poly = Polynomial::build(&expand(1.0 + 2.0 * i, &roots)).unwrap();
+
+    assert_roots(&poly.solve_complex_cubic().unwrap(), &roots, 1e-13);
+
+    // Triple root
+    let root = Complex64::new(1.0, -1.0);
+    let poly = Polynomial::build(&expand(i, &[root; 3])).unwrap();
+    assert_roots(&poly.solve_complex_cubic().unwrap(), &[root; 3], 1e-13);
+
+    let zero = Complex64::new(0.0, 0.0);
+    let poly = Polynomial::build(&[zero, zero, zero, i]).unwrap();
+    assert_eq!(poly.solve_complex_cubic().unwrap(), [zero; 3]);
+}
+
+#[test]
+fn test_complex_incorrect_order() {
+    let i = Complex64::i();
+    let zero = Complex64::new(0.0, 0.0);
+
+    assert!(matches!(
+        Polynomial::build(&[i, i])
+            .unwrap()
+            .solve_complex_quadratic(),
+        Err(PolyError::IncorrectOrder {
+            expected: 2,
+            got: 1
+        })
+    ));
+    assert!(matches!(
+        Polynomial::build(&[i, i, zero])
+            .unwrap()
+            .solve_complex_quadratic(),
+        Err(PolyError::IncorrectOrder {
+            expected: 2,
+            got: 1
+        })
+    ));
+    assert!(matches!(
+        Polynomial::build(&[i, i, i]).unwrap().solve_complex_cubic(),
+        Err(PolyError::IncorrectOrder { expected: 3, .. })
+    ));
+}
+
+#[test]
+fn test_solve_binomial() {
+    // 2x⁵ + 64 = 0, x⁵ = −32
+    let poly = Polynomial::build(&[64.0, 0.0, 0.0, 0.0, 0.0, 2.0]).unwrap();
+    let roots = poly.solve_binomial().unwrap();
+
+    assert_eq!(roots.len(), 5);
+    for (k, z) in roots.iter().enumerate() {
+        let expected = Complex64::from_polar(2.0, std::f64::consts::PI * (2 * k + 1) as f64 / 5.0);
+        assert!((z - expected).norm() < 1e-15);
+        assert!((z.powi(5) + 32.0).norm() < 1e-13);
+    }
+
+    // Complex constant term: x² = i
+    let i = Complex64::i();
+    let one = Complex64::new(1.0, 0.0);
+    let poly = Polynomial::build(&[-i, 0.0 * i, one]).unwrap();
+    for z in poly.solve_binomial().unwrap() {
+        assert!((z * z - i).norm() < 1e-15);
+    }
+
+    // a·xⁿ alone has a root of multiplicity n at 0
+    let poly = Polynomial::build(&[0.0, 0.0, 0.0, 3.0]).unwrap();
+    assert!(
+        poly.solve_binomial()
+            .unwrap()
+            .iter()
+            .all(|z| z.norm() == 0.0)
+    );
+
+    let linear = Polynomial::build(&[3.0, 2.0, 0.0]).unwrap();
+    assert_eq!(
+        linear.solve_binomial().unwrap(),
+        [Complex64::new(-1.5, 0.0)]
+    );
+
+    assert!(matches!(
+        Polynomial::build(&[1.0, 1.0, 1.0])
+            .unwrap()
+            .solve_binomial(),
+        Err(PolyError::NotBinomial)
+    ));
+    assert!(matches!(
+        Polynomial::build(&[1.0, 0.0]).unwrap().solve_binomial(),
+        Err(PolyError::ConstantPoly)
+    ));
+}
@@ -0,0 +1,81 @@
+use num::complex::Complex64;
+
+use crate::{PolyError, Polynomial};
+
+const ORIGIN: Complex64 = Complex64::new(0.0, 0.0);
+
+#[test]
+fn test_count_roots_in_disk() {
+    let roots: [f64; 5] = [-3.0, -0.5, 0.2, 1.0, 2.5];
+    let poly = Polynomial::from_roots(&roots);
+
+    for radius in [0.1, 0.3, 0.7, 1.5, 2.8, 4.0] {
+        let expected = roots.iter().filter(|r| r.abs() < radius).count();
+        assert_eq!(poly.count_roots_in_disk(ORIGIN, radius).unwrap(), expected);
+    }
+    assert_eq!(
+        poly.count_roots_in_disk(Complex64::new(2.5, 1.0), 1.2)
+            .unwrap(),
+        1
+    );
+}
+
+#[test]
+fn test_count_roots_in_disk_multiple_and_complex() {
+    // (x-1)³(x²+4)
+    let roots = [1.0, 1.0, 1.0].map(|r| Complex64::new(r, 0.0));
+    let poly = &Polynomial::from_roots(&roots)
+        * &Polynomial::build(&[4.0, 0.0, 1.0].map(|c| Complex64::new(c, 0.0))).unwrap();
+
+    assert_eq!(poly.count_roots_in_disk(ORIGIN, 1.5).unwrap(), 3);
+    assert_eq!(poly.count_roots_in_disk(ORIGIN, 3.0).unwrap(), 5);
+    assert_eq!(
+        poly.count_roots_in_disk(Complex64::new(0.0, 2.0), 0.5)
+            .unwrap(),
+        1
+    );
+}
+
+#[test]
+fn test_count_roots_in_disk_high_order() {
+    // Roots of unity: x²⁰-1
+    let mut coef = vec![0.0; 21];
+    coef[0] = -1.0;
+    coef[20] = 1.0;
+    let poly = Polynomial::build(&coef).unwrap();
+
+    assert_eq!(poly.count_roots_in_disk(ORIGIN, 0.99).unwrap(), 0);
+    assert_eq!(poly.count_roots_in_disk(ORIGIN, 1.01).unwrap(), 20);
+    assert_eq!(
+        poly.count_roots_in_disk(Complex64::new(1.0, 0.0), 0.1)
+            .unwrap(),
+        1
+    );
+}
+
+#[test]
+fn test_count_roots_in_disk_errors() {
+    let poly = Polynomial::build(&[-1.0, 0.0, 1.0]).unwrap();
+
+    assert!(matches!(
+        poly.count_roots_in_disk(ORIGIN, 1.0),
+        Err(PolyError::RootOnContour)
+    ));
+    assert!(matches!(
+        poly.count_roots_in_disk(ORIGIN, -1.0),
+        Err(PolyError::InvalidRadius(_))
+    ));
+    assert!(matches!(
+        Polynomial::build(&[0.0, 0.0])
+            .unwrap()
+            .count_roots_in_disk(ORIGIN, 1.0),
+        Err(PolyError::Trivial)
+    ));
+    assert_eq!(
+        Polynomial::build(&[2.0])
+            .unwrap()
+            .count_roots_in_disk(ORIGIN, 1.0)
+            .unwrap(),
+        0
+    );
+}
@@ -0,0 +1,79 @@
+use num::complex::Complex64;
+
+use crate::Polynomial;
+
+/// Returns G∘H, by evaluating G at H with Horner's method.
+fn compose(g: &Polynomial<f64>, h: &Polynomial<f64>) -> Polynomial<f64> {
+    g.coef
+        .iter()
+        .rev()
+        .fold(Polynomial::new(), |acc, c| &(&acc * h) + *c)
+        .to_trimmed()
+}
+
+#[test]
+fn test_decompose_nested_cubics() {
+    let g = Polynomial::build(&[2.0, -1.0, 0.5, 3.0]).unwrap();
+    let h = Polynomial::build(&[0.0, 1.5, -2.0, 1.0]).unwrap();
+    let poly = compose(&g, &h);
+    assert_eq!(poly.coef.len(), 10);
+
+    let (g2, h2) = poly.decompose().unwrap();
+    assert!(g2.approx_eq(&g, 1e-10, 1e-10));
+    assert!(h2.approx_eq(&h, 1e-10, 1e-10));
+}
+
+#[test]
+fn test_decompose_normalizes_inner() {
+    // G(y) = y³ with H(x) = 2x² + 1 is found as G'(y) = 8(y + 1/2)³ with H'(x) = x²
+    let h = Polynomial::build(&[1.0, 0.0, 2.0]).unwrap();
+    let g = Polynomial::build(&[0.0, 0.0, 0.0, 1.0]).unwrap();
+    let poly = compose(&g, &h);
+
+    let (g2, h2) = poly.decompose().unwrap();
+    assert!(h2.approx_eq(&Polynomial::build(&[0.0, 0.0, 1.0]).unwrap(), 1e-12, 1e-12));
+    for x in [-1.3, 0.2, 2.0] {
+        let expected = poly.eval(x);
+        assert!((g2.eval(h2.eval(x)) - expected).abs() < 1e-12 * expected.abs());
+    }
+}
+
+#[test]
+fn test_decompose_none() {
+    // Prime order
+    let poly = Polynomial::build(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+    assert!(poly.decompose().is_none());
+
+    // Order 4, but not a composition: x⁴ + x
+    let poly = Polynomial::build(&[0.0, 1.0, 0.0, 0.0, 1.0]).unwrap();
+    assert!(poly.decompose().is_none());
+
+    assert!(
+        Polynomial::build(&[1.0, 1.0, 1.0])
+            .unwrap()
+            .decompose()
+            .is_none()
+    );
+    assert!(Polynomial::<f64>::new().decompose().is_none());
+    assert!(Polynomial::<f64> { coef: vec![] }.decompose().is_none());
+}
+
+#[test]
+fn test_decompose_complex() {
+    let i = Complex64::i();
+    let one = Complex64::new(1.0, 0.0);
+    // (x² + ix)² + 1 = x⁴ + 2ix³ − x² + 1
+    let poly = Polynomial::build(&[one, 0.0 * i, -one, 2.0 * i, one]).unwrap();
+
+    let (g, h) = poly.decompose().unwrap();
+    assert!(h.approx_eq(
+        &Polynomial::build(&[0.0 * i, i, one]).unwrap(),
+        1e-12,
+        1e-12
+    ));
+    assert!(g.approx_eq(
+        &Polynomial::build(&[one, 0.0 * i, one]).unwrap(),
+        1e-12,
+        1e-12
+    ));
+}
@@ -0,0 +1,84 @@
+use is_close::is_close;
+
+use crate::{Norm, PolyError, PolyErrorKind, Polynomial};
+
+#[test]
+fn test_distance_sup() {
+    // x³-x has its extrema at ±1/√3 inside [-1, 1]
+    let p = Polynomial::build(&[0.0, -1.0, 0.0, 1.0]).unwrap();
+    let q = Polynomial::new();
+    let extremum = 2.0 / (27.0f64).sqrt();
+
+    let d = p.distance_to(&q, -1.0, 1.0, Norm::Sup).unwrap();
+    assert!(is_close!(d, extremum, rel_tol = 1e-12));
+
+    // Endpoints dominate
+    let d = p.distance_to(&q, -2.0, 2.0, Norm::Sup).unwrap();
+    assert!(is_close!(d, 6.0, rel_tol = 1e-12));
+
+    // Constant difference
+    let r = Polynomial::build(&[-0.5, -1.0, 0.0, 1.0]).unwrap();
+    let d = p.distance_to(&r, -1.0, 1.0, Norm::Sup).unwrap();
+    assert!(is_close!(d, 0.5, rel_tol = 1e-15));
+}
+
+#[test]
+fn test_distance_l2() {
+    let p = Polynomial::build(&[1.0, 2.0]).unwrap();
+    let q = Polynomial::build(&[1.0]).unwrap();
+
+    // ∫(2x)² dx on [0, 3] = 36
+    let d = p.distance_to(&q, 0.0, 3.0, Norm::L2).unwrap();
+    assert!(is_close!(d, 6.0, rel_tol = 1e-14));
+
+    assert_eq!(p.distance_to(&p, 0.0, 3.0, Norm::L2).unwrap(), 0.0);
+    assert_eq!(p.distance_to(&q, 1.0, 1.0, Norm::L2).unwrap(), 0.0);
+}
+
+#[test]
+fn test_distance_symmetric() {
+    let p = Polynomial::build(&[0.3, -1.2, 0.5, 2.0, -0.7]).unwrap();
+    let q = Polynomial::build(&[1.0, 0.1, -0.4]).unwrap();
+
+    for norm in [Norm::L2, Norm::Sup] {
+        let pq = p.distance_to(&q, -1.5, 2.0, norm).unwrap();
+        let qp = q.distance_to(&p, -1.5, 2.0, norm).unwrap();
+        assert!(is_close!(pq, qp, rel_tol = 1e-12));
+    }
+}
+
+#[test]
+fn test_distance_invalid_interval() {
+    let p = Polynomial::build(&[1.0]).unwrap();
+
+    assert!(matches!(
+        p.distance_to(&p, 1.0, 0.0, Norm::L2).unwrap_err(),
+        PolyError::InvalidInterval(_, _)
+    ));
+}
+
+#[test]
+fn test_distance_non_finite() {
+    let p = Polynomial {
+        coef: vec![1.0, f64::NAN, 1.0],
+    };
+    let q = Polynomial::build(&[1.0]).unwrap();
+
+    for norm in [Norm::L2, Norm::Sup] {
+        for (lhs, rhs) in [(&p, &q), (&q, &p)] {
+            assert_eq!(
+                lhs.distance_to(rhs, 0.0, 1.0, norm).unwrap_err().kind(),
+                PolyErrorKind::InvalidCoefficients
+            );
+        }
+    }
+
+    // Finite coefficients whose difference overflows
+    let big = Polynomial::build(&[0.0, 0.0, f64::MAX / 4.0]).unwrap();
+    for norm in [Norm::L2, Norm::Sup] {
+        assert_eq!(
+            big.distance_to(&q, 0.0, 10.0, norm).unwrap_err().kind(),
+            PolyErrorKind::InvalidData
+        );
+    }
+}
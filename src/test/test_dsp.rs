@@ -0,0 +1,111 @@
+use num::complex::Complex64;
+
+use crate::dsp::{self, GainDistribution};
+use crate::{PolyError, Polynomial};
+
+/// Evaluates a cascade of second-order sections at w = z⁻¹.
+fn eval_sos(sos: &[[f64; 6]], w: Complex64) -> Complex64 {
+    sos.iter()
+        .map(|[b0, b1, b2, a0, a1, a2]| (b0 + w * (b1 + w * b2)) / (a0 + w * (a1 + w * a2)))
+        .product()
+}
+
+fn assert_same_response(num: &[f64], den: &[f64], sos: &[[f64; 6]]) {
+    let num = Polynomial::build(num).unwrap();
+    let den = Polynomial::build(den).unwrap();
+    for k in 0..16 {
+        let w = Complex64::from_polar(1.0, k as f64 * 0.4);
+        let to_complex = |p: &Polynomial<f64>| Polynomial {
+            coef: p.coef.iter().map(|c| Complex64::new(*c, 0.0)).collect(),
+        };
+        let expected = to_complex(&num).eval(w) / to_complex(&den).eval(w);
+        let got = eval_sos(sos, w);
+        assert!(
+            (expected - got).norm() < 1e-10 * expected.norm().max(1.0),
+            "{w}: {expected} vs {got}"
+        );
+    }
+}
+
+#[test]
+fn test_tf2sos_response() {
+    // 4th order, with complex poles and zeros
+    let num = [0.2, -0.1, 0.35, 0.05, 0.1];
+    let den = [1.0, -1.2, 1.1, -0.5, 0.12];
+    let sos = dsp::tf2sos(
+        &Polynomial::build(&num).unwrap(),
+        &Polynomial::build(&den).unwrap(),
+        GainDistribution::First,
+    )
+    .unwrap();
+
+    assert_eq!(sos.len(), 2);
+    assert!(sos.iter().all(|s| s[3] == 1.0));
+    assert_same_response(&num, &den, &sos);
+}
+
+#[test]
+fn test_tf2sos_odd_order() {
+    // 3rd order, with a shorter numerator
+    let num = [1.0, 0.5];
+    let den = [2.0, -0.4, 0.3, -0.06];
+    let sos = dsp::tf2sos(
+        &Polynomial::build(&num).unwrap(),
+        &Polynomial::build(&den).unwrap(),
+        GainDistribution::First,
+    )
+    .unwrap();
+
+    assert_eq!(sos.len(), 2);
+    // The first order section is padded with b2 = a2 = 0
+    assert!(sos.iter().any(|s| s[2] == 0.0 && s[5] == 0.0));
+    assert_same_response(&num, &den, &sos);
+}
+
+#[test]
+fn test_tf2sos_gain_distribution() {
+    let num = Polynomial::build(&[-8.0, 0.0, 0.0, 0.0, 8.0]).unwrap();
+    let den = Polynomial::build(&[1.0, 0.0, 0.5, 0.0, 0.0625]).unwrap();
+
+    for gain in [
+        GainDistribution::First,
+        GainDistribution::Last,
+        GainDistribution::Even,
+    ] {
+        let sos = dsp::tf2sos(&num, &den, gain).unwrap();
+        assert_eq!(sos.len(), 2);
+        assert_same_response(&num.coef, &den.coef, &sos);
+
+        let b0: Vec<f64> = sos.iter().map(|s| s[0]).collect();
+        match gain {
+            GainDistribution::First => assert_eq!(b0[1].abs(), 1.0),
+            GainDistribution::Last => assert_eq!(b0[0].abs(), 1.0),
+            GainDistribution::Even => {
+                assert!(b0.iter().all(|b| (b.abs() - 8f64.sqrt()).abs() < 1e-12));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_tf2sos_constant() {
+    let sos = dsp::tf2sos(
+        &Polynomial::build(&[3.0]).unwrap(),
+        &Polynomial::build(&[2.0]).unwrap(),
+        GainDistribution::First,
+    )
+    .unwrap();
+
+    assert_eq!(sos, [[1.5, 0.0, 0.0, 1.0, 0.0, 0.0]]);
+}
+
+#[test]
+fn test_tf2sos_zero_a0() {
+    let res = dsp::tf2sos(
+        &Polynomial::build(&[1.0]).unwrap(),
+        &Polynomial::build(&[0.0, 1.0]).unwrap(),
+        GainDistribution::First,
+    );
+
+    assert!(matches!(res, Err(PolyError::DivisionByZero)));
+}
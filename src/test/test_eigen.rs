@@ -0,0 +1,75 @@
+use std::cell::Cell;
+
+use num::complex::Complex64;
+
+#[cfg(feature = "nalgebra")]
+use crate::solve::NalgebraEigen;
+use crate::solve::{BuiltinQr, EigenBackend};
+use crate::{PolyError, Polynomial, Result};
+
+/// Sorts roots by their real, and then imaginary part.
+fn sorted(mut roots: Vec<Complex64>) -> Vec<Complex64> {
+    roots.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+    roots
+}
+
+struct Failing;
+
+impl EigenBackend for Failing {
+    fn hessenberg_eigenvalues(&self, _: &mut [f64], _: usize) -> Result<Vec<Complex64>> {
+        Err(PolyError::NoConvergence)
+    }
+}
+
+struct Counting(Cell<usize>);
+
+impl EigenBackend for Counting {
+    fn hessenberg_eigenvalues(&self, h: &mut [f64], n: usize) -> Result<Vec<Complex64>> {
+        self.0.set(self.0.get() + 1);
+        BuiltinQr.hessenberg_eigenvalues(h, n)
+    }
+}
+
+#[test]
+fn test_builtin_backend_is_default() {
+    let poly = Polynomial::build(&[-6.0, 11.0, -6.0, 1.0, 0.0]).unwrap();
+
+    let default = sorted(poly.solve_complex().unwrap());
+    let builtin = sorted(poly.solve_complex_with(&BuiltinQr).unwrap());
+    assert_eq!(default, builtin);
+}
+
+#[test]
+fn test_custom_backend() {
+    let poly = Polynomial::build(&[1.0, 2.0, 3.0]).unwrap();
+    let counting = Counting(Cell::new(0));
+
+    poly.solve_complex_with(&counting).unwrap();
+    assert_eq!(counting.0.get(), 1);
+    assert!(matches!(
+        poly.solve_complex_with(&Failing),
+        Err(PolyError::NoConvergence)
+    ));
+
+    // Complex coefficients don't use the backend
+    let complex = Polynomial::build(&[Complex64::new(0.0, 1.0), Complex64::new(1.0, 0.0)]).unwrap();
+    assert_eq!(complex.solve_complex_with(&Failing).unwrap().len(), 1);
+
+    // Trait objects can be used too
+    let backend: &dyn EigenBackend = &counting;
+    poly.solve_complex_with(backend).unwrap();
+    assert_eq!(counting.0.get(), 2);
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn test_nalgebra_backend() {
+    let poly = Polynomial::build(&[3.0, -1.0, 0.5, 2.0, -4.0, 1.0, 0.25]).unwrap();
+
+    let builtin = sorted(poly.solve_complex().unwrap());
+    let nalgebra = sorted(poly.solve_complex_with(&NalgebraEigen).unwrap());
+    assert_eq!(builtin.len(), nalgebra.len());
+    for (a, b) in builtin.iter().zip(&nalgebra) {
+        assert!((a - b).norm() < 1e-10);
+    }
+}
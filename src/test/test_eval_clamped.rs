@@ -0,0 +1,66 @@
+use crate::{NanPolicy, PolyError, Polynomial};
+
+#[test]
+fn test_eval_clamped() {
+    let poly = Polynomial::build(&[0.0, 0.0, 1.0]).unwrap();
+
+    assert_eq!(poly.eval_clamped(0.5, -1.0, 1.0).unwrap(), 0.25);
+    assert_eq!(poly.eval_clamped(-3.0, -1.0, 1.0).unwrap(), 1.0);
+    assert_eq!(poly.eval_clamped(0.5, 2.0, 3.0).unwrap(), 2.0);
+    assert_eq!(poly.eval_clamped(1e300, -1.0, 1.0).unwrap(), 1.0);
+    assert!(poly.eval_clamped(f64::NAN, -1.0, 1.0).unwrap().is_nan());
+}
+
+#[test]
+fn test_eval_clamped_invalid_bounds() {
+    let poly = Polynomial::build(&[1.0, 2.0]).unwrap();
+
+    assert!(matches!(
+        poly.eval_clamped(0.0, 1.0, -1.0).unwrap_err(),
+        PolyError::InvalidInterval(_, _)
+    ));
+    assert!(matches!(
+        poly.eval_clamped(0.0, f64::NAN, 1.0).unwrap_err(),
+        PolyError::InvalidInterval(_, _)
+    ));
+    // Infinite bounds are fine
+    assert_eq!(
+        poly.eval_clamped(1.0, f64::NEG_INFINITY, f64::INFINITY)
+            .unwrap(),
+        3.0
+    );
+}
+
+#[test]
+fn test_nan_policy() {
+    let poly = Polynomial::build(&[0.0, -1.0, 1.0]).unwrap();
+    let x = f64::NAN;
+
+    assert!(
+        poly.eval_clamped_with(x, -1.0, 1.0, NanPolicy::Propagate)
+            .unwrap()
+            .is_nan()
+    );
+    assert!(matches!(
+        poly.eval_clamped_with(x, -1.0, 1.0, NanPolicy::Error)
+            .unwrap_err(),
+        PolyError::NanEvaluation(_)
+    ));
+    assert_eq!(
+        poly.eval_clamped_with(x, -1.0, 1.0, NanPolicy::Replace(-5.0))
+            .unwrap(),
+        -5.0
+    );
+
+    // The policy does not affect regular values
+    for policy in [
+        NanPolicy::Propagate,
+        NanPolicy::Error,
+        NanPolicy::Replace(0.0),
+    ] {
+        assert_eq!(
+            poly.eval_clamped_with(0.5, -1.0, 1.0, policy).unwrap(),
+            -0.25
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{PolyError, Polynomial};
+
+#[test]
+fn test_clear_denominators() {
+    let poly = Polynomial::build(&[0.1, -2.0 / 3.0, 0.0, 1.25]).unwrap();
+    let (integer, denominator) = poly.clear_denominators().unwrap();
+
+    assert_eq!(integer.coef, [6.0, -40.0, 0.0, 75.0]);
+    assert_eq!(denominator, 60.0);
+
+    let integer_poly = Polynomial::build(&[3.0, -5.0]).unwrap();
+    let (integer, denominator) = integer_poly.clear_denominators().unwrap();
+    assert_eq!(integer.coef, [3.0, -5.0]);
+    assert_eq!(denominator, 1.0);
+}
+
+#[test]
+fn test_clear_denominators_irrational() {
+    let poly = Polynomial::build(&[std::f64::consts::PI, 1.0]).unwrap();
+
+    assert!(matches!(
+        poly.clear_denominators().unwrap_err(),
+        PolyError::NotRational(_)
+    ));
+}
+
+#[test]
+fn test_divide_by_content() {
+    let poly = Polynomial::build(&[12.0, 0.0, -18.0, 6.0]).unwrap();
+    let (primitive, content) = poly.divide_by_content().unwrap();
+    assert_eq!(primitive.coef, [2.0, 0.0, -3.0, 1.0]);
+    assert_eq!(content, 6.0);
+
+    // Negative leading coefficient
+    let poly = Polynomial::build(&[4.0, -6.0, 0.0]).unwrap();
+    let (primitive, content) = poly.divide_by_content().unwrap();
+    assert_eq!(primitive.coef, [-2.0, 3.0, 0.0]);
+    assert_eq!(content, -2.0);
+
+    let zero = Polynomial::build(&[0.0, 0.0]).unwrap();
+    assert_eq!(zero.divide_by_content().unwrap().1, 0.0);
+
+    assert!(matches!(
+        Polynomial::build(&[0.5, 1.0])
+            .unwrap()
+            .divide_by_content()
+            .unwrap_err(),
+        PolyError::NotIntegerCoefficients
+    ));
+}
+
+#[test]
+fn test_rational_round_trip() {
+    let poly = Polynomial::build(&[2.0 / 7.0, 4.0 / 21.0, -6.0 / 35.0]).unwrap();
+    let (integer, denominator) = poly.clear_denominators().unwrap();
+    let (primitive, content) = integer.divide_by_content().unwrap();
+
+    assert_eq!(primitive.coef, [-15.0, -10.0, 9.0]);
+    for (c, p) in poly.coef.iter().zip(&primitive.coef) {
+        assert!((c - p * content / denominator).abs() < 1e-15);
+    }
+}
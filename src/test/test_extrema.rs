@@ -0,0 +1,112 @@
+use is_close::is_close;
+
+use crate::{Polynomial, StationaryKind};
+
+#[test]
+fn test_critical_points() {
+    // P' = 4(x+1)x(x-2)
+    let poly = Polynomial::build(&[1.0, 0.0, -4.0, -4.0 / 3.0, 1.0]).unwrap();
+    let points = poly.critical_points().unwrap();
+
+    let expected = [
+        (-1.0, StationaryKind::Minimum),
+        (0.0, StationaryKind::Maximum),
+        (2.0, StationaryKind::Minimum),
+    ];
+    assert_eq!(points.len(), 3);
+    for (point, (x, kind)) in points.iter().zip(expected) {
+        assert!(is_close!(point.x, x, abs_tol = 1e-12));
+        assert!(is_close!(point.value, poly.eval(x), abs_tol = 1e-12));
+        assert_eq!(point.kind, kind);
+    }
+}
+
+#[test]
+fn test_critical_points_multiple() {
+    // x³ has a saddle point, x⁴ a (flat) minimum, and -x⁴ a maximum
+    let cubic = Polynomial::build(&[0.0, 0.0, 0.0, 1.0]).unwrap();
+    let quartic = Polynomial::build(&[0.0, 0.0, 0.0, 0.0, 1.0]).unwrap();
+
+    let points = cubic.critical_points().unwrap();
+    assert_eq!(points.len(), 1);
+    assert_eq!(points[0].kind, StationaryKind::Saddle);
+
+    let points = quartic.critical_points().unwrap();
+    assert_eq!(points.len(), 1);
+    assert_eq!(points[0].kind, StationaryKind::Minimum);
+
+    let points = (-&quartic).critical_points().unwrap();
+    assert_eq!(points[0].kind, StationaryKind::Maximum);
+}
+
+#[test]
+fn test_critical_points_none() {
+    let linear = Polynomial::build(&[1.0, 2.0]).unwrap();
+    let constant = Polynomial::build(&[1.0]).unwrap();
+    let no_real = Polynomial::build(&[0.0, 1.0, 0.0, 1.0]).unwrap(); // P' = 3x²+1
+
+    assert!(linear.critical_points().unwrap().is_empty());
+    assert!(constant.critical_points().unwrap().is_empty());
+    assert!(no_real.critical_points().unwrap().is_empty());
+}
+
+#[test]
+fn test_inflection_points() {
+    // P'' = 12x² - 12 = 12(x-1)(x+1)
+    let poly = Polynomial::build(&[0.0, 0.0, -6.0, 0.0, 1.0]).unwrap();
+    let points = poly.inflection_points().unwrap();
+
+    assert_eq!(points.len(), 2);
+    assert!(is_close!(points[0], -1.0, abs_tol = 1e-14));
+    assert!(is_close!(points[1], 1.0, abs_tol = 1e-14));
+
+    // x⁴ is convex everywhere, even though P''(0) = 0
+    let quartic = Polynomial::build(&[0.0, 0.0, 0.0, 0.0, 1.0]).unwrap();
+    assert!(quartic.inflection_points().unwrap().is_empty());
+
+    let quadratic = Polynomial::build(&[1.0, 0.0, 1.0]).unwrap();
+    assert!(quadratic.inflection_points().unwrap().is_empty());
+}
+
+#[test]
+fn test_minimum_maximum_on() {
+    // Minima at -1 and 2, maximum at 0
+    let poly = Polynomial::build(&[1.0, 0.0, -4.0, -4.0 / 3.0, 1.0]).unwrap();
+
+    let (x, min) = poly.minimum_on(-3.0, 3.0).unwrap();
+    assert!(is_close!(x, 2.0, abs_tol = 1e-12));
+    assert!(is_close!(min, poly.eval(2.0), abs_tol = 1e-12));
+
+    let (x, max) = poly.maximum_on(-1.0, 2.5).unwrap();
+    assert!(is_close!(x, 0.0, abs_tol = 1e-12));
+    assert!(is_close!(max, 1.0, abs_tol = 1e-12));
+
+    // Endpoint extrema
+    assert_eq!(poly.maximum_on(-3.0, 3.0).unwrap().0, -3.0);
+    assert_eq!(poly.minimum_on(0.5, 1.0).unwrap().0, 1.0);
+
+    // Degenerate interval
+    assert_eq!(poly.minimum_on(1.0, 1.0).unwrap(), (1.0, poly.eval(1.0)));
+}
+
+#[test]
+fn test_minimum_on_multiple_root() {
+    // (x-1)⁴ has a flat minimum, found as a cluster of critical points
+    let poly = Polynomial::build(&[1.0, -4.0, 6.0, -4.0, 1.0]).unwrap();
+    let (x, min) = poly.minimum_on(-2.0, 3.0).unwrap();
+
+    assert!(is_close!(x, 1.0, abs_tol = 1e-4));
+    assert!(min.abs() < 1e-15);
+}
+
+#[test]
+fn test_extremum_on_errors() {
+    let poly = Polynomial::build(&[1.0, 2.0]).unwrap();
+
+    assert!(matches!(
+        poly.minimum_on(1.0, 0.0),
+        Err(crate::PolyError::InvalidInterval(..))
+    ));
+    assert!(poly.maximum_on(0.0, f64::NAN).is_err());
+    assert_eq!(poly.maximum_on(0.0, 1.0).unwrap(), (1.0, 3.0));
+}
@@ -0,0 +1,132 @@
+use crate::ffi::*;
+use is_close::is_close;
+
+const EPS: f64 = 100.0 * f64::EPSILON;
+
+#[test]
+fn test_ffi_eval() {
+    let c = [1.0, 0.5, 0.3];
+    unsafe {
+        assert_eq!(gsl_poly_eval(c.as_ptr(), 3, 0.5), 1.0 + 0.25 + 0.075);
+        assert_eq!(gsl_poly_eval(c.as_ptr(), 0, 0.5), 0.0);
+        assert_eq!(gsl_poly_eval(std::ptr::null(), 3, 0.5), 0.0);
+    }
+}
+
+#[test]
+fn test_ffi_complex_eval() {
+    let c = [1.0, 0.0, 1.0];
+    let i = GslComplex { dat: [0.0, 1.0] };
+    let res = unsafe { gsl_poly_complex_eval(c.as_ptr(), 3, i) };
+    assert_eq!(res, GslComplex { dat: [0.0, 0.0] });
+
+    let c = [
+        GslComplex { dat: [0.0, 1.0] },
+        GslComplex { dat: [1.0, 0.0] },
+    ];
+    let res = unsafe { gsl_complex_poly_complex_eval(c.as_ptr(), 2, i) };
+    assert_eq!(res, GslComplex { dat: [0.0, 2.0] });
+}
+
+#[test]
+fn test_ffi_eval_derivs() {
+    let c = [1.0, 2.0, 3.0];
+    let mut res = [f64::NAN; 4];
+    let status = unsafe { gsl_poly_eval_derivs(c.as_ptr(), 3, 1.0, res.as_mut_ptr(), 4) };
+    assert_eq!(status, GSL_SUCCESS);
+    assert_eq!(res, [6.0, 8.0, 6.0, 0.0]);
+    let status = unsafe { gsl_poly_eval_derivs(c.as_ptr(), 3, 1.0, std::ptr::null_mut(), 4) };
+    assert_eq!(status, GSL_EFAULT);
+}
+
+#[test]
+fn test_ffi_solve_quadratic() {
+    let (mut x0, mut x1) = (f64::NAN, f64::NAN);
+    let n = unsafe { gsl_poly_solve_quadratic(4.0, -20.0, 26.0, &mut x0, &mut x1) };
+    assert_eq!(n, 0);
+    let n = unsafe { gsl_poly_solve_quadratic(4.0, -20.0, 25.0, &mut x0, &mut x1) };
+    assert_eq!((n, x0, x1), (2, 2.5, 2.5));
+    let n = unsafe { gsl_poly_solve_quadratic(5.0, 0.0, -20.0, &mut x0, &mut x1) };
+    assert_eq!(n, 2);
+    assert!(is_close!(x0, -2.0, rel_tol = EPS) && is_close!(x1, 2.0, rel_tol = EPS));
+    let n = unsafe { gsl_poly_solve_quadratic(0.0, 3.0, -2.0, &mut x0, &mut x1) };
+    assert_eq!(n, 1);
+    assert!(is_close!(x0, 2.0 / 3.0, rel_tol = EPS));
+    let n = unsafe { gsl_poly_solve_quadratic(0.0, 0.0, 1.0, &mut x0, &mut x1) };
+    assert_eq!(n, 0);
+}
+
+#[test]
+fn test_ffi_complex_solve_quadratic() {
+    let (mut z0, mut z1) = (GslComplex::default(), GslComplex::default());
+    let n = unsafe { gsl_poly_complex_solve_quadratic(4.0, -20.0, 26.0, &mut z0, &mut z1) };
+    assert_eq!(n, 2);
+    assert!(is_close!(z0.dat[0], 2.5, rel_tol = EPS) && is_close!(z0.dat[1], -0.5, rel_tol = EPS));
+    assert!(is_close!(z1.dat[0], 2.5, rel_tol = EPS) && is_close!(z1.dat[1], 0.5, rel_tol = EPS));
+    let n = unsafe { gsl_poly_complex_solve_quadratic(0.0, 2.0, -1.0, &mut z0, &mut z1) };
+    assert_eq!((n, z0), (1, GslComplex { dat: [0.5, 0.0] }));
+}
+
+#[test]
+fn test_ffi_solve_cubic() {
+    let mut x = [f64::NAN; 3];
+    let [p0, p1, p2] = x.each_mut().map(|x| x as *mut f64);
+    let n = unsafe { gsl_poly_solve_cubic(-6.0, 11.0, -6.0, p0, p1, p2) };
+    assert_eq!(n, 3);
+    x.iter()
+        .zip([1.0, 2.0, 3.0])
+        .for_each(|(x, r)| assert!(is_close!(*x, r, rel_tol = EPS)));
+
+    let n = unsafe { gsl_poly_solve_cubic(0.0, 0.0, -1.0, p0, p1, p2) };
+    assert_eq!(n, 1);
+    assert!(is_close!(x[0], 1.0, rel_tol = EPS));
+
+    let n = unsafe { gsl_poly_solve_cubic(-3.0, 3.0, -1.0, p0, p1, p2) };
+    assert_eq!((n, x), (3, [1.0; 3]));
+}
+
+#[test]
+fn test_ffi_complex_solve_cubic() {
+    let mut z = [GslComplex::default(); 3];
+    let [p0, p1, p2] = z.each_mut().map(|z| z as *mut GslComplex);
+    let n = unsafe { gsl_poly_complex_solve_cubic(0.0, 0.0, -1.0, p0, p1, p2) };
+    assert_eq!(n, 3);
+    let h = 3f64.sqrt() / 2.0;
+    z.iter()
+        .zip([[-0.5, -h], [-0.5, h], [1.0, 0.0]])
+        .for_each(|(z, r)| {
+            assert!(is_close!(z.dat[0], r[0], abs_tol = EPS));
+            assert!(is_close!(z.dat[1], r[1], abs_tol = EPS));
+        });
+}
+
+#[test]
+fn test_ffi_complex_solve() {
+    // (x−1)(x−2)(x²+1)
+    let a = [2.0, -3.0, 3.0, -3.0, 1.0];
+    let w = gsl_poly_complex_workspace_alloc(a.len());
+    assert!(!w.is_null());
+    let mut z = [f64::NAN; 8];
+    let status = unsafe { gsl_poly_complex_solve(a.as_ptr(), a.len(), w, z.as_mut_ptr()) };
+    assert_eq!(status, GSL_SUCCESS);
+
+    let mut roots: Vec<[f64; 2]> = z.chunks_exact(2).map(|c| [c[0], c[1]]).collect();
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    roots
+        .iter()
+        .zip([[0.0, -1.0], [0.0, 1.0], [1.0, 0.0], [2.0, 0.0]])
+        .for_each(|(z, r)| {
+            assert!(is_close!(z[0], r[0], abs_tol = 1e-12));
+            assert!(is_close!(z[1], r[1], abs_tol = 1e-12));
+        });
+
+    // Size mismatch and zero leading coefficient
+    let status = unsafe { gsl_poly_complex_solve(a.as_ptr(), 3, w, z.as_mut_ptr()) };
+    assert_eq!(status, GSL_EINVAL);
+    let b = [1.0, 2.0, 3.0, 4.0, 0.0];
+    let status = unsafe { gsl_poly_complex_solve(b.as_ptr(), b.len(), w, z.as_mut_ptr()) };
+    assert_eq!(status, GSL_EINVAL);
+
+    unsafe { gsl_poly_complex_workspace_free(w) };
+    assert!(gsl_poly_complex_workspace_alloc(1).is_null());
+}
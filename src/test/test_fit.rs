@@ -0,0 +1,362 @@
+use crate::{PolyError, Polynomial};
+use is_close::is_close;
+
+const TOL: f64 = 1e-10;
+
+#[test]
+fn test_fit_exact_quadratic() {
+    let xs = [-2.0, -1.0, 0.0, 1.0, 2.0, 3.0];
+    let ys: Vec<f64> = xs.iter().map(|x| 1.0 - 2.0 * x + 0.5 * x * x).collect();
+    let fit = Polynomial::fit(&xs, &ys, 2).unwrap();
+
+    assert!(is_close!(fit.poly.coef[0], 1.0, rel_tol = TOL));
+    assert!(is_close!(fit.poly.coef[1], -2.0, rel_tol = TOL));
+    assert!(is_close!(fit.poly.coef[2], 0.5, rel_tol = TOL));
+    assert!(is_close!(fit.chi2, 0.0, abs_tol = TOL));
+    assert_eq!(fit.dof, 3);
+}
+
+#[test]
+fn test_fit_weighted_line_covariance() {
+    // Closed form weighted straight line fit, as in `gsl_fit_wlinear()`
+    let xs = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let ys = [2.1, 3.9, 6.2, 7.8, 10.3];
+    let ws = [1.0, 4.0, 2.0, 0.5, 1.0];
+    let fit = Polynomial::fit_weighted(&xs, &ys, &ws, 1).unwrap();
+
+    let sw: f64 = ws.iter().sum();
+    let swx: f64 = xs.iter().zip(&ws).map(|(x, w)| w * x).sum();
+    let swxx: f64 = xs.iter().zip(&ws).map(|(x, w)| w * x * x).sum();
+    let swy: f64 = ys.iter().zip(&ws).map(|(y, w)| w * y).sum();
+    let swxy: f64 = xs
+        .iter()
+        .zip(&ys)
+        .zip(&ws)
+        .map(|((x, y), w)| w * x * y)
+        .sum();
+    let delta = sw * swxx - swx * swx;
+
+    assert!(is_close!(
+        fit.poly.coef[0],
+        (swxx * swy - swx * swxy) / delta,
+        rel_tol = TOL
+    ));
+    assert!(is_close!(
+        fit.poly.coef[1],
+        (sw * swxy - swx * swy) / delta,
+        rel_tol = TOL
+    ));
+    assert!(is_close!(fit.covariance[0][0], swxx / delta, rel_tol = TOL));
+    assert!(is_close!(fit.covariance[1][1], sw / delta, rel_tol = TOL));
+    assert!(is_close!(fit.covariance[0][1], -swx / delta, rel_tol = TOL));
+    assert!(is_close!(fit.covariance[1][0], -swx / delta, rel_tol = TOL));
+    assert!(is_close!(fit.chi2_dof(), fit.chi2 / 3.0, rel_tol = TOL));
+}
+
+#[test]
+fn test_fit_invalid_input() {
+    let xs = [0.0, 1.0, 2.0];
+
+    assert!(matches!(
+        Polynomial::fit(&xs, &[1.0, 2.0], 1).unwrap_err(),
+        PolyError::LengthMismatch(3, 2)
+    ));
+    assert!(matches!(
+        Polynomial::fit(&xs, &[1.0, f64::NAN, 2.0], 1).unwrap_err(),
+        PolyError::InvalidData
+    ));
+    assert!(matches!(
+        Polynomial::fit_weighted(&xs, &xs, &[1.0, -1.0, 1.0], 1).unwrap_err(),
+        PolyError::InvalidWeights
+    ));
+    assert!(matches!(
+        Polynomial::fit(&xs, &xs, 3).unwrap_err(),
+        PolyError::NotEnoughPoints(4)
+    ));
+    assert!(matches!(
+        Polynomial::fit(&[1.0, 1.0, 1.0], &xs, 1).unwrap_err(),
+        PolyError::SingularMatrix
+    ));
+}
+
+#[test]
+fn test_prediction_interval_line() {
+    // Textbook prediction interval for simple linear regression:
+    // ŷ ± t·s·√(1 + 1/n + (x−x̄)²/Sxx)
+    let xs = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let ys = [1.2, 1.9, 3.2, 3.8, 5.1, 6.2];
+    let fit = Polynomial::fit(&xs, &ys, 1).unwrap();
+
+    let n = xs.len() as f64;
+    let mean = xs.iter().sum::<f64>() / n;
+    let sxx: f64 = xs.iter().map(|x| (x - mean).powi(2)).sum();
+    let s = fit.residual_variance.sqrt();
+    let t = 2.776445105197793; // t(0.975, 4)
+
+    let x = 4.5;
+    let (value, band) = fit.prediction_interval(x, 0.95).unwrap();
+    let expected = t * s * (1.0 + 1.0 / n + (x - mean).powi(2) / sxx).sqrt();
+
+    assert!(is_close!(value, fit.poly.eval(x), rel_tol = TOL));
+    assert!(is_close!(band, expected, rel_tol = 1e-9));
+    assert!(is_close!(
+        fit.residual_variance,
+        fit.chi2_dof(),
+        rel_tol = TOL
+    ));
+}
+
+#[test]
+fn test_prediction_interval_invalid() {
+    let fit = Polynomial::fit(&[0.0, 1.0], &[1.0, 2.0], 1).unwrap();
+    let fit2 = Polynomial::fit(&[0.0, 1.0, 2.0], &[1.0, 2.0, 2.5], 1).unwrap();
+
+    assert!(matches!(
+        fit.prediction_interval(0.5, 0.95).unwrap_err(),
+        PolyError::NoDegreesOfFreedom
+    ));
+    assert!(matches!(
+        fit2.prediction_interval(0.5, 1.0).unwrap_err(),
+        PolyError::InvalidConfidence(_)
+    ));
+}
+
+/// Standard normal samples from a xorshift generator and the Box-Muller transform.
+struct Gaussian(u64);
+
+impl Gaussian {
+    fn uniform(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        ((self.0 >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+
+    fn sample(&mut self) -> f64 {
+        let (u1, u2) = (self.uniform(), self.uniform());
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+#[test]
+fn test_prediction_interval_weighted_coverage() {
+    // Heteroscedastic data around 1 + 2x − 0.5x², with σ(x) = 0.1 + 0.5x
+    let truth = Polynomial::build(&[1.0, 2.0, -0.5]).unwrap();
+    let sigma = |x: f64| 0.1 + 0.5 * x;
+    let xs: Vec<f64> = (0..12).map(|i| i as f64 / 4.0).collect();
+    let weights: Vec<f64> = xs.iter().map(|x| 1.0 / sigma(*x).powi(2)).collect();
+
+    let mut rng = Gaussian(0x2545F4914F6CDD1D);
+    let trials = 4000;
+    let mut covered = [0; 2];
+    for _ in 0..trials {
+        let ys: Vec<f64> = xs
+            .iter()
+            .map(|x| truth.eval(*x) + sigma(*x) * rng.sample())
+            .collect();
+        let fit = Polynomial::fit_weighted(&xs, &ys, &weights, 2).unwrap();
+
+        for (k, x) in [0.3, 2.6].into_iter().enumerate() {
+            let observation = truth.eval(x) + sigma(x) * rng.sample();
+            let (value, band) = fit
+                .prediction_interval_weighted(x, 1.0 / sigma(x).powi(2), 0.9)
+                .unwrap();
+            if (observation - value).abs() <= band {
+                covered[k] += 1;
+            }
+        }
+    }
+
+    for count in covered {
+        let coverage = count as f64 / trials as f64;
+        assert!((coverage - 0.9).abs() < 0.02, "{coverage}");
+    }
+}
+
+#[test]
+fn test_prediction_interval_weighted_unit_weights() {
+    let xs = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let ys = [1.2, 1.9, 3.2, 3.8, 5.1, 6.2];
+    let fit = Polynomial::fit(&xs, &ys, 1).unwrap();
+    let weighted = Polynomial::fit_weighted(&xs, &ys, &[1.0; 6], 1).unwrap();
+
+    let expected = fit.prediction_interval(4.5, 0.95).unwrap();
+    let (value, band) = weighted.prediction_interval(4.5, 0.95).unwrap();
+    assert!(is_close!(value, expected.0, rel_tol = TOL));
+    assert!(is_close!(band, expected.1, rel_tol = 1e-12));
+
+    assert!(matches!(
+        fit.prediction_interval_weighted(4.5, 0.0, 0.95)
+            .unwrap_err(),
+        PolyError::InvalidWeights
+    ));
+}
+
+#[test]
+fn test_predict_with_std() {
+    // Weighted line with absolute errors: var(a + bx) = C₀₀ + 2xC₀₁ + x²C₁₁
+    let xs = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let ys = [2.1, 3.9, 6.2, 7.8, 10.3];
+    let ws = [1.0, 4.0, 2.0, 0.5, 1.0];
+    let fit = Polynomial::fit_weighted(&xs, &ys, &ws, 1).unwrap();
+    let c = &fit.covariance;
+    for x in [0.0, 2.5, 7.0] {
+        let (value, std) = fit.predict_with_std(x).unwrap();
+        let expected = (c[0][0] + 2.0 * x * c[0][1] + x * x * c[1][1]).sqrt();
+        assert_eq!(value, fit.poly.eval(x));
+        assert!(is_close!(std, expected, rel_tol = TOL));
+    }
+
+    // The fitted value is less uncertain than a new observation
+    let fit = Polynomial::fit(&xs, &ys, 2).unwrap();
+    let (_, std) = fit.predict_with_std(3.5).unwrap();
+    let (_, band) = fit.prediction_interval(3.5, 0.6827).unwrap();
+    assert!(std > 0.0 && std < band);
+
+    // No degrees of freedom: unknown covariance
+    let fit = Polynomial::fit(&xs[..2], &ys[..2], 1).unwrap();
+    assert!(fit.predict_with_std(1.5).unwrap().1.is_nan());
+    assert!(matches!(
+        fit.predict_with_std(f64::NAN),
+        Err(PolyError::InvalidData)
+    ));
+}
+
+#[test]
+fn test_fit_orthogonal_matches_fit() {
+    let xs = [0.5, 1.0, 1.7, 2.0, 3.1, 4.0, 4.4, 5.0];
+    let ys = [2.3, 1.9, 0.4, 0.8, -1.2, 0.1, 0.9, 3.2];
+    let fit = Polynomial::fit(&xs, &ys, 3).unwrap();
+    let orth = Polynomial::fit_orthogonal(&xs, &ys, 3).unwrap();
+
+    for (a, b) in orth.poly.coef.iter().zip(&fit.poly.coef) {
+        assert!(is_close!(*a, *b, rel_tol = TOL));
+    }
+    assert!(is_close!(orth.chi2, fit.chi2, rel_tol = TOL));
+    assert_eq!(orth.dof, 4);
+    assert_eq!(orth.coef.len(), 4);
+    for x in xs {
+        assert!(is_close!(orth.eval(x), fit.poly.eval(x), rel_tol = TOL));
+    }
+}
+
+#[test]
+fn test_fit_orthogonal_high_degree() {
+    // Far from the origin, where the Vandermonde matrix is hopeless at this order
+    let xs: Vec<f64> = (0..300).map(|i| 100.0 + i as f64 / 30.0).collect();
+    let f = |x: f64| (x - 100.0).sin() + 0.1 * x;
+    let ys: Vec<f64> = xs.iter().map(|x| f(*x)).collect();
+    let fit = Polynomial::fit_orthogonal(&xs, &ys, 50).unwrap();
+
+    assert!(fit.chi2 < 1e-20);
+    for i in 0..100 {
+        let x = 100.0 + i as f64 / 10.0;
+        assert!(is_close!(fit.eval(x), f(x), abs_tol = 1e-12));
+    }
+}
+
+#[test]
+fn test_fit_orthogonal_exact() {
+    // A polynomial through as many points as coefficients
+    let xs = [-1.0, 0.0, 2.0];
+    let ys = [4.0, 1.0, 1.0];
+    let fit = Polynomial::fit_orthogonal(&xs, &ys, 2).unwrap();
+    for (c, expected) in fit.poly.coef.iter().zip([1.0, -2.0, 1.0]) {
+        assert!(is_close!(*c, expected, abs_tol = TOL));
+    }
+    assert_eq!(fit.dof, 0);
+
+    let fit = Polynomial::fit_orthogonal(&[3.0, 3.0], &[1.0, 2.0], 0).unwrap();
+    assert_eq!(fit.poly.coef, vec![1.5]);
+}
+
+#[test]
+fn test_fit_orthogonal_errors() {
+    assert!(matches!(
+        Polynomial::fit_orthogonal(&[1.0, 2.0], &[1.0], 1),
+        Err(PolyError::LengthMismatch(2, 1))
+    ));
+    assert!(matches!(
+        Polynomial::fit_orthogonal(&[1.0, f64::NAN], &[1.0, 2.0], 1),
+        Err(PolyError::InvalidData)
+    ));
+    assert!(matches!(
+        Polynomial::fit_orthogonal(&[1.0, 2.0], &[1.0, 2.0], 2),
+        Err(PolyError::NotEnoughPoints(3))
+    ));
+    assert!(matches!(
+        Polynomial::fit_orthogonal(&[1.0, 1.0, 2.0], &[1.0, 2.0, 3.0], 2),
+        Err(PolyError::SingularMatrix)
+    ));
+}
+
+#[test]
+fn test_select_degree() {
+    use crate::DegreeCriterion;
+
+    // A cubic with small deterministic noise
+    let xs: Vec<f64> = (0..40).map(|i| -2.0 + i as f64 / 10.0).collect();
+    let ys: Vec<f64> = xs
+        .iter()
+        .enumerate()
+        .map(|(i, x)| 2.0 + x - 0.8 * x.powi(3) + 0.02 * ((i * 5 % 9) as f64 - 4.0) / 4.0)
+        .collect();
+
+    for criterion in [
+        DegreeCriterion::KFold(4),
+        DegreeCriterion::Aic,
+        DegreeCriterion::Bic,
+    ] {
+        let selection = Polynomial::select_degree(&xs, &ys, 10, criterion).unwrap();
+        assert_eq!(selection.degree, 3);
+        assert_eq!(selection.scores.len(), 11);
+        assert_eq!(selection.fit.poly.coef.len(), 4);
+        assert!(selection.scores[3] < selection.scores[2]);
+    }
+}
+
+#[test]
+fn test_select_degree_few_points() {
+    use crate::DegreeCriterion;
+
+    // A line through 4 points: the information criteria need a degree of freedom
+    let xs = [0.0, 1.0, 2.0, 3.0];
+    let ys = [1.0, 3.0, 5.0, 7.0];
+    let selection = Polynomial::select_degree(&xs, &ys, 5, DegreeCriterion::Bic).unwrap();
+    assert_eq!(selection.degree, 1);
+    assert_eq!(selection.scores[1], f64::NEG_INFINITY);
+    assert!(selection.scores[3..].iter().all(|s| *s == f64::INFINITY));
+
+    // Every training set of 2-fold CV has 2 points
+    let selection = Polynomial::select_degree(&xs, &ys, 5, DegreeCriterion::KFold(2)).unwrap();
+    assert_eq!(selection.degree, 1);
+    assert!(selection.scores[2..].iter().all(|s| *s == f64::INFINITY));
+}
+
+#[test]
+fn test_select_degree_errors() {
+    use crate::DegreeCriterion;
+
+    let xs = [0.0, 1.0, 2.0];
+    let ys = [1.0, 2.0, 4.0];
+    assert!(matches!(
+        Polynomial::select_degree(&xs, &ys[..2], 2, DegreeCriterion::Aic),
+        Err(PolyError::LengthMismatch(3, 2))
+    ));
+    assert!(matches!(
+        Polynomial::select_degree(&xs, &ys, 2, DegreeCriterion::KFold(1)),
+        Err(PolyError::InvalidFolds(1))
+    ));
+    assert!(matches!(
+        Polynomial::select_degree(&xs, &ys, 2, DegreeCriterion::KFold(5)),
+        Err(PolyError::NotEnoughPoints(5))
+    ));
+    assert!(matches!(
+        Polynomial::select_degree(&[], &[], 2, DegreeCriterion::Aic),
+        Err(PolyError::NotEnoughPoints(1))
+    ));
+    assert!(matches!(
+        Polynomial::select_degree(&[1.0], &[2.0], 2, DegreeCriterion::Aic),
+        Err(PolyError::NotEnoughPoints(2))
+    ));
+}
@@ -0,0 +1,116 @@
+use is_close::is_close;
+use num::complex::Complex64;
+
+use crate::{CubicRoots, PolyError, PolyN, Polynomial, QuadraticRoots};
+
+const EPS: f64 = 100.0 * f64::EPSILON;
+
+#[test]
+fn test_build() {
+    assert!(PolyN::build([1.0, 2.0, 3.0]).is_ok());
+    assert!(PolyN::<f64, 0>::build([]).is_ok());
+    assert!(matches!(
+        PolyN::build([1.0, f64::NAN]),
+        Err(PolyError::InvalidCoefficients { .. })
+    ));
+    assert!(matches!(
+        PolyN::build([f64::INFINITY]),
+        Err(PolyError::InvalidCoefficients { .. })
+    ));
+}
+
+#[test]
+fn test_eval_matches_dynamic() {
+    let fixed = PolyN::build([0.3, -1.2, 0.5, 2.0, -0.7]).unwrap();
+    let dynamic = Polynomial::from(fixed);
+
+    for x in [-1.7, 0.0, 0.4, 3.0, f64::INFINITY] {
+        assert_eq!(fixed.eval(x), dynamic.eval(x));
+        assert_eq!(
+            fixed.eval_derivs::<6>(x).to_vec(),
+            dynamic.eval_derivs(x, 6)
+        );
+        assert_eq!(
+            fixed.eval_derivs::<3>(x).to_vec(),
+            dynamic.eval_derivs(x, 3)
+        );
+    }
+
+    let z = Complex64::new(0.5, -1.0);
+    let complex = PolyN::build([Complex64::new(1.0, 1.0), Complex64::new(0.0, 2.0)]).unwrap();
+    assert_eq!(complex.eval(z), Polynomial::from(complex).eval(z));
+}
+
+#[test]
+fn test_degenerate_sizes() {
+    let empty = PolyN::<f64, 0>::build([]).unwrap();
+    assert_eq!(empty.eval(2.0), 0.0);
+    assert_eq!(empty.eval_derivs::<2>(2.0), [0.0, 0.0]);
+    assert_eq!(empty.derivative().coef, [0.0f64; 0]);
+
+    let constant = PolyN::build([5.0]).unwrap();
+    assert_eq!(constant.eval_derivs::<3>(2.0), [5.0, 0.0, 0.0]);
+    assert_eq!(constant.eval_derivs::<0>(2.0), [0.0f64; 0]);
+    assert_eq!(constant.derivative().coef, [0.0]);
+}
+
+#[test]
+fn test_derivative() {
+    let poly = PolyN::build([1.0, 2.0, 3.0, 4.0]).unwrap();
+    assert_eq!(poly.derivative().coef, [2.0, 6.0, 12.0, 0.0]);
+}
+
+#[test]
+fn test_solve() {
+    assert_eq!(PolyN::build([-3.0, 2.0]).unwrap().solve().unwrap(), 1.5);
+
+    let roots = PolyN::build([-2.0, 1.0, 1.0]).unwrap().solve().unwrap();
+    assert_eq!(roots, QuadraticRoots::Two(-2.0, 1.0));
+
+    // (x+1)(x−2)(x−3)
+    let CubicRoots::Three(roots) = PolyN::build([6.0, 1.0, -4.0, 1.0])
+        .unwrap()
+        .solve()
+        .unwrap()
+    else {
+        panic!("expected three real roots");
+    };
+    for (r, e) in roots.iter().zip([-1.0, 2.0, 3.0]) {
+        assert!(is_close!(*r, e, rel_tol = EPS));
+    }
+
+    // (x²−1)(x²−4)
+    let (roots, n) = PolyN::build([4.0, 0.0, -5.0, 0.0, 1.0])
+        .unwrap()
+        .solve()
+        .unwrap();
+    assert_eq!(n, 4);
+    for (r, e) in roots.iter().zip([-2.0, -1.0, 1.0, 2.0]) {
+        assert!(is_close!(*r, e, rel_tol = EPS));
+    }
+
+    assert!(matches!(
+        PolyN::build([6.0, 1.0, -4.0, 0.0]).unwrap().solve(),
+        Err(PolyError::IncorrectOrder { expected: 3, .. })
+    ));
+}
+
+#[test]
+fn test_conversions() {
+    let poly = Polynomial::build(&[1.0, 2.0, 0.0]).unwrap();
+
+    let fixed: PolyN<f64, 4> = (&poly).try_into().unwrap();
+    assert_eq!(fixed.coef, [1.0, 2.0, 0.0, 0.0]);
+    assert_eq!(Polynomial::from(fixed), poly);
+
+    let fixed: PolyN<f64, 2> = (&poly).try_into().unwrap();
+    assert_eq!(fixed.coef, [1.0, 2.0]);
+
+    assert!(matches!(
+        PolyN::<f64, 1>::try_from(&poly),
+        Err(PolyError::IncorrectOrder { expected: 0, .. })
+    ));
+
+    let zero: PolyN<f64, 0> = (&Polynomial::new()).try_into().unwrap();
+    assert_eq!(Polynomial::from(zero).coef, [0.0]);
+}
@@ -0,0 +1,59 @@
+use num::complex::Complex64;
+
+use crate::{PolyError, Polynomial};
+
+#[test]
+fn test_grad_coeffs_matches_finite_differences() {
+    let poly = Polynomial::build(&[0.5, -1.0, 2.0, 0.25]).unwrap();
+    let x = 1.3;
+    let grad = poly.eval_grad_coeffs(x);
+
+    assert_eq!(grad.len(), poly.coef.len());
+    for (i, g) in grad.iter().enumerate() {
+        let mut shifted = poly.clone();
+        shifted.coef[i] += 1e-6;
+        let fd: f64 = (shifted.eval(x) - poly.eval(x)) / 1e-6;
+        assert!((fd - g).abs() < 1e-8, "{i}: {fd} vs {g}");
+    }
+    // P is linear in its coefficients
+    let value: f64 = grad.iter().zip(&poly.coef).map(|(g, c)| g * c).sum();
+    assert!((value - poly.eval(x)).abs() < 1e-14);
+}
+
+#[test]
+fn test_grad_coeffs_edge_cases() {
+    assert_eq!(Polynomial::<f64>::new().eval_grad_coeffs(2.0), [1.0]);
+    assert_eq!(
+        Polynomial::build(&[1.0, 1.0, 1.0])
+            .unwrap()
+            .eval_grad_coeffs(0.0),
+        [1.0, 0.0, 0.0]
+    );
+
+    let i = Complex64::new(0.0, 1.0);
+    let poly = Polynomial::build(&[i, i, i]).unwrap();
+    assert_eq!(
+        poly.eval_grad_coeffs(i),
+        [Complex64::new(1.0, 0.0), i, Complex64::new(-1.0, 0.0)]
+    );
+}
+
+#[test]
+fn test_gauss_newton_step_fits_exactly() {
+    // A single Gauss-Newton step solves the linear least squares problem
+    let xs = [-1.0, 0.0, 1.0, 2.0];
+    let ys: Vec<f64> = xs.iter().map(|x| 1.0 - 2.0 * x + 0.5 * x * x).collect();
+    let poly = Polynomial::build(&[0.0, 0.0, 0.0]).unwrap();
+    let (res, jac) = poly.residuals_and_jacobian(&xs, &ys).unwrap();
+
+    let (step, _) = crate::linalg::lstsq(&jac, &res).unwrap();
+    let fitted: Vec<f64> = poly.coef.iter().zip(&step).map(|(c, s)| c - s).collect();
+    for (c, e) in fitted.iter().zip([1.0, -2.0, 0.5]) {
+        assert!((c - e).abs() < 1e-12);
+    }
+
+    assert!(matches!(
+        poly.residuals_and_jacobian(&xs, &ys[1..]),
+        Err(PolyError::LengthMismatch(4, 3))
+    ));
+}
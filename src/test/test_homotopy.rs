@@ -0,0 +1,173 @@
+use num::complex::Complex64;
+
+use crate::homotopy::{self, TrackOptions};
+use crate::{PolyError, Polynomial};
+
+#[test]
+fn test_track_smooth_paths() {
+    // (x − t)(x − 2 − t)(x + 1): three real roots that never collide
+    let family = |t: f64| Polynomial::from_roots(&[t, 2.0 + t, -1.0]);
+    let paths = homotopy::track(family, 0.0, 3.0, TrackOptions::default()).unwrap();
+
+    assert!(paths.collisions.is_empty());
+    assert_eq!(paths.t.len(), paths.roots.len());
+    assert_eq!(*paths.t.last().unwrap(), 3.0);
+    assert!(paths.t.windows(2).all(|t| t[0] < t[1]));
+
+    // Every path continues the root it started from
+    let start: Vec<f64> = paths.roots[0].iter().map(|x| x.re).collect();
+    for (t, roots) in paths.t.iter().zip(&paths.roots) {
+        for (x, x0) in roots.iter().zip(&start) {
+            let expected = match *x0 < -0.5 {
+                true => -1.0,
+                false => x0 + t,
+            };
+            assert!((x.re - expected).abs() < 1e-10 && x.im.abs() < 1e-10);
+        }
+    }
+}
+
+#[test]
+fn test_track_backwards() {
+    let family = |t: f64| Polynomial::build(&[-t, 0.0, 0.0, 1.0]).unwrap();
+    let paths = homotopy::track(family, 8.0, 1.0, TrackOptions::default()).unwrap();
+
+    assert_eq!(*paths.t.last().unwrap(), 1.0);
+    assert!(paths.t.windows(2).all(|t| t[0] > t[1]));
+    for x in paths.roots.last().unwrap() {
+        assert!((x.norm() - 1.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_track_collision() {
+    // x² − 2x + t: real roots 1 ± √(1−t) that collide at t = 1
+    let family = |t: f64| Polynomial::build(&[t, -2.0, 1.0]).unwrap();
+    let paths = homotopy::track(family, 0.0, 2.0, TrackOptions::default()).unwrap();
+
+    assert_eq!(paths.collisions.len(), 1);
+    assert!((paths.collisions[0].t - 1.0).abs() < 1e-6);
+    assert_eq!(paths.collisions[0].roots, [0, 1]);
+    let last = paths.roots.last().unwrap();
+    assert!(
+        last.iter()
+            .all(|x| (x.re - 1.0).abs() < 1e-12 && (x.im.abs() - 1.0).abs() < 1e-12)
+    );
+}
+
+#[test]
+fn test_track_errors() {
+    let opts = TrackOptions::default();
+    let linear = |t: f64| Polynomial::build(&[1.0, t]).unwrap();
+
+    assert!(matches!(
+        homotopy::track(linear, 0.0, f64::NAN, opts),
+        Err(PolyError::InvalidInterval(..))
+    ));
+    // The order drops at t = 0.5
+    let drops = |t: f64| Polynomial::build(&[1.0, 1.0, if t < 0.5 { 1.0 } else { 0.0 }]).unwrap();
+    assert!(matches!(
+        homotopy::track(drops, 0.0, 1.0, opts),
+        Err(PolyError::IncorrectOrder {
+            expected: 2,
+            got: 1
+        })
+    ));
+    assert!(matches!(
+        homotopy::track(|_| Polynomial::build(&[1.0]).unwrap(), 0.0, 1.0, opts),
+        Err(PolyError::ConstantPoly)
+    ));
+
+    // Steps that are not positive, not ordered or not finite
+    let steps = |initial_step, min_step, max_step| TrackOptions {
+        initial_step,
+        min_step,
+        max_step,
+    };
+    for opts in [
+        steps(0.0, 1e-9, 0.1),
+        steps(-0.01, 1e-9, 0.1),
+        steps(0.01, 0.0, 0.1),
+        steps(0.01, -1e-9, 0.1),
+        steps(1e-10, 1e-9, 0.1),
+        steps(0.2, 1e-9, 0.1),
+        steps(f64::NAN, 1e-9, 0.1),
+        steps(0.01, 1e-9, f64::INFINITY),
+    ] {
+        assert!(
+            matches!(
+                homotopy::track(
+                    |t| Polynomial::build(&[-t, 0.0, 1.0]).unwrap(),
+                    -1.0,
+                    1.0,
+                    opts
+                ),
+                Err(PolyError::InvalidData)
+            ),
+            "{opts:?}"
+        );
+    }
+}
+
+#[test]
+fn test_solve_by_continuation() {
+    let roots = [
+        Complex64::new(-3.0, 0.0),
+        Complex64::new(0.5, 2.0),
+        Complex64::new(0.5, -2.0),
+        Complex64::new(1.0, 0.0),
+        Complex64::new(4.0, 0.0),
+    ];
+    let poly = Polynomial::from_roots_with_leading(Complex64::new(2.0, 0.0), &roots);
+    let found = homotopy::solve(&poly).unwrap();
+
+    assert_eq!(found.len(), 5);
+    assert!(poly.verify_roots(&found).passes(1e-10));
+
+    // Complex coefficients
+    let poly = Polynomial::build(&[
+        Complex64::new(1.0, 1.0),
+        Complex64::new(0.0, -2.0),
+        1.0.into(),
+    ])
+    .unwrap();
+    assert!(
+        poly.verify_roots(&homotopy::solve(&poly).unwrap())
+            .passes(1e-12)
+    );
+
+    assert!(matches!(
+        homotopy::solve(&Polynomial::build(&[2.0]).unwrap()),
+        Err(PolyError::ConstantPoly)
+    ));
+
+    // Steps that are not positive, not ordered or not finite
+    let steps = |initial_step, min_step, max_step| TrackOptions {
+        initial_step,
+        min_step,
+        max_step,
+    };
+    for opts in [
+        steps(0.0, 1e-9, 0.1),
+        steps(-0.01, 1e-9, 0.1),
+        steps(0.01, 0.0, 0.1),
+        steps(0.01, -1e-9, 0.1),
+        steps(1e-10, 1e-9, 0.1),
+        steps(0.2, 1e-9, 0.1),
+        steps(f64::NAN, 1e-9, 0.1),
+        steps(0.01, 1e-9, f64::INFINITY),
+    ] {
+        assert!(
+            matches!(
+                homotopy::track(
+                    |t| Polynomial::build(&[-t, 0.0, 1.0]).unwrap(),
+                    -1.0,
+                    1.0,
+                    opts
+                ),
+                Err(PolyError::InvalidData)
+            ),
+            "{opts:?}"
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use is_close::is_close;
+
+use crate::{PolyError, solve::earliest_impact};
+
+#[test]
+fn test_impact_crossing() {
+    // (t-0.5)(t-2)(t-3)
+    let (a, b, c, d) = (1.0, -5.5, 8.5, -3.0);
+    let t = earliest_impact(a, b, c, d, 10.0).unwrap().unwrap();
+    assert!(is_close!(t, 0.5, rel_tol = 1e-14));
+
+    // Same roots with a negative leading coefficient
+    let t = earliest_impact(-a, -b, -c, -d, 10.0).unwrap().unwrap();
+    assert!(is_close!(t, 0.5, rel_tol = 1e-14));
+
+    // t_max is inclusive
+    assert!(earliest_impact(1.0, 0.0, 0.0, -8.0, 2.0).unwrap().is_some());
+    assert!(earliest_impact(1.0, 0.0, 0.0, -8.0, 1.9).unwrap().is_none());
+}
+
+#[test]
+fn test_impact_ignores_non_positive_roots() {
+    // t(t+1)(t-4)
+    let t = earliest_impact(1.0, -3.0, -4.0, 0.0, 10.0)
+        .unwrap()
+        .unwrap();
+    assert!(is_close!(t, 4.0, rel_tol = 1e-14));
+}
+
+#[test]
+fn test_impact_grazing() {
+    // (t-0.1)²(t+5): the double root is usually computed as a complex pair or missed
+    for offset in [0.1, 0.3, 1.0 / 3.0] {
+        let (a, b, c, d) = (
+            1.0,
+            5.0 - 2.0 * offset,
+            offset * offset - 10.0 * offset,
+            5.0 * offset * offset,
+        );
+        let t = earliest_impact(a, b, c, d, 1.0).unwrap().unwrap();
+        assert!(is_close!(t, offset, rel_tol = 1e-7));
+    }
+
+    // Near miss: the minimum stays clearly above zero
+    assert!(
+        earliest_impact(0.0, 1.0, -2.0, 1.001, 5.0)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_impact_degenerate() {
+    // Linear
+    assert_eq!(
+        earliest_impact(0.0, 0.0, 2.0, -1.0, 1.0).unwrap(),
+        Some(0.5)
+    );
+    // Constant, even if identically zero
+    assert_eq!(earliest_impact(0.0, 0.0, 0.0, 1.0, 1.0).unwrap(), None);
+    assert_eq!(earliest_impact(0.0, 0.0, 0.0, 0.0, 1.0).unwrap(), None);
+
+    assert!(matches!(
+        earliest_impact(1.0, 0.0, 0.0, 0.0, -1.0).unwrap_err(),
+        PolyError::InvalidInterval(_, _)
+    ));
+    assert!(matches!(
+        earliest_impact(f64::NAN, 0.0, 0.0, 0.0, 1.0).unwrap_err(),
+        PolyError::InvalidCoefficients { .. }
+    ));
+}
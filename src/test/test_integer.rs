@@ -0,0 +1,142 @@
+use crate::{PolyError, Polynomial};
+
+#[test]
+fn test_eval_exact() {
+    let poly = Polynomial::<i64>::from_integers(&[1, 2, 3]);
+    assert_eq!(poly.eval_exact(2).unwrap(), 17);
+    assert_eq!(poly.eval_exact(-3).unwrap(), 22);
+
+    // Exact where f64 rounds: x² + 1 at 2³⁰ + 1
+    let poly = Polynomial::<i64>::from_integers(&[1, 0, 1]);
+    let x = (1 << 30) + 1;
+    assert_eq!(poly.eval_exact(x).unwrap(), x * x + 1);
+
+    let big = Polynomial::<i128>::from_integers(&[1, 0, 1]);
+    assert_eq!(big.eval_exact(1 << 60).unwrap(), (1 << 120) + 1);
+
+    assert!(matches!(
+        poly.eval_exact(1 << 32),
+        Err(PolyError::IntegerOverflow)
+    ));
+    assert_eq!(
+        Polynomial::<i64>::from_integers(&[]).eval_exact(5).unwrap(),
+        0
+    );
+}
+
+#[test]
+fn test_checked_arithmetic() {
+    let p = Polynomial::<i64>::from_integers(&[1, 2, 3]);
+    let q = Polynomial::<i64>::from_integers(&[-1, 1]);
+
+    assert_eq!(p.checked_add(&q).unwrap().coef, [0, 3, 3]);
+    assert_eq!(p.checked_sub(&q).unwrap().coef, [2, 1, 3]);
+    assert_eq!(p.checked_mul(&q).unwrap().coef, [-1, -1, -1, 3]);
+
+    let max = Polynomial::<i64>::from_integers(&[i64::MAX]);
+    assert!(matches!(
+        max.checked_add(&max),
+        Err(PolyError::IntegerOverflow)
+    ));
+    assert!(matches!(
+        max.checked_mul(&p),
+        Err(PolyError::IntegerOverflow)
+    ));
+    assert!(matches!(
+        max.checked_sub(&Polynomial::<i64>::from_integers(&[-1])),
+        Err(PolyError::IntegerOverflow)
+    ));
+}
+
+#[test]
+fn test_content() {
+    let poly = Polynomial::<i64>::from_integers(&[6, -4, -8]);
+    assert_eq!(poly.content(), -2);
+    assert_eq!(poly.primitive_part().coef, [-3, 2, 4]);
+
+    let poly = Polynomial::<i128>::from_integers(&[0, 9, 0, 15, 0]);
+    assert_eq!(poly.content(), 3);
+    assert_eq!(poly.primitive_part().coef, [0, 3, 0, 5, 0]);
+
+    let zero = Polynomial::<i64>::from_integers(&[0, 0]);
+    assert_eq!(zero.content(), 0);
+    assert_eq!(zero.primitive_part().coef, [0, 0]);
+}
+
+#[test]
+fn test_integer_roots() {
+    // x(x−3)(x+2)(2x−1)
+    let poly = Polynomial::<i64>::from_integers(&[0, 6, -11, -3, 2]);
+    assert_eq!(poly.integer_roots().unwrap(), [-2, 0, 3]);
+
+    // (x−1)²(x²+1) has a single distinct integer root
+    let poly = Polynomial::<i64>::from_integers(&[1, -2, 2, -2, 1]);
+    assert_eq!(poly.integer_roots().unwrap(), [1]);
+
+    // (x − 999983)(x + 7)
+    let poly = Polynomial::<i128>::from_integers(&[-6999881, -999976, 1]);
+    assert_eq!(poly.integer_roots().unwrap(), [-7, 999983]);
+
+    assert!(
+        Polynomial::<i64>::from_integers(&[1, 0, 1])
+            .integer_roots()
+            .unwrap()
+            .is_empty()
+    );
+    assert!(matches!(
+        Polynomial::<i64>::from_integers(&[0]).integer_roots(),
+        Err(PolyError::Trivial)
+    ));
+    assert!(matches!(
+        Polynomial::<i64>::from_integers(&[i64::MIN, 1]).integer_roots(),
+        Err(PolyError::IntegerOverflow)
+    ));
+}
+
+#[test]
+fn test_rational_roots() {
+    // (2x−1)(3x+2)(x²+1)
+    let poly = Polynomial::<i64>::from_integers(&[-2, 1, 4, 1, 6]);
+    assert_eq!(poly.rational_roots().unwrap(), [(-2, 3), (1, 2)]);
+
+    // x²(4x−3)(4x+3)(x−5), with a repeated root at 0
+    let poly = Polynomial::<i128>::from_integers(&[0, 0, 45, -9, -80, 16]);
+    assert_eq!(
+        poly.rational_roots().unwrap(),
+        [(-3, 4), (0, 1), (3, 4), (5, 1)]
+    );
+
+    // Irrational roots only: 2x² − 1
+    let poly = Polynomial::<i64>::from_integers(&[-1, 0, 2]);
+    assert!(poly.rational_roots().unwrap().is_empty());
+
+    // Non-primitive polynomials have the same roots: 6(2x − 3)
+    let poly = Polynomial::<i64>::from_integers(&[-18, 12]);
+    assert_eq!(poly.rational_roots().unwrap(), [(3, 2)]);
+
+    assert!(matches!(
+        Polynomial::<i64>::from_integers(&[0, 0]).rational_roots(),
+        Err(PolyError::Trivial)
+    ));
+}
+
+#[test]
+fn test_rational_float_polynomials() {
+    // (x − 1/2)(x + 2/3) = x² + x/6 − 1/3
+    let poly = Polynomial::build(&[-1.0 / 3.0, 1.0 / 6.0, 1.0]).unwrap();
+    assert_eq!(poly.rational_roots().unwrap(), [(-2, 3), (1, 2)]);
+    assert_eq!(poly.content().unwrap(), 1.0 / 6.0);
+    assert_eq!(poly.primitive_part().unwrap().coef, [-2.0, 1.0, 6.0]);
+
+    let poly = Polynomial::build(&[-4.0, 0.0, -6.0]).unwrap();
+    assert_eq!(poly.content().unwrap(), -2.0);
+    assert_eq!(poly.primitive_part().unwrap().coef, [2.0, 0.0, 3.0]);
+
+    assert_eq!(Polynomial::<f64>::new().content().unwrap(), 0.0);
+    assert!(matches!(
+        Polynomial::build(&[std::f64::consts::PI, 1.0])
+            .unwrap()
+            .rational_roots(),
+        Err(PolyError::NotRational(_))
+    ));
+}
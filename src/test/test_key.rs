@@ -0,0 +1,59 @@
+use std::collections::{HashMap, HashSet};
+
+use num::complex::Complex64;
+
+use crate::{PolyKey, Polynomial};
+
+#[test]
+fn test_canonical_key() {
+    let p = Polynomial::build(&[1.0, -0.0, 2.0, 0.0, -0.0]).unwrap();
+    let q = Polynomial::build(&[1.0, 0.0, 2.0]).unwrap();
+    let r = Polynomial::build(&[1.0, 0.0, 2.0 + f64::EPSILON * 2.0]).unwrap();
+
+    assert_eq!(p.canonical_key(), q.canonical_key());
+    assert_ne!(p.canonical_key(), r.canonical_key());
+    assert_eq!(q.canonical_key().len(), 6);
+
+    assert!(Polynomial::<f64>::new().canonical_key().is_empty());
+    assert!(
+        Polynomial::build(&[0.0, -0.0])
+            .unwrap()
+            .canonical_key()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_canonical_key_complex() {
+    let p = Polynomial::build(&[Complex64::new(1.0, 2.0)]).unwrap();
+    let q = Polynomial::build(&[Complex64::new(1.0, -2.0)]).unwrap();
+
+    assert_ne!(p.canonical_key(), q.canonical_key());
+    assert_eq!(p.canonical_key(), vec![1.0f64.to_bits(), 2.0f64.to_bits()]);
+}
+
+#[test]
+fn test_poly_key() {
+    let p = Polynomial::build(&[-1.0, 0.0, 1.0]).unwrap();
+    let q = Polynomial::build(&[-1.0, 0.0, 1.0, 0.0]).unwrap();
+    let nan = Polynomial {
+        coef: vec![f64::NAN, 1.0],
+    };
+
+    let mut map = HashMap::new();
+    map.insert(PolyKey::new(p.clone()), 1);
+    map.insert(PolyKey::from(q.clone()), 2);
+    assert_eq!(map.len(), 1);
+    assert_eq!(map[&PolyKey::new(p)], 2);
+
+    let set: HashSet<PolyKey<f64>> = [nan.clone(), nan.clone()]
+        .into_iter()
+        .map(PolyKey::new)
+        .collect();
+    assert_eq!(set.len(), 1);
+
+    let key = PolyKey::new(q.clone());
+    assert_eq!(key.poly(), &q);
+    assert_eq!(key.key(), q.canonical_key().as_slice());
+    assert_eq!(key.into_inner().coef, q.coef);
+}
@@ -0,0 +1,51 @@
+use crate::{LutNodes, PolyError, Polynomial};
+use is_close::is_close;
+
+#[test]
+fn test_lut_nodes() {
+    let poly = Polynomial::build(&[1.0, -2.0, 0.5, 1.0]).unwrap();
+
+    for nodes in [LutNodes::Uniform, LutNodes::Chebyshev, LutNodes::EqualError] {
+        let lut = poly.to_lut(-1.0, 3.0, 10, nodes).unwrap();
+
+        assert_eq!(lut.points.len(), 10);
+        assert_eq!(lut.points[0].0, -1.0);
+        assert_eq!(lut.points[9].0, 3.0);
+        assert!(lut.points.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(lut.points.iter().all(|(x, y)| *y == poly.eval(*x)));
+        assert!(lut.rms_error <= lut.max_error);
+    }
+}
+
+#[test]
+fn test_lut_linear_is_exact() {
+    let poly = Polynomial::build(&[1.0, 2.0]).unwrap();
+    let lut = poly.to_lut(0.0, 1.0, 5, LutNodes::EqualError).unwrap();
+
+    assert!(is_close!(lut.max_error, 0.0, abs_tol = 1e-14));
+    assert!(is_close!(lut.eval(0.3), 1.6, rel_tol = 1e-14));
+}
+
+#[test]
+fn test_lut_equal_error_is_better() {
+    // Steep curvature near x=1
+    let poly = Polynomial::build(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0]).unwrap();
+    let uniform = poly.to_lut(0.0, 1.0, 20, LutNodes::Uniform).unwrap();
+    let equal = poly.to_lut(0.0, 1.0, 20, LutNodes::EqualError).unwrap();
+
+    assert!(equal.max_error < 0.5 * uniform.max_error);
+}
+
+#[test]
+fn test_lut_invalid() {
+    let poly = Polynomial::build(&[1.0, 2.0]).unwrap();
+
+    assert!(matches!(
+        poly.to_lut(0.0, 1.0, 1, LutNodes::Uniform).unwrap_err(),
+        PolyError::NotEnoughPoints(2)
+    ));
+    assert!(matches!(
+        poly.to_lut(1.0, 0.0, 4, LutNodes::Uniform).unwrap_err(),
+        PolyError::InvalidInterval(_, _)
+    ));
+}
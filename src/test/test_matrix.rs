@@ -0,0 +1,124 @@
+use nalgebra::DMatrix;
+use num::complex::Complex64;
+
+use crate::{PolyError, Polynomial};
+
+/// Evaluates P(A) with Horner's method, for reference.
+fn horner(poly: &Polynomial<f64>, a: &DMatrix<f64>) -> DMatrix<f64> {
+    let n = a.nrows();
+    poly.coef.iter().rev().fold(DMatrix::zeros(n, n), |acc, c| {
+        acc * a + DMatrix::identity(n, n) * *c
+    })
+}
+
+#[test]
+fn test_eval_matrix() {
+    let a = DMatrix::from_row_slice(3, 3, &[0.5, 1.0, 0.0, -0.2, 0.3, 0.4, 0.1, 0.0, -0.6]);
+
+    for order in 0..12 {
+        let coef: Vec<f64> = (0..=order).map(|i| (i as f64 * 0.7).sin()).collect();
+        let poly = Polynomial::build(&coef).unwrap();
+
+        let res = poly.eval_matrix(&a).unwrap();
+        assert!((res - horner(&poly, &a)).amax() < 1e-14);
+    }
+}
+
+#[test]
+fn test_eval_matrix_diagonal() {
+    // P(diag(d)) = diag(P(d))
+    let poly = Polynomial::build(&[1.0, -2.0, 0.5, 3.0, 1.0]).unwrap();
+    let d: [f64; 3] = [-1.0, 0.5, 2.0];
+    let a = DMatrix::from_diagonal(&nalgebra::DVector::from_row_slice(&d));
+
+    let res = poly.eval_matrix(&a).unwrap();
+    for (i, x) in d.iter().enumerate() {
+        assert!((res[(i, i)] - poly.eval(*x)).abs() < 1e-13);
+    }
+}
+
+#[test]
+fn test_eval_matrix_complex() {
+    let i = Complex64::new(0.0, 1.0);
+    let poly = Polynomial::build(&[Complex64::new(1.0, 0.0), i]).unwrap(); // 1+ix
+    let a = DMatrix::from_row_slice(1, 1, &[i]);
+
+    assert_eq!(
+        poly.eval_matrix(&a).unwrap()[(0, 0)],
+        Complex64::new(0.0, 0.0)
+    );
+}
+
+#[test]
+fn test_eval_matrix_not_square() {
+    let poly = Polynomial::build(&[1.0, 1.0]).unwrap();
+    assert!(matches!(
+        poly.eval_matrix(&DMatrix::zeros(2, 3)),
+        Err(PolyError::NotSquareMatrix(2, 3))
+    ));
+}
+
+#[test]
+fn test_companion_matrix() {
+    let poly = Polynomial::build(&[-6.0, 11.0, -6.0, 1.0, 0.0]).unwrap();
+    let companion = poly.companion_matrix().unwrap();
+
+    let expected = DMatrix::from_row_slice(3, 3, &[0.0, 0.0, 6.0, 1.0, 0.0, -11.0, 0.0, 1.0, 6.0]);
+    assert_eq!(companion, expected);
+
+    let mut eigenvalues: Vec<f64> = companion
+        .complex_eigenvalues()
+        .iter()
+        .map(|z| z.re)
+        .collect();
+    eigenvalues.sort_by(f64::total_cmp);
+    for (e, r) in eigenvalues.iter().zip([1.0, 2.0, 3.0]) {
+        assert!((e - r).abs() < 1e-12);
+    }
+
+    assert!(matches!(
+        Polynomial::build(&[1.0, 0.0]).unwrap().companion_matrix(),
+        Err(PolyError::ConstantPoly)
+    ));
+}
+
+#[test]
+fn test_characteristic() {
+    // The characteristic polynomial of the companion matrix is the monic polynomial
+    let poly = Polynomial::build(&[3.0, -1.0, 0.5, 2.0, -4.0]).unwrap();
+    let monic = &poly * (1.0 / -4.0);
+    let charpoly = Polynomial::characteristic(&poly.companion_matrix().unwrap()).unwrap();
+    assert!(charpoly.approx_eq(&monic, 1e-13, 1e-14));
+
+    let empty = Polynomial::<f64>::characteristic(&DMatrix::zeros(0, 0)).unwrap();
+    assert_eq!(empty.coef, [1.0]);
+
+    assert!(matches!(
+        Polynomial::<f64>::characteristic(&DMatrix::zeros(1, 2)),
+        Err(PolyError::NotSquareMatrix(1, 2))
+    ));
+}
+
+#[test]
+fn test_characteristic_cayley_hamilton() {
+    let a = DMatrix::from_fn(6, 6, |i, j| ((3 * i + 7 * j) as f64 * 0.37).sin());
+    let charpoly = Polynomial::characteristic(&a).unwrap();
+
+    assert_eq!(charpoly.coef.len(), 7);
+    assert!((charpoly.coef[6] - 1.0).abs() < 1e-15);
+    assert!((charpoly.coef[5] + a.trace()).abs() < 1e-13);
+    assert!((charpoly.coef[0] - a.determinant()).abs() < 1e-13);
+    assert!(charpoly.eval_matrix(&a).unwrap().amax() < 1e-12);
+}
+
+#[test]
+fn test_characteristic_complex() {
+    let i = Complex64::new(0.0, 1.0);
+    let one = Complex64::new(1.0, 0.0);
+    let a = DMatrix::from_row_slice(2, 2, &[i, one, one, -i]);
+    let charpoly = Polynomial::characteristic(&a).unwrap(); // x²
+
+    assert!(charpoly.coef[0].norm() < 1e-15);
+    assert!(charpoly.coef[1].norm() < 1e-15);
+    assert!((charpoly.coef[2] - one).norm() < 1e-15);
+}
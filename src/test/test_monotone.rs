@@ -0,0 +1,62 @@
+use is_close::is_close;
+
+use crate::{Norm, PolyError, Polynomial};
+
+fn min_derivative(poly: &Polynomial<f64>, a: f64, b: f64) -> f64 {
+    let deriv = poly.derivative();
+    (0..=1000)
+        .map(|i| deriv.eval(a + (b - a) * i as f64 / 1000.0))
+        .fold(f64::INFINITY, f64::min)
+}
+
+#[test]
+fn test_monotone_unchanged() {
+    let poly = Polynomial::build(&[1.0, 2.0, 0.5, 0.1]).unwrap();
+    let monotone = poly.nearest_monotone_on(0.0, 2.0).unwrap();
+
+    assert_eq!(monotone.coef, poly.coef);
+}
+
+#[test]
+fn test_monotone_increasing() {
+    let poly = Polynomial::build(&[0.0, -0.2, 0.0, 1.0]).unwrap();
+    assert!(min_derivative(&poly, -1.0, 1.0) < 0.0);
+
+    let monotone = poly.nearest_monotone_on(-1.0, 1.0).unwrap();
+
+    assert_eq!(monotone.coef.len(), 4);
+    assert!(min_derivative(&monotone, -1.0, 1.0) > -1e-9);
+
+    // The correction is small, and smaller than shifting to a trivially monotone polynomial
+    let dist = poly.distance_to(&monotone, -1.0, 1.0, Norm::L2).unwrap();
+    let cube = Polynomial::build(&[0.0, 0.0, 0.0, 1.0]).unwrap();
+    assert!(dist < poly.distance_to(&cube, -1.0, 1.0, Norm::L2).unwrap());
+}
+
+#[test]
+fn test_monotone_decreasing_shifted_interval() {
+    // -(x-3)³ + 0.3(x-3) on [2, 4]
+    let poly = Polynomial::build(&[26.1, -27.3, 9.0, -1.0]).unwrap();
+    let monotone = poly.nearest_monotone_on(2.0, 4.0).unwrap();
+
+    let deriv = monotone.derivative();
+    assert!((0..=1000).all(|i| deriv.eval(2.0 + 0.002 * i as f64) < 1e-9));
+
+    // Symmetric problem around x=3, so the midpoint value is preserved
+    assert!(is_close!(
+        monotone.eval(3.0),
+        poly.eval(3.0),
+        abs_tol = 1e-9
+    ));
+}
+
+#[test]
+fn test_monotone_linear_and_invalid() {
+    let poly = Polynomial::build(&[1.0, -2.0]).unwrap();
+    assert_eq!(poly.nearest_monotone_on(0.0, 1.0).unwrap().coef, poly.coef);
+
+    assert!(matches!(
+        poly.nearest_monotone_on(1.0, 1.0).unwrap_err(),
+        PolyError::InvalidInterval(_, _)
+    ));
+}
@@ -0,0 +1,82 @@
+use is_close::is_close;
+
+use crate::{MultiPoly, PolyError, Polynomial};
+
+#[test]
+fn test_multi_build_eval() {
+    // x²y + 2yz − z³ + 4
+    let poly = MultiPoly::build(&[
+        ([2, 1, 0], 1.0),
+        ([0, 1, 1], 2.0),
+        ([0, 0, 3], -1.0),
+        ([0, 0, 0], 4.0),
+    ])
+    .unwrap();
+
+    assert_eq!(poly.degree(), 3);
+    assert_eq!(poly.terms().len(), 4);
+
+    let (x, y, z) = (1.5, -0.5, 2.0);
+    let expected = x * x * y + 2.0 * y * z - z * z * z + 4.0;
+    assert!(is_close!(poly.eval([x, y, z]), expected, rel_tol = 1e-15));
+
+    let merged = MultiPoly::build(&[([1, 1], 1.0), ([1, 1], -1.0)]).unwrap();
+    assert_eq!(merged, MultiPoly::new());
+    assert_eq!(merged.eval([3.0, 4.0]), 0.0);
+
+    assert!(matches!(
+        MultiPoly::build(&[([1, 1], f64::INFINITY)]).unwrap_err(),
+        PolyError::InvalidCoefficients { .. }
+    ));
+}
+
+#[test]
+fn test_multi_partial_derivative() {
+    // x³y² + xy
+    let poly = MultiPoly::build(&[([3, 2], 1.0), ([1, 1], 1.0)]).unwrap();
+
+    let dx = poly.partial_derivative(0).unwrap();
+    assert_eq!(dx.terms(), [([0, 1], 1.0), ([2, 2], 3.0)]);
+
+    let dy = poly.partial_derivative(1).unwrap();
+    assert_eq!(dy.terms(), [([1, 0], 1.0), ([3, 1], 2.0)]);
+
+    assert!(matches!(
+        poly.partial_derivative(2).unwrap_err(),
+        PolyError::InvalidVariable(2, 2)
+    ));
+}
+
+#[test]
+fn test_multi_substitute() {
+    // x²+xy−y
+    let poly = MultiPoly::build(&[([2, 0], 1.0), ([1, 1], 1.0), ([0, 1], -1.0)]).unwrap();
+    let parabola = Polynomial::build(&[0.0, 0.0, 1.0]).unwrap(); // y = x²
+
+    let on_curve = poly.substitute(1, &parabola, 0).unwrap();
+    let univariate = on_curve.to_univariate(0).unwrap();
+    assert_eq!(univariate.coef, [0.0, 0.0, 0.0, 1.0]);
+
+    for x in [-1.0, 0.3, 2.0] {
+        assert!(is_close!(
+            univariate.eval(x),
+            poly.eval([x, parabola.eval(x)]),
+            rel_tol = 1e-15
+        ));
+    }
+
+    // x = 2t in the same variable
+    let scaled = poly
+        .substitute(0, &Polynomial::build(&[0.0, 2.0]).unwrap(), 0)
+        .unwrap();
+    assert!(is_close!(
+        scaled.eval([0.5, 3.0]),
+        poly.eval([1.0, 3.0]),
+        rel_tol = 1e-15
+    ));
+
+    assert!(matches!(
+        poly.to_univariate(0).unwrap_err(),
+        PolyError::NotUnivariate(0)
+    ));
+}
@@ -0,0 +1,83 @@
+use is_close::is_close;
+
+use crate::{OnlinePolyFit, PolyError, Polynomial};
+
+const TOL: f64 = 1e-10;
+
+#[test]
+fn test_online_matches_fit() {
+    let xs: Vec<f64> = (0..50).map(|i| -1.0 + i as f64 / 20.0).collect();
+    let ys: Vec<f64> = xs
+        .iter()
+        .enumerate()
+        .map(|(i, x)| 0.3 - x + 2.0 * x.powi(3) + 0.05 * ((i * 7 % 13) as f64 - 6.0) / 6.0)
+        .collect();
+
+    let mut online = OnlinePolyFit::new(3);
+    assert!(online.is_empty());
+    for (x, y) in xs.iter().zip(&ys) {
+        online.update(*x, *y).unwrap();
+    }
+    assert_eq!(online.len(), 50);
+    assert_eq!(online.degree(), 3);
+
+    let batch = Polynomial::fit(&xs, &ys, 3).unwrap();
+    let fit = online.fit().unwrap();
+    for (a, b) in fit.poly.coef.iter().zip(&batch.poly.coef) {
+        assert!(is_close!(*a, *b, rel_tol = TOL));
+    }
+    for (row_a, row_b) in fit.covariance.iter().zip(&batch.covariance) {
+        for (a, b) in row_a.iter().zip(row_b) {
+            assert!(is_close!(*a, *b, rel_tol = 1e-8));
+        }
+    }
+    assert!(is_close!(fit.chi2, batch.chi2, rel_tol = 1e-8));
+    assert_eq!(fit.dof, batch.dof);
+
+    // Prediction bands work as for the batch fit
+    let (_, band) = fit.prediction_interval(0.2, 0.95).unwrap();
+    let (_, expected) = batch.prediction_interval(0.2, 0.95).unwrap();
+    assert!(is_close!(band, expected, rel_tol = 1e-8));
+}
+
+#[test]
+fn test_online_refresh() {
+    // The fit can be read at any point of the stream
+    let mut online = OnlinePolyFit::new(1);
+    online.update(0.0, 1.0).unwrap();
+    assert!(matches!(online.fit(), Err(PolyError::NotEnoughPoints(2))));
+    online.update(0.0, 3.0).unwrap();
+    assert!(matches!(online.fit(), Err(PolyError::SingularMatrix)));
+
+    online.update(1.0, 4.0).unwrap();
+    let fit = online.fit().unwrap();
+    assert!(is_close!(fit.poly.coef[0], 2.0, rel_tol = TOL));
+    assert!(is_close!(fit.poly.coef[1], 2.0, rel_tol = TOL));
+    assert!(is_close!(fit.chi2, 2.0, rel_tol = TOL));
+}
+
+#[test]
+fn test_online_invalid() {
+    let mut online = OnlinePolyFit::new(2);
+    online.update(1.0, 1.0).unwrap();
+    assert!(matches!(
+        online.update(f64::NAN, 1.0),
+        Err(PolyError::InvalidData)
+    ));
+    assert!(matches!(
+        online.update(1.0, f64::INFINITY),
+        Err(PolyError::InvalidData)
+    ));
+    assert!(matches!(
+        online.update(1e200, 1.0),
+        Err(PolyError::InvalidData)
+    ));
+    assert_eq!(online.len(), 1);
+
+    online.update(2.0, 4.0).unwrap();
+    online.update(3.0, 9.0).unwrap();
+    let fit = online.fit().unwrap();
+    assert!(is_close!(fit.poly.coef[2], 1.0, rel_tol = TOL));
+    assert_eq!(fit.dof, 0);
+    assert!(fit.residual_variance.is_nan());
+}
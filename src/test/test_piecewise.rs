@@ -0,0 +1,96 @@
+use is_close::is_close;
+
+use crate::{PiecewisePolynomial, PolyError, Polynomial};
+
+/// x² on [0, 1) and 1+2(x−1) on [1, 3]
+fn parabola_then_line() -> PiecewisePolynomial {
+    PiecewisePolynomial::build(
+        &[0.0, 1.0, 3.0],
+        &[
+            Polynomial::build(&[0.0, 0.0, 1.0]).unwrap(),
+            Polynomial::build(&[1.0, 2.0]).unwrap(),
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_piecewise_build_invalid() {
+    let p = Polynomial::build(&[1.0]).unwrap();
+
+    assert!(matches!(
+        PiecewisePolynomial::build(&[0.0], &[]).unwrap_err(),
+        PolyError::NotEnoughPoints(2)
+    ));
+    assert!(matches!(
+        PiecewisePolynomial::build(&[0.0, 1.0, 2.0], std::slice::from_ref(&p)).unwrap_err(),
+        PolyError::LengthMismatch(3, 2)
+    ));
+    assert!(matches!(
+        PiecewisePolynomial::build(&[0.0, 0.0], &[p]).unwrap_err(),
+        PolyError::InvalidInterval(_, _)
+    ));
+}
+
+#[test]
+fn test_piecewise_eval() {
+    let pw = parabola_then_line();
+
+    assert_eq!(pw.eval(0.5), 0.25);
+    assert_eq!(pw.eval(1.0), 1.0);
+    assert_eq!(pw.eval(2.0), 3.0);
+    assert_eq!(pw.eval(3.0), 5.0);
+    // Extrapolation
+    assert_eq!(pw.eval(-1.0), 1.0);
+    assert_eq!(pw.eval(4.0), 7.0);
+
+    let deriv = pw.derivative();
+    assert_eq!(deriv.eval(0.5), 1.0);
+    assert_eq!(deriv.eval(2.0), 2.0);
+    assert_eq!(deriv.breaks(), pw.breaks());
+}
+
+#[test]
+fn test_piecewise_integrate() {
+    let pw = parabola_then_line();
+
+    // 1/3 + ∫₁³ (2x − 1) dx = 1/3 + 6
+    assert!(is_close!(
+        pw.integrate(0.0, 3.0).unwrap(),
+        1.0 / 3.0 + 6.0,
+        rel_tol = 1e-15
+    ));
+    assert!(is_close!(
+        pw.integrate(3.0, 0.0).unwrap(),
+        -1.0 / 3.0 - 6.0,
+        rel_tol = 1e-15
+    ));
+    assert!(is_close!(
+        pw.integrate(0.5, 0.75).unwrap(),
+        (0.75f64.powi(3) - 0.125) / 3.0,
+        rel_tol = 1e-15
+    ));
+    assert_eq!(pw.integrate(2.0, 2.0).unwrap(), 0.0);
+    assert!(pw.integrate(0.0, f64::NAN).is_err());
+}
+
+#[test]
+fn test_piecewise_roots() {
+    // x−1 on [0, 1), (x−1)(x−2) on [1, 3]: the root at 1 is shared
+    let pw = PiecewisePolynomial::build(
+        &[0.0, 1.0, 3.0],
+        &[
+            Polynomial::build(&[-1.0, 1.0]).unwrap(),
+            Polynomial::build(&[0.0, -1.0, 1.0]).unwrap(),
+        ],
+    )
+    .unwrap();
+    let roots = pw.roots().unwrap();
+
+    assert_eq!(roots.len(), 2);
+    assert!(is_close!(roots[0], 1.0, abs_tol = 1e-14));
+    assert!(is_close!(roots[1], 2.0, rel_tol = 1e-14));
+
+    // Double root at 0
+    assert_eq!(parabola_then_line().roots().unwrap(), [0.0, 0.0]);
+}
@@ -0,0 +1,149 @@
+use num::complex::Complex64;
+
+use crate::{PolyError, PolyMatrix, Polynomial, Tolerance};
+
+fn poly(coef: &[f64]) -> Polynomial<f64> {
+    Polynomial::build(coef).unwrap()
+}
+
+/// The determinant of a numeric matrix, by cofactor expansion, for reference.
+fn det(m: &[Vec<f64>]) -> f64 {
+    if m.is_empty() {
+        return 1.0;
+    }
+    (0..m.len())
+        .map(|j| {
+            let minor: Vec<Vec<f64>> = m[1..]
+                .iter()
+                .map(|row| [&row[..j], &row[j + 1..]].concat())
+                .collect();
+            let sign = if j % 2 == 0 { 1.0 } else { -1.0 };
+            sign * m[0][j] * det(&minor)
+        })
+        .sum()
+}
+
+#[test]
+fn test_polymatrix_construction() {
+    let m = PolyMatrix::from_rows(vec![
+        vec![poly(&[1.0]), poly(&[0.0, 1.0]), poly(&[2.0])],
+        vec![poly(&[0.0]), poly(&[1.0, 1.0]), poly(&[0.0, 0.0, 3.0])],
+    ])
+    .unwrap();
+    assert_eq!((m.nrows(), m.ncols()), (2, 3));
+    assert_eq!(m[(1, 2)].coef, [0.0, 0.0, 3.0]);
+
+    let t = m.transpose();
+    assert_eq!((t.nrows(), t.ncols()), (3, 2));
+    assert_eq!(t[(2, 1)], m[(1, 2)]);
+    assert_eq!(t.transpose(), m);
+
+    assert_eq!(m.eval(2.0), [[1.0, 2.0, 2.0], [0.0, 3.0, 12.0]]);
+
+    let ragged = PolyMatrix::from_rows(vec![vec![poly(&[1.0])], vec![]]);
+    assert!(matches!(ragged, Err(PolyError::LengthMismatch(1, 0))));
+}
+
+#[test]
+fn test_polymatrix_matmul() {
+    let a = PolyMatrix::from_rows(vec![
+        vec![poly(&[1.0, 1.0]), poly(&[2.0])],
+        vec![poly(&[0.0, 1.0]), poly(&[-1.0, 0.0, 1.0])],
+    ])
+    .unwrap();
+    let b = PolyMatrix::from_rows(vec![vec![poly(&[3.0, 1.0])], vec![poly(&[0.0, 2.0])]]).unwrap();
+    let ab = a.matmul(&b).unwrap();
+    assert_eq!((ab.nrows(), ab.ncols()), (2, 1));
+
+    for x in [-1.5, 0.0, 0.7, 2.0] {
+        let (av, bv, abv) = (a.eval(x), b.eval(x), ab.eval(x));
+        for i in 0..2 {
+            let expected = av[i][0] * bv[0][0] + av[i][1] * bv[1][0];
+            assert!((abv[i][0] - expected).abs() < 1e-12);
+        }
+    }
+    assert_eq!(a.matmul(&PolyMatrix::identity(2)).unwrap(), a);
+    assert!(matches!(b.matmul(&b), Err(PolyError::LengthMismatch(1, 2))));
+}
+
+#[test]
+fn test_polymatrix_determinant() {
+    // Determinant commutes with evaluation
+    let m = PolyMatrix::from_rows(vec![
+        vec![poly(&[1.0, 2.0]), poly(&[0.0, 0.0, 1.0]), poly(&[3.0])],
+        vec![poly(&[-1.0]), poly(&[2.0, 1.0]), poly(&[0.0, 1.0, 1.0])],
+        vec![poly(&[0.5, 0.0, 1.0]), poly(&[1.0]), poly(&[4.0, -1.0])],
+    ])
+    .unwrap();
+    let d = m.determinant().unwrap();
+    for x in [-2.0, -0.3, 0.0, 1.0, 1.7] {
+        let expected = det(&m.eval(x));
+        assert!((d.eval(x) - expected).abs() < 1e-10 * expected.abs().max(1.0));
+    }
+
+    // A zero pivot needs a row swap
+    let swap = PolyMatrix::from_rows(vec![
+        vec![poly(&[0.0]), poly(&[1.0, 1.0])],
+        vec![poly(&[0.0, 1.0]), poly(&[2.0])],
+    ])
+    .unwrap();
+    assert_eq!(swap.determinant().unwrap().coef, [0.0, -1.0, -1.0]);
+
+    // Singular
+    let singular = PolyMatrix::from_rows(vec![
+        vec![poly(&[0.0]), poly(&[1.0])],
+        vec![poly(&[0.0]), poly(&[2.0])],
+    ])
+    .unwrap();
+    assert_eq!(singular.determinant().unwrap().coef, [0.0]);
+
+    assert_eq!(
+        PolyMatrix::<f64>::zeros(0, 0).determinant().unwrap().coef,
+        [1.0]
+    );
+    assert!(matches!(
+        PolyMatrix::<f64>::zeros(2, 3).determinant(),
+        Err(PolyError::NotSquareMatrix(2, 3))
+    ));
+
+    let complex = PolyMatrix::from_rows(vec![vec![
+        Polynomial::build(&[Complex64::new(0.0, 1.0), Complex64::new(1.0, 0.0)]).unwrap(),
+    ]])
+    .unwrap();
+    assert_eq!(
+        complex.determinant().unwrap().coef[0],
+        Complex64::new(0.0, 1.0)
+    );
+}
+
+#[test]
+fn test_polymatrix_degrees() {
+    // [s²+1   s ]
+    // [  s    1 ]
+    let m = PolyMatrix::from_rows(vec![
+        vec![poly(&[1.0, 0.0, 1.0]), poly(&[0.0, 1.0])],
+        vec![poly(&[0.0, 1.0]), poly(&[1.0])],
+    ])
+    .unwrap();
+    assert_eq!(m.row_degrees(), [Some(2), Some(1)]);
+    assert_eq!(m.column_degrees(), [Some(2), Some(1)]);
+    assert_eq!(m.leading_column_matrix(), [[1.0, 1.0], [0.0, 0.0]]);
+
+    // det = 1, so it is unimodular but not column reduced
+    assert_eq!(m.determinant().unwrap().coef, [1.0]);
+    assert!(m.is_unimodular(Tolerance::EXACT).unwrap());
+    assert!(!m.is_column_reduced(Tolerance::EXACT).unwrap());
+
+    let reduced = PolyMatrix::from_rows(vec![
+        vec![poly(&[1.0, 1.0]), poly(&[0.0, 1.0])],
+        vec![poly(&[2.0]), poly(&[1.0, 0.0, 1.0])],
+    ])
+    .unwrap();
+    assert!(reduced.is_column_reduced(Tolerance::EXACT).unwrap());
+    assert!(!reduced.is_unimodular(Tolerance::EXACT).unwrap());
+
+    let zero_column = PolyMatrix::<f64>::zeros(2, 2);
+    assert_eq!(zero_column.column_degrees(), [None, None]);
+    assert!(!zero_column.is_column_reduced(Tolerance::EXACT).unwrap());
+    assert!(!zero_column.is_unimodular(Tolerance::EXACT).unwrap());
+}
@@ -1,7 +1,7 @@
 use is_close::is_close;
 use num::complex::Complex64;
 
-use crate::{PolyError, Polynomial};
+use crate::{PolyError, PolyErrorKind, Polynomial, SanitizePolicy, solve};
 
 // GSL's tests use this tolerance
 const EPS: f64 = 100.0 * f64::EPSILON;
@@ -13,6 +13,20 @@ fn test_build_polynomial() {
     assert!(Polynomial::build(&[1.0, 2.0, 3.0]).is_ok());
 }
 
+#[test]
+fn test_build_desc() {
+    let poly = Polynomial::build_desc(&[2.0, 0.0, -1.0, 5.0]).unwrap();
+    assert_eq!(poly.coef, [5.0, -1.0, 0.0, 2.0]);
+    assert_eq!(poly.eval(2.0), 19.0);
+    assert_eq!(poly.coef_desc(), [2.0, 0.0, -1.0, 5.0]);
+
+    assert_eq!(Polynomial::<f64>::build_desc(&[]).unwrap().coef, [0.0]);
+    assert_eq!(
+        Polynomial::build_desc(&[1.0, f64::NAN]).unwrap_err().kind(),
+        PolyErrorKind::InvalidCoefficients
+    );
+}
+
 #[test]
 fn test_new_polynomial() {
     let float_poly = Polynomial::<f64>::new();
@@ -33,11 +47,17 @@ fn test_default_polynomial() {
 
 #[test]
 fn test_build_polynomial_invalid() {
-    let poly1 = Polynomial::build(&[1.0, 2.0, std::f64::NAN]);
-    let poly2 = Polynomial::build(&[1.0, 2.0, std::f64::INFINITY]);
+    let poly1 = Polynomial::build(&[1.0, 2.0, f64::NAN]);
+    let poly2 = Polynomial::build(&[1.0, 2.0, f64::INFINITY]);
 
-    assert!(matches!(poly1.unwrap_err(), PolyError::InvalidCoefficients));
-    assert!(matches!(poly2.unwrap_err(), PolyError::InvalidCoefficients));
+    assert!(matches!(
+        poly1.unwrap_err(),
+        PolyError::InvalidCoefficients { .. }
+    ));
+    assert!(matches!(
+        poly2.unwrap_err(),
+        PolyError::InvalidCoefficients { .. }
+    ));
 }
 
 #[test]
@@ -92,3 +112,484 @@ fn test_to_depressed_cubic() {
     assert!(is_close!(poly1.coef[2], 0.0, rel_tol = EPS));
     assert!(is_close!(poly1.coef[3], 1.0, rel_tol = EPS));
 }
+
+#[test]
+fn test_terms() {
+    let poly = Polynomial::build(&[0.0, 1.0, 0.0, -2.0, 0.0]).unwrap();
+    let terms: Vec<(usize, f64)> = poly.terms().collect();
+    assert_eq!(terms, [(1, 1.0), (3, -2.0)]);
+
+    assert_eq!(Polynomial::<f64>::new().terms().count(), 0);
+
+    let complex = Polynomial::build(&[Complex64::new(0.0, 1.0), Complex64::new(0.0, 0.0)]).unwrap();
+    let terms: Vec<(usize, Complex64)> = complex.terms().collect();
+    assert_eq!(terms, [(0, Complex64::new(0.0, 1.0))]);
+}
+
+#[test]
+fn test_from_terms() {
+    let poly: Polynomial<f64> = [(3, 2.0), (0, 1.0), (3, 1.0)].into_iter().collect();
+    assert_eq!(poly.coef, [1.0, 0.0, 0.0, 3.0]);
+
+    let empty: Polynomial<f64> = std::iter::empty().collect();
+    assert_eq!(empty.coef, [0.0]);
+
+    // Round trip
+    let poly = Polynomial::build(&[0.0, 1.0, 0.0, -2.0]).unwrap();
+    let collected: Polynomial<f64> = poly.terms().collect();
+    assert_eq!(collected.coef, poly.coef);
+}
+
+#[test]
+fn test_eval_pair_many() {
+    let p = Polynomial::build(&[0.3, -1.2, 0.5, 2.0, -0.7]).unwrap();
+    let q = Polynomial::build(&[1.0, 0.1, -0.4]).unwrap();
+    let r = Polynomial::build(&[2.5]).unwrap();
+
+    for x in [-1.7, 0.0, 0.4, 3.0] {
+        let (pv, qv) = Polynomial::eval_pair(&p, &q, x);
+        assert!(is_close!(pv, p.eval(x), rel_tol = EPS));
+        assert!(is_close!(qv, q.eval(x), rel_tol = EPS));
+
+        let [pv, qv, rv] = Polynomial::eval_many([&p, &q, &r], x);
+        assert!(is_close!(pv, p.eval(x), rel_tol = EPS));
+        assert!(is_close!(qv, q.eval(x), rel_tol = EPS));
+        assert_eq!(rv, 2.5);
+    }
+
+    let z = Complex64::new(0.5, -1.0);
+    let pc = Polynomial::build(&[Complex64::new(1.0, 1.0), Complex64::new(0.0, 2.0)]).unwrap();
+    let qc = Polynomial::build(&[Complex64::new(-3.0, 0.0)]).unwrap();
+    assert_eq!(Polynomial::eval_pair(&pc, &qc, z), (pc.eval(z), qc.eval(z)));
+
+    assert_eq!(Polynomial::<f64>::eval_many([], 1.0), [0.0f64; 0]);
+
+    // Same as eval at infinity, where a zero seed would give 0·∞ = NaN
+    let one = Polynomial::build(&[1.0]).unwrap();
+    let line = Polynomial::build(&[1.0, 1.0]).unwrap();
+    for x in [f64::INFINITY, f64::NEG_INFINITY] {
+        let (a, b) = Polynomial::eval_pair(&one, &line, x);
+        assert_eq!((a, b), (one.eval(x), line.eval(x)));
+        let [pv, rv] = Polynomial::eval_many([&p, &r], x);
+        assert_eq!((pv, rv), (p.eval(x), 2.5));
+    }
+}
+
+#[test]
+fn test_partial_eq() {
+    let p = Polynomial::build(&[1.0, 2.0, 0.0]).unwrap();
+    let q = Polynomial::build(&[1.0, 2.0]).unwrap();
+    let r = Polynomial::build(&[1.0, 2.0, 1e-300]).unwrap();
+
+    assert_eq!(p, q);
+    assert_eq!(q, p);
+    assert_ne!(p, r);
+    assert_eq!(
+        Polynomial::<f64>::new(),
+        Polynomial::build(&[0.0, 0.0]).unwrap()
+    );
+    assert_eq!(p.to_trimmed(), p);
+
+    let pc = Polynomial::build(&[Complex64::new(1.0, 1.0), Complex64::new(0.0, 0.0)]).unwrap();
+    let qc = Polynomial::build(&[Complex64::new(1.0, 1.0)]).unwrap();
+    assert_eq!(pc, qc);
+}
+
+#[test]
+fn test_approx_eq() {
+    let p = Polynomial::build(&[1.0, 1e-20, 100.0]).unwrap();
+    let q = Polynomial::build(&[1.0 + 1e-13, 0.0, 100.0 + 1e-11, 1e-16]).unwrap();
+
+    assert!(p.approx_eq(&q, 1e-12, 1e-15));
+    assert!(q.approx_eq(&p, 1e-12, 1e-15));
+    assert!(!p.approx_eq(&q, 1e-14, 1e-15));
+    assert!(!p.approx_eq(&q, 1e-12, 0.0));
+
+    let pc = Polynomial::build(&[Complex64::new(1.0, 1.0)]).unwrap();
+    let qc = Polynomial::build(&[Complex64::new(1.0, 1.0 + 1e-14)]).unwrap();
+    assert!(pc.approx_eq(&qc, 1e-12, 0.0));
+}
+
+#[test]
+fn test_try_from() {
+    let expected = Polynomial::build(&[1.0, 2.0, 3.0]).unwrap();
+
+    assert_eq!(Polynomial::try_from([1.0, 2.0, 3.0]).unwrap(), expected);
+    assert_eq!(
+        Polynomial::try_from(&[1.0, 2.0, 3.0][..]).unwrap(),
+        expected
+    );
+    assert_eq!(Polynomial::try_from(vec![1.0, 2.0, 3.0]).unwrap(), expected);
+    assert_eq!(Polynomial::<f64>::try_from(vec![]).unwrap().coef, vec![0.0]);
+    assert_eq!(Polynomial::<f64>::try_from([]).unwrap().coef, vec![0.0]);
+
+    assert!(matches!(
+        Polynomial::try_from([1.0, f64::INFINITY]),
+        Err(PolyError::InvalidCoefficients { .. })
+    ));
+    assert!(matches!(
+        Polynomial::try_from(vec![f64::NAN]),
+        Err(PolyError::InvalidCoefficients { .. })
+    ));
+
+    let complex: Polynomial<Complex64> = vec![Complex64::new(1.0, 1.0)].try_into().unwrap();
+    assert_eq!(complex.coef, vec![Complex64::new(1.0, 1.0)]);
+}
+
+#[test]
+fn test_poly_macro() {
+    let p = crate::poly![1.0, 2.0, 3.0,];
+    assert_eq!(p, Polynomial::build(&[1.0, 2.0, 3.0]).unwrap());
+
+    let zero: Polynomial<f64> = crate::poly![];
+    assert_eq!(zero.coef, vec![0.0]);
+}
+
+#[test]
+#[should_panic]
+fn test_poly_macro_invalid() {
+    let _ = crate::poly![1.0, f64::NAN];
+}
+
+#[test]
+fn test_horner_expansion() {
+    let p = Polynomial::build(&[0.3, -1.2, 0.5, 2.0, -0.7]).unwrap();
+
+    for x in [-1.7, 0.0, 0.4, 3.0] {
+        let b = p.horner_expansion(x);
+        assert_eq!(b.len(), p.coef.len());
+        assert_eq!(b[0], p.eval(x));
+
+        // P(x) = (x − x₀)Q(x) + P(x₀)
+        let q = Polynomial::build(&b[1..]).unwrap();
+        for t in [-2.0, 0.5, 1.3] {
+            let expected = (t - x) * q.eval(t) + b[0];
+            assert!(is_close!(p.eval(t), expected, rel_tol = EPS, abs_tol = EPS));
+        }
+    }
+
+    assert_eq!(
+        Polynomial::build(&[2.5]).unwrap().horner_expansion(3.0),
+        [2.5]
+    );
+    assert!(
+        Polynomial::<f64> { coef: vec![] }
+            .horner_expansion(1.0)
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_even_odd() {
+    let p = Polynomial::build(&[0.3, -1.2, 0.5, 2.0, -0.7]).unwrap();
+    let (even, odd) = p.split_even_odd();
+    assert_eq!(even.coef, [0.3, 0.5, -0.7]);
+    assert_eq!(odd.coef, [-1.2, 2.0]);
+
+    for x in [-1.7, 0.0, 0.4, 3.0] {
+        let (pos, neg) = p.eval_even_odd(x);
+        assert!(is_close!(pos, p.eval(x), rel_tol = EPS, abs_tol = EPS));
+        assert!(is_close!(neg, p.eval(-x), rel_tol = EPS, abs_tol = EPS));
+        assert!(is_close!(
+            pos,
+            even.eval(x * x) + x * odd.eval(x * x),
+            rel_tol = EPS
+        ));
+    }
+
+    let constant = Polynomial::build(&[2.5]).unwrap();
+    assert_eq!(
+        constant.split_even_odd(),
+        (constant.clone(), Polynomial::new())
+    );
+    assert_eq!(constant.eval_even_odd(f64::INFINITY), (2.5, 2.5));
+
+    let z = Complex64::new(0.5, -1.0);
+    let pc = Polynomial::build(&[Complex64::new(1.0, 1.0), Complex64::new(0.0, 2.0)]).unwrap();
+    let (pos, neg) = pc.eval_even_odd(z);
+    assert_eq!((pos, neg), (pc.eval(z), pc.eval(-z)));
+}
+
+#[test]
+fn test_eval_value_and_deriv() {
+    let p = Polynomial::build(&[0.3, -1.2, 0.5, 2.0, -0.7]).unwrap();
+
+    for x in [-1.7, 0.0, 0.4, 3.0, f64::INFINITY] {
+        let derivs = p.eval_derivs(x, 2);
+        assert_eq!(p.eval_value_and_deriv(x), (derivs[0], derivs[1]));
+        assert_eq!(p.eval_deriv1(x), derivs[1]);
+    }
+
+    let line = Polynomial::build(&[1.0, 3.0]).unwrap();
+    assert_eq!(
+        line.eval_value_and_deriv(f64::INFINITY),
+        (f64::INFINITY, 3.0)
+    );
+    assert_eq!(
+        Polynomial::build(&[2.5]).unwrap().eval_value_and_deriv(1.0),
+        (2.5, 0.0)
+    );
+    assert_eq!(
+        Polynomial::<f64> { coef: vec![] }.eval_value_and_deriv(1.0),
+        (0.0, 0.0)
+    );
+
+    let z = Complex64::new(0.5, -1.0);
+    let pc = Polynomial::build(&[Complex64::new(1.0, 1.0), Complex64::new(0.0, 2.0)]).unwrap();
+    assert_eq!(
+        pc.eval_value_and_deriv(z),
+        (pc.eval(z), Complex64::new(0.0, 2.0))
+    );
+}
+
+#[test]
+fn test_eval_derivs_into() {
+    let p = Polynomial::build(&[0.3, -1.2, 0.5, 2.0, -0.7]).unwrap();
+
+    // Reused buffer, with stale values from a previous call
+    let mut out = [f64::NAN; 7];
+    for x in [-1.7, 0.4, 3.0] {
+        p.eval_derivs_into(x, &mut out);
+        assert_eq!(out.to_vec(), p.eval_derivs(x, 7));
+        assert_eq!(out[5..], [0.0, 0.0]);
+    }
+
+    assert!(p.eval_derivs(1.0, 0).is_empty());
+    assert_eq!(p.eval_derivs(1.0, 1), [p.eval(1.0)]);
+    p.eval_derivs_into(1.0, &mut []);
+
+    let mut out = [1.0; 2];
+    Polynomial::<f64> { coef: vec![] }.eval_derivs_into(1.0, &mut out);
+    assert_eq!(out, [0.0, 0.0]);
+}
+
+#[test]
+fn test_error_payloads() {
+    let err = Polynomial::build(&[1.0, 2.0, f64::NAN, f64::INFINITY]).unwrap_err();
+    assert_eq!(err.kind(), PolyErrorKind::InvalidCoefficients);
+    match err {
+        PolyError::InvalidCoefficients { index, value } => {
+            assert_eq!(index, 2);
+            assert_eq!(&*value, "NaN");
+        }
+        _ => unreachable!(),
+    }
+
+    let err = solve::cubic(0.0, 0.0, 1.0, 2.0).unwrap_err();
+    assert!(matches!(
+        err,
+        PolyError::IncorrectOrder {
+            expected: 3,
+            got: 1
+        }
+    ));
+    assert_eq!(err.kind(), PolyErrorKind::IncorrectOrder);
+    assert_eq!(
+        err.to_string(),
+        "Supplied Polynomial must be of order 3, got 1"
+    );
+
+    let err = solve::quadratic(1.0, f64::NEG_INFINITY, 0.0).unwrap_err();
+    assert!(matches!(
+        err,
+        PolyError::InvalidCoefficients { index: 1, .. }
+    ));
+    assert_eq!(PolyError::NoRealRoots.kind(), PolyErrorKind::NoRealRoots);
+}
+
+#[test]
+fn test_build_sanitized() {
+    let coef = [f64::INFINITY, 1.0, f64::NAN, 2.0, f64::NEG_INFINITY];
+
+    let poly = Polynomial::build_sanitized(&coef, SanitizePolicy::Zero).unwrap();
+    assert_eq!(poly.coef, [0.0, 1.0, 0.0, 2.0, 0.0]);
+
+    // The samples after a dropped one move down in order: 1x + 2x³ becomes 1 + 2x
+    let poly = Polynomial::build_sanitized(&coef, SanitizePolicy::DropSample).unwrap();
+    assert_eq!(poly.coef, [1.0, 2.0]);
+    assert_eq!(poly.eval(2.0), 5.0);
+
+    assert!(matches!(
+        Polynomial::build_sanitized(&coef, SanitizePolicy::Error),
+        Err(PolyError::InvalidCoefficients { index: 0, .. })
+    ));
+
+    // Every coefficient dropped results in the zero polynomial
+    let poly = Polynomial::build_sanitized(&[f64::NAN], SanitizePolicy::DropSample).unwrap();
+    assert_eq!(poly.coef, [0.0]);
+
+    let valid = [1.0, 2.0];
+    for policy in [
+        SanitizePolicy::Error,
+        SanitizePolicy::DropSample,
+        SanitizePolicy::Zero,
+    ] {
+        let poly = Polynomial::build_sanitized(&valid, policy).unwrap();
+        assert_eq!(poly.coef, valid);
+    }
+
+    let z = Complex64::new(f64::NAN, 1.0);
+    let poly = Polynomial::build_sanitized(&[z, Complex64::new(1.0, 0.0)], SanitizePolicy::Zero);
+    assert_eq!(
+        poly.unwrap().coef,
+        [Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)]
+    );
+}
+
+#[test]
+fn test_from_raw_unchecked() {
+    let poly = Polynomial::from_raw_unchecked(vec![1.0, 2.0, 3.0]);
+    assert_eq!(poly, Polynomial::build(&[1.0, 2.0, 3.0]).unwrap());
+    assert_eq!(Polynomial::<f64>::from_raw_unchecked(vec![]).coef, [0.0]);
+}
+
+/// A minimal dual number a + bε, with ε² = 0.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Dual(f64, f64);
+
+impl From<f64> for Dual {
+    fn from(a: f64) -> Self {
+        Dual(a, 0.0)
+    }
+}
+
+impl std::ops::Add for Dual {
+    type Output = Dual;
+
+    fn add(self, rhs: Dual) -> Dual {
+        Dual(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl std::ops::Mul for Dual {
+    type Output = Dual;
+
+    fn mul(self, rhs: Dual) -> Dual {
+        Dual(self.0 * rhs.0, self.0 * rhs.1 + self.1 * rhs.0)
+    }
+}
+
+#[test]
+fn test_eval_generic_dual() {
+    let poly = Polynomial::build(&[3.0, -2.0, 0.5, 1.0]).unwrap();
+    for x in [-2.0, 0.0, 0.7, 5.0] {
+        let Dual(value, deriv) = poly.eval_generic(Dual(x, 1.0));
+        assert_eq!(value, poly.eval(x));
+        assert!((deriv - poly.eval_deriv1(x)).abs() < 1e-12);
+    }
+    assert_eq!(
+        Polynomial::<f64>::new().eval_generic(Dual(1.0, 1.0)),
+        Dual(0.0, 0.0)
+    );
+}
+
+#[test]
+fn test_eval_fma() {
+    let poly = Polynomial::build(&[3.0f64, -2.0, 0.5, 1.0]).unwrap();
+    for x in [-2.0, 0.0, 0.7, 5.0] {
+        assert!((poly.eval_fma(x) - poly.eval(x)).abs() < 1e-13);
+    }
+    let single = Polynomial::build(&[3.0f32, -2.0, 0.5, 1.0]).unwrap();
+    assert_eq!(single.eval_fma(2.0), 9.0);
+    assert_eq!(Polynomial::<f64>::new().eval_fma(1.0), 0.0);
+
+    // (x − 1)⁷ expanded, near its root, where the terms cancel out
+    let poly = Polynomial::from_roots(&[1.0; 7]);
+    let errors = |eval: &dyn Fn(f64) -> f64| -> f64 {
+        (0..200)
+            .map(|i| {
+                let x = 1.0 + (i as f64 - 100.0) * 1e-4;
+                (eval(x) - (x - 1.0).powi(7)).abs()
+            })
+            .sum()
+    };
+    assert!(errors(&|x| poly.eval_fma(x)) <= errors(&|x| poly.eval(x)));
+}
+
+#[test]
+fn test_eval_estrin() {
+    for len in 0..30 {
+        let coef: Vec<f64> = (0..len)
+            .map(|i| ((i * 7 % 11) as f64 - 5.0) / 3.0)
+            .collect();
+        let poly = Polynomial { coef };
+        for x in [-1.3, -0.5, 0.0, 0.25, 1.0, 1.7] {
+            let (estrin, horner) = (poly.eval_estrin(x), poly.eval(x));
+            assert!(
+                (estrin - horner).abs() <= 1e-13 * horner.abs().max(1.0),
+                "{len} {x}: {estrin} vs {horner}"
+            );
+        }
+    }
+
+    let i = Complex64::new(0.0, 1.0);
+    let poly = Polynomial::build(&[Complex64::new(1.0, 0.0); 12]).unwrap();
+    // Σ iᵏ over 12 terms is 0
+    assert!(poly.eval_estrin(i).norm() < 1e-15);
+}
+
+#[test]
+fn test_eval_arithmetic_progression() {
+    for order in 0..6 {
+        let coef: Vec<f64> = (0..=order)
+            .map(|i| ((i * 5 % 7) as f64 - 3.0) / 2.0)
+            .collect();
+        let poly = Polynomial::build(&coef).unwrap();
+        for count in [0, 1, order, order + 1, order + 2, 200] {
+            let values = poly.eval_arithmetic_progression(-1.0, 0.01, count);
+            assert_eq!(values.len(), count);
+            values.iter().enumerate().for_each(|(i, v)| {
+                let horner = poly.eval(-1.0 + i as f64 * 0.01);
+                let tol = 1e-15 * (count as f64).powi(order as i32 + 1);
+                assert!((v - horner).abs() <= tol.max(1e-15) * horner.abs().max(1.0));
+            });
+        }
+    }
+
+    // Trailing zero coefficients do not enlarge the table
+    let poly = Polynomial {
+        coef: vec![2.0, 3.0, 0.0, 0.0],
+    };
+    assert_eq!(
+        poly.eval_arithmetic_progression(1.0, 1.0, 4),
+        [5.0, 8.0, 11.0, 14.0]
+    );
+
+    let poly = Polynomial::build(&[Complex64::new(0.0, 1.0), Complex64::new(1.0, 0.0)]).unwrap();
+    let values =
+        poly.eval_arithmetic_progression(Complex64::default(), Complex64::new(0.0, 1.0), 3);
+    assert_eq!(values[2], Complex64::new(0.0, 3.0));
+}
+
+#[test]
+fn test_eval_map() {
+    let poly = Polynomial::build(&[2.0, -3.0, 0.5, 1.0]).unwrap();
+    let xs: Vec<f64> = (-10..=10).map(|i| i as f64 / 4.0).collect();
+
+    let values: Vec<f64> = poly.eval_map(xs.iter().copied()).collect();
+    let horner: Vec<f64> = xs.iter().map(|x| poly.eval(*x)).collect();
+    assert_eq!(values, horner);
+
+    // Only the pulled values are evaluated, even from an unbounded iterator
+    let mut pulled = 0;
+    let first: Vec<f64> = poly
+        .eval_map((0..).map(|i| {
+            pulled += 1;
+            i as f64
+        }))
+        .take(3)
+        .collect();
+    assert_eq!(first, [2.0, 0.5, 6.0]);
+    assert_eq!(pulled, 3);
+
+    assert_eq!(poly.eval_map(std::iter::empty()).count(), 0);
+
+    let poly = Polynomial::build(&[
+        Complex64::new(1.0, 0.0),
+        Complex64::new(0.0, 0.0),
+        Complex64::new(1.0, 0.0),
+    ])
+    .unwrap();
+    let values: Vec<Complex64> = poly.eval_map([Complex64::new(0.0, 1.0)]).collect();
+    assert_eq!(values, [Complex64::new(0.0, 0.0)]);
+}
@@ -0,0 +1,48 @@
+use crate::{FormatOptions, Polynomial};
+
+#[test]
+fn test_to_unicode() {
+    let opts = FormatOptions::default();
+    let poly = Polynomial::build(&[1.0, -4.0, 3.0]).unwrap();
+    assert_eq!(poly.to_unicode(&opts), "3x² − 4x + 1");
+
+    let poly = Polynomial::build(&[
+        -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0,
+    ])
+    .unwrap();
+    assert_eq!(poly.to_unicode(&opts), "−x¹² − 1");
+
+    let poly = Polynomial::build(&[0.0, 1.0]).unwrap();
+    assert_eq!(poly.to_unicode(&opts), "x");
+    assert_eq!(Polynomial::<f64>::new().to_unicode(&opts), "0");
+    assert_eq!(
+        Polynomial::build(&[-2.5]).unwrap().to_unicode(&opts),
+        "−2.5"
+    );
+}
+
+#[test]
+fn test_to_unicode_options() {
+    let poly = Polynomial::build(&[0.125, 1.0, -2.0 / 3.0]).unwrap();
+    let opts = FormatOptions {
+        variable: "λ".into(),
+        precision: Some(3),
+        descending: false,
+    };
+    assert_eq!(poly.to_unicode(&opts), "0.125 + λ − 0.667λ²");
+}
+
+#[test]
+fn test_to_latex() {
+    let opts = FormatOptions::default();
+    let poly = Polynomial::build(&[1.0, -4.0, 3.0]).unwrap();
+    assert_eq!(poly.to_latex(&opts), "3x^{2} - 4x + 1");
+
+    let opts = FormatOptions {
+        variable: "\\omega".into(),
+        ..FormatOptions::default()
+    };
+    let poly = Polynomial::build(&[0.0, -1.0, 0.0, 0.5f32]).unwrap();
+    assert_eq!(poly.to_latex(&opts), "0.5\\omega^{3} - \\omega");
+    assert_eq!(Polynomial::<f64>::new().to_latex(&opts), "0");
+}
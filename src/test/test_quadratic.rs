@@ -1,4 +1,5 @@
-use crate::{PolyError, Polynomial};
+use crate::{PolyError, Polynomial, QuadraticRoots, Tolerance, solve};
+use is_close::is_close;
 use num::complex::Complex64;
 
 #[test]
@@ -7,7 +8,7 @@ fn test_solve_real_quadratic_wrong_order() {
 
     assert!(matches!(
         p.solve_real_quadratic().unwrap_err(),
-        PolyError::IncorrectOrder(2)
+        PolyError::IncorrectOrder { expected: 2, .. }
     ));
 }
 
@@ -25,3 +26,105 @@ fn test_solve_real_quadratic_complex_coefs() {
         PolyError::NotRealCoefficients
     ));
 }
+
+#[test]
+/// The smaller root of x²-10⁸x+1 is ~1e-8, which the naive formula gets wrong by ~25%.
+fn test_quadratic_cancellation() {
+    let p = Polynomial::build(&[1.0, -1e8, 1.0]).unwrap();
+    let y = p.solve_real_quadratic().unwrap();
+
+    assert!(is_close!(y[0], 1e-8, rel_tol = 1e-15));
+    assert!(is_close!(y[1], 1e8, rel_tol = 1e-15));
+
+    let p = Polynomial::build(&[-1.0, 1e8, 1.0]).unwrap();
+    let y = p.solve_real_quadratic().unwrap();
+
+    assert!(is_close!(y[0], -1e8, rel_tol = 1e-15));
+    assert!(is_close!(y[1], 1e-8, rel_tol = 1e-15));
+}
+
+#[test]
+fn test_quadratic_sorted() {
+    for (c, b, a) in [
+        (-6.0, 1.0, 1.0),
+        (6.0, 5.0, -1.0),
+        (1.0, 0.0, -0.25),
+        (0.0, -3.0, 2.0),
+    ] {
+        let p: Polynomial<f64> = Polynomial::build(&[c, b, a]).unwrap();
+        let y = p.solve_real_quadratic().unwrap();
+
+        assert_eq!(y.len(), 2);
+        assert!(y[0] < y[1]);
+        assert!(y.iter().all(|x| p.eval(*x).abs() < 1e-14));
+    }
+}
+
+#[test]
+fn test_quadratic_with_tol() {
+    let tol = Tolerance::new(1e-12);
+
+    // Negligible leading coefficient
+    let p = Polynomial::build(&[3.0, -1.5, 1e-300]).unwrap();
+    assert_eq!(
+        p.solve_real_quadratic_structured_with_tol(tol).unwrap(),
+        QuadraticRoots::Linear(2.0)
+    );
+    assert!(matches!(
+        p.solve_real_quadratic_structured().unwrap(),
+        QuadraticRoots::Two(_, _)
+    ));
+
+    // Relevant leading coefficient
+    let p = Polynomial::build(&[-1.0, 0.0, 1e-6]).unwrap();
+    assert_eq!(
+        p.solve_real_quadratic_structured_with_tol(tol).unwrap(),
+        p.solve_real_quadratic_structured().unwrap()
+    );
+
+    // Nearly constant
+    assert!(matches!(
+        solve::quadratic_with_tol(1e-300, -1e-290, 1.0, tol).unwrap_err(),
+        PolyError::ConstantPoly
+    ));
+
+    // The exact tolerance is the default behaviour
+    assert_eq!(Tolerance::default(), Tolerance::EXACT);
+    assert_eq!(
+        solve::quadratic_with_tol(1e-300, 1.0, -1.0, Tolerance::EXACT).unwrap(),
+        solve::quadratic(1e-300, 1.0, -1.0).unwrap()
+    );
+    assert_eq!(solve::linear_with_tol(2.0, -1.0, tol).unwrap(), 0.5);
+}
+
+#[test]
+fn test_quadratic_large_coefficients() {
+    // b² − 4ac overflows without scaling: x² + x + 1 has no real roots
+    assert_eq!(
+        solve::quadratic(1e200, 1e200, 1e200).unwrap(),
+        QuadraticRoots::None
+    );
+    let poly = Polynomial::build(&[1e200; 3]).unwrap();
+    assert!(matches!(
+        poly.solve_real_quadratic(),
+        Err(PolyError::NoRealRoots)
+    ));
+
+    // 1e200·(x − 1)(x − 3)
+    let roots = solve::quadratic(1e200, -4e200, 3e200).unwrap();
+    let QuadraticRoots::Two(x1, x2) = roots else {
+        panic!("{roots:?}")
+    };
+    assert!(is_close!(x1, 1.0, rel_tol = 1e-15));
+    assert!(is_close!(x2, 3.0, rel_tol = 1e-15));
+
+    // Scaling is exact, so double roots are still detected
+    assert_eq!(
+        solve::quadratic(4e-300, -20e-300, 25e-300).unwrap(),
+        QuadraticRoots::Double(2.5)
+    );
+    assert_eq!(
+        solve::quadratic(4e300, -20e300, 25e300).unwrap(),
+        QuadraticRoots::Double(2.5)
+    );
+}
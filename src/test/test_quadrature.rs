@@ -0,0 +1,101 @@
+use is_close::is_close;
+
+use crate::quadrature::gauss_legendre;
+use crate::{PolyErrorKind, Polynomial};
+
+/// Pₙ in the monomial basis, with the three-term recurrence.
+fn legendre_poly(n: usize) -> Polynomial<f64> {
+    let (mut prev, mut cur) = (vec![1.0], vec![0.0, 1.0]);
+    for k in 1..n {
+        let mut next = vec![0.0; k + 2];
+        for (j, c) in cur.iter().enumerate() {
+            next[j + 1] += (2 * k + 1) as f64 * c / (k + 1) as f64;
+        }
+        for (j, c) in prev.iter().enumerate() {
+            next[j] -= k as f64 * c / (k + 1) as f64;
+        }
+        (prev, cur) = (cur, next);
+    }
+    Polynomial::build(&cur).unwrap()
+}
+
+#[test]
+fn test_gauss_legendre_small() {
+    let (nodes, weights) = gauss_legendre(1).unwrap();
+    assert_eq!((nodes, weights), (vec![0.0], vec![2.0]));
+
+    let (nodes, weights) = gauss_legendre(2).unwrap();
+    assert!(is_close!(nodes[1], 1.0 / 3f64.sqrt(), rel_tol = 1e-15));
+    assert_eq!(nodes[0], -nodes[1]);
+    assert!(weights.iter().all(|w| is_close!(*w, 1.0, rel_tol = 1e-15)));
+
+    let (nodes, weights) = gauss_legendre(3).unwrap();
+    assert!(is_close!(nodes[2], 0.6f64.sqrt(), rel_tol = 1e-15));
+    assert_eq!(nodes[1], 0.0);
+    assert!(is_close!(weights[0], 5.0 / 9.0, rel_tol = 1e-15));
+    assert!(is_close!(weights[1], 8.0 / 9.0, rel_tol = 1e-15));
+}
+
+#[test]
+fn test_gauss_legendre_exactness() {
+    for n in 1..=60 {
+        let (nodes, weights) = gauss_legendre(n).unwrap();
+        assert!(nodes.windows(2).all(|w| w[0] < w[1]));
+        assert!(nodes.iter().zip(nodes.iter().rev()).all(|(a, b)| *a == -b));
+
+        // ∫₋₁¹ x²ᵏ dx = 2/(2k + 1), up to order 2n − 2
+        for k in 0..n {
+            let integral: f64 = nodes
+                .iter()
+                .zip(&weights)
+                .map(|(x, w)| w * x.powi(2 * k as i32))
+                .sum();
+            assert!(is_close!(
+                integral,
+                2.0 / (2 * k + 1) as f64,
+                rel_tol = 1e-13
+            ));
+        }
+    }
+}
+
+#[test]
+fn test_gauss_legendre_against_solver() {
+    // The nodes are the roots of Pₙ, as found by the general solver
+    for n in [4, 9, 16] {
+        let (nodes, _) = gauss_legendre(n).unwrap();
+        let mut roots: Vec<f64> = legendre_poly(n)
+            .solve_complex()
+            .unwrap()
+            .iter()
+            .map(|z| {
+                assert!(z.im.abs() < 1e-10);
+                z.re
+            })
+            .collect();
+        roots.sort_by(f64::total_cmp);
+        for (x, r) in nodes.iter().zip(&roots) {
+            assert!(is_close!(*x, *r, abs_tol = 1e-12));
+        }
+    }
+}
+
+#[test]
+fn test_gauss_legendre_large() {
+    let (nodes, weights) = gauss_legendre(1000).unwrap();
+    assert!(is_close!(weights.iter().sum::<f64>(), 2.0, rel_tol = 1e-13));
+    assert!(nodes.windows(2).all(|w| w[0] < w[1]));
+    assert!(nodes[0] > -1.0 && nodes[999] < 1.0);
+
+    // ∫₋₁¹ cos(x)dx = 2sin(1)
+    let integral: f64 = nodes.iter().zip(&weights).map(|(x, w)| w * x.cos()).sum();
+    assert!(is_close!(integral, 2.0 * 1f64.sin(), rel_tol = 1e-13));
+}
+
+#[test]
+fn test_gauss_legendre_errors() {
+    assert_eq!(
+        gauss_legendre(0).unwrap_err().kind(),
+        PolyErrorKind::NotEnoughPoints
+    );
+}
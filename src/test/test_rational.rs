@@ -0,0 +1,247 @@
+use is_close::is_close;
+
+use crate::rational::RationalFunction;
+use crate::{PolyError, Polynomial, rational};
+
+#[test]
+fn test_eval_stable_removable_singularity() {
+    let p: Polynomial<f64> = Polynomial::build(&[-2.0, 1.0, 1.0]).unwrap(); // (x−1)(x+2)
+    let q = Polynomial::build(&[3.0, -4.0, 1.0]).unwrap(); // (x−1)(x−3)
+
+    assert!((p.eval(1.0) / q.eval(1.0)).is_nan());
+    let value = rational::eval_stable(&p, &q, 1.0).unwrap();
+    assert!(is_close!(value, -1.5, rel_tol = 1e-13));
+
+    // Away from the singularity nothing changes
+    let x = 0.3;
+    let value = rational::eval_stable(&p, &q, x).unwrap();
+    assert!(is_close!(value, p.eval(x) / q.eval(x), rel_tol = 1e-13));
+}
+
+#[test]
+fn test_cancel_complex_pair() {
+    // (x²+1)(x+2) / ((x²+1)(x-5))
+    let p = Polynomial::build(&[2.0, 1.0, 2.0, 1.0]).unwrap();
+    let q = Polynomial::build(&[-5.0, 1.0, -5.0, 1.0]).unwrap();
+    let (p_red, q_red) = rational::cancel_common_roots(&p, &q, 1e-8).unwrap();
+
+    assert_eq!(p_red.coef.len(), 2);
+    assert_eq!(q_red.coef.len(), 2);
+    assert!(is_close!(p_red.coef[0], 2.0, rel_tol = 1e-13));
+    assert!(is_close!(q_red.coef[0], -5.0, rel_tol = 1e-13));
+}
+
+#[test]
+fn test_cancel_nothing_common() {
+    let p = Polynomial::build(&[-1.0, 1.0]).unwrap();
+    let q = Polynomial::build(&[1.0, 1.0]).unwrap();
+    let (p_red, q_red) = rational::cancel_common_roots(&p, &q, 1e-8).unwrap();
+
+    assert_eq!(p_red.coef, p.coef);
+    assert_eq!(q_red.coef, q.coef);
+
+    // Constant numerator
+    let one = Polynomial::build(&[1.0]).unwrap();
+    assert_eq!(rational::eval_stable(&one, &q, 1.0).unwrap(), 0.5);
+}
+
+#[test]
+fn test_eval_stable_zero_denominator() {
+    let p = Polynomial::build(&[1.0, 1.0]).unwrap();
+    let zero = Polynomial::build(&[0.0, 0.0]).unwrap();
+
+    assert!(matches!(
+        rational::eval_stable(&p, &zero, 1.0).unwrap_err(),
+        PolyError::DivisionByZero
+    ));
+}
+
+#[test]
+fn test_rational_function_arith() {
+    let f = RationalFunction::new(
+        Polynomial::build(&[1.0]).unwrap(),
+        Polynomial::build(&[-1.0, 1.0]).unwrap(), // 1/(x-1)
+    )
+    .unwrap();
+    let g = RationalFunction::new(
+        Polynomial::build(&[0.0, 2.0]).unwrap(),
+        Polynomial::build(&[2.0, 1.0]).unwrap(), // 2x/(x+2)
+    )
+    .unwrap();
+
+    for x in [-3.0, -0.5, 0.0, 0.7, 4.0] {
+        let (fx, gx) = (f.eval(x), g.eval(x));
+        assert!(is_close!((&f + &g).eval(x), fx + gx, rel_tol = 1e-13));
+        assert!(is_close!((&f - &g).eval(x), fx - gx, rel_tol = 1e-13));
+        assert!(is_close!(
+            (f.clone() * g.clone()).eval(x),
+            fx * gx,
+            rel_tol = 1e-13
+        ));
+        assert!(is_close!((-&f).eval(x), -fx, rel_tol = 1e-13));
+        assert!(is_close!(
+            g.recip().unwrap().eval(x),
+            1.0 / gx,
+            rel_tol = 1e-13
+        ));
+    }
+
+    // Same denominators are not multiplied together
+    let sum = &f + &f;
+    assert_eq!(sum.den, f.den);
+    assert_eq!(sum.num.coef, [2.0]);
+}
+
+#[test]
+fn test_rational_function_poles_zeros() {
+    // (x−1)(x+2) / ((x−1)(x−3))
+    let f = RationalFunction::new(
+        Polynomial::build(&[-2.0, 1.0, 1.0]).unwrap(),
+        Polynomial::build(&[3.0, -4.0, 1.0]).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(f.zeros().unwrap().len(), 2);
+    assert_eq!(f.poles().unwrap().len(), 2);
+
+    let g = f.simplify(1e-8).unwrap();
+    let zeros = g.zeros().unwrap();
+    let poles = g.poles().unwrap();
+    assert_eq!((zeros.len(), poles.len()), (1, 1));
+    assert!(is_close!(zeros[0].re, -2.0, rel_tol = 1e-13));
+    assert!(is_close!(poles[0].re, 3.0, rel_tol = 1e-13));
+
+    assert!(is_close!(
+        f.eval_checked(1.0).unwrap(),
+        -1.5,
+        rel_tol = 1e-13
+    ));
+    assert!(matches!(f.eval_checked(3.0), Err(PolyError::Pole(_))));
+    assert!(f.eval(3.0).is_infinite());
+
+    // Constant numerator
+    let h = RationalFunction::new(Polynomial::build(&[2.0]).unwrap(), g.den.clone()).unwrap();
+    assert!(h.zeros().unwrap().is_empty());
+}
+
+#[test]
+fn test_rational_function_zero_denominator() {
+    let zero: Polynomial<f64> = Polynomial::build(&[0.0, 0.0]).unwrap();
+    let one = Polynomial::build(&[1.0]).unwrap();
+
+    assert!(matches!(
+        RationalFunction::new(one.clone(), zero.clone()),
+        Err(PolyError::DivisionByZero)
+    ));
+    assert!(matches!(
+        RationalFunction::new(zero, one).unwrap().recip(),
+        Err(PolyError::DivisionByZero)
+    ));
+}
+
+#[test]
+fn test_pade_exp() {
+    // eˣ, with the known [2/2] approximant (12 + 6x + x²)/(12 − 6x + x²)
+    let series: Vec<f64> = (0..8)
+        .scan(1.0, |fact, k| {
+            let term = 1.0 / *fact;
+            *fact *= (k + 1) as f64;
+            Some(term)
+        })
+        .collect();
+    let f = rational::pade(&series, 2, 2).unwrap();
+
+    let expected_num = [1.0, 0.5, 1.0 / 12.0];
+    let expected_den = [1.0, -0.5, 1.0 / 12.0];
+    for k in 0..3 {
+        assert!(is_close!(f.num.coef[k], expected_num[k], rel_tol = 1e-13));
+        assert!(is_close!(f.den.coef[k], expected_den[k], rel_tol = 1e-13));
+    }
+
+    // Higher orders converge
+    let f = rational::pade(&series, 3, 3).unwrap();
+    for x in [-1.0, -0.3, 0.0, 0.4, 1.0] {
+        assert!(is_close!(f.eval(x), f64::exp(x), rel_tol = 1e-4));
+    }
+}
+
+#[test]
+fn test_pade_degenerate() {
+    let series = [1.0, 2.0, 3.0, 4.0];
+
+    // [m/0] is the truncated series itself
+    let f = rational::pade(&series, 3, 0).unwrap();
+    assert_eq!(f.num.coef, series);
+    assert_eq!(f.den.coef, [1.0]);
+
+    // 1/(1-x) is reproduced exactly
+    let f = rational::pade(&[1.0; 5], 0, 1).unwrap();
+    assert_eq!(f.num.coef, [1.0]);
+    assert!(is_close!(f.den.coef[1], -1.0, rel_tol = 1e-15));
+
+    assert!(matches!(
+        rational::pade(&series, 2, 2),
+        Err(PolyError::NotEnoughCoefficients(5))
+    ));
+    assert!(matches!(
+        rational::pade(&[1.0, 0.0, 0.0], 1, 1),
+        Err(PolyError::SingularMatrix)
+    ));
+}
+
+#[test]
+fn test_root_locus_matches_solver() {
+    // G = (s+3)/(s(s+1)(s+5))
+    let g = RationalFunction::new(
+        Polynomial::build(&[3.0, 1.0]).unwrap(),
+        Polynomial::build(&[0.0, 5.0, 6.0, 1.0]).unwrap(),
+    )
+    .unwrap();
+    let gains: Vec<f64> = (0..200).map(|i| 0.25 * i as f64).collect();
+    let locus = g.root_locus(&gains).unwrap();
+
+    assert_eq!(locus.len(), gains.len());
+    for (k, poles) in gains.iter().zip(&locus) {
+        let closed = &g.den + &(&g.num * *k);
+        let complex = Polynomial {
+            coef: closed.coef.iter().map(|c| c.into()).collect(),
+        };
+        assert!(complex.verify_roots(poles).passes(1e-10), "{k}: {poles:?}");
+    }
+}
+
+#[test]
+fn test_root_locus_branches_are_continuous() {
+    // G = 1/((s+1)(s+2)(s+3))
+    let g = RationalFunction::new(
+        Polynomial::build(&[1.0]).unwrap(),
+        Polynomial::build(&[6.0, 11.0, 6.0, 1.0]).unwrap(),
+    )
+    .unwrap();
+    // Past the breakaway point, with one real and two complex branches
+    let gains: Vec<f64> = (2..100).map(|i| 0.5 * i as f64).collect();
+    let locus = g.root_locus(&gains).unwrap();
+
+    let real = locus[0].iter().position(|p| p.im.abs() < 1e-12).unwrap();
+    assert!(locus.iter().all(|poles| poles[real].im.abs() < 1e-12));
+    for pair in locus.windows(2) {
+        for (a, b) in pair[0].iter().zip(&pair[1]) {
+            assert!((a - b).norm() < 0.5, "{a} -> {b}");
+        }
+    }
+}
+
+#[test]
+fn test_root_locus_errors() {
+    let g = RationalFunction::new(
+        Polynomial::build(&[0.0, -1.0]).unwrap(),
+        Polynomial::build(&[1.0, 1.0]).unwrap(),
+    )
+    .unwrap();
+
+    assert!(matches!(
+        g.root_locus(&[0.5, f64::NAN]),
+        Err(PolyError::InvalidCoefficients { .. })
+    ));
+    // Q + P = 1
+    assert!(matches!(g.root_locus(&[1.0]), Err(PolyError::ConstantPoly)));
+}
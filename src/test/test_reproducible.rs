@@ -0,0 +1,36 @@
+use num::complex::Complex64;
+
+use crate::Polynomial;
+
+/// Returns the bit patterns of the real and imaginary parts of the roots.
+fn bits(roots: &[Complex64]) -> Vec<(u64, u64)> {
+    roots
+        .iter()
+        .map(|z| (z.re.to_bits(), z.im.to_bits()))
+        .collect()
+}
+
+#[test]
+/// The companion QR only uses arithmetic and `sqrt`, so its roots are the same on every platform.
+fn test_reproducible_companion_roots() {
+    let poly = Polynomial::build(&[3.0, -1.5, 0.25, 2.0, -0.75, 1.0]).unwrap();
+    let expected = [
+        (0xbff12db8f21ec3b6, 0x0000000000000000),
+        (0x3fe8f41b5725e89e, 0x3fe83ccfee2691ac),
+        (0x3fe8f41b5725e89e, 0xbfe83ccfee2691ac),
+        (0x3fc0e6766be36c34, 0x3ff882fa58d78c7f),
+        (0x3fc0e6766be36c34, 0xbff882fa58d78c7f),
+    ];
+    assert_eq!(bits(&poly.solve_complex().unwrap()), expected);
+}
+
+#[test]
+fn test_reproducible_aberth_roots() {
+    let i = Complex64::i();
+    let poly =
+        Polynomial::build(&[1.0 + i, -2.0 * i, 0.5 + 0.0 * i, 3.0 - i, 1.0 + 0.0 * i]).unwrap();
+    let first = bits(&poly.solve_complex().unwrap());
+    for _ in 0..10 {
+        assert_eq!(bits(&poly.solve_complex().unwrap()), first);
+    }
+}
@@ -0,0 +1,133 @@
+use is_close::is_close;
+
+use crate::{PolyErrorKind, Polynomial, RansacOptions, RobustLoss};
+
+/// A quadratic with deterministic noise of amplitude 0.01, and a few gross outliers.
+fn data() -> (Vec<f64>, Vec<f64>) {
+    let xs: Vec<f64> = (0..40).map(|i| i as f64 / 4.0).collect();
+    let mut ys: Vec<f64> = xs
+        .iter()
+        .enumerate()
+        .map(|(i, x)| 3.0 - 2.0 * x + 0.25 * x * x + 0.01 * ((i * 7 % 11) as f64 / 5.0 - 1.0))
+        .collect();
+    for (i, outlier) in [(3, 40.0), (17, -25.0), (18, 60.0), (31, -80.0)] {
+        ys[i] += outlier;
+    }
+    (xs, ys)
+}
+
+#[test]
+fn test_fit_robust_outliers() {
+    let (xs, ys) = data();
+    let plain = Polynomial::fit(&xs, &ys, 2).unwrap();
+    assert!((plain.poly.coef[0] - 3.0).abs() > 0.5);
+
+    let huber = Polynomial::fit_robust(&xs, &ys, 2, RobustLoss::Huber(1.345)).unwrap();
+    let tukey = Polynomial::fit_robust(&xs, &ys, 2, RobustLoss::Tukey(4.685)).unwrap();
+    for (c, expected) in huber.poly.coef.iter().zip([3.0, -2.0, 0.25]) {
+        assert!(is_close!(*c, expected, abs_tol = 0.1));
+    }
+    // The biweight ignores the outliers entirely
+    for (c, expected) in tukey.poly.coef.iter().zip([3.0, -2.0, 0.25]) {
+        assert!(is_close!(*c, expected, abs_tol = 0.01));
+    }
+}
+
+#[test]
+fn test_fit_robust_clean_data() {
+    // Without outliers, both agree with ordinary least squares
+    let xs = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+    let ys = [1.0, 3.0, 5.0, 7.0, 9.0, 11.0];
+    for loss in [RobustLoss::Huber(1.345), RobustLoss::Tukey(4.685)] {
+        let fit = Polynomial::fit_robust(&xs, &ys, 1, loss).unwrap();
+        assert!(is_close!(fit.poly.coef[0], 1.0, abs_tol = 1e-10));
+        assert!(is_close!(fit.poly.coef[1], 2.0, rel_tol = 1e-10));
+    }
+}
+
+#[test]
+fn test_fit_ransac() {
+    let (xs, ys) = data();
+    let opts = RansacOptions {
+        threshold: Some(0.05),
+        ..Default::default()
+    };
+    let (fit, inliers) = Polynomial::fit_ransac(&xs, &ys, 2, opts).unwrap();
+    for (i, inlier) in inliers.iter().enumerate() {
+        assert_eq!(*inlier, ![3, 17, 18, 31].contains(&i));
+    }
+    for (c, expected) in fit.poly.coef.iter().zip([3.0, -2.0, 0.25]) {
+        assert!(is_close!(*c, expected, abs_tol = 0.01));
+    }
+
+    // Reproducible, and with an estimated threshold
+    let again = Polynomial::fit_ransac(&xs, &ys, 2, opts).unwrap();
+    assert_eq!(fit.poly.coef, again.0.poly.coef);
+    let (_, estimated) = Polynomial::fit_ransac(&xs, &ys, 2, RansacOptions::default()).unwrap();
+    assert_eq!(estimated.iter().filter(|i| !**i).count(), 4);
+}
+
+#[test]
+fn test_fit_ransac_majority_outliers() {
+    // 60% of the points off the line, but scattered
+    let xs: Vec<f64> = (0..50).map(|i| i as f64).collect();
+    let ys: Vec<f64> = xs
+        .iter()
+        .enumerate()
+        .map(|(i, x)| match i % 5 {
+            0 | 1 => 0.5 * x - 3.0,
+            k => (i * 37 % 101) as f64 * k as f64,
+        })
+        .collect();
+    let opts = RansacOptions {
+        threshold: Some(1e-9),
+        iterations: 2000,
+        seed: 42,
+    };
+    let (fit, inliers) = Polynomial::fit_ransac(&xs, &ys, 1, opts).unwrap();
+    assert_eq!(inliers.iter().filter(|i| **i).count(), 20);
+    assert!(is_close!(fit.poly.coef[1], 0.5, rel_tol = 1e-12));
+}
+
+#[test]
+fn test_fit_robust_errors() {
+    let kind = |res: crate::Result<crate::FitResult>| res.unwrap_err().kind();
+    let xs = [0.0, 1.0, 2.0];
+    let ys = [0.0, 1.0, 2.0];
+
+    let huber = RobustLoss::Huber(1.345);
+    assert_eq!(
+        kind(Polynomial::fit_robust(&xs, &ys[..2], 1, huber)),
+        PolyErrorKind::LengthMismatch
+    );
+    assert_eq!(
+        kind(Polynomial::fit_robust(&xs, &ys, 3, huber)),
+        PolyErrorKind::NotEnoughPoints
+    );
+    assert_eq!(
+        kind(Polynomial::fit_robust(&xs, &ys, 1, RobustLoss::Tukey(-1.0))),
+        PolyErrorKind::InvalidTuning
+    );
+
+    let ransac = |threshold, ys: &[f64]| {
+        let opts = RansacOptions {
+            threshold,
+            ..Default::default()
+        };
+        Polynomial::fit_ransac(&xs, ys, 1, opts).map(|(fit, _)| fit)
+    };
+    assert_eq!(
+        kind(ransac(Some(f64::NAN), &ys)),
+        PolyErrorKind::InvalidTuning
+    );
+    assert_eq!(
+        kind(ransac(Some(1.0), &[0.0, f64::INFINITY, 1.0])),
+        PolyErrorKind::InvalidData
+    );
+    assert_eq!(
+        kind(
+            Polynomial::fit_ransac(&[1.0, 1.0, 1.0], &ys, 1, RansacOptions::default()).map(|r| r.0)
+        ),
+        PolyErrorKind::SingularMatrix
+    );
+}
@@ -0,0 +1,190 @@
+use crate::roots::{filter_real, pair_conjugates, sort_by_magnitude, sort_real_then_complex};
+use crate::{CLUSTER_TOL, PolyError, PolyErrorKind, Polynomial};
+use is_close::is_close;
+use num::complex::Complex64;
+
+// GSL's tests use this tolerance
+const EPS: f64 = 100.0 * f64::EPSILON;
+
+#[test]
+fn test_clustered_triple_root() {
+    let p = Polynomial::build(&[-4913.0, 867.0, -51.0, 1.0]).unwrap();
+    let roots = p.solve_real_clustered(CLUSTER_TOL).unwrap();
+
+    assert_eq!(roots.len(), 1);
+    assert!(is_close!(roots[0].value, 17.0, rel_tol = EPS));
+    assert_eq!(roots[0].multiplicity, 3);
+    assert!(roots[0].condition.is_none());
+}
+
+#[test]
+fn test_clustered_double_root() {
+    let p = Polynomial::build(&[-6647.0, 1071.0, -57.0, 1.0]).unwrap();
+    let roots = p.solve_real_clustered(CLUSTER_TOL).unwrap();
+
+    assert_eq!(roots.len(), 2);
+    assert!(is_close!(roots[0].value, 17.0, rel_tol = EPS));
+    assert_eq!(roots[0].multiplicity, 2);
+    assert!(is_close!(roots[1].value, 23.0, rel_tol = EPS));
+    assert_eq!(roots[1].multiplicity, 1);
+}
+
+#[test]
+fn test_clustered_single_real_root() {
+    // x³-27 has a single real root, which must not be reported as a triple root
+    let p = Polynomial::build(&[-27.0, 0.0, 0.0, 1.0]).unwrap();
+    let roots = p.solve_real_clustered(CLUSTER_TOL).unwrap();
+
+    assert_eq!(roots.len(), 1);
+    assert!(is_close!(roots[0].value, 3.0, rel_tol = EPS));
+    assert_eq!(roots[0].multiplicity, 1);
+}
+
+#[test]
+fn test_clustered_quadratic_and_linear() {
+    let double = Polynomial::build(&[25.0, -20.0, 4.0]).unwrap();
+    let linear = Polynomial::build(&[-21.0, 3.0, 0.0]).unwrap();
+
+    let roots = double.solve_real_clustered(CLUSTER_TOL).unwrap();
+    assert_eq!(roots.len(), 1);
+    assert_eq!(roots[0].multiplicity, 2);
+
+    let roots = linear.solve_real_clustered(CLUSTER_TOL).unwrap();
+    assert_eq!(roots.len(), 1);
+    assert!(is_close!(roots[0].value, 7.0, rel_tol = EPS));
+    assert_eq!(roots[0].multiplicity, 1);
+}
+
+#[test]
+fn test_clustered_invalid() {
+    let constant = Polynomial::build(&[1.0, 0.0]).unwrap();
+    let zero = Polynomial::build(&[0.0, 0.0]).unwrap();
+    let quartic = Polynomial::build(&[1.0, 0.0, 0.0, 0.0, 1.0]).unwrap();
+
+    assert!(matches!(
+        constant.solve_real_clustered(CLUSTER_TOL).unwrap_err(),
+        PolyError::ConstantPoly
+    ));
+    assert!(matches!(
+        zero.solve_real_clustered(CLUSTER_TOL).unwrap_err(),
+        PolyError::ConstantPoly
+    ));
+    assert!(matches!(
+        quartic.solve_real_clustered(CLUSTER_TOL).unwrap_err(),
+        PolyError::IncorrectOrder { expected: 3, .. }
+    ));
+}
+
+#[test]
+fn test_diagnostics_condition_numbers() {
+    // (x-1)(x-2)(x-3): κ(r) = P̃(r) / (r·|P'(r)|), with P̃(x) = 6+11x+6x²+x³
+    let p = Polynomial::build(&[-6.0, 11.0, -6.0, 1.0]).unwrap();
+    let roots = p.solve_with_diagnostics().unwrap();
+    let expected = [24.0 / 2.0, 60.0 / 2.0, 120.0 / 6.0];
+
+    assert_eq!(roots.len(), 3);
+    for (root, k) in roots.iter().zip(expected) {
+        assert!(is_close!(root.condition.unwrap(), k, rel_tol = 1e-12));
+    }
+}
+
+#[test]
+fn test_diagnostics_multiple_root() {
+    let p = Polynomial::build(&[-6647.0, 1071.0, -57.0, 1.0]).unwrap();
+    let roots = p.solve_with_diagnostics().unwrap();
+
+    assert!(roots[0].condition.unwrap() > 1e10);
+    assert!(roots[1].condition.unwrap() < 1e4);
+}
+
+#[test]
+fn test_diagnostics_zero_root() {
+    // x²+2x: absolute condition number at 0 is P̃(0)/|P'(0)| = 0
+    let p = Polynomial::build(&[0.0, 2.0, 1.0]).unwrap();
+    let roots = p.solve_with_diagnostics().unwrap();
+
+    assert_eq!(roots[1].value, 0.0);
+    assert_eq!(roots[1].condition, Some(0.0));
+}
+
+#[test]
+fn test_filter_real_keeps_order() {
+    let roots = [
+        Complex64::new(3.0, 1e-14),
+        Complex64::new(1.0, 2.0),
+        Complex64::new(-1.0, 0.0),
+        Complex64::new(1e-3, 1e-13),
+    ];
+    assert_eq!(filter_real(&roots, 1e-12), [3.0, -1.0, 1e-3]);
+    assert_eq!(filter_real(&roots, 0.0), [-1.0]);
+}
+
+#[test]
+fn test_sort_by_magnitude() {
+    let mut roots = [
+        Complex64::new(0.0, -2.0),
+        Complex64::new(1.0, 0.0),
+        Complex64::new(0.0, 2.0),
+        Complex64::new(-0.5, 0.0),
+    ];
+    sort_by_magnitude(&mut roots);
+    assert_eq!(
+        roots,
+        [
+            Complex64::new(-0.5, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, -2.0),
+            Complex64::new(0.0, 2.0),
+        ]
+    );
+}
+
+#[test]
+fn test_sort_real_then_complex() {
+    let poly = Polynomial::from_roots(&[
+        Complex64::new(1.0, 1.0),
+        Complex64::new(1.0, -1.0),
+        Complex64::new(2.0, 0.0),
+        Complex64::new(-1.0, 0.0),
+    ]);
+    let mut roots = poly.solve_complex().unwrap();
+    sort_real_then_complex(&mut roots, 1e-10);
+    let expected = [(-1.0, 0.0), (2.0, 0.0), (1.0, -1.0), (1.0, 1.0)];
+    for (z, (re, im)) in roots.iter().zip(expected) {
+        assert!((z - Complex64::new(re, im)).norm() < 1e-12);
+    }
+}
+
+#[test]
+fn test_pair_conjugates() {
+    let roots = [
+        Complex64::new(1.0, 2.0 + 1e-15),
+        Complex64::new(4.0, 0.0),
+        Complex64::new(-3.0, -1.0),
+        Complex64::new(1.0 + 1e-15, -2.0),
+        Complex64::new(-3.0, 1.0),
+    ];
+    let split = pair_conjugates(&roots, 1e-12).unwrap();
+    assert_eq!(split.real, [4.0]);
+    assert_eq!(split.pairs.len(), 2);
+    assert_eq!(split.pairs[0], Complex64::new(-3.0, 1.0));
+    assert!((split.pairs[1] - Complex64::new(1.0, 2.0)).norm() < 1e-14);
+
+    let all = split.roots();
+    assert_eq!(all.len(), 5);
+    assert_eq!(all[3], all[4].conj());
+}
+
+#[test]
+fn test_pair_conjugates_unpaired() {
+    let roots = [Complex64::new(1.0, 2.0), Complex64::new(1.0, -2.5)];
+    assert_eq!(
+        pair_conjugates(&roots, 1e-12).unwrap_err().kind(),
+        PolyErrorKind::UnpairedRoot
+    );
+    let roots = [Complex64::new(1.0, -2.0)];
+    assert!(matches!(
+        pair_conjugates(&roots, 1e-12),
+        Err(PolyError::UnpairedRoot(z)) if z == roots[0]
+    ));
+}
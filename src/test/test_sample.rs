@@ -0,0 +1,73 @@
+use crate::{PolyErrorKind, Polynomial, SampleOptions};
+
+/// Returns the largest distance of the curve from the polyline through `points`, checked at the
+/// midpoints of its segments.
+fn max_chord_error(poly: &Polynomial<f64>, points: &[(f64, f64)]) -> f64 {
+    points
+        .windows(2)
+        .map(|w| {
+            let mid = 0.5 * (w[0].0 + w[1].0);
+            (poly.eval(mid) - 0.5 * (w[0].1 + w[1].1)).abs()
+        })
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn test_sample_refines_steep_regions() {
+    // (x² − 1)⁴·x, flat in the middle and steep near the ends
+    let poly = Polynomial::from_roots(&[-1.0, -1.0, -1.0, -1.0, 0.0, 1.0, 1.0, 1.0, 1.0]);
+    let opts = SampleOptions::default();
+    let points = poly.sample(-1.5, 1.5, opts).unwrap();
+
+    assert_eq!(points.first().unwrap().0, -1.5);
+    assert_eq!(points.last().unwrap().0, 1.5);
+    assert!(points.windows(2).all(|w| w[0].0 < w[1].0));
+    assert!(points.iter().all(|(x, y)| *y == poly.eval(*x)));
+
+    let range = 2.0 * poly.eval(1.5);
+    assert!(max_chord_error(&poly, &points) <= opts.tol * range);
+    assert!(points.len() < 500);
+}
+
+#[test]
+fn test_sample_line_is_not_refined() {
+    let poly = Polynomial::build(&[1.0, 2.0]).unwrap();
+    let opts = SampleOptions {
+        initial: 5,
+        ..SampleOptions::default()
+    };
+    let points = poly.sample(0.0, 1.0, opts).unwrap();
+    assert_eq!(
+        points,
+        [(0.0, 1.0), (0.25, 1.5), (0.5, 2.0), (0.75, 2.5), (1.0, 3.0)]
+    );
+}
+
+#[test]
+fn test_sample_max_points() {
+    let poly = Polynomial::build(&[0.0, 0.0, 1.0]).unwrap();
+    let opts = SampleOptions {
+        initial: 2,
+        tol: 0.0,
+        max_points: 40,
+    };
+    assert_eq!(poly.sample(-1.0, 1.0, opts).unwrap().len(), 40);
+}
+
+#[test]
+fn test_sample_invalid_interval() {
+    let poly = Polynomial::build(&[0.0, 1.0]).unwrap();
+    for (a, b) in [
+        (1.0, 0.0),
+        (0.0, 0.0),
+        (f64::NAN, 1.0),
+        (0.0, f64::INFINITY),
+    ] {
+        assert_eq!(
+            poly.sample(a, b, SampleOptions::default())
+                .unwrap_err()
+                .kind(),
+            PolyErrorKind::InvalidInterval
+        );
+    }
+}
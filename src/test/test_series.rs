@@ -0,0 +1,162 @@
+use num::complex::Complex64;
+
+use crate::{PolyError, Polynomial, TruncatedSeries};
+
+/// Composes the series `p` and `q` (with q(0)=0), truncated to `n` coefficients.
+fn compose(p: &Polynomial<f64>, q: &Polynomial<f64>, n: usize) -> Vec<f64> {
+    let mut res = vec![0.0; n];
+    let mut power = vec![1.0];
+    for c in &p.coef {
+        res.iter_mut().zip(&power).for_each(|(r, x)| *r += c * x);
+        power = (&Polynomial { coef: power } * q).coef;
+        power.truncate(n);
+    }
+    res
+}
+
+#[test]
+fn test_series_inverse() {
+    let poly = Polynomial::build(&[2.0f64, -1.0, 0.5, 3.0]).unwrap();
+    for n in 1..12 {
+        let inv = poly.series_inverse(n).unwrap();
+        assert_eq!(inv.coef.len(), n);
+        let product = &poly * &inv;
+        assert!((product.coef[0] - 1.0).abs() < 1e-14);
+        assert!(product.coef[1..n].iter().all(|c| c.abs() < 1e-12));
+    }
+
+    // 1/(1−x) = 1+x+x²+...
+    let geometric = Polynomial::build(&[1.0, -1.0]).unwrap();
+    assert_eq!(geometric.series_inverse(5).unwrap().coef, [1.0; 5]);
+    assert_eq!(geometric.series_inverse(0).unwrap().coef, [0.0]);
+
+    let complex = Polynomial::build(&[Complex64::new(0.0, 1.0), Complex64::new(1.0, 0.0)]).unwrap();
+    let inv = complex.series_inverse(3).unwrap();
+    let product = &complex * &inv;
+    assert!((product.coef[0] - 1.0).norm() < 1e-15);
+    assert!(product.coef[1..3].iter().all(|c| c.norm() < 1e-15));
+
+    assert!(matches!(
+        Polynomial::build(&[0.0, 1.0]).unwrap().series_inverse(3),
+        Err(PolyError::NotInvertibleSeries)
+    ));
+}
+
+#[test]
+fn test_compositional_inverse() {
+    let poly = Polynomial::build(&[0.0, 2.0, -1.0, 0.3, 0.7]).unwrap();
+    let n = 10;
+    let inv = poly.compositional_inverse(n).unwrap();
+    assert_eq!(inv.coef.len(), n);
+
+    for (p, q) in [(&poly, &inv), (&inv, &poly)] {
+        let identity = compose(p, q, n);
+        assert!((identity[1] - 1.0).abs() < 1e-12);
+        identity
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != 1)
+            .for_each(|(_, c)| assert!(c.abs() < 1e-10));
+    }
+
+    // The inverse of x+x² is (√(1+4x)−1)/2
+    let inv = Polynomial::build(&[0.0, 1.0, 1.0])
+        .unwrap()
+        .compositional_inverse(5)
+        .unwrap();
+    assert_eq!(inv.coef, [0.0, 1.0, -1.0, 2.0, -5.0]);
+
+    for invalid in [[1.0, 1.0, 1.0], [0.0, 0.0, 1.0]] {
+        assert!(matches!(
+            Polynomial::build(&invalid)
+                .unwrap()
+                .compositional_inverse(3),
+            Err(PolyError::NotInvertibleSeries)
+        ));
+    }
+}
+
+fn series(coef: &[f64], n: usize) -> TruncatedSeries<f64> {
+    TruncatedSeries::new(&Polynomial::build(coef).unwrap(), n)
+}
+
+fn assert_series_eq(a: &TruncatedSeries<f64>, b: &TruncatedSeries<f64>) {
+    assert_eq!(a.len(), b.len());
+    a.coef()
+        .iter()
+        .zip(b.coef())
+        .for_each(|(x, y)| assert!((x - y).abs() < 1e-12, "{a:?} != {b:?}"));
+}
+
+#[test]
+fn test_truncated_series_arithmetic() {
+    let f = series(&[1.0, 2.0, 3.0], 4);
+    assert_eq!(f.coef(), [1.0, 2.0, 3.0, 0.0]);
+    assert_eq!(series(&[1.0, 2.0, 3.0], 2).coef(), [1.0, 2.0]);
+    assert_eq!(f.to_polynomial().coef, [1.0, 2.0, 3.0, 0.0]);
+
+    let g = series(&[2.0, -1.0, 0.0, 5.0, 7.0], 5);
+    assert_eq!((&f + &g).coef(), [3.0, 1.0, 3.0, 5.0]);
+    assert_eq!((f.clone() - g.clone()).coef(), [-1.0, 3.0, 3.0, -5.0]);
+    assert_eq!((&f * &g).coef(), [2.0, 3.0, 4.0, 2.0]);
+    assert_eq!((-&f).coef(), [-1.0, -2.0, -3.0, 0.0]);
+
+    let q = f.divide(&g).unwrap();
+    assert_series_eq(&(&q * &g), &f);
+    assert_series_eq(&(&g.inverse().unwrap() * &g), &series(&[1.0], 5));
+
+    let zero = series(&[0.0, 1.0], 3);
+    assert!(matches!(
+        f.divide(&zero),
+        Err(PolyError::NotInvertibleSeries)
+    ));
+    assert!(matches!(
+        zero.inverse(),
+        Err(PolyError::NotInvertibleSeries)
+    ));
+}
+
+#[test]
+fn test_truncated_series_functions() {
+    let f = series(&[0.5, -1.0, 2.0, 0.25], 8);
+
+    assert_series_eq(&f.exp().log().unwrap(), &f);
+    assert_series_eq(&f.log().unwrap().exp(), &f);
+    let root = f.sqrt().unwrap();
+    assert_series_eq(&(&root * &root), &f);
+
+    // exp(f+g) = exp(f)exp(g)
+    let g = series(&[-0.3, 0.0, 1.5], 8);
+    assert_series_eq(&(&f + &g).exp(), &(&f.exp() * &g.exp()));
+
+    // √(1+x) = 1 + x/2 − x²/8 + x³/16
+    assert_series_eq(
+        &series(&[1.0, 1.0], 4).sqrt().unwrap(),
+        &series(&[1.0, 0.5, -0.125, 0.0625], 4),
+    );
+
+    for invalid in [[0.0, 1.0], [-1.0, 1.0]] {
+        let s = series(&invalid, 3);
+        assert!(matches!(s.log(), Err(PolyError::InvalidConstantTerm)));
+        assert!(matches!(s.sqrt(), Err(PolyError::InvalidConstantTerm)));
+    }
+
+    // Complex coefficients have a logarithm and a square root for any nonzero constant term
+    let z = TruncatedSeries::new(
+        &Polynomial::build(&[Complex64::new(-1.0, 0.0), Complex64::new(1.0, 0.0)]).unwrap(),
+        4,
+    );
+    let root = z.sqrt().unwrap();
+    let square = &root * &root;
+    square
+        .coef()
+        .iter()
+        .zip(z.coef())
+        .for_each(|(a, b)| assert!((a - b).norm() < 1e-14));
+    assert!(z.log().is_ok());
+
+    let empty = series(&[1.0], 0);
+    assert!(empty.is_empty());
+    assert!(empty.log().unwrap().is_empty());
+    assert!(empty.exp().is_empty());
+}
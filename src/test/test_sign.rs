@@ -0,0 +1,69 @@
+use is_close::is_close;
+
+use crate::{Polynomial, Sign};
+
+/// Returns the signs of the intervals of `poly`.
+fn signs(poly: &Polynomial<f64>) -> Vec<Sign> {
+    poly.sign_changes()
+        .unwrap()
+        .iter()
+        .map(|i| i.sign)
+        .collect()
+}
+
+#[test]
+fn test_sign_changes() {
+    // -(x+2)(x-1)(x-3)
+    let poly = Polynomial::build(&[-6.0, 5.0, 2.0, -1.0]).unwrap();
+    let intervals = poly.sign_changes().unwrap();
+
+    assert_eq!(intervals.len(), 4);
+    assert_eq!(intervals[0].start, f64::NEG_INFINITY);
+    assert_eq!(intervals[3].end, f64::INFINITY);
+    for (interval, root) in intervals.iter().zip([-2.0, 1.0, 3.0]) {
+        assert!(is_close!(interval.end, root, abs_tol = 1e-12));
+    }
+    for pair in intervals.windows(2) {
+        assert_eq!(pair[0].end, pair[1].start);
+    }
+    assert_eq!(
+        signs(&poly),
+        [
+            Sign::Positive,
+            Sign::Negative,
+            Sign::Positive,
+            Sign::Negative
+        ]
+    );
+}
+
+#[test]
+fn test_sign_changes_even_multiplicity() {
+    // (x-1)²(x+1): the sign is kept at the double root
+    let poly = Polynomial::build(&[1.0, -1.0, -1.0, 1.0]).unwrap();
+    let intervals = poly.sign_changes().unwrap();
+
+    assert_eq!(intervals.len(), 3);
+    assert!(is_close!(intervals[1].end, 1.0, abs_tol = 1e-6));
+    assert_eq!(
+        signs(&poly),
+        [Sign::Negative, Sign::Positive, Sign::Positive]
+    );
+}
+
+#[test]
+fn test_sign_changes_no_roots() {
+    let positive = Polynomial::build(&[1.0, 0.0, 1.0]).unwrap();
+    let negative = Polynomial::build(&[-3.0, 0.0, 0.0]).unwrap();
+    let zero = Polynomial::build(&[0.0, 0.0]).unwrap();
+
+    assert_eq!(signs(&positive), [Sign::Positive]);
+    assert_eq!(signs(&negative), [Sign::Negative]);
+    assert_eq!(signs(&zero), [Sign::Zero]);
+
+    let intervals = positive.sign_changes().unwrap();
+    assert_eq!(
+        (intervals[0].start, intervals[0].end),
+        (f64::NEG_INFINITY, f64::INFINITY)
+    );
+}
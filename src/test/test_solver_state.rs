@@ -0,0 +1,149 @@
+use std::ops::ControlFlow;
+
+use num::complex::Complex64;
+
+use crate::{PolyError, Polynomial, RootSolverState};
+
+fn poly() -> Polynomial<f64> {
+    // Roots at 0 (twice), and on a spiral
+    let roots: Vec<Complex64> = (0..12)
+        .map(|k| Complex64::from_polar(0.5 + 0.1 * k as f64, 0.9 * k as f64))
+        .chain([Complex64::default(); 2])
+        .collect();
+    let complex = Polynomial::from_roots(&roots);
+    Polynomial::build(&complex.coef.iter().map(|c| c.re).collect::<Vec<f64>>()).unwrap()
+}
+
+#[test]
+fn test_resumed_solve_matches_uninterrupted() {
+    let mut full = RootSolverState::new(&poly()).unwrap();
+    assert!(full.run(500));
+    let iterations = full.iterations();
+
+    let mut chunked = RootSolverState::new(&poly()).unwrap();
+    while !chunked.run(3) {
+        assert!(chunked.iterations() < iterations);
+    }
+    assert_eq!(chunked.iterations(), iterations);
+    assert_eq!(chunked.roots(), full.roots());
+
+    let roots = chunked.into_roots().unwrap();
+    assert_eq!(roots.len(), 14);
+    assert_eq!(roots[..2], [Complex64::default(); 2]);
+    let complex = Polynomial {
+        coef: poly()
+            .coef
+            .iter()
+            .map(|c| Complex64::new(*c, 0.0))
+            .collect(),
+    };
+    assert!(complex.verify_roots(&roots).max_relative_residual < 1e-13);
+}
+
+#[test]
+fn test_solver_state_errors() {
+    let mut state = RootSolverState::new(&poly()).unwrap();
+    assert!(!state.run(1));
+    assert!(matches!(state.into_roots(), Err(PolyError::NoConvergence)));
+
+    assert!(matches!(
+        RootSolverState::new(&Polynomial::build(&[3.0, 0.0]).unwrap()),
+        Err(PolyError::ConstantPoly)
+    ));
+    assert!(matches!(
+        RootSolverState::new(&Polynomial {
+            coef: vec![1.0, f64::INFINITY]
+        }),
+        Err(PolyError::InvalidCoefficients { .. })
+    ));
+
+    // Only roots at 0
+    let mut state = RootSolverState::new(&Polynomial::build(&[0.0, 0.0, 2.0]).unwrap()).unwrap();
+    assert!(state.run(0));
+    assert_eq!(state.into_roots().unwrap(), [Complex64::default(); 2]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_solver_state_serde_round_trip() {
+    let mut full = RootSolverState::new(&poly()).unwrap();
+    full.run(500);
+
+    let mut state = RootSolverState::new(&poly()).unwrap();
+    state.run(4);
+    let json = serde_json::to_string(&state).unwrap();
+    let mut resumed: RootSolverState = serde_json::from_str(&json).unwrap();
+    assert_eq!(resumed, state);
+    assert!(resumed.run(500));
+    assert_eq!(resumed.roots(), full.roots());
+
+    // Inconsistent states are rejected
+    let broken = json.replacen("\"converged\":[true,", "\"converged\":[", 1);
+    assert!(serde_json::from_str::<RootSolverState>(&broken).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_roots_serde_round_trip() {
+    let poly = Polynomial::build(&[1.0, -2.0, 3.0]).unwrap();
+    let json = serde_json::to_string(&poly).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Polynomial<f64>>(&json).unwrap(),
+        poly
+    );
+
+    let roots = crate::CubicRoots::Three([1.0, 2.0, 3.0]);
+    let json = serde_json::to_string(&roots).unwrap();
+    assert_eq!(
+        serde_json::from_str::<crate::CubicRoots>(&json).unwrap(),
+        roots
+    );
+}
+
+#[test]
+fn test_progress_callback() {
+    let mut infos = Vec::new();
+    let roots = poly()
+        .solve_complex_with_progress(|info| {
+            infos.push((info.iteration, info.converged, info.roots.len()));
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+    assert_eq!(roots.len(), 14);
+
+    let mut full = RootSolverState::new(&poly()).unwrap();
+    full.run(500);
+    assert_eq!(roots, full.roots());
+    assert_eq!(infos.len(), full.iterations());
+    assert!(
+        infos
+            .iter()
+            .enumerate()
+            .all(|(i, info)| info.0 == i + 1 && info.2 == 14)
+    );
+    assert!(infos.windows(2).all(|w| w[0].1 <= w[1].1));
+    assert_eq!(infos.last().unwrap().1, 14);
+}
+
+#[test]
+fn test_progress_cancellation() {
+    let cancelled = poly().solve_complex_with_progress(|info| match info.converged >= 2 {
+        true => ControlFlow::Break(()),
+        false => ControlFlow::Continue(()),
+    });
+    assert!(matches!(cancelled, Err(PolyError::Cancelled)));
+
+    // A cancelled state can be resumed
+    let mut state = RootSolverState::new(&poly()).unwrap();
+    let flow = state.run_with_progress(500, |info| match info.iteration {
+        4 => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    });
+    assert_eq!(flow, ControlFlow::Break(()));
+    assert_eq!(state.iterations(), 4);
+    assert_eq!(
+        state.run_with_progress(500, |_| ControlFlow::Continue(())),
+        ControlFlow::Continue(true)
+    );
+    assert!(state.into_roots().is_ok());
+}
@@ -0,0 +1,71 @@
+use is_close::is_close;
+use num::complex::Complex64;
+
+use crate::{PolyError, Polynomial, SparsePolynomial};
+
+#[test]
+fn test_sparse_build() {
+    let poly = SparsePolynomial::build(&[(3, 1.0), (0, 2.0), (3, 1.0), (1, 0.0)]).unwrap();
+    assert_eq!(poly.terms(), [(0, 2.0), (3, 2.0)]);
+    assert_eq!(poly.degree(), 3);
+
+    let cancel = SparsePolynomial::build(&[(2, 1.0), (2, -1.0)]).unwrap();
+    assert_eq!(cancel, SparsePolynomial::new());
+    assert_eq!(cancel.degree(), 0);
+
+    assert!(matches!(
+        SparsePolynomial::build(&[(1, f64::NAN)]).unwrap_err(),
+        PolyError::InvalidCoefficients { .. }
+    ));
+}
+
+#[test]
+fn test_sparse_eval() {
+    let poly = SparsePolynomial::build(&[(0, 1.0), (2, -3.0), (7, 0.5)]).unwrap();
+    let dense = Polynomial::from(poly.clone());
+
+    for x in [-1.3, -0.2, 0.0, 0.7, 2.1] {
+        assert!(is_close!(poly.eval(x), dense.eval(x), rel_tol = 1e-14));
+    }
+
+    let big = SparsePolynomial::build(&[(5000, 1.0), (0, -1.0)]).unwrap();
+    assert_eq!(big.eval(1.0), 0.0);
+    assert!(is_close!(
+        big.eval(1.0001),
+        1.0001f64.powi(5000) - 1.0,
+        rel_tol = 1e-12
+    ));
+
+    let i = Complex64::new(0.0, 1.0);
+    let complex = SparsePolynomial::build(&[(4, Complex64::new(1.0, 0.0))]).unwrap();
+    assert_eq!(complex.eval(i), Complex64::new(1.0, 0.0));
+
+    assert_eq!(SparsePolynomial::<f64>::new().eval(3.0), 0.0);
+}
+
+#[test]
+fn test_sparse_conversions() {
+    let dense = Polynomial::build(&[1.0, 0.0, 0.0, -2.0, 0.0]).unwrap();
+    let sparse = SparsePolynomial::from(dense);
+    assert_eq!(sparse.terms(), [(0, 1.0), (3, -2.0)]);
+
+    let back = Polynomial::from(sparse);
+    assert_eq!(back.coef, [1.0, 0.0, 0.0, -2.0]);
+}
+
+#[test]
+fn test_sparse_arithmetic() {
+    let p = SparsePolynomial::build(&[(1000, 1.0), (0, -1.0)]).unwrap();
+    let q = SparsePolynomial::build(&[(1000, 1.0), (0, 1.0)]).unwrap();
+
+    assert_eq!((&p + &q).terms(), [(1000, 2.0)]);
+    assert_eq!((&p - &q).terms(), [(0, -2.0)]);
+    assert_eq!((-&p).terms(), [(0, 1.0), (1000, -1.0)]);
+    assert_eq!((p * q).terms(), [(0, -1.0), (2000, 1.0)]);
+
+    // Agrees with dense arithmetic
+    let a = Polynomial::build(&[1.0, 2.0, 0.0, 3.0]).unwrap();
+    let b = Polynomial::build(&[0.0, -1.0, 4.0]).unwrap();
+    let product = SparsePolynomial::from(a.clone()) * SparsePolynomial::from(b.clone());
+    assert_eq!(Polynomial::from(product).coef, (a * b).coef);
+}
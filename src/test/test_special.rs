@@ -0,0 +1,128 @@
+use num::complex::Complex64;
+
+use crate::{PolyError, Polynomial};
+
+#[test]
+fn test_cyclotomic() {
+    let expected: [&[f64]; 6] = [
+        &[-1.0, 1.0],
+        &[1.0, 1.0],
+        &[1.0, 1.0, 1.0],
+        &[1.0, 0.0, 1.0],
+        &[1.0, 1.0, 1.0, 1.0, 1.0],
+        &[1.0, -1.0, 1.0],
+    ];
+    for (n, coef) in expected.iter().enumerate() {
+        assert_eq!(Polynomial::cyclotomic(n + 1).unwrap().coef, *coef);
+    }
+
+    // The first cyclotomic polynomial with a coefficient other than 0, ±1
+    let phi105 = Polynomial::cyclotomic(105).unwrap();
+    assert_eq!(phi105.coef.len(), 49);
+    assert_eq!(phi105.coef[7], -2.0);
+    assert_eq!(phi105.coef[41], -2.0);
+
+    // xⁿ − 1 = ∏ Φ_d(x) over the divisors of n
+    let n: usize = 12;
+    let product = (1..=n)
+        .filter(|d| n.is_multiple_of(*d))
+        .map(|d| Polynomial::cyclotomic(d).unwrap())
+        .fold(Polynomial::build(&[1.0]).unwrap(), |res, p| &res * &p);
+    let mut expected = vec![0.0; n + 1];
+    expected[0] = -1.0;
+    expected[n] = 1.0;
+    assert_eq!(product.coef, expected);
+
+    // The roots are primitive roots of unity
+    for z in Polynomial::cyclotomic(9).unwrap().solve_complex().unwrap() {
+        assert!((z.norm() - 1.0).abs() < 1e-12);
+        assert!((z.powu(9) - Complex64::new(1.0, 0.0)).norm() < 1e-12);
+        assert!((z.powu(3) - Complex64::new(1.0, 0.0)).norm() > 0.1);
+    }
+
+    assert!(matches!(
+        Polynomial::cyclotomic(0),
+        Err(PolyError::InvalidIndex(0))
+    ));
+}
+
+#[test]
+fn test_wilkinson() {
+    assert_eq!(Polynomial::wilkinson(0).coef, [1.0]);
+
+    let w = Polynomial::wilkinson(10);
+    assert_eq!(w.coef[0], 3628800.0);
+    assert_eq!(w.coef[9], -55.0);
+    for k in 1..=10 {
+        assert_eq!(w.eval(k as f64), 0.0);
+    }
+}
+
+#[test]
+fn test_bernstein() {
+    let n = 5;
+    let polys: Vec<_> = (0..=n)
+        .map(|i| Polynomial::bernstein(i, n).unwrap())
+        .collect();
+
+    // Partition of unity
+    let sum = polys
+        .iter()
+        .fold(Polynomial::new(), |res: Polynomial<f64>, p| &res + p);
+    assert_eq!(sum.to_trimmed().coef, [1.0]);
+
+    for x in [0.0, 0.3, 0.8, 1.0] {
+        assert!((polys[2].eval(x) - 10.0 * x * x * (1.0 - x).powi(3)).abs() < 1e-14);
+    }
+
+    assert!(matches!(
+        Polynomial::bernstein(3, 2),
+        Err(PolyError::InvalidIndex(3))
+    ));
+}
+
+#[test]
+fn test_taylor_exp_coefficients_are_correctly_rounded() {
+    // Correctly rounded values of 1/k!, including subnormal ones
+    let expected = [
+        (23, 3.868170170630684e-23),
+        (25, 6.446950284384474e-26),
+        (30, 3.7699876288159054e-33),
+        (50, 3.287949416633158e-65),
+        (100, 1.071510288125467e-158),
+        (170, 1.3779009677917706e-307),
+        (171, 8.05790039644312e-310),
+        (172, 4.684825811886e-312),
+        (175, 8.89323e-319),
+        (177, 3e-323),
+        (178, 0.0),
+    ];
+    let poly = Polynomial::taylor_exp(200);
+    assert_eq!(poly.coef.len(), 201);
+    for (k, c) in expected {
+        assert_eq!(poly.coef[k], c, "1/{k}!");
+    }
+    assert_eq!(poly.coef[22], 1.0 / 1124000727777607680000.0);
+}
+
+#[test]
+fn test_taylor_trig() {
+    let sin = Polynomial::taylor_sin(25);
+    let cos = Polynomial::taylor_cos(25);
+    for x in [-1.0, -0.3, 0.0, 0.5, 1.0f64] {
+        assert!((sin.eval(x) - x.sin()).abs() < 1e-16);
+        assert!((cos.eval(x) - x.cos()).abs() < 1e-16);
+    }
+    assert!(
+        sin.derivative()
+            .approx_eq(&Polynomial::taylor_cos(24), 1e-15, 0.0)
+    );
+}
+
+#[test]
+fn test_taylor_ln1p() {
+    let poly = Polynomial::taylor_ln1p(60);
+    assert_eq!(poly.coef[..5], [0.0, 1.0, -0.5, 1.0 / 3.0, -0.25]);
+    assert!((poly.eval(0.25) - 0.25f64.ln_1p()).abs() < 1e-16);
+    assert_eq!(Polynomial::taylor_ln1p(0).coef, [0.0]);
+}
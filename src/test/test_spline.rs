@@ -0,0 +1,97 @@
+use is_close::is_close;
+
+use crate::{PiecewisePolynomial, PolyError, SplineBoundary};
+
+#[test]
+fn test_spline_interpolates() {
+    let xs = [0.0, 0.5, 1.5, 2.0, 4.0];
+    let ys = [1.0, -0.5, 2.0, 0.0, 3.0];
+
+    for boundary in [SplineBoundary::Natural, SplineBoundary::Clamped(1.0, -2.0)] {
+        let spline = PiecewisePolynomial::cubic_spline(&xs, &ys, boundary).unwrap();
+        let d1 = spline.derivative();
+        let d2 = d1.derivative();
+
+        for (x, y) in xs.iter().zip(&ys) {
+            assert!(is_close!(spline.eval(*x), *y, abs_tol = 1e-14));
+        }
+
+        // Continuity of the derivatives on the interior knots
+        for i in 1..xs.len() - 1 {
+            let (left, right) = (&spline.pieces()[i - 1], &spline.pieces()[i]);
+            let h = xs[i] - xs[i - 1];
+            assert!(is_close!(
+                left.derivative().eval(h),
+                d1.eval(xs[i]),
+                abs_tol = 1e-12
+            ));
+            assert!(is_close!(
+                left.derivative().derivative().eval(h),
+                right.derivative().derivative().eval(0.0),
+                abs_tol = 1e-12
+            ));
+        }
+
+        match boundary {
+            SplineBoundary::Natural => {
+                assert!(is_close!(d2.eval(0.0), 0.0, abs_tol = 1e-13));
+                assert!(is_close!(d2.eval(4.0), 0.0, abs_tol = 1e-13));
+            }
+            SplineBoundary::Clamped(s0, sn) => {
+                assert!(is_close!(d1.eval(0.0), s0, abs_tol = 1e-13));
+                assert!(is_close!(d1.eval(4.0), sn, abs_tol = 1e-13));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_spline_reproduces_cubic() {
+    // A clamped spline reproduces a cubic exactly
+    let f = |x: f64| x.powi(3) - 2.0 * x + 1.0;
+    let df = |x: f64| 3.0 * x.powi(2) - 2.0;
+    let xs = [-1.0, 0.0, 0.7, 2.0];
+    let ys: Vec<f64> = xs.iter().map(|x| f(*x)).collect();
+
+    let spline =
+        PiecewisePolynomial::cubic_spline(&xs, &ys, SplineBoundary::Clamped(df(-1.0), df(2.0)))
+            .unwrap();
+    for x in [-0.5, 0.3, 1.1, 1.9] {
+        assert!(is_close!(spline.eval(x), f(x), abs_tol = 1e-13));
+    }
+}
+
+#[test]
+fn test_spline_two_points() {
+    let spline =
+        PiecewisePolynomial::cubic_spline(&[0.0, 2.0], &[1.0, 5.0], SplineBoundary::Natural)
+            .unwrap();
+    assert_eq!(spline.pieces()[0].coef, [1.0, 2.0, 0.0, 0.0]);
+}
+
+#[test]
+fn test_spline_invalid() {
+    let natural = SplineBoundary::Natural;
+
+    assert!(matches!(
+        PiecewisePolynomial::cubic_spline(&[0.0, 1.0], &[1.0], natural).unwrap_err(),
+        PolyError::LengthMismatch(2, 1)
+    ));
+    assert!(matches!(
+        PiecewisePolynomial::cubic_spline(&[0.0], &[1.0], natural).unwrap_err(),
+        PolyError::NotEnoughPoints(2)
+    ));
+    assert!(matches!(
+        PiecewisePolynomial::cubic_spline(&[0.0, 1.0, 1.0], &[1.0, 2.0, 3.0], natural).unwrap_err(),
+        PolyError::InvalidInterval(_, _)
+    ));
+    assert!(matches!(
+        PiecewisePolynomial::cubic_spline(
+            &[0.0, 1.0],
+            &[1.0, 2.0],
+            SplineBoundary::Clamped(f64::NAN, 0.0)
+        )
+        .unwrap_err(),
+        PolyError::InvalidData
+    ));
+}
@@ -0,0 +1,314 @@
+use crate::{CubicRoots, PolyError, Polynomial, QuadraticRoots, solve};
+use is_close::is_close;
+use num::complex::Complex32;
+
+// GSL's tests use this tolerance
+const EPS: f64 = 100.0 * f64::EPSILON;
+
+#[test]
+fn test_quadratic_structured() {
+    let none = Polynomial::build(&[26.0, -20.0, 4.0]).unwrap();
+    let double = Polynomial::build(&[25.0, -20.0, 4.0]).unwrap();
+    let two = Polynomial::build(&[21.0, -20.0, 4.0]).unwrap();
+    let linear = Polynomial::build(&[-21.0, 3.0, 0.0]).unwrap();
+    let constant = Polynomial::build(&[1.0, 0.0, 0.0]).unwrap();
+
+    assert_eq!(
+        none.solve_real_quadratic_structured().unwrap(),
+        QuadraticRoots::None
+    );
+    assert_eq!(
+        double.solve_real_quadratic_structured().unwrap(),
+        QuadraticRoots::Double(2.5)
+    );
+    assert_eq!(
+        linear.solve_real_quadratic_structured().unwrap(),
+        QuadraticRoots::Linear(7.0)
+    );
+    assert!(matches!(
+        constant.solve_real_quadratic_structured().unwrap_err(),
+        PolyError::ConstantPoly
+    ));
+
+    let QuadraticRoots::Two(x1, x2) = two.solve_real_quadratic_structured().unwrap() else {
+        panic!("Expected two distinct roots");
+    };
+    assert!(is_close!(x1, 1.5, rel_tol = EPS));
+    assert!(is_close!(x2, 3.5, rel_tol = EPS));
+}
+
+#[test]
+fn test_cubic_structured() {
+    let one = Polynomial::build(&[-27.0, 0.0, 0.0, 1.0]).unwrap();
+    let triple = Polynomial::build(&[-4913.0, 867.0, -51.0, 1.0]).unwrap();
+    let three = Polynomial::build(&[-6647.0, 1071.0, -57.0, 1.0]).unwrap();
+    let not_cubic = Polynomial::build(&[1.0, 1.0, 1.0, 0.0]).unwrap();
+
+    assert!(matches!(
+        one.solve_real_cubic_structured().unwrap(),
+        CubicRoots::One(x) if is_close!(x, 3.0, rel_tol = EPS)
+    ));
+    assert!(matches!(
+        triple.solve_real_cubic_structured().unwrap(),
+        CubicRoots::TripleRepeated(x) if is_close!(x, 17.0, rel_tol = EPS)
+    ));
+    assert!(matches!(
+        not_cubic.solve_real_cubic_structured().unwrap_err(),
+        PolyError::IncorrectOrder { expected: 3, .. }
+    ));
+
+    let CubicRoots::Three(roots) = three.solve_real_cubic_structured().unwrap() else {
+        panic!("Expected three real roots");
+    };
+    for (x, expected) in roots.iter().zip([17.0, 17.0, 23.0]) {
+        assert!(is_close!(*x, expected, rel_tol = EPS));
+    }
+}
+
+#[test]
+fn test_structured_to_vec() {
+    assert!(QuadraticRoots::<f64>::None.to_vec().is_empty());
+    assert_eq!(QuadraticRoots::Double(1.0).to_vec(), [1.0, 1.0]);
+    assert_eq!(CubicRoots::One(2.0).to_vec(), [2.0]);
+    assert_eq!(CubicRoots::TripleRepeated(2.0).to_vec(), [2.0, 2.0, 2.0]);
+}
+
+#[test]
+fn test_quadratic_arr() {
+    let two: Polynomial<f64> = Polynomial::build(&[-20.0, 0.0, 5.0]).unwrap();
+    let double: Polynomial<f64> = Polynomial::build(&[25.0, -20.0, 4.0]).unwrap();
+    let none: Polynomial<f64> = Polynomial::build(&[26.0, -20.0, 4.0]).unwrap();
+    let linear = Polynomial::build(&[-4.0, 2.0, 0.0]).unwrap();
+
+    assert_eq!(two.solve_real_quadratic_arr().unwrap(), ([-2.0, 2.0], 2));
+
+    let (roots, n) = double.solve_real_quadratic_arr().unwrap();
+    assert_eq!((roots[0], n), (2.5, 1));
+    assert!(roots[1].is_nan());
+
+    let (roots, n) = none.solve_real_quadratic_arr().unwrap();
+    assert_eq!(n, 0);
+    assert!(roots.iter().all(|x| x.is_nan()));
+
+    assert_eq!(linear.solve_real_quadratic_arr().unwrap().0[0], 2.0);
+
+    for poly in [two, double, none, linear] {
+        let (roots, n) = poly.solve_real_quadratic_arr().unwrap();
+        assert_eq!(
+            roots[..n].to_vec(),
+            poly.solve_real_quadratic().unwrap_or_default()
+        );
+    }
+}
+
+#[test]
+fn test_cubic_arr() {
+    let three: Polynomial<f64> = Polynomial::build(&[-6.0, 11.0, -6.0, 1.0]).unwrap();
+    let one: Polynomial<f64> = Polynomial::build(&[-2.0, 0.0, 0.0, 2.0]).unwrap();
+    let triple = Polynomial::build(&[-1.0, 3.0, -3.0, 1.0]).unwrap();
+
+    assert_eq!(three.solve_real_cubic_arr().unwrap(), ([1.0, 2.0, 3.0], 3));
+    assert_eq!(triple.solve_real_cubic_arr().unwrap(), ([1.0; 3], 3));
+
+    let (roots, n) = one.solve_real_cubic_arr().unwrap();
+    assert_eq!((roots[0], n), (1.0, 1));
+    assert!(roots[1..].iter().all(|x| x.is_nan()));
+
+    assert!(matches!(
+        Polynomial::build(&[1.0, 2.0, 3.0, 0.0])
+            .unwrap()
+            .solve_real_cubic_arr()
+            .unwrap_err(),
+        PolyError::IncorrectOrder { expected: 3, .. }
+    ));
+}
+
+#[test]
+fn test_free_function_solvers() {
+    use crate::solve;
+
+    assert_eq!(
+        solve::quadratic(4.0, -20.0, 26.0).unwrap(),
+        QuadraticRoots::None
+    );
+    assert_eq!(
+        solve::quadratic(0.0, 2.0, -4.0).unwrap(),
+        QuadraticRoots::Linear(2.0)
+    );
+    assert!(matches!(
+        solve::quadratic(0.0, 0.0, 1.0).unwrap_err(),
+        PolyError::ConstantPoly
+    ));
+
+    // Leading coefficient is divided out
+    assert_eq!(
+        solve::cubic(-3.0, 9.0, -9.0, 3.0).unwrap(),
+        CubicRoots::TripleRepeated(1.0)
+    );
+    assert!(matches!(
+        solve::cubic(0.0, 1.0, 2.0, 3.0).unwrap_err(),
+        PolyError::IncorrectOrder { expected: 3, .. }
+    ));
+
+    let poly = Polynomial::build(&[-6.0, 11.0, -6.0, 1.0]).unwrap();
+    assert_eq!(
+        solve::cubic(1.0, -6.0, 11.0, -6.0).unwrap(),
+        poly.solve_real_cubic_structured().unwrap()
+    );
+}
+
+#[test]
+fn test_free_function_solvers_non_finite() {
+    let nan = f64::NAN;
+    let inf = f64::INFINITY;
+
+    assert!(matches!(
+        solve::linear(nan, 1.0),
+        Err(PolyError::InvalidCoefficients { .. })
+    ));
+    assert!(matches!(
+        solve::quadratic(nan, 1.0, 1.0),
+        Err(PolyError::InvalidCoefficients { .. })
+    ));
+    assert!(matches!(
+        solve::quadratic(1.0, inf, 1.0),
+        Err(PolyError::InvalidCoefficients { .. })
+    ));
+    assert!(matches!(
+        solve::cubic(1.0, 2.0, 3.0, nan),
+        Err(PolyError::InvalidCoefficients { .. })
+    ));
+
+    // Coefficients that bypass validation result in an error instead of a panic
+    let poly = Polynomial {
+        coef: vec![nan, 1.0, 1.0],
+    };
+    assert!(matches!(
+        poly.solve_real_quadratic(),
+        Err(PolyError::NanDiscriminant)
+    ));
+}
+
+/// Checks that two f32 values agree to a few ulps.
+fn close_f32(x: f32, expected: f32) -> bool {
+    (x - expected).abs() <= 8.0 * f32::EPSILON * expected.abs().max(1.0)
+}
+
+#[test]
+fn test_solve_f32() {
+    assert_eq!(solve::linear(4.0f32, 2.0).unwrap(), -0.5f32);
+    assert!(matches!(
+        solve::linear(0.0f32, 2.0),
+        Err(PolyError::ConstantPoly)
+    ));
+
+    let QuadraticRoots::Two(x1, x2) = solve::quadratic(1.0f32, -0.3, -0.1).unwrap() else {
+        panic!("Expected two real roots");
+    };
+    assert!(close_f32(x1, -0.2));
+    assert!(close_f32(x2, 0.5));
+    assert_eq!(
+        solve::quadratic(1.0f32, 0.0, 1.0).unwrap(),
+        QuadraticRoots::None
+    );
+
+    let CubicRoots::Three(roots) = solve::cubic(2.0f32, -2.0, -8.0, 8.0).unwrap() else {
+        panic!("Expected three real roots");
+    };
+    for (x, expected) in roots.iter().zip([-2.0f32, 1.0, 2.0]) {
+        assert!(close_f32(*x, expected));
+    }
+    assert_eq!(
+        solve::cubic(2.0f32, 0.0, 0.0, -2.0).unwrap(),
+        CubicRoots::One(1.0)
+    );
+}
+
+#[test]
+fn test_solve_f32_matches_f64() {
+    for (a, b, c, d) in [
+        (1.0, -6.0, 11.0, -6.0),
+        (3.0, 1.0, -4.0, 0.5),
+        (-0.5, 2.0, 0.0, 7.0),
+    ] {
+        let roots64: Vec<f64> = solve::cubic(a, b, c, d).unwrap().to_vec();
+        let roots32 = solve::cubic(a as f32, b as f32, c as f32, d as f32)
+            .unwrap()
+            .to_vec();
+
+        assert_eq!(roots64.len(), roots32.len());
+        for (x64, x32) in roots64.iter().zip(&roots32) {
+            assert!(is_close!(*x32 as f64, *x64, rel_tol = 1e-5, abs_tol = 1e-5));
+        }
+    }
+}
+
+#[test]
+fn test_solve_methods_native_precision() {
+    let quadratic = Polynomial::build(&[-0.1f32, -0.3, 1.0]).unwrap();
+    let roots: Vec<f32> = quadratic.solve_real_quadratic().unwrap();
+    assert!(close_f32(roots[0], -0.2));
+    assert!(close_f32(roots[1], 0.5));
+
+    let (roots, n) = quadratic.solve_real_quadratic_arr().unwrap();
+    assert_eq!(n, 2);
+    assert!(close_f32(roots[1], 0.5));
+
+    let cubic = Polynomial::build(&[8.0f32, -8.0, -2.0, 2.0]).unwrap();
+    let roots: Vec<f32> = cubic.solve_real_cubic().unwrap();
+    for (x, expected) in roots.iter().zip([-2.0f32, 1.0, 2.0]) {
+        assert!(close_f32(*x, expected));
+    }
+    assert!(matches!(
+        cubic.solve_real_cubic_structured().unwrap(),
+        CubicRoots::Three(_)
+    ));
+
+    // Complex32 coefficients are solved in f32 too
+    let complex = Polynomial::build(&[
+        Complex32::new(-4.0, 0.0),
+        Complex32::new(0.0, 0.0),
+        Complex32::new(1.0, 0.0),
+    ])
+    .unwrap();
+    assert_eq!(
+        complex.solve_real_quadratic_structured().unwrap(),
+        QuadraticRoots::Two(-2.0f32, 2.0)
+    );
+}
+
+#[test]
+/// x³+x+1 has a single real root, found with Cardano's formula.
+fn test_cubic_single_root_regression() {
+    let p = Polynomial::build(&[1.0, 1.0, 0.0, 1.0]).unwrap();
+    let y = p.solve_real_cubic().unwrap();
+
+    assert!(
+        y.iter()
+            .all(|x| is_close!(*x, -0.682_327_803_828_019_3, rel_tol = EPS))
+    );
+
+    let p: Polynomial<f64> = Polynomial::build(&[-5.0, 2.0, -3.0, 1.0]).unwrap();
+    let CubicRoots::One(x) = p.solve_real_cubic_structured().unwrap() else {
+        panic!("Expected one real root");
+    };
+    assert!(p.eval(x).abs() < 1e-13);
+}
+
+#[test]
+fn test_cubic_zero_leading_coefficient() {
+    let p = Polynomial::build(&[1.0, 2.0, 3.0, 0.0]).unwrap();
+
+    assert!(matches!(
+        p.solve_real_cubic(),
+        Err(PolyError::IncorrectOrder { expected: 3, .. })
+    ));
+    assert!(matches!(
+        p.solve_real_cubic_structured(),
+        Err(PolyError::IncorrectOrder { expected: 3, .. })
+    ));
+    assert!(matches!(
+        p.solve_real_cubic_arr(),
+        Err(PolyError::IncorrectOrder { expected: 3, .. })
+    ));
+}
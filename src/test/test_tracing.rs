@@ -0,0 +1,89 @@
+use std::sync::{Arc, Mutex};
+
+use num::complex::Complex64;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+use crate::{Polynomial, RootTracker};
+
+/// Records the fields of every event, formatted as `name=value`.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+struct Fields(Vec<String>);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.push(format!("{}={:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields(Vec::new());
+        event.record(&mut fields);
+        self.0.lock().unwrap().push(fields.0.join(" "));
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+/// Returns the events recorded while running `f`.
+fn record_events(f: impl FnOnce()) -> Vec<String> {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), f);
+    recorder.0.lock().unwrap().clone()
+}
+
+#[test]
+fn test_tracing_algorithm_and_residual() {
+    let events = record_events(|| {
+        Polynomial::build(&[-6.0, 11.0, -6.0, 1.0])
+            .unwrap()
+            .solve_complex()
+            .unwrap();
+    });
+    assert!(events.iter().any(|e| e.contains("algorithm=\"companion\"")));
+    assert!(events.iter().any(|e| e.contains("residual=")));
+
+    let events = record_events(|| {
+        let i = Complex64::i();
+        Polynomial::build(&[i, Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)])
+            .unwrap()
+            .solve_complex()
+            .unwrap();
+    });
+    assert!(events.iter().any(|e| e.contains("algorithm=\"aberth\"")));
+    assert!(
+        events
+            .iter()
+            .any(|e| e.contains("Aberth iterations converged"))
+    );
+}
+
+#[test]
+fn test_tracing_tracker_fallback() {
+    let mut tracker = RootTracker::new();
+    let events = record_events(|| {
+        tracker
+            .update(&Polynomial::build(&[2.0, -3.0, 1.0]).unwrap())
+            .unwrap();
+    });
+    assert!(events.iter().any(|e| e.contains("solving from scratch")));
+    assert_eq!(tracker.restarts(), 1);
+}
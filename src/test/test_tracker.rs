@@ -0,0 +1,82 @@
+use num::complex::Complex64;
+
+use crate::{PolyError, Polynomial, RootTracker};
+
+/// (x − r₀(t))(x − r₁(t))(x − r₂(t))(x − r₃(t)), with roots moving on circles.
+fn moving_roots(t: f64) -> [Complex64; 4] {
+    let z = Complex64::from_polar(1.0, t);
+    [
+        Complex64::new(-2.0, 0.0) + 0.3 * z,
+        Complex64::new(-2.0, 0.0) + 0.3 * z.conj(),
+        Complex64::new(0.5 + 0.2 * t.sin(), 0.0),
+        Complex64::new(3.0 + t, 0.0),
+    ]
+}
+
+#[test]
+fn test_tracker_follows_roots() {
+    let mut tracker = RootTracker::new();
+    assert!(tracker.roots().is_empty());
+
+    let mut previous: Vec<Complex64> = Vec::new();
+    for step in 0..200 {
+        let t = 0.3 + step as f64 * 0.01;
+        let expected = Polynomial::from_roots(&moving_roots(t));
+        let poly =
+            Polynomial::build(&expected.coef.iter().map(|c| c.re).collect::<Vec<f64>>()).unwrap();
+        let roots = tracker.update(&poly).unwrap().to_vec();
+
+        let complex = Polynomial {
+            coef: poly.coef.iter().map(|c| c.into()).collect(),
+        };
+        assert!(complex.verify_roots(&roots).passes(1e-10));
+        // Every root continues from the previous step
+        for (a, b) in previous.iter().zip(&roots) {
+            assert!((a - b).norm() < 0.05);
+        }
+        previous = roots;
+    }
+    assert_eq!(tracker.restarts(), 1);
+}
+
+#[test]
+fn test_tracker_restarts() {
+    let mut tracker = RootTracker::new();
+    tracker
+        .update(&Polynomial::build(&[2.0, -3.0, 1.0]).unwrap())
+        .unwrap();
+    // The number of roots changes
+    tracker
+        .update(&Polynomial::build(&[-6.0, 11.0, -6.0, 1.0]).unwrap())
+        .unwrap();
+    assert_eq!(tracker.restarts(), 2);
+
+    tracker.reset();
+    let roots = tracker
+        .update(&Polynomial::build(&[-6.0, 11.0, -6.0, 1.0]).unwrap())
+        .unwrap();
+    assert_eq!(roots.len(), 3);
+    assert_eq!(tracker.restarts(), 3);
+}
+
+#[test]
+fn test_tracker_errors_keep_roots() {
+    let mut tracker = RootTracker::new();
+    tracker
+        .update(&Polynomial::build(&[2.0, -3.0, 1.0]).unwrap())
+        .unwrap();
+    let roots = tracker.roots().to_vec();
+
+    let nan = Polynomial {
+        coef: vec![f64::NAN, 1.0],
+    };
+    assert!(matches!(
+        tracker.update(&nan),
+        Err(PolyError::InvalidCoefficients { .. })
+    ));
+    assert!(matches!(
+        tracker.update(&Polynomial::build(&[1.0, 0.0]).unwrap()),
+        Err(PolyError::ConstantPoly)
+    ));
+    assert_eq!(tracker.roots(), roots);
+}
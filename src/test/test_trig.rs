@@ -0,0 +1,114 @@
+use std::f64::consts::PI;
+
+use crate::{PolyError, Polynomial, TrigPoly};
+use is_close::is_close;
+use num::complex::Complex64;
+
+const TOL: f64 = 1e-12;
+
+#[test]
+fn test_solve_complex_complex_coefficients() {
+    // (x-i)(x+2) = x² + (2-i)x - 2i
+    let p = Polynomial::build(&[
+        Complex64::new(0.0, -2.0),
+        Complex64::new(2.0, -1.0),
+        Complex64::new(1.0, 0.0),
+    ])
+    .unwrap();
+    let mut z = p.solve_complex().unwrap();
+    z.sort_by(|a, b| a.re.total_cmp(&b.re));
+
+    assert!(is_close!(z[0].re, -2.0, rel_tol = TOL));
+    assert!(is_close!(z[0].im, 0.0, abs_tol = TOL));
+    assert!(is_close!(z[1].re, 0.0, abs_tol = TOL));
+    assert!(is_close!(z[1].im, 1.0, rel_tol = TOL));
+}
+
+#[test]
+fn test_solve_complex_constant() {
+    let p = Polynomial::build(&[1.0, 0.0]).unwrap();
+
+    assert!(matches!(
+        p.solve_complex().unwrap_err(),
+        PolyError::ConstantPoly
+    ));
+}
+
+#[test]
+fn test_trig_build_invalid() {
+    let zero = Complex64::new(0.0, 0.0);
+
+    assert!(matches!(
+        TrigPoly::build(&[zero, zero]).unwrap_err(),
+        PolyError::InvalidTrigOrder(2)
+    ));
+    assert!(matches!(
+        TrigPoly::from_cos_sin(&[0.0, 1.0], &[0.0]).unwrap_err(),
+        PolyError::LengthMismatch(2, 1)
+    ));
+}
+
+#[test]
+fn test_trig_eval_and_derivative() {
+    // T(θ) = 1 + 2cos(θ) − sin(2θ)
+    let trig = TrigPoly::from_cos_sin(&[1.0, 2.0, 0.0], &[0.0, 0.0, -1.0]).unwrap();
+    let deriv = trig.derivative();
+
+    for theta in [0.0, 0.3, 1.7, 4.0] {
+        let value = 1.0 + 2.0 * f64::cos(theta) - f64::sin(2.0 * theta);
+        let dvalue = -2.0 * f64::sin(theta) - 2.0 * f64::cos(2.0 * theta);
+
+        assert!(is_close!(
+            trig.eval(theta).re,
+            value,
+            rel_tol = TOL,
+            abs_tol = TOL
+        ));
+        assert!(is_close!(trig.eval(theta).im, 0.0, abs_tol = TOL));
+        assert!(is_close!(
+            deriv.eval(theta).re,
+            dvalue,
+            rel_tol = TOL,
+            abs_tol = TOL
+        ));
+    }
+}
+
+#[test]
+fn test_trig_product() {
+    let a = TrigPoly::from_cos_sin(&[1.0, 2.0], &[0.0, 1.0]).unwrap();
+    let b = TrigPoly::from_cos_sin(&[0.5, 0.0, 3.0], &[0.0, -1.0, 0.0]).unwrap();
+    let ab = a.product(&b);
+
+    assert_eq!(ab.order(), 3);
+    for theta in [0.0, 0.3, 1.7, 4.0] {
+        let expected = a.eval(theta) * b.eval(theta);
+        assert!(is_close!(
+            ab.eval(theta).re,
+            expected.re,
+            rel_tol = TOL,
+            abs_tol = TOL
+        ));
+    }
+}
+
+#[test]
+fn test_trig_roots() {
+    // cos(2θ) − 1/2 vanishes at θ = ±π/6 + kπ
+    let trig = TrigPoly::from_cos_sin(&[-0.5, 0.0, 1.0], &[0.0, 0.0, 0.0]).unwrap();
+    let roots = trig.roots().unwrap();
+    let expected = [PI / 6.0, 5.0 * PI / 6.0, 7.0 * PI / 6.0, 11.0 * PI / 6.0];
+
+    assert_eq!(roots.len(), 4);
+    for (r, e) in roots.iter().zip(expected) {
+        assert!(is_close!(*r, e, rel_tol = TOL));
+    }
+}
+
+#[test]
+fn test_trig_no_real_roots() {
+    // 2 + cos(θ) > 0
+    let trig = TrigPoly::from_cos_sin(&[2.0, 1.0], &[0.0, 0.0]).unwrap();
+
+    assert!(trig.roots().unwrap().is_empty());
+}
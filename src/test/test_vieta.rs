@@ -0,0 +1,134 @@
+use is_close::is_close;
+use num::complex::Complex64;
+
+use crate::{PolyError, Polynomial};
+
+#[test]
+fn test_from_roots() {
+    assert_eq!(Polynomial::<f64>::from_roots(&[]).coef, [1.0]);
+    assert_eq!(Polynomial::from_roots(&[3.0]).coef, [-3.0, 1.0]);
+    assert_eq!(Polynomial::from_roots(&[2.0, 2.0]).coef, [4.0, -4.0, 1.0]);
+    assert_eq!(
+        Polynomial::from_roots(&[4.0, -1.0, 3.0, 2.0]).coef,
+        [-24.0, 2.0, 17.0, -8.0, 1.0]
+    );
+}
+
+#[test]
+fn test_from_roots_with_leading() {
+    let poly = Polynomial::from_roots_with_leading(-3.0, &[1.0, 2.0]);
+    assert_eq!(poly.coef, [-6.0, 9.0, -3.0]);
+
+    let constant = Polynomial::from_roots_with_leading(5.0, &[]);
+    assert_eq!(constant.coef, [5.0]);
+}
+
+#[test]
+fn test_from_roots_complex() {
+    // (x - i)(x + i) = x² + 1
+    let i = Complex64::new(0.0, 1.0);
+    let poly = Polynomial::from_roots(&[i, -i]);
+
+    assert_eq!(poly.coef.len(), 3);
+    assert!(is_close!(poly.coef[0].re, 1.0) && poly.coef[0].im.abs() < 1e-15);
+    assert!(poly.coef[1].norm() < 1e-15);
+    assert!(is_close!(poly.coef[2].re, 1.0) && poly.coef[2].im.abs() < 1e-15);
+}
+
+#[test]
+fn test_from_roots_round_trip() {
+    let roots = [-0.9, -0.5, -0.1, 0.2, 0.3, 0.7, 1.5, 4.0];
+    let poly = Polynomial::from_roots(&roots);
+
+    let mut solved: Vec<f64> = poly.solve_complex().unwrap().iter().map(|z| z.re).collect();
+    solved.sort_by(f64::total_cmp);
+    for (r, s) in roots.iter().zip(&solved) {
+        assert!(is_close!(*r, *s, abs_tol = 1e-10));
+    }
+}
+
+#[test]
+fn test_verify_roots() {
+    let roots = [-2.0, 0.5, 1.0, 3.0];
+    let poly = Polynomial::from_roots_with_leading(2.0, &roots);
+
+    let report = poly.verify_roots(&roots);
+    assert_eq!(report.residuals.len(), 4);
+    assert!(report.residuals.iter().all(|r| *r < 1e-13));
+    assert!(report.count_matches);
+    assert!(report.passes(1e-15));
+
+    // A perturbed root
+    let report = poly.verify_roots(&[-2.0, 0.5, 1.0, 3.001]);
+    assert!(report.residuals[3] > 1e-3);
+    assert!(report.coefficient_error > 1e-5);
+    assert!(!report.passes(1e-6));
+    assert!(report.passes(1e-2));
+
+    // A missing root
+    let report = poly.verify_roots(&roots[..3]);
+    assert!(!report.count_matches);
+    assert!(!report.passes(1.0));
+}
+
+#[test]
+fn test_verify_roots_trailing_zeros() {
+    let poly = Polynomial::build(&[-1.0, 0.0, 1.0, 0.0]).unwrap();
+    assert!(poly.verify_roots(&[1.0, -1.0]).passes(1e-15));
+
+    let zero = Polynomial::<f64>::build(&[0.0, 0.0]).unwrap();
+    assert!(zero.verify_roots(&[]).passes(0.0));
+    assert!(!zero.verify_roots(&[1.0]).passes(1.0));
+}
+
+#[test]
+fn test_verify_roots_complex_solver() {
+    let one = Complex64::new(1.0, 0.0);
+    let zero = Complex64::new(0.0, 0.0);
+    let poly = Polynomial::build(&[one, zero, zero, zero, one]).unwrap(); // x⁴+1
+    let roots = poly.solve_complex().unwrap();
+
+    assert!(poly.verify_roots(&roots).passes(1e-14));
+}
+
+#[test]
+fn test_factor_real() {
+    // 3(x + 2)(x − 0.5)(x² − 2x + 5)(x² + 1)
+    let roots = [
+        Complex64::new(-2.0, 0.0),
+        Complex64::new(0.5, 0.0),
+        Complex64::new(1.0, 2.0),
+        Complex64::new(1.0, -2.0),
+        Complex64::new(0.0, 1.0),
+        Complex64::new(0.0, -1.0),
+    ];
+    let complex = Polynomial::from_roots_with_leading(Complex64::new(3.0, 0.0), &roots);
+    let poly = Polynomial::build(&complex.coef.iter().map(|z| z.re).collect::<Vec<f64>>()).unwrap();
+
+    let (factors, report) = poly.factor_real().unwrap();
+    let expected = [
+        vec![3.0],
+        vec![2.0, 1.0],
+        vec![-0.5, 1.0],
+        vec![1.0, 0.0, 1.0],
+        vec![5.0, -2.0, 1.0],
+    ];
+    assert_eq!(factors.len(), expected.len());
+    for (f, e) in factors.iter().zip(expected) {
+        assert!(
+            f.approx_eq(&Polynomial::build(&e).unwrap(), 1e-13, 1e-13),
+            "{f:?}"
+        );
+    }
+    assert!(report.passes(1e-13));
+
+    let product = factors
+        .iter()
+        .fold(Polynomial::build(&[1.0]).unwrap(), |acc, f| &acc * f);
+    assert!(product.approx_eq(&poly, 1e-13, 1e-13));
+
+    assert!(matches!(
+        Polynomial::build(&[2.0, 0.0]).unwrap().factor_real(),
+        Err(PolyError::ConstantPoly)
+    ));
+}
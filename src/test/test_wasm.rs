@@ -0,0 +1,25 @@
+use crate::wasm::*;
+
+#[test]
+fn test_wasm_eval() {
+    let coef = [1.0, 2.0, 3.0];
+    assert_eq!(eval_polynomial(&coef, 1.0), 6.0);
+    assert_eq!(eval_polynomial(&[], 1.0), 0.0);
+    assert_eq!(
+        eval_polynomial_many(&coef, &[0.0, 1.0, 2.0]),
+        [1.0, 6.0, 17.0]
+    );
+    assert_eq!(derivative(&coef), [2.0, 6.0]);
+}
+
+#[test]
+fn test_wasm_solve() {
+    assert_eq!(solve_quadratic(4.0, -20.0, 25.0).unwrap(), [2.5, 2.5]);
+    assert_eq!(solve_cubic(1.0, -6.0, 11.0, -6.0).unwrap(), [1.0, 2.0, 3.0]);
+
+    // x²+1
+    let roots = solve_complex(&[1.0, 0.0, 1.0]).unwrap();
+    assert_eq!(roots.len(), 4);
+    assert!(roots[0].abs() < 1e-15 && roots[2].abs() < 1e-15);
+    assert!((roots[1] * roots[3] + 1.0).abs() < 1e-15);
+}
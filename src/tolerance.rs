@@ -0,0 +1,53 @@
+//! Tolerances for the detection of negligible coefficients.
+
+use num::Float;
+
+use crate::utils::cast;
+
+/// The threshold under which a leading coefficient is considered to be zero, relative to the
+/// largest coefficient of the polynomial, so that a nearly degenerate equation is solved as one of
+/// lower order.
+///
+/// The default is [`Tolerance::EXACT`], where only exact zeros are negligible.
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{QuadraticRoots, Result, Tolerance, solve};
+/// # fn main() -> Result<()> {
+/// // 1e-300x²+x-1, with a leading coefficient left over from cancellation upstream
+/// let exact = solve::quadratic(1e-300, 1.0, -1.0)?;
+/// let relative = solve::quadratic_with_tol(1e-300, 1.0, -1.0, Tolerance::new(1e-12))?;
+///
+/// assert!(matches!(exact, QuadraticRoots::Two(_, _)));
+/// assert_eq!(relative, QuadraticRoots::Linear(1.0));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tolerance {
+    /// The relative threshold: |a| ≤ zero·max|cᵢ| is treated as 0.
+    pub zero: f64,
+}
+
+impl Tolerance {
+    /// Only exact zeros are negligible.
+    pub const EXACT: Tolerance = Tolerance { zero: 0.0 };
+
+    /// Creates a tolerance with the relative threshold `zero`.
+    pub fn new(zero: f64) -> Self {
+        Tolerance { zero }
+    }
+
+    /// Checks whether `x` is negligible compared to `scale`, the magnitude of the largest
+    /// coefficient.
+    pub(crate) fn is_negligible<F: Float>(&self, x: F, scale: F) -> bool {
+        x.is_zero() || x.abs() <= cast::<F>(self.zero) * scale
+    }
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance::EXACT
+    }
+}
@@ -0,0 +1,97 @@
+//! Tracking the roots of a sequence of slowly varying polynomials.
+
+use num::complex::Complex64;
+
+use crate::utils::{check_finite_coef, convert_to_complex64};
+use crate::{PolyError, Polynomial, Result, solve};
+
+/// Stateful solver for a sequence of polynomials with slowly changing coefficients, e.g. the
+/// characteristic polynomials of the steps of a simulation.
+///
+/// Every call to [`update`](RootTracker::update) refines the roots of the previous polynomial
+/// with the Aberth-Ehrlich method, which converges in a few iterations when the roots have not
+/// moved much, instead of solving the polynomial from scratch. The i-th root therefore follows
+/// the same root from step to step. If there are no previous roots, their number has changed, or
+/// the refinement fails to converge, the tracker falls back to
+/// [`solve_complex`](Polynomial::solve_complex).
+///
+/// ## Example
+///
+/// ```
+/// # use rsl_polynomials::{Polynomial, Result, RootTracker};
+/// # fn main() -> Result<()> {
+/// let mut tracker = RootTracker::new();
+///
+/// for step in 0..100 {
+///     let t = step as f64 * 0.01;
+///     let poly = Polynomial::build(&[2.0, -3.0 - t, 1.0])?; // x²−(3+t)x+2
+///     let roots = tracker.update(&poly)?;
+///     let product = roots[0] * roots[1];
+///     assert!((product.re - 2.0).abs() < 1e-12 && product.im.abs() < 1e-12);
+/// }
+/// assert_eq!(tracker.restarts(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RootTracker {
+    roots: Vec<Complex64>,
+    restarts: usize,
+}
+
+impl RootTracker {
+    /// Creates a new tracker with no previous roots.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Finds the roots of `poly`, starting from the roots of the previous polynomial.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if any of the coefficients is NaN or Infinity, if the (trimmed)
+    /// Polynomial is constant, or if the roots could not be found. The previous roots are kept
+    /// in that case.
+    pub fn update<T>(&mut self, poly: &Polynomial<T>) -> Result<&[Complex64]>
+    where
+        T: num::complex::ComplexFloat + std::fmt::Debug,
+    {
+        check_finite_coef(poly.coef.iter().copied())?;
+        let poly = poly.to_trimmed();
+        if poly.coef.len() < 2 {
+            return Err(PolyError::ConstantPoly);
+        }
+
+        let coef: Vec<Complex64> = poly.coef.iter().map(|c| convert_to_complex64(*c)).collect();
+        self.roots = match solve::refine_aberth(&coef, &self.roots) {
+            Ok(roots) => roots,
+            Err(_) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    restarts = self.restarts + 1,
+                    "the previous roots could not be refined, solving from scratch"
+                );
+                let roots = poly.solve_complex()?;
+                self.restarts += 1;
+                roots
+            }
+        };
+        Ok(&self.roots)
+    }
+
+    /// Returns the roots of the last polynomial, or no roots before the first update.
+    pub fn roots(&self) -> &[Complex64] {
+        &self.roots
+    }
+
+    /// Returns the number of updates in which the roots were found from scratch, instead of
+    /// being refined.
+    pub fn restarts(&self) -> usize {
+        self.restarts
+    }
+
+    /// Forgets the previous roots, so that the next update solves its polynomial from scratch.
+    pub fn reset(&mut self) {
+        self.roots.clear();
+    }
+}
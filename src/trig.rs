@@ -0,0 +1,206 @@
+//! Trigonometric polynomials (truncated Fourier series).
+
+use std::f64::consts::PI;
+
+use num::Zero;
+use num::complex::Complex64;
+
+use crate::utils::check_finite_coef;
+use crate::{PolyError, Polynomial, Result};
+
+/// Maximum distance from the unit circle for a root z of the associated algebraic polynomial to be
+/// considered a real root θ = arg(z).
+const UNIT_CIRCLE_TOL: f64 = 1e-6;
+
+/// Representation of a trigonometric polynomial of order `n`, represented with a [`Vec`] of
+/// length `2n+1` containing its spectrum `c[k]`:
+///
+/// T(θ) = c[0]e⁻ⁱⁿᶿ + ... + c[n−1]e⁻ⁱᶿ + c[n] + c[n+1]eⁱᶿ + ... + c[2n]eⁱⁿᶿ
+///
+/// [`Vec`]: std::vec::Vec
+#[derive(Clone, Debug)]
+pub struct TrigPoly {
+    /// The polynomial's spectrum, from the e⁻ⁱⁿᶿ to the eⁱⁿᶿ term.
+    pub coef: Vec<Complex64>,
+}
+
+impl TrigPoly {
+    /// Creates a new trigonometric polynomial from its spectrum.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the number of coefficients is even, or if any of them is NaN or
+    /// Infinity.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{TrigPoly, Result};
+    /// # use num::complex::Complex64;
+    /// # fn main() -> Result<()> {
+    /// // cos(θ) = (e⁻ⁱᶿ + eⁱᶿ)/2
+    /// let half = Complex64::new(0.5, 0.0);
+    /// let trig = TrigPoly::build(&[half, Complex64::new(0.0, 0.0), half])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build(coef: &[Complex64]) -> Result<Self> {
+        if coef.len().is_multiple_of(2) {
+            return Err(PolyError::InvalidTrigOrder(coef.len()));
+        }
+        check_finite_coef(coef.iter().copied())?;
+        Ok(TrigPoly {
+            coef: coef.to_vec(),
+        })
+    }
+
+    /// Creates a new real trigonometric polynomial
+    ///
+    /// T(θ) = a[0] + a[1]cos(θ) + b[1]sin(θ) + ... + a[n]cos(nθ) + b[n]sin(nθ)
+    ///
+    /// from its cosine and sine coefficients. `b[0]` is ignored.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the slices have different lengths or contain NaN or Infinity.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{TrigPoly, Result};
+    /// # fn main() -> Result<()> {
+    /// let trig = TrigPoly::from_cos_sin(&[1.0, 0.0, 2.0], &[0.0, 3.0, 0.0])?; // 1+3sin(θ)+2cos(2θ)
+    ///
+    /// assert!((trig.eval(0.0).re - 3.0).abs() < 1e-15);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_cos_sin(a: &[f64], b: &[f64]) -> Result<Self> {
+        if a.len() != b.len() {
+            return Err(PolyError::LengthMismatch(a.len(), b.len()));
+        }
+        if a.is_empty() {
+            return TrigPoly::build(&[Complex64::zero()]);
+        }
+
+        let n = a.len() - 1;
+        let mut coef = vec![Complex64::zero(); 2 * n + 1];
+        coef[n] = Complex64::new(a[0], 0.0);
+        for k in 1..=n {
+            coef[n + k] = Complex64::new(a[k], -b[k]) / 2.0;
+            coef[n - k] = Complex64::new(a[k], b[k]) / 2.0;
+        }
+        TrigPoly::build(&coef)
+    }
+
+    /// Returns the order `n` of the trigonometric polynomial.
+    pub fn order(&self) -> usize {
+        self.coef.len() / 2
+    }
+
+    /// Evaluates the trigonometric polynomial at `theta`.
+    ///
+    /// The result is real (up to rounding) if the polynomial was created with
+    /// [`from_cos_sin`](TrigPoly::from_cos_sin).
+    pub fn eval(&self, theta: f64) -> Complex64 {
+        let n = self.order();
+        let z = Complex64::from_polar(1.0, theta);
+        // T(θ) = e⁻ⁱⁿᶿ·P(eⁱᶿ), where P is the algebraic polynomial with the same coefficients
+        self.coef
+            .iter()
+            .rev()
+            .copied()
+            .reduce(|res, coef| coef + z * res)
+            .unwrap_or(Complex64::zero())
+            * Complex64::from_polar(1.0, -(n as f64) * theta)
+    }
+
+    /// Returns the derivative dT/dθ.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{TrigPoly, Result};
+    /// # fn main() -> Result<()> {
+    /// let sin = TrigPoly::from_cos_sin(&[0.0, 0.0], &[0.0, 1.0])?;
+    /// let cos = sin.derivative();
+    ///
+    /// assert!((cos.eval(0.0).re - 1.0).abs() < 1e-15);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn derivative(&self) -> Self {
+        let n = self.order() as f64;
+        let coef = self
+            .coef
+            .iter()
+            .enumerate()
+            .map(|(j, c)| c * Complex64::new(0.0, j as f64 - n))
+            .collect();
+        TrigPoly { coef }
+    }
+
+    /// Returns the product of two trigonometric polynomials, calculated as the convolution of
+    /// their spectra.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{TrigPoly, Result};
+    /// # fn main() -> Result<()> {
+    /// let sin = TrigPoly::from_cos_sin(&[0.0, 0.0], &[0.0, 1.0])?;
+    /// let cos = TrigPoly::from_cos_sin(&[0.0, 1.0], &[0.0, 0.0])?;
+    /// let sin2 = sin.product(&cos); // sin(2θ)/2
+    ///
+    /// assert!((sin2.eval(0.3).re - (0.6f64).sin() / 2.0).abs() < 1e-15);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn product(&self, other: &TrigPoly) -> Self {
+        let mut coef = vec![Complex64::zero(); self.coef.len() + other.coef.len() - 1];
+        for (i, a) in self.coef.iter().enumerate() {
+            for (j, b) in other.coef.iter().enumerate() {
+                coef[i + j] += a * b;
+            }
+        }
+        TrigPoly { coef }
+    }
+
+    /// Calculates the real roots of the trigonometric polynomial in [0, 2π).
+    ///
+    /// The roots are found by substituting z = eⁱᶿ, which turns the trigonometric polynomial into
+    /// an algebraic one of order 2n, and keeping the roots that lie on the unit circle. They are
+    /// returned in increasing order.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the trigonometric polynomial is constant, or if the complex solver
+    /// fails to converge.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{TrigPoly, Result};
+    /// # fn main() -> Result<()> {
+    /// let sin = TrigPoly::from_cos_sin(&[0.0, 0.0], &[0.0, 1.0])?;
+    /// let roots = sin.roots()?;
+    ///
+    /// assert_eq!(roots.len(), 2);
+    /// assert!(roots[0].abs() < 1e-14);
+    /// assert!((roots[1] - std::f64::consts::PI).abs() < 1e-14);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn roots(&self) -> Result<Vec<f64>> {
+        let zroots = Polynomial::build(&self.coef)?.solve_complex()?;
+
+        let mut roots: Vec<f64> = zroots
+            .iter()
+            .filter(|z| (z.norm() - 1.0).abs() <= UNIT_CIRCLE_TOL)
+            .map(|z| z.arg().rem_euclid(2.0 * PI))
+            .map(|theta| if theta >= 2.0 * PI { 0.0 } else { theta })
+            .collect();
+        roots.sort_by(f64::total_cmp);
+        Ok(roots)
+    }
+}
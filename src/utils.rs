@@ -1,14 +1,74 @@
-use crate::{PolyError, Result};
+use crate::{PolyError, Polynomial, Result};
 use num::{ToPrimitive, Zero};
 
+/// Converts an [`f64`] constant to the float type `F`.
+pub(crate) fn cast<F: num::Float>(x: f64) -> F {
+    match F::from(x) {
+        Some(x) => x,
+        None => unreachable!("Could not convert constant {x} to float"),
+    }
+}
+
+/// Writes the value and the first `out.len() − 1` derivatives of the polynomial with the given
+/// coefficients at `x` into `out`. Derivatives of order higher than the degree are set to 0.
+pub(crate) fn eval_derivs_into<T: num::complex::ComplexFloat>(coef: &[T], x: T, out: &mut [T]) {
+    out.iter_mut().for_each(|e| *e = T::zero());
+    let (Some(&lead), Some(last)) = (coef.last(), out.len().checked_sub(1)) else {
+        return;
+    };
+
+    let last_idx = coef.len() - 1;
+    let nmax = last_idx.min(last);
+
+    // Partially fill out with the dominant term's coefficient
+    out.iter_mut().take(nmax + 1).for_each(|e| *e = lead);
+
+    for k in (1..=last_idx).rev() {
+        out[0] = x * out[0] + coef[k - 1];
+        for j in 1..=nmax.min(k - 1) {
+            out[j] = x * out[j] + out[j - 1];
+        }
+    }
+
+    // Multiply each term by the corresponding exponents
+    let mut f = T::one();
+    for (i, d) in out.iter_mut().enumerate().take(nmax + 1).skip(2) {
+        f = f * T::from(i).unwrap();
+        *d = *d * f;
+    }
+}
+
 /// Checks if a polynomial is of the expected order.
 pub(crate) fn check_if_correct_order<T>(coef: &[T], expected_order: usize) -> Result<()> {
     if coef.len() != expected_order + 1 {
-        return Err(PolyError::IncorrectOrder(expected_order));
+        return Err(PolyError::IncorrectOrder {
+            expected: expected_order,
+            got: coef.len().saturating_sub(1),
+        });
     }
     Ok(())
 }
 
+/// Returns the order of the polynomial with the given coefficients, from constant to leading
+/// term, ignoring higher order terms with 0 coefficient.
+pub(crate) fn order<T: Zero>(coef: &[T]) -> usize {
+    coef.iter().rposition(|c| !c.is_zero()).unwrap_or(0)
+}
+
+/// Checks that all the coefficients are finite, reporting the first one that is not.
+pub(crate) fn check_finite_coef<C>(coef: impl IntoIterator<Item = C>) -> Result<()>
+where
+    C: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    match coef.into_iter().enumerate().find(|(_, c)| !c.is_finite()) {
+        Some((index, value)) => Err(PolyError::InvalidCoefficients {
+            index,
+            value: format!("{value:?}").into(),
+        }),
+        None => Ok(()),
+    }
+}
+
 /// Checks if all the coefficients of a poly are real, i.e. their imaginary part is 0.
 pub(crate) fn check_if_real_coefficients<C: num::complex::ComplexFloat>(coef: &[C]) -> Result<()> {
     for c in coef.iter() {
@@ -29,14 +89,73 @@ pub(crate) fn convert_complex_to_real<C>(number: C) -> Result<f64>
 where
     C: num::complex::ComplexFloat + std::fmt::Debug,
 {
-    let err = PolyError::ComplexTof64Conversion(format!("{number:?}").into());
+    // Built lazily, so that the (hot) successful path does not allocate.
+    let err = || PolyError::ComplexTof64Conversion(format!("{number:?}").into());
 
     // Complex64.to_f64() returns the real part, even if the imaginary part is not 0.
     if !number.is_finite() | !number.im().is_zero() {
-        return Err(err);
+        return Err(err());
+    }
+
+    number.re().to_f64().ok_or_else(err)
+}
+
+/// Converts the coefficients of a polynomial of order `N-1` to an array of their real type (e.g.
+/// f32 for f32 and Complex32 coefficients), without allocating.
+pub(crate) fn convert_to_real_array<C, const N: usize>(coef: &[C]) -> Result<[C::Real; N]>
+where
+    C: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    check_if_correct_order(coef, N - 1)?;
+    check_if_real_coefficients(coef)?;
+
+    let mut reals = [C::Real::zero(); N];
+    for (r, c) in reals.iter_mut().zip(coef) {
+        *r = c.re();
     }
+    Ok(reals)
+}
 
-    number.re().to_f64().ok_or(err)
+/// Converts a real number to f64, or NaN if it is not representable.
+pub(crate) fn to_f64<R: ToPrimitive>(x: R) -> f64 {
+    x.to_f64().unwrap_or(f64::NAN)
+}
+
+/// Converts a ComplexFloat number to Complex64.
+pub(crate) fn convert_to_complex64<C>(number: C) -> num::complex::Complex64
+where
+    C: num::complex::ComplexFloat,
+{
+    num::complex::Complex64::new(
+        number.re().to_f64().unwrap_or(f64::NAN),
+        number.im().to_f64().unwrap_or(f64::NAN),
+    )
+}
+
+/// Returns the coefficients of P(αx + β).
+pub(crate) fn compose_linear(poly: &Polynomial<f64>, alpha: f64, beta: f64) -> Polynomial<f64> {
+    let linear = Polynomial {
+        coef: vec![beta, alpha],
+    };
+    let mut res = poly
+        .coef
+        .iter()
+        .rev()
+        .fold(Polynomial { coef: vec![0.0] }, |acc, c| {
+            &(&acc * &linear) + &Polynomial { coef: vec![*c] }
+        });
+    // The starting zero polynomial adds a (zero) leading term
+    res.coef.truncate(poly.coef.len());
+    res
+}
+
+/// Evaluates the closure `f` at `x`, checking that the result is finite.
+pub(crate) fn eval_checked(f: &impl Fn(f64) -> f64, x: f64) -> Result<f64> {
+    let y = f(x);
+    match y.is_finite() {
+        true => Ok(y),
+        false => Err(PolyError::InvalidData),
+    }
 }
 
 #[cfg(test)]
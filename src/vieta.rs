@@ -0,0 +1,241 @@
+//! Conversion between the roots and the coefficients of a polynomial.
+
+use num::complex::Complex64;
+
+use crate::utils::to_f64;
+use crate::{Polynomial, Result};
+
+/// Relative imaginary part under which a computed root of a real polynomial is considered real.
+const REAL_ROOT_TOL: f64 = 1e3 * f64::EPSILON;
+
+/// Diagnostics of a set of computed roots of a polynomial, created with
+/// [`Polynomial::verify_roots`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RootReport {
+    /// The residuals |P(rᵢ)| of every root, in the order they were given.
+    pub residuals: Vec<f64>,
+    /// The largest residual, relative to Σ|aⱼ||rᵢ|ʲ, i.e. the largest backward error of a single
+    /// root.
+    pub max_relative_residual: f64,
+    /// The largest difference between the polynomial's coefficients and the ones reconstructed
+    /// from the roots with Vieta's formulas, relative to the largest coefficient.
+    pub coefficient_error: f64,
+    /// Whether the number of roots is equal to the polynomial's degree.
+    pub count_matches: bool,
+}
+
+impl RootReport {
+    /// Returns `true` if the number of roots is correct, and both the relative residuals and the
+    /// coefficient error are at most `tol`.
+    pub fn passes(&self, tol: f64) -> bool {
+        self.count_matches && self.max_relative_residual <= tol && self.coefficient_error <= tol
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    /// Returns the monic polynomial ∏(x − rᵢ) with the given roots, repeated roots included as
+    /// many times as their multiplicity.
+    ///
+    /// The linear factors are sorted by magnitude and multiplied in a balanced product tree, so
+    /// that every product combines factors of similar size and degree, which keeps the rounding
+    /// errors of the expansion small. The polynomial with no roots is the constant 1. The roots
+    /// are not checked for NaN or Infinity.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::Polynomial;
+    /// let poly = Polynomial::from_roots(&[1.0, 2.0, 3.0]);
+    ///
+    /// assert_eq!(poly.coef, [-6.0, 11.0, -6.0, 1.0]);
+    /// ```
+    pub fn from_roots(roots: &[T]) -> Self {
+        let mut sorted = roots.to_vec();
+        sorted.sort_by(|a, b| {
+            a.abs()
+                .partial_cmp(&b.abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        product_tree(&sorted)
+    }
+
+    /// Returns the polynomial k∏(x − rᵢ) with leading coefficient `k` and the given roots.
+    ///
+    /// See [`from_roots`](Polynomial::from_roots).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::Polynomial;
+    /// let poly = Polynomial::from_roots_with_leading(2.0, &[-1.0, 1.0]);
+    ///
+    /// assert_eq!(poly.coef, [-2.0, 0.0, 2.0]);
+    /// ```
+    pub fn from_roots_with_leading(k: T, roots: &[T]) -> Self {
+        Polynomial::from_roots(roots) * k
+    }
+
+    /// Checks how well `roots` solve the polynomial, by computing the residual of every root and
+    /// comparing the polynomial with the one reconstructed from the roots and its leading
+    /// coefficient. Repeated roots must be included as many times as their multiplicity.
+    ///
+    /// The zero polynomial only passes with no roots.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[2.0, -3.0, 1.0])?; // (x-1)(x-2)
+    ///
+    /// assert!(poly.verify_roots(&[1.0, 2.0]).passes(1e-15));
+    /// assert!(!poly.verify_roots(&[1.0, 2.001]).passes(1e-6));
+    /// assert!(!poly.verify_roots(&[1.0]).passes(1e-6));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verify_roots(&self, roots: &[T]) -> RootReport {
+        let len = self
+            .coef
+            .iter()
+            .rposition(|c| !c.is_zero())
+            .map_or(0, |i| i + 1);
+        let coef = &self.coef[..len];
+        let scale = coef.iter().map(|c| to_f64(c.abs())).fold(0.0, f64::max);
+
+        let residuals: Vec<f64> = roots.iter().map(|r| to_f64(self.eval(*r).abs())).collect();
+        let max_relative_residual = roots
+            .iter()
+            .zip(&residuals)
+            .map(|(r, res)| {
+                let abs_eval = coef
+                    .iter()
+                    .rev()
+                    .fold(0.0, |acc, c| to_f64(c.abs()) + to_f64(r.abs()) * acc);
+                match abs_eval {
+                    0.0 => 0.0,
+                    _ => res / abs_eval,
+                }
+            })
+            .fold(0.0, f64::max);
+
+        let coefficient_error = match coef.last() {
+            None if roots.is_empty() => 0.0,
+            None => f64::INFINITY,
+            Some(leading) => {
+                let rebuilt = Polynomial::from_roots_with_leading(*leading, roots);
+                let n = coef.len().max(rebuilt.coef.len());
+                (0..n)
+                    .map(|i| {
+                        let a = coef.get(i).copied().unwrap_or(T::zero());
+                        let b = rebuilt.coef.get(i).copied().unwrap_or(T::zero());
+                        to_f64((a - b).abs()) / scale
+                    })
+                    .fold(0.0, f64::max)
+            }
+        };
+
+        RootReport {
+            residuals,
+            max_relative_residual,
+            coefficient_error,
+            count_matches: roots.len() == len.saturating_sub(1),
+        }
+    }
+}
+
+impl Polynomial<f64> {
+    /// Factors a real polynomial into real linear and irreducible quadratic factors, e.g. to
+    /// implement it as a cascade of first and second order sections.
+    ///
+    /// All the roots are found with [`solve_complex`](Polynomial::solve_complex). Roots with a
+    /// negligible imaginary part (relative to their magnitude) give the linear factors x − r,
+    /// while each complex root z with a positive imaginary part is paired with its conjugate into
+    /// the quadratic factor x² − 2Re(z)x + |z|². The first factor is the constant leading
+    /// coefficient, so that the product of all the factors is the polynomial. The linear factors
+    /// come before the quadratic ones, each sorted by their roots' real parts.
+    ///
+    /// A [`RootReport`] of the roots the factors were built from is returned along with them, to
+    /// check the accuracy of the reconstruction.
+    ///
+    /// # Error
+    ///
+    /// Returns an error if the (trimmed) Polynomial is constant, or if the solver fails to
+    /// converge.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use rsl_polynomials::{Polynomial, Result};
+    /// # fn main() -> Result<()> {
+    /// let poly = Polynomial::build(&[-4.0, 4.0, -2.0, 2.0])?; // 2(x − 1)(x² + 2)
+    /// let (factors, report) = poly.factor_real()?;
+    ///
+    /// assert_eq!(factors.len(), 3);
+    /// assert_eq!(factors[0].coef, [2.0]);
+    /// assert!(factors[1].approx_eq(&Polynomial::build(&[-1.0, 1.0])?, 1e-14, 1e-14));
+    /// assert!(factors[2].approx_eq(&Polynomial::build(&[2.0, 0.0, 1.0])?, 1e-14, 1e-14));
+    /// assert!(report.passes(1e-14));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn factor_real(&self) -> Result<(Vec<Polynomial<f64>>, RootReport)> {
+        let roots = self.solve_complex()?;
+        let lead = self
+            .coef
+            .iter()
+            .rev()
+            .copied()
+            .find(|c| *c != 0.0)
+            .unwrap_or(0.0);
+
+        let is_real = |z: &Complex64| z.im.abs() <= REAL_ROOT_TOL * z.norm();
+        let mut reals: Vec<f64> = roots.iter().filter(|z| is_real(z)).map(|z| z.re).collect();
+        let mut pairs: Vec<Complex64> = roots
+            .iter()
+            .filter(|z| !is_real(z) && z.im > 0.0)
+            .copied()
+            .collect();
+        reals.sort_by(f64::total_cmp);
+        pairs.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+
+        let mut factors = vec![Polynomial { coef: vec![lead] }];
+        factors.extend(reals.iter().map(|r| Polynomial {
+            coef: vec![-r, 1.0],
+        }));
+        factors.extend(pairs.iter().map(|z| Polynomial {
+            coef: vec![z.norm_sqr(), -2.0 * z.re, 1.0],
+        }));
+
+        // The roots the factors represent, with exactly real and conjugate values
+        let mut used: Vec<Complex64> = reals.iter().map(|r| Complex64::new(*r, 0.0)).collect();
+        used.extend(pairs.iter().flat_map(|z| [*z, z.conj()]));
+        let complex = Polynomial {
+            coef: self.coef.iter().map(|c| Complex64::new(*c, 0.0)).collect(),
+        };
+
+        Ok((factors, complex.verify_roots(&used)))
+    }
+}
+
+/// Expands the product of the linear factors of `roots`, splitting them in halves recursively.
+fn product_tree<T>(roots: &[T]) -> Polynomial<T>
+where
+    T: num::complex::ComplexFloat + std::fmt::Debug,
+{
+    match roots {
+        [] => Polynomial {
+            coef: vec![T::one()],
+        },
+        [r] => Polynomial {
+            coef: vec![-*r, T::one()],
+        },
+        _ => {
+            let (left, right) = roots.split_at(roots.len() / 2);
+            &product_tree(left) * &product_tree(right)
+        }
+    }
+}
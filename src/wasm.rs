@@ -0,0 +1,79 @@
+//! JavaScript bindings of the evaluation and the solvers, with the `wasm-bindgen` feature.
+//!
+//! Polynomials are passed as `Float64Array`s of coefficients, from constant to leading term, and
+//! errors are thrown as JavaScript `Error`s. Built e.g. with
+//! `wasm-pack build --target web -- --features wasm-bindgen`:
+//!
+//! ```js
+//! import init, { solveCubic, evalPolynomialMany } from "./pkg/rsl_polynomials.js";
+//!
+//! await init();
+//! solveCubic(1, -6, 11, -6);                                 // Float64Array [1, 2, 3]
+//! evalPolynomialMany(new Float64Array([0, 0, 1]), xs);        // x² at every point of xs
+//! ```
+
+use wasm_bindgen::prelude::*;
+
+use crate::{PolyError, Polynomial, solve};
+
+/// Converts an error to a JavaScript `Error`.
+fn to_js_error(err: PolyError) -> JsError {
+    JsError::new(&err.to_string())
+}
+
+/// Evaluates the polynomial with coefficients `coef` at `x`.
+#[wasm_bindgen(js_name = evalPolynomial)]
+pub fn eval_polynomial(coef: &[f64], x: f64) -> f64 {
+    Polynomial::from_raw_unchecked(coef.to_vec()).eval(x)
+}
+
+/// Evaluates the polynomial with coefficients `coef` at every point of `xs`, e.g. to plot it.
+#[wasm_bindgen(js_name = evalPolynomialMany)]
+pub fn eval_polynomial_many(coef: &[f64], xs: &[f64]) -> Vec<f64> {
+    let poly = Polynomial::from_raw_unchecked(coef.to_vec());
+    xs.iter().map(|x| poly.eval(*x)).collect()
+}
+
+/// Returns the coefficients of the derivative of the polynomial with coefficients `coef`.
+#[wasm_bindgen]
+pub fn derivative(coef: &[f64]) -> Vec<f64> {
+    Polynomial::from_raw_unchecked(coef.to_vec())
+        .derivative()
+        .coef
+}
+
+/// Returns the real roots of ax²+bx+c = 0, in increasing order, with a double root listed twice.
+///
+/// # Error
+///
+/// Throws if the coefficients are NaN or Infinity, or if a=b=0.
+#[wasm_bindgen(js_name = solveQuadratic)]
+pub fn solve_quadratic(a: f64, b: f64, c: f64) -> Result<Vec<f64>, JsError> {
+    Ok(solve::quadratic(a, b, c).map_err(to_js_error)?.to_vec())
+}
+
+/// Returns the real roots of ax³+bx²+cx+d = 0, in increasing order, with repeated roots listed as
+/// many times as their multiplicity.
+///
+/// # Error
+///
+/// Throws if the coefficients are NaN or Infinity, or if a=0.
+#[wasm_bindgen(js_name = solveCubic)]
+pub fn solve_cubic(a: f64, b: f64, c: f64, d: f64) -> Result<Vec<f64>, JsError> {
+    Ok(solve::cubic(a, b, c, d).map_err(to_js_error)?.to_vec())
+}
+
+/// Returns all the complex roots of the polynomial with coefficients `coef`, packed as
+/// `[re₀, im₀, re₁, im₁, ...]`.
+///
+/// # Error
+///
+/// Throws if the coefficients are NaN or Infinity, if the polynomial is constant, or if the
+/// solver fails to converge.
+#[wasm_bindgen(js_name = solveComplex)]
+pub fn solve_complex(coef: &[f64]) -> Result<Vec<f64>, JsError> {
+    let roots = Polynomial::build(coef)
+        .and_then(|poly| poly.solve_complex())
+        .map_err(to_js_error)?;
+    Ok(roots.iter().flat_map(|z| [z.re, z.im]).collect())
+}